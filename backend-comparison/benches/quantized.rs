@@ -0,0 +1,189 @@
+use backend_comparison::persistence::save;
+use burn::tensor::{
+    backend::Backend,
+    module::{conv2d, linear},
+    ops::ConvOptions,
+    quantization::{QuantizationScheme, QuantizationType},
+    Distribution, Shape, Tensor,
+};
+use burn_common::{
+    benchmark::{run_benchmark, Benchmark},
+    sync_type::SyncType,
+};
+
+/// Scheme of the quantized activations.
+const ACTIVATION_SCHEME: QuantizationScheme =
+    QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+
+/// Quantized linear layer, either with the quantized kernel or by dequantizing the inputs to
+/// run the floating point kernel before requantizing the output.
+struct QuantizedLinearBenchmark<B: Backend> {
+    input_shape: Shape,
+    weight_shape: Shape,
+    dequantize: bool,
+    device: B::Device,
+}
+
+impl<B: Backend> Benchmark for QuantizedLinearBenchmark<B> {
+    type Args = (Tensor<B, 3>, Tensor<B, 2>, Tensor<B, 1>);
+
+    fn name(&self) -> String {
+        match self.dequantize {
+            true => "q_linear_dequantized".into(),
+            false => "q_linear".into(),
+        }
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![
+            self.input_shape.dims.clone(),
+            self.weight_shape.dims.clone(),
+        ]
+    }
+
+    fn num_samples(&self) -> usize {
+        10
+    }
+
+    fn execute(&self, (x, weight, bias): Self::Args) {
+        if self.dequantize {
+            linear(x.dequantize(), weight.dequantize(), Some(bias))
+                .quantize_dynamic(&ACTIVATION_SCHEME);
+        } else {
+            linear(x, weight, Some(bias));
+        }
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let [_, d_output] = self.weight_shape.dims();
+        let x = Tensor::random(
+            self.input_shape.clone(),
+            Distribution::Default,
+            &self.device,
+        )
+        .quantize_dynamic(&ACTIVATION_SCHEME);
+        let weight = Tensor::random(
+            self.weight_shape.clone(),
+            Distribution::Default,
+            &self.device,
+        )
+        .quantize_dynamic(&QuantizationScheme::PerChannelSymmetric(
+            QuantizationType::QInt8,
+            1,
+        ));
+        let bias = Tensor::random([d_output], Distribution::Default, &self.device);
+
+        (x, weight, bias)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device, SyncType::Wait)
+    }
+}
+
+/// Quantized 2D convolution, either with the quantized kernel or by dequantizing the inputs to
+/// run the floating point kernel before requantizing the output.
+struct QuantizedConv2dBenchmark<B: Backend> {
+    input_shape: Shape,
+    weight_shape: Shape,
+    options: ConvOptions<2>,
+    dequantize: bool,
+    device: B::Device,
+}
+
+impl<B: Backend> Benchmark for QuantizedConv2dBenchmark<B> {
+    type Args = (Tensor<B, 4>, Tensor<B, 4>, Tensor<B, 1>);
+
+    fn name(&self) -> String {
+        match self.dequantize {
+            true => "q_conv2d_dequantized".into(),
+            false => "q_conv2d".into(),
+        }
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![
+            self.input_shape.dims.clone(),
+            self.weight_shape.dims.clone(),
+        ]
+    }
+
+    fn num_samples(&self) -> usize {
+        10
+    }
+
+    fn execute(&self, (x, weight, bias): Self::Args) {
+        if self.dequantize {
+            conv2d(
+                x.dequantize(),
+                weight.dequantize(),
+                Some(bias),
+                self.options.clone(),
+            )
+            .quantize_dynamic(&ACTIVATION_SCHEME);
+        } else {
+            conv2d(x, weight, Some(bias), self.options.clone());
+        }
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let [channels_out, _, _, _] = self.weight_shape.dims();
+        let x = Tensor::random(
+            self.input_shape.clone(),
+            Distribution::Default,
+            &self.device,
+        )
+        .quantize_dynamic(&ACTIVATION_SCHEME);
+        let weight = Tensor::random(
+            self.weight_shape.clone(),
+            Distribution::Default,
+            &self.device,
+        )
+        .quantize_dynamic(&QuantizationScheme::PerChannelSymmetric(
+            QuantizationType::QInt8,
+            0,
+        ));
+        let bias = Tensor::random([channels_out], Distribution::Default, &self.device);
+
+        (x, weight, bias)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device, SyncType::Wait)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(
+    device: &B::Device,
+    feature_name: &str,
+    url: Option<&str>,
+    token: Option<&str>,
+) {
+    let mut results = Vec::new();
+
+    for dequantize in [false, true] {
+        let benchmark = QuantizedLinearBenchmark::<B> {
+            input_shape: [8, 256, 1024].into(),
+            weight_shape: [1024, 1024].into(),
+            dequantize,
+            device: device.clone(),
+        };
+        results.push(run_benchmark(benchmark));
+
+        let benchmark = QuantizedConv2dBenchmark::<B> {
+            input_shape: [8, 16, 128, 128].into(),
+            weight_shape: [32, 16, 3, 3].into(),
+            options: ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+            dequantize,
+            device: device.clone(),
+        };
+        results.push(run_benchmark(benchmark));
+    }
+
+    save::<B>(results, device, feature_name, url, token).unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
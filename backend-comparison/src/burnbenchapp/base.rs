@@ -94,6 +94,8 @@ enum BenchmarkValues {
     Data,
     #[strum(to_string = "matmul")]
     Matmul,
+    #[strum(to_string = "quantized")]
+    Quantized,
     #[strum(to_string = "unary")]
     Unary,
     #[strum(to_string = "max-pool2d")]
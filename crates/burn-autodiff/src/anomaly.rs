@@ -0,0 +1,46 @@
+use core::cell::Cell;
+use core::fmt::Display;
+
+use crate::NodeID;
+
+std::thread_local! {
+    static ANOMALY_DETECTION: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Enable or disable the anomaly detection for the backward passes started on the current thread.
+///
+/// When enabled, the gradients registered by each backward step are checked: the backward pass
+/// panics with the operation and the node of the first step producing infinite or NaN gradients.
+/// Since the gradients are read after each step, this is much slower and only meant to debug a
+/// training.
+pub fn set_anomaly_detection(enabled: bool) {
+    ANOMALY_DETECTION.with(|detection| detection.set(enabled));
+}
+
+/// Whether the anomaly detection is enabled on the current thread, see
+/// [set_anomaly_detection](set_anomaly_detection).
+pub fn is_anomaly_detection_enabled() -> bool {
+    ANOMALY_DETECTION.with(|detection| detection.get())
+}
+
+/// A backward step producing infinite or NaN gradients.
+#[derive(new, Debug)]
+pub(crate) struct Anomaly {
+    /// The name of the operation of the step.
+    name: &'static str,
+    /// The node of the step.
+    node: NodeID,
+    /// The parent of the node with non-finite gradients.
+    parent: NodeID,
+}
+
+impl Display for Anomaly {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Anomaly detected: the backward step of `{}` (node {}) produced infinite or NaN \
+             gradients for its parent node {}",
+            self.name, self.node.value, self.parent.value
+        )
+    }
+}
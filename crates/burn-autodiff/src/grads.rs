@@ -1,6 +1,8 @@
+use burn_common::reader::try_read_sync;
 use burn_tensor::{backend::Backend, container::TensorContainer};
 
 use crate::{
+    anomaly::is_anomaly_detection_enabled,
     graph::{NodeRef, Requirement},
     tensor::AutodiffTensor,
     NodeID,
@@ -12,6 +14,11 @@ pub type GradID = u64;
 /// Gradients container used during the backward pass.
 pub struct Gradients {
     container: TensorContainer<GradID>,
+    /// Whether the gradients are checked after each backward step, see
+    /// [set_anomaly_detection](crate::set_anomaly_detection).
+    detect_anomaly: bool,
+    /// Checks if the gradients of a node are finite, with the backend of the container.
+    is_finite: fn(&TensorContainer<GradID>, &GradID) -> bool,
 }
 
 type TensorPrimitive<B> = <B as Backend>::FloatTensorPrimitive;
@@ -21,6 +28,8 @@ impl Gradients {
     pub fn new<B: Backend>(root_node: NodeRef, root_tensor: TensorPrimitive<B>) -> Self {
         let mut gradients = Self {
             container: TensorContainer::new(),
+            detect_anomaly: is_anomaly_detection_enabled(),
+            is_finite: is_finite::<B>,
         };
         gradients.register::<B>(
             root_node.id,
@@ -63,6 +72,16 @@ impl Gradients {
             .map(|tensor| tensor.tensor())
     }
 
+    /// Whether the gradients should be checked after each backward step.
+    pub(crate) fn detect_anomaly(&self) -> bool {
+        self.detect_anomaly
+    }
+
+    /// Returns false if the gradients of the given node contain infinite or NaN values.
+    pub(crate) fn is_finite(&self, node_id: &NodeID) -> bool {
+        (self.is_finite)(&self.container, &node_id.value)
+    }
+
     /// Register a grad tensor in the container.
     ///
     /// If the tensor already exists, add both tensors together before saving the result.
@@ -78,3 +97,18 @@ impl Gradients {
         }
     }
 }
+
+fn is_finite<B: Backend>(container: &TensorContainer<GradID>, id: &GradID) -> bool {
+    let grad = match container.get::<B>(id) {
+        Some(grad) => grad.tensor(),
+        None => return true,
+    };
+
+    // Infinite and NaN values are the only ones not equal to zero once subtracted from
+    // themselves, which makes the sum NaN.
+    let sum = B::float_sum(B::float_sub(grad.clone(), grad));
+    let data = try_read_sync(B::float_into_data(sum))
+        .expect("Failed to read the gradients synchronously to detect anomalies.");
+
+    data.iter::<f64>().all(|value| value == 0.0)
+}
@@ -12,6 +12,15 @@ pub trait Step: Send + std::fmt::Debug {
     fn node(&self) -> NodeID;
     /// The parents of the node associated to the step.
     fn parents(&self) -> Vec<NodeID>;
+    /// The name of the operation of the step, used to report anomalies.
+    fn name(&self) -> &'static str;
+}
+
+/// The name of a backward operation, without the path of the trait implementation it is defined
+/// in, e.g. `float_sqrt::Sqrt`.
+pub fn op_name<T>() -> &'static str {
+    let name = core::any::type_name::<T>();
+    name.rsplit(">::").next().unwrap_or(name)
 }
 
 pub type StepBoxed = Box<dyn Step>;
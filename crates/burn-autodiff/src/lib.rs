@@ -25,11 +25,13 @@ pub use graph::NodeID;
 pub(crate) mod tensor;
 pub(crate) mod utils;
 
+mod anomaly;
 mod backend;
 mod bridge;
 
 pub(crate) mod runtime;
 
+pub use anomaly::{is_anomaly_detection_enabled, set_anomaly_detection};
 pub use backend::*;
 pub use bridge::*;
 
@@ -7,7 +7,7 @@ use crate::{
         strategy::CheckpointStrategy,
     },
     grads::Gradients,
-    graph::{ComputingProperty, NodeID, NodeRef, Requirement, Step},
+    graph::{op_name, ComputingProperty, NodeID, NodeRef, Requirement, Step},
     tensor::AutodiffTensor,
 };
 use burn_tensor::{backend::Backend, Shape};
@@ -267,6 +267,10 @@ where
     fn depth(&self) -> usize {
         self.ops.node.order
     }
+
+    fn name(&self) -> &'static str {
+        op_name::<T>()
+    }
 }
 
 #[derive(new, Debug)]
@@ -289,6 +293,10 @@ impl<const N: usize> Step for UntrackedOpsStep<N> {
     fn depth(&self) -> usize {
         self.ops.node.order
     }
+
+    fn name(&self) -> &'static str {
+        "untracked"
+    }
 }
 
 /// Make sure the grad tensor has the given shape.
@@ -2,35 +2,41 @@ use std::ops::Range;
 
 use burn_tensor::{
     backend::Backend,
-    ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
+    ops::{ConvOptions, FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{QuantizationParametersPrimitive, QuantizationScheme},
     Device, Shape, TensorData,
 };
 
-use crate::{checkpoint::strategy::CheckpointStrategy, Autodiff};
+use crate::{checkpoint::strategy::CheckpointStrategy, tensor::AutodiffTensor, Autodiff};
 
 impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
-    fn q_from_data(_data: TensorData, _device: &Device<Self>) -> QuantizedTensor<Self> {
-        todo!()
+    fn q_from_data(data: TensorData, device: &Device<Self>) -> QuantizedTensor<Self> {
+        B::q_from_data(data, device)
     }
 
+    // Quantized tensors are not tracked, gradients through quantization are computed with
+    // `Tensor::fake_quantize` instead.
     fn quantize(
-        _tensor: FloatTensor<Self>,
-        _scheme: &QuantizationScheme,
-        _qparams: QuantizationParametersPrimitive<Self>,
+        tensor: FloatTensor<Self>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
     ) -> QuantizedTensor<Self> {
-        todo!() // required for QAT
+        let qparams = QuantizationParametersPrimitive {
+            scale: qparams.scale.primitive,
+            offset: qparams.offset,
+        };
+        B::quantize(tensor.primitive, scheme, qparams)
     }
 
     fn quantize_dynamic(
-        _tensor: FloatTensor<Self>,
-        _scheme: &QuantizationScheme,
+        tensor: FloatTensor<Self>,
+        scheme: &QuantizationScheme,
     ) -> QuantizedTensor<Self> {
-        todo!()
+        B::quantize_dynamic(tensor.primitive, scheme)
     }
 
-    fn dequantize(_tensor: QuantizedTensor<Self>) -> FloatTensor<Self> {
-        todo!()
+    fn dequantize(tensor: QuantizedTensor<Self>) -> FloatTensor<Self> {
+        AutodiffTensor::new(B::dequantize(tensor))
     }
 
     fn q_shape(tensor: &QuantizedTensor<Self>) -> Shape {
@@ -41,11 +47,8 @@ impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
         B::q_device(tensor)
     }
 
-    fn q_to_device(
-        _tensor: QuantizedTensor<Self>,
-        _device: &Device<Self>,
-    ) -> QuantizedTensor<Self> {
-        unimplemented!()
+    fn q_to_device(tensor: QuantizedTensor<Self>, device: &Device<Self>) -> QuantizedTensor<Self> {
+        B::q_to_device(tensor, device)
     }
 
     fn q_reshape(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
@@ -57,39 +60,39 @@ impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
     }
 
     fn q_swap_dims(
-        _tensor: QuantizedTensor<Self>,
-        _dim1: usize,
-        _dim2: usize,
+        tensor: QuantizedTensor<Self>,
+        dim1: usize,
+        dim2: usize,
     ) -> QuantizedTensor<Self> {
-        unimplemented!()
+        B::q_swap_dims(tensor, dim1, dim2)
     }
 
-    fn q_permute(_tensor: QuantizedTensor<Self>, _axes: &[usize]) -> QuantizedTensor<Self> {
-        unimplemented!()
+    fn q_permute(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
+        B::q_permute(tensor, axes)
     }
 
-    fn q_flip(_tensor: QuantizedTensor<Self>, _axes: &[usize]) -> QuantizedTensor<Self> {
-        unimplemented!()
+    fn q_flip(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
+        B::q_flip(tensor, axes)
     }
 
     fn q_gather(
-        _dim: usize,
-        _tensor: QuantizedTensor<Self>,
-        _indices: IntTensor<Self>,
+        dim: usize,
+        tensor: QuantizedTensor<Self>,
+        indices: IntTensor<Self>,
     ) -> QuantizedTensor<Self> {
-        unimplemented!()
+        B::q_gather(dim, tensor, indices)
     }
 
     fn q_select(
-        _tensor: QuantizedTensor<Self>,
-        _dim: usize,
-        _indices: IntTensor<Self>,
+        tensor: QuantizedTensor<Self>,
+        dim: usize,
+        indices: IntTensor<Self>,
     ) -> QuantizedTensor<Self> {
-        unimplemented!()
+        B::q_select(tensor, dim, indices)
     }
 
-    fn q_slice(_tensor: QuantizedTensor<Self>, _ranges: &[Range<usize>]) -> QuantizedTensor<Self> {
-        unimplemented!()
+    fn q_slice(tensor: QuantizedTensor<Self>, ranges: &[Range<usize>]) -> QuantizedTensor<Self> {
+        B::q_slice(tensor, ranges)
     }
 
     fn q_argmax(tensor: QuantizedTensor<Self>, dim: usize) -> IntTensor<Self> {
@@ -100,7 +103,28 @@ impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
         B::q_argmin(tensor, dim)
     }
 
-    fn q_expand(_tensor: QuantizedTensor<Self>, _shape: Shape) -> QuantizedTensor<Self> {
-        unimplemented!()
+    fn q_expand(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
+        B::q_expand(tensor, shape)
+    }
+
+    fn q_matmul(lhs: QuantizedTensor<Self>, rhs: QuantizedTensor<Self>) -> QuantizedTensor<Self> {
+        B::q_matmul(lhs, rhs)
+    }
+
+    fn q_linear(
+        x: QuantizedTensor<Self>,
+        weight: QuantizedTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+    ) -> QuantizedTensor<Self> {
+        B::q_linear(x, weight, bias.map(|bias| bias.primitive))
+    }
+
+    fn q_conv2d(
+        x: QuantizedTensor<Self>,
+        weight: QuantizedTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvOptions<2>,
+    ) -> QuantizedTensor<Self> {
+        B::q_conv2d(x, weight, bias.map(|bias| bias.primitive), options)
     }
 }
@@ -1972,6 +1972,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
             fn depth(&self) -> usize {
                 self.output.order
             }
+
+            fn name(&self) -> &'static str {
+                "float_cat"
+            }
         }
 
         let mut nodes = Vec::with_capacity(tensors.len());
@@ -1,5 +1,6 @@
 use super::memory_management::GraphMemoryManagement;
 use crate::{
+    anomaly::Anomaly,
     checkpoint::{base::Checkpointer, builder::CheckpointerBuilder},
     grads::Gradients,
     graph::{traversal::BreadthFirstSearch, StepBoxed},
@@ -36,7 +37,7 @@ impl AutodiffServer {
         let (tape, builder) = self.build_tape(node_id, step, builder);
         let checkpointer = builder.build(&self.steps);
 
-        let gradients = Self::execute_steps(tape, grads, checkpointer);
+        let (gradients, anomaly) = Self::execute_steps(tape, grads, checkpointer);
 
         // Cleanup
         self.memory_management
@@ -45,6 +46,10 @@ impl AutodiffServer {
                 self.actions_builder.remove(node_id);
             });
 
+        if let Some(anomaly) = anomaly {
+            panic!("{anomaly}");
+        }
+
         gradients
     }
 
@@ -82,16 +87,31 @@ impl AutodiffServer {
         tape: Vec<Vec<StepBoxed>>,
         mut grads: Gradients,
         mut checkpointer: Checkpointer,
-    ) -> Gradients {
+    ) -> (Gradients, Option<Anomaly>) {
+        let mut anomaly = None;
+
         tape.into_iter().rev().for_each(|steps| {
-            steps
-                .into_iter()
-                .for_each(|step| step.step(&mut grads, &mut checkpointer))
+            steps.into_iter().for_each(|step| {
+                // Only the first anomaly is reported, the following steps are likely to propagate
+                // the same non-finite values.
+                if !grads.detect_anomaly() || anomaly.is_some() {
+                    step.step(&mut grads, &mut checkpointer);
+                    return;
+                }
+
+                let (name, node, parents) = (step.name(), step.node(), step.parents());
+                step.step(&mut grads, &mut checkpointer);
+
+                anomaly = parents
+                    .into_iter()
+                    .find(|parent| !grads.is_finite(parent))
+                    .map(|parent| Anomaly::new(name, node, parent));
+            })
         });
 
         #[cfg(feature = "export_tests")]
         // For checkpointing tests
         assert!(checkpointer.is_empty());
-        grads
+        (grads, anomaly)
     }
 }
@@ -38,6 +38,10 @@ impl Step for RootStep {
     fn depth(&self) -> usize {
         self.node.order
     }
+
+    fn name(&self) -> &'static str {
+        "root"
+    }
 }
 
 impl<B: Backend> AutodiffTensor<B> {
@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(anomaly)]
+mod tests {
+    use super::*;
+    use burn_autodiff::set_anomaly_detection;
+    use burn_tensor::TensorData;
+
+    #[test]
+    #[should_panic(expected = "float_sqrt::Sqrt")]
+    fn should_report_the_op_producing_non_finite_gradients() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_data([0.0, 1.0], &device).require_grad();
+
+        set_anomaly_detection(true);
+        let _grads = tensor.sqrt().sum().backward();
+    }
+
+    #[test]
+    fn should_not_check_the_gradients_when_disabled() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_data([0.0, 1.0], &device).require_grad();
+
+        set_anomaly_detection(false);
+        let grads = tensor.clone().sqrt().sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap().into_data();
+        assert!(grad.iter::<f32>().next().unwrap().is_infinite());
+    }
+
+    #[test]
+    fn should_not_report_finite_gradients() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_data([1.0, 4.0], &device).require_grad();
+
+        set_anomaly_detection(true);
+        let grads = tensor.clone().sqrt().sum().backward();
+        set_anomaly_detection(false);
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.5, 0.25]), 3);
+    }
+}
@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(ad_fake_quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        quantization::{fake_quantize, QuantizationScheme, QuantizationType},
+        TensorData,
+    };
+
+    #[test]
+    fn should_diff_fake_quantize_straight_through() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data(
+            TensorData::from([-2.0, -0.5, 0.123, 1.0, 3.0]),
+            &device,
+        )
+        .require_grad();
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8);
+        let scale = TestAutodiffTensor::<1>::from_data(TensorData::from([0.01]), &device);
+
+        let y = fake_quantize(x.clone(), &scheme, scale, None);
+        let grads = y.clone().sum().backward();
+        let grad = x.grad(&grads).unwrap();
+
+        y.to_data()
+            .assert_approx_eq(&TensorData::from([-1.27, -0.5, 0.12, 1.0, 1.27]), 4);
+        // The gradients of the clipped values are zeroed
+        grad.to_data()
+            .assert_eq(&TensorData::from([0.0, 1.0, 1.0, 1.0, 0.0]), false);
+    }
+
+    #[test]
+    fn should_diff_fake_quantize_learned_scale() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data(TensorData::from([0.3, 0.9, 10.0]), &device)
+            .require_grad();
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+        let scale =
+            TestAutodiffTensor::<1>::from_data(TensorData::from([0.5]), &device).require_grad();
+
+        let y = fake_quantize(x.clone(), &scheme, scale.clone(), None);
+        let grads = y.clone().sum().backward();
+        let grad_x = x.grad(&grads).unwrap();
+        let grad_scale = scale.grad(&grads).unwrap();
+
+        y.to_data()
+            .assert_approx_eq(&TensorData::from([0.5, 1.0, 3.5]), 4);
+        grad_x
+            .to_data()
+            .assert_eq(&TensorData::from([1.0, 1.0, 0.0]), false);
+        // LSQ gradient: `round(x / s) - x / s` within the range, and the range bound otherwise
+        grad_scale
+            .to_data()
+            .assert_approx_eq(&TensorData::from([7.6]), 4);
+    }
+
+    #[test]
+    fn should_diff_fake_quantize_learned_offset() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data(TensorData::from([0.26, -1.0, 2.0]), &device)
+            .require_grad();
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt4);
+        let scale = TestAutodiffTensor::<1>::from_data(TensorData::from([0.1]), &device);
+        let offset =
+            TestAutodiffTensor::<1>::from_data(TensorData::from([2.0]), &device).require_grad();
+
+        let y = fake_quantize(x.clone(), &scheme, scale, Some(offset.clone()));
+        let grads = y.clone().sum().backward();
+        let grad_offset = offset.grad(&grads).unwrap();
+
+        // Quantized values [5, -8, 7] are clamped to the int4 range [-8, 7]
+        y.to_data()
+            .assert_approx_eq(&TensorData::from([0.3, -1.0, 0.5]), 4);
+        // Only the clipped value depends on the offset
+        grad_offset
+            .to_data()
+            .assert_approx_eq(&TensorData::from([-0.1]), 4);
+    }
+}
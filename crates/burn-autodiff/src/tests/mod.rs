@@ -5,6 +5,7 @@ mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod add;
 mod aggregation;
+mod anomaly;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
@@ -26,6 +27,7 @@ mod div;
 mod erf;
 mod exp;
 mod expand;
+mod fake_quantize;
 mod flip;
 mod gather_scatter;
 mod gelu;
@@ -74,6 +76,7 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_bridge!();
         burn_autodiff::testgen_checkpoint!();
         burn_autodiff::testgen_memory_management!();
+        burn_autodiff::testgen_anomaly!();
 
         // Activation
         burn_autodiff::testgen_ad_relu!();
@@ -134,6 +137,7 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_flip!();
         burn_autodiff::testgen_ad_nonzero!();
         burn_autodiff::testgen_ad_sign!();
+        burn_autodiff::testgen_ad_fake_quantize!();
         burn_autodiff::testgen_ad_expand!();
         burn_autodiff::testgen_ad_sort!();
         burn_autodiff::testgen_ad_repeat_dim!();
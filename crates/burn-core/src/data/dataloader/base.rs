@@ -15,6 +15,13 @@ pub struct Progress {
 pub trait DataLoaderIterator<O>: Iterator<Item = O> {
     /// Returns the progress of the data loader.
     fn progress(&self) -> Progress;
+    /// Returns the progress of each worker of the data loader, used to resume each of them with
+    /// [iter_from_workers](DataLoader::iter_from_workers).
+    ///
+    /// Data loaders without workers have a single progress.
+    fn progress_workers(&self) -> Vec<Progress> {
+        vec![self.progress()]
+    }
 }
 
 /// A data loader that can be used to iterate over a dataset.
@@ -24,6 +31,46 @@ pub trait DataLoader<O>: Send {
     /// The number of items (not the number of batches nor the number of iterations),
     /// corresponding to the items_total of the progress returned by the iterator.
     fn num_items(&self) -> usize;
+    /// Returns a boxed [iterator](DataLoaderIterator) resuming an interrupted iteration, skipping
+    /// the items already processed according to the given progress.
+    ///
+    /// The items are only the same as the interrupted iteration if the data loader is in the same
+    /// random state as when it was created, see [restore_iterations](DataLoader::restore_iterations).
+    fn iter_from<'a>(&'a self, progress: &Progress) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let mut iterator = self.iter();
+
+        while iterator.progress().items_processed < progress.items_processed {
+            if iterator.next().is_none() {
+                break;
+            }
+        }
+
+        iterator
+    }
+    /// Returns a boxed [iterator](DataLoaderIterator) resuming an interrupted iteration from the
+    /// progress of each of its workers, see [progress_workers](DataLoaderIterator::progress_workers).
+    ///
+    /// Data loaders without workers resume from the sum of the given progresses.
+    fn iter_from_workers<'a>(
+        &'a self,
+        progresses: &[Progress],
+    ) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let progress = progresses
+            .iter()
+            .fold(Progress::new(0, 0), |acc, progress| {
+                Progress::new(
+                    acc.items_processed + progress.items_processed,
+                    acc.items_total + progress.items_total,
+                )
+            });
+
+        self.iter_from(&progress)
+    }
+    /// Restores the random state of the data loader to the one it had after creating the given
+    /// number of iterations, so the next iterations are shuffled the same way.
+    ///
+    /// Data loaders without random state don't need to implement this method.
+    fn restore_iterations(&self, _num_iterations: usize) {}
 }
 
 /// A super trait for [dataloader](DataLoader) that allows it to be cloned dynamically.
@@ -15,6 +15,8 @@ pub struct BatchDataLoader<I, O> {
     dataset: Arc<dyn Dataset<I>>,
     batcher: Box<dyn DynBatcher<I, O>>,
     rng: Option<Arc<spin::Mutex<rand::rngs::StdRng>>>,
+    /// The rng as it was when the data loader was created, used to restore its state.
+    rng_initial: Option<rand::rngs::StdRng>,
 }
 
 impl<I, O> Clone for BatchDataLoader<I, O> {
@@ -24,6 +26,7 @@ impl<I, O> Clone for BatchDataLoader<I, O> {
             dataset: self.dataset.clone(),
             batcher: self.batcher.clone_dyn(),
             rng: self.rng.clone(),
+            rng_initial: self.rng_initial.clone(),
         }
     }
 }
@@ -52,6 +55,7 @@ impl<I, O> BatchDataLoader<I, O> {
             strategy,
             dataset,
             batcher,
+            rng_initial: rng.clone(),
             rng: rng.map(|rng| Arc::new(spin::Mutex::new(rng))),
         }
     }
@@ -116,6 +120,37 @@ where
     O: Send + 'static,
 {
     fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        Box::new(self.iterator(0))
+    }
+
+    fn iter_from<'a>(&'a self, progress: &Progress) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        Box::new(self.iterator(progress.items_processed))
+    }
+
+    fn num_items(&self) -> usize {
+        self.dataset.len()
+    }
+
+    fn restore_iterations(&self, num_iterations: usize) {
+        if let (Some(rng), Some(rng_initial)) = (&self.rng, &self.rng_initial) {
+            let mut rng = rng.lock();
+            *rng = rng_initial.clone();
+
+            // Each iteration samples the seed used to shuffle the dataset.
+            for _ in 0..num_iterations {
+                rng.sample::<u64, _>(Standard);
+            }
+        }
+    }
+}
+
+impl<I, O> BatchDataLoader<I, O>
+where
+    I: Send + Sync + Clone + 'static,
+    O: Send + 'static,
+{
+    /// Creates an iterator starting at the given item index.
+    fn iterator(&self, current_index: usize) -> BatchDataloaderIterator<I, O> {
         // When starting a new iteration, we first check if the dataloader was created with an rng,
         // implying that we should shuffle the dataset beforehand, while advancing the current
         // rng to ensure that each new iteration shuffles the dataset differently.
@@ -130,15 +165,13 @@ where
             }
             None => self.dataset.clone(),
         };
-        Box::new(BatchDataloaderIterator::new(
+        let mut iterator = BatchDataloaderIterator::new(
             self.strategy.clone_dyn(),
             dataset,
             self.batcher.clone_dyn(),
-        ))
-    }
-
-    fn num_items(&self) -> usize {
-        self.dataset.len()
+        );
+        iterator.current_index = current_index;
+        iterator
     }
 }
 
@@ -266,4 +299,111 @@ mod tests {
 
         assert_eq!(items_single_thread, items_multi_thread);
     }
+
+    #[test]
+    fn test_restore_iterations_should_replay_shuffling() {
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = |seed| {
+            BatchDataLoader::new(
+                Box::new(FixBatchStrategy::new(5)),
+                dataset.clone(),
+                Box::new(TestBatcher::new()),
+                Some(StdRng::seed_from_u64(seed)),
+            )
+        };
+
+        let dataloader_original = dataloader(42);
+        let items_original = (0..3)
+            .map(|_| dataloader_original.iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let dataloader_restored = dataloader(42);
+        dataloader_restored.restore_iterations(2);
+        let items_restored = dataloader_restored.iter().collect::<Vec<_>>();
+
+        assert_eq!(items_restored, items_original[2]);
+        assert_ne!(items_restored, items_original[1]);
+    }
+
+    #[test]
+    fn test_iter_from_should_skip_processed_items() {
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = || {
+            BatchDataLoader::new(
+                Box::new(FixBatchStrategy::new(5)),
+                dataset.clone(),
+                Box::new(TestBatcher::new()),
+                Some(StdRng::seed_from_u64(42)),
+            )
+        };
+
+        let items_original = dataloader().iter().collect::<Vec<_>>();
+        let dataloader_resumed = dataloader();
+        let mut iterator = dataloader_resumed.iter_from(&Progress::new(10, 27));
+        assert_eq!(iterator.progress().items_processed, 10);
+        let items_resumed = iterator.by_ref().collect::<Vec<_>>();
+
+        assert_eq!(items_resumed, items_original[2..]);
+        assert_eq!(iterator.progress().items_processed, 27);
+    }
+
+    #[test]
+    fn test_multi_thread_iter_from_should_skip_processed_items_of_each_worker() {
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = BatchDataLoader::multi_thread(
+            Box::new(FixBatchStrategy::new(5)),
+            dataset.clone(),
+            Box::new(TestBatcher::new()),
+            3,
+            None,
+        );
+
+        let mut iterator = dataloader.iter_from(&Progress::new(12, 27));
+        assert_eq!(iterator.progress().items_processed, 12);
+        let items_resumed = iterator.by_ref().flatten().collect::<HashSet<_>>();
+
+        // Each worker iterates over 9 items and skips its first 4 items.
+        let items_expected = (0..3)
+            .flat_map(|worker| (4..9).map(move |index| worker * 9 + index))
+            .map(|index| dataset.get(index).unwrap())
+            .collect::<HashSet<_>>();
+        assert_eq!(items_resumed, items_expected);
+        assert_eq!(iterator.progress().items_processed, 27);
+    }
+
+    #[test]
+    fn test_multi_thread_iter_from_workers_should_resume_each_worker() {
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = || {
+            BatchDataLoader::multi_thread(
+                Box::new(FixBatchStrategy::new(5)),
+                dataset.clone(),
+                Box::new(TestBatcher::new()),
+                3,
+                None,
+            )
+        };
+
+        // The batches of the workers are received in any order.
+        let dataloader_interrupted = dataloader();
+        let mut iterator = dataloader_interrupted.iter();
+        let items_processed = iterator.by_ref().take(3).flatten().collect::<Vec<_>>();
+        let progresses = iterator.progress_workers();
+        drop(iterator);
+
+        let dataloader_resumed = dataloader();
+        let items_resumed = dataloader_resumed
+            .iter_from_workers(&progresses)
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let mut items = items_processed
+            .into_iter()
+            .chain(items_resumed)
+            .collect::<Vec<_>>();
+        items.sort();
+        let mut items_expected = dataset.iter().collect::<Vec<_>>();
+        items_expected.sort();
+        assert_eq!(items, items_expected);
+    }
 }
@@ -41,26 +41,25 @@ impl<O> MultiThreadDataLoader<O> {
     }
 }
 
-impl<O> DataLoader<O> for MultiThreadDataLoader<O>
+impl<O> MultiThreadDataLoader<O>
 where
     O: Send + 'static + std::fmt::Debug,
 {
-    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+    /// Creates an iterator where each worker starts at the given progress.
+    fn iterator(&self, progresses: Vec<Progress>) -> MultiThreadsDataloaderIterator<O> {
         let (sender, receiver) = mpsc::sync_channel::<Message<O>>(MAX_QUEUED_ITEMS);
 
-        let mut progresses = Vec::with_capacity(self.dataloaders.len());
-
         let handlers: Vec<_> = self
             .dataloaders
             .iter()
+            .zip(progresses.iter().cloned())
             .enumerate()
-            .map(|(index, dataloader)| {
+            .map(|(index, (dataloader, progress))| {
                 let dataloader_cloned = dataloader.clone_dyn();
                 let sender_cloned = sender.clone();
-                progresses.push(Progress::new(0, dataloader_cloned.num_items()));
 
                 thread::spawn(move || {
-                    let mut iterator = dataloader_cloned.iter();
+                    let mut iterator = dataloader_cloned.iter_from(&progress);
                     while let Some(item) = iterator.next() {
                         let progress = iterator.progress();
 
@@ -77,14 +76,71 @@ where
             })
             .collect();
 
-        Box::new(MultiThreadsDataloaderIterator::new(
-            receiver, handlers, progresses,
-        ))
+        MultiThreadsDataloaderIterator::new(receiver, handlers, progresses)
+    }
+}
+
+impl<O> DataLoader<O> for MultiThreadDataLoader<O>
+where
+    O: Send + 'static + std::fmt::Debug,
+{
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let progresses = self
+            .dataloaders
+            .iter()
+            .map(|dataloader| Progress::new(0, dataloader.num_items()))
+            .collect();
+
+        Box::new(self.iterator(progresses))
+    }
+
+    fn iter_from<'a>(&'a self, progress: &Progress) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        // Without the progress of each worker, the processed items are split between the workers
+        // in proportion to their number of items. The batches of the workers are interleaved in
+        // any order, so the items may not be exactly the remaining ones.
+        let items_total = self.num_items().max(1);
+        let progresses = self
+            .dataloaders
+            .iter()
+            .map(|dataloader| {
+                let num_items = dataloader.num_items();
+                let items_processed = progress.items_processed * num_items / items_total;
+                Progress::new(items_processed.min(num_items), num_items)
+            })
+            .collect();
+
+        Box::new(self.iterator(progresses))
+    }
+
+    fn iter_from_workers<'a>(
+        &'a self,
+        progresses: &[Progress],
+    ) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        // The progresses saved with a different number of workers can only be summed.
+        if progresses.len() != self.dataloaders.len() {
+            let progress = progresses
+                .iter()
+                .fold(Progress::new(0, 0), |acc, progress| {
+                    Progress::new(
+                        acc.items_processed + progress.items_processed,
+                        acc.items_total + progress.items_total,
+                    )
+                });
+            return self.iter_from(&progress);
+        }
+
+        Box::new(self.iterator(progresses.to_vec()))
     }
 
     fn num_items(&self) -> usize {
         self.dataloaders.iter().map(|dl| dl.num_items()).sum()
     }
+
+    fn restore_iterations(&self, num_iterations: usize) {
+        for dataloader in self.dataloaders.iter() {
+            dataloader.restore_iterations(num_iterations);
+        }
+    }
 }
 
 impl<O> MultiThreadsDataloaderIterator<O> {
@@ -113,6 +169,10 @@ impl<O: std::fmt::Debug> DataLoaderIterator<O> for MultiThreadsDataloaderIterato
 
         Progress::new(items_processed, items_total)
     }
+
+    fn progress_workers(&self) -> Vec<Progress> {
+        self.progresses.clone()
+    }
 }
 
 impl<O: std::fmt::Debug> Iterator for MultiThreadsDataloaderIterator<O> {
@@ -1,7 +1,27 @@
+use alloc::string::String;
 use burn_tensor::backend::Backend;
 
 use crate::{record::Record, LearningRate};
 
+/// A metric collected by the training loop, whose value is given to a
+/// [learning rate scheduler](LrScheduler) at the end of each evaluation step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MonitoredMetric {
+    /// The name of the metric.
+    pub name: String,
+    /// Whether the metric is collected during validation, otherwise during training.
+    pub valid: bool,
+    /// How the values of the metric collected during the step are aggregated.
+    pub aggregate: MetricAggregate,
+}
+
+/// How to aggregate the values of a [monitored metric](MonitoredMetric).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MetricAggregate {
+    /// Compute the average.
+    Mean,
+}
+
 /// Learning rate scheduler defines how the learning rate will evolve during training.
 pub trait LrScheduler: Send + Sync {
     /// Scheduler associative type to be used when saving and loading the state.
@@ -16,4 +36,18 @@ pub trait LrScheduler: Send + Sync {
 
     /// Load the state of the scheduler as a [record](Record).
     fn load_record<B: Backend>(self, record: Self::Record<B>) -> Self;
+
+    /// The metric the scheduler reacts to, none by default.
+    fn monitored_metric(&self) -> Option<MonitoredMetric> {
+        None
+    }
+
+    /// Update the scheduler at the end of an evaluation step of the training loop, which is the
+    /// end of each epoch unless intermediate evaluations are scheduled.
+    ///
+    /// The training loop gives the aggregated value of the
+    /// [monitored metric](LrScheduler::monitored_metric) over the step, or `None` when no metric
+    /// is monitored or its value wasn't collected. The schedulers not reacting to metrics ignore
+    /// it, which is the default.
+    fn on_evaluation_end(&mut self, _step: usize, _metric: Option<f64>) {}
 }
@@ -13,6 +13,21 @@ pub mod exponential;
 /// Cosine learning rate scheduler
 pub mod cosine;
 
+/// Step and multi-step learning rate schedulers
+pub mod step;
+
+/// Polynomial learning rate scheduler
+pub mod polynomial;
+
+/// One-cycle learning rate scheduler
+pub mod one_cycle;
+
+/// Linear warmup learning rate scheduler
+pub mod warmup;
+
+/// Sequential learning rate scheduler
+pub mod sequential;
+
 mod base;
 
 pub use base::*;
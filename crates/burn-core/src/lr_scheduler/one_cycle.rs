@@ -0,0 +1,183 @@
+use super::LrScheduler;
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a one-cycle learning rate scheduler, as described in
+/// [Super-Convergence: Very Fast Training of Neural Networks Using Large Learning Rates](https://arxiv.org/abs/1708.07120).
+///
+/// This scheduler starts at a learning rate `max_lr / div_factor`, increases it up to `max_lr` during the first
+/// `pct_start` fraction of the `num_iters` iterations, then decreases it down to
+/// `max_lr / (div_factor * final_div_factor)` at the last iteration. Both phases follow a cosine function, and the
+/// learning rate remains at its minimum after `num_iters` iterations.
+#[derive(Config)]
+pub struct OneCycleLrSchedulerConfig {
+    // The maximum learning rate, reached at the end of the first phase.
+    max_lr: LearningRate,
+    // The total number of iterations of the cycle.
+    num_iters: usize,
+    // The fraction of the cycle spent increasing the learning rate.
+    #[config(default = 0.3)]
+    pct_start: f64,
+    // The initial learning rate is given by `max_lr / div_factor`.
+    #[config(default = 25.0)]
+    div_factor: f64,
+    // The final learning rate is given by `max_lr / (div_factor * final_div_factor)`.
+    #[config(default = 1e4)]
+    final_div_factor: f64,
+}
+
+impl OneCycleLrSchedulerConfig {
+    /// Initializes a [one-cycle learning rate scheduler](OneCycleLrScheduler).
+    ///
+    /// # Panics
+    /// This function panics if `max_lr` is not between 0 and 1, if `pct_start` is not strictly between 0 and 1, if
+    /// the division factors are not positive, or if `num_iters` is 0.
+    pub fn init(&self) -> OneCycleLrScheduler {
+        assert!(
+            self.max_lr > 0. && self.max_lr <= 1.,
+            "Maximum learning rate must be greater than 0 and at most 1"
+        );
+        assert!(
+            self.pct_start > 0. && self.pct_start < 1.,
+            "Percentage of the cycle increasing the learning rate must be greater than 0 and less than 1"
+        );
+        assert!(
+            self.div_factor > 0. && self.final_div_factor > 0.,
+            "Division factors must be greater than 0"
+        );
+        assert!(
+            self.num_iters > 0,
+            "Number of iterations must be at least 1"
+        );
+
+        let initial_lr = self.max_lr / self.div_factor;
+
+        OneCycleLrScheduler {
+            initial_lr,
+            max_lr: self.max_lr,
+            min_lr: initial_lr / self.final_div_factor,
+            pct_start: self.pct_start,
+            num_iters: self.num_iters,
+            current_iter: 0,
+        }
+    }
+}
+
+/// A one-cycle learning rate scheduler.
+///
+/// See [OneCycleLrSchedulerConfig] for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct OneCycleLrScheduler {
+    initial_lr: LearningRate,
+    max_lr: LearningRate,
+    min_lr: LearningRate,
+    pct_start: f64,
+    num_iters: usize,
+    current_iter: usize,
+}
+
+impl OneCycleLrScheduler {
+    /// Cosine interpolation from `start` to `end`, with `pct` going from 0 to 1.
+    fn anneal(start: LearningRate, end: LearningRate, pct: f64) -> LearningRate {
+        end + 0.5 * (start - end) * (1.0 + (pct * std::f64::consts::PI).cos())
+    }
+}
+
+impl LrScheduler for OneCycleLrScheduler {
+    type Record<B: Backend> = (LearningRate, LearningRate, LearningRate, f64, usize, usize);
+
+    fn step(&mut self) -> LearningRate {
+        let progress = match self.num_iters {
+            1 => 1.0,
+            _ => {
+                usize::min(self.current_iter, self.num_iters - 1) as f64
+                    / (self.num_iters - 1) as f64
+            }
+        };
+        self.current_iter = usize::min(self.current_iter + 1, self.num_iters);
+
+        if progress <= self.pct_start {
+            Self::anneal(self.initial_lr, self.max_lr, progress / self.pct_start)
+        } else {
+            let pct = (progress - self.pct_start) / (1.0 - self.pct_start);
+            Self::anneal(self.max_lr, self.min_lr, pct)
+        }
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        (
+            self.initial_lr,
+            self.max_lr,
+            self.min_lr,
+            self.pct_start,
+            self.num_iters,
+            self.current_iter,
+        )
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        (
+            self.initial_lr,
+            self.max_lr,
+            self.min_lr,
+            self.pct_start,
+            self.num_iters,
+            self.current_iter,
+        ) = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Percentage of the cycle increasing the learning rate must be greater than 0 and less than 1"]
+    fn config_pct_start_too_high() {
+        OneCycleLrSchedulerConfig::new(0.5, 10)
+            .with_pct_start(1.0)
+            .init();
+    }
+
+    #[test]
+    fn test_lr_change() {
+        const MAX_LR: LearningRate = 0.5;
+        const NUM_ITERS: usize = 11;
+
+        let mut scheduler = OneCycleLrSchedulerConfig::new(MAX_LR, NUM_ITERS)
+            .with_pct_start(0.4)
+            .with_div_factor(10.0)
+            .with_final_div_factor(100.0)
+            .init();
+
+        let lrs = (0..NUM_ITERS + 2)
+            .map(|_| scheduler.step())
+            .collect::<Vec<_>>();
+
+        assert!((lrs[0] - MAX_LR / 10.0).abs() < 1e-9);
+        assert!((lrs[4] - MAX_LR).abs() < 1e-9);
+        assert!(lrs[..5].windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(lrs[4..NUM_ITERS].windows(2).all(|pair| pair[0] > pair[1]));
+        for lr in &lrs[NUM_ITERS - 1..] {
+            assert!((lr - MAX_LR / 1000.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let config = OneCycleLrSchedulerConfig::new(0.5, 10);
+        let mut scheduler = config.init();
+        scheduler.step();
+        scheduler.step();
+
+        let record = scheduler.to_record::<TestBackend>();
+        let mut loaded = config.init().load_record::<TestBackend>(record);
+
+        for _ in 0..10 {
+            assert_eq!(loaded.step(), scheduler.step());
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use super::LrScheduler;
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a polynomial learning rate scheduler.
+///
+/// This scheduler starts at a learning rate `initial_lr`, then decays the learning rate following a polynomial of
+/// degree `power` until reaching a final learning rate `final_lr` after `num_iters` iterations. At any iteration
+/// `i`, the learning rate is given by `(initial_lr - final_lr) * (1 - i / num_iters)^power + final_lr`.
+#[derive(Config)]
+pub struct PolynomialLrSchedulerConfig {
+    // The initial learning rate.
+    initial_lr: LearningRate,
+    // The final learning rate.
+    #[config(default = 0.0)]
+    final_lr: LearningRate,
+    // The degree of the polynomial.
+    #[config(default = 1.0)]
+    power: f64,
+    // The number of iterations before reaching the final learning rate.
+    num_iters: usize,
+}
+
+impl PolynomialLrSchedulerConfig {
+    /// Initializes a [polynomial learning rate scheduler](PolynomialLrScheduler).
+    ///
+    /// # Panics
+    /// This function panics if `initial_lr` and `final_lr` are not between 0 and 1, if `power` is not positive, or
+    /// if `num_iters` is 0.
+    pub fn init(&self) -> PolynomialLrScheduler {
+        assert!(
+            self.initial_lr > 0. && self.initial_lr <= 1.,
+            "Initial learning rate must be greater than 0 and at most 1"
+        );
+        assert!(
+            self.final_lr >= 0. && self.final_lr <= 1.,
+            "Final learning rate must be at least 0 and at most 1"
+        );
+        assert!(self.power > 0., "Power must be greater than 0");
+        assert!(
+            self.num_iters > 0,
+            "Number of iterations must be at least 1"
+        );
+
+        PolynomialLrScheduler {
+            initial_lr: self.initial_lr,
+            final_lr: self.final_lr,
+            power: self.power,
+            num_iters: self.num_iters,
+            current_iter: 0,
+        }
+    }
+}
+
+/// A polynomial learning rate scheduler.
+///
+/// See [PolynomialLrSchedulerConfig] for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct PolynomialLrScheduler {
+    initial_lr: LearningRate,
+    final_lr: LearningRate,
+    power: f64,
+    num_iters: usize,
+    current_iter: usize,
+}
+
+impl LrScheduler for PolynomialLrScheduler {
+    type Record<B: Backend> = (LearningRate, LearningRate, f64, usize, usize);
+
+    fn step(&mut self) -> LearningRate {
+        if self.current_iter < self.num_iters {
+            self.current_iter += 1;
+        }
+        let remaining = 1.0 - self.current_iter as f64 / self.num_iters as f64;
+
+        (self.initial_lr - self.final_lr) * remaining.powf(self.power) + self.final_lr
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        (
+            self.initial_lr,
+            self.final_lr,
+            self.power,
+            self.num_iters,
+            self.current_iter,
+        )
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        (
+            self.initial_lr,
+            self.final_lr,
+            self.power,
+            self.num_iters,
+            self.current_iter,
+        ) = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Power must be greater than 0"]
+    fn config_power_too_low() {
+        PolynomialLrSchedulerConfig::new(0.5, 10)
+            .with_power(0.)
+            .init();
+    }
+
+    #[test]
+    #[should_panic = "Number of iterations must be at least 1"]
+    fn config_num_iters_too_low() {
+        PolynomialLrSchedulerConfig::new(0.5, 0).init();
+    }
+
+    #[test]
+    fn test_lr_change() {
+        const INITIAL_LR: LearningRate = 0.5;
+        const FINAL_LR: LearningRate = 0.1;
+        const NUM_ITERS: usize = 4;
+
+        let mut scheduler = PolynomialLrSchedulerConfig::new(INITIAL_LR, NUM_ITERS)
+            .with_final_lr(FINAL_LR)
+            .with_power(2.0)
+            .init();
+
+        // (0.5 - 0.1) * (1 - i / 4)^2 + 0.1
+        let expected = [0.325, 0.2, 0.125, 0.1, 0.1];
+        for expected in expected {
+            let lr = scheduler.step();
+            assert!(
+                (lr - expected).abs() < 1e-9,
+                "Expected learning rate {expected}, got {lr}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let config = PolynomialLrSchedulerConfig::new(0.5, 10);
+        let mut scheduler = config.init();
+        scheduler.step();
+        scheduler.step();
+
+        let record = scheduler.to_record::<TestBackend>();
+        let mut loaded = config.init().load_record::<TestBackend>(record);
+
+        for _ in 0..10 {
+            assert_eq!(loaded.step(), scheduler.step());
+        }
+    }
+}
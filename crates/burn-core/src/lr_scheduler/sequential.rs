@@ -0,0 +1,129 @@
+use super::{LrScheduler, MonitoredMetric};
+use crate::LearningRate;
+use burn_tensor::backend::Backend;
+
+/// Learning rate scheduler running two schedulers one after the other.
+///
+/// The first scheduler is used for the first `milestone` iterations, then the second scheduler takes over, starting
+/// from its own first iteration. More schedulers can be chained by nesting sequential schedulers, in which case the
+/// milestone of the outer scheduler counts the iterations of the inner one.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // Linear warmup for 100 iterations, then cosine annealing for 1000 iterations.
+/// let scheduler = SequentialLrScheduler::new(
+///     LinearLrSchedulerConfig::new(1e-4, 1e-2, 100).init(),
+///     100,
+///     CosineAnnealingLrSchedulerConfig::new(1e-2, 1000).init(),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct SequentialLrScheduler<S1, S2> {
+    first: S1,
+    second: S2,
+    milestone: usize,
+    current_iter: usize,
+}
+
+impl<S1: LrScheduler, S2: LrScheduler> SequentialLrScheduler<S1, S2> {
+    /// Create a scheduler using `first` for `milestone` iterations, then `second`.
+    pub fn new(first: S1, milestone: usize, second: S2) -> Self {
+        Self {
+            first,
+            second,
+            milestone,
+            current_iter: 0,
+        }
+    }
+}
+
+impl<S1: LrScheduler, S2: LrScheduler> LrScheduler for SequentialLrScheduler<S1, S2> {
+    type Record<B: Backend> = (S1::Record<B>, S2::Record<B>, usize);
+
+    fn step(&mut self) -> LearningRate {
+        if self.current_iter < self.milestone {
+            self.current_iter += 1;
+            return self.first.step();
+        }
+
+        self.second.step()
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        (
+            self.first.to_record(),
+            self.second.to_record(),
+            self.current_iter,
+        )
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        let (first, second, current_iter) = record;
+        self.first = self.first.load_record(first);
+        self.second = self.second.load_record(second);
+        self.current_iter = current_iter;
+        self
+    }
+
+    fn monitored_metric(&self) -> Option<MonitoredMetric> {
+        if self.current_iter < self.milestone {
+            self.first.monitored_metric()
+        } else {
+            self.second.monitored_metric()
+        }
+    }
+
+    fn on_evaluation_end(&mut self, step: usize, metric: Option<f64>) {
+        if self.current_iter < self.milestone {
+            self.first.on_evaluation_end(step, metric);
+        } else {
+            self.second.on_evaluation_end(step, metric);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lr_scheduler::{
+        exponential::ExponentialLrSchedulerConfig, linear::LinearLrSchedulerConfig,
+        step::StepLrSchedulerConfig,
+    };
+    use crate::TestBackend;
+
+    #[test]
+    fn test_lr_change() {
+        let mut scheduler = SequentialLrScheduler::new(
+            SequentialLrScheduler::new(0.1, 2, 0.2),
+            3,
+            ExponentialLrSchedulerConfig::new(0.8, 0.5).init(),
+        );
+
+        let lrs = (0..6).map(|_| scheduler.step()).collect::<Vec<_>>();
+
+        assert_eq!(lrs, vec![0.1, 0.1, 0.2, 0.4, 0.2, 0.1]);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let init = || {
+            SequentialLrScheduler::new(
+                LinearLrSchedulerConfig::new(0.1, 0.5, 4).init(),
+                4,
+                StepLrSchedulerConfig::new(0.5, 2).init(),
+            )
+        };
+        let mut scheduler = init();
+        for _ in 0..6 {
+            scheduler.step();
+        }
+
+        let record = scheduler.to_record::<TestBackend>();
+        let mut loaded = init().load_record::<TestBackend>(record);
+
+        for _ in 0..10 {
+            assert_eq!(loaded.step(), scheduler.step());
+        }
+    }
+}
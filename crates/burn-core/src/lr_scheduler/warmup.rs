@@ -0,0 +1,155 @@
+use super::{LrScheduler, MonitoredMetric};
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a linear warmup learning rate scheduler.
+///
+/// This scheduler wraps another scheduler. During the first `warmup_iters` iterations, the learning rate increases
+/// linearly from `start_factor` times the first learning rate of the wrapped scheduler up to that learning rate.
+/// The wrapped scheduler only starts after the warmup.
+#[derive(Config)]
+pub struct LinearWarmupLrSchedulerConfig {
+    // The number of warmup iterations.
+    warmup_iters: usize,
+    // The fraction of the learning rate used before the first iteration.
+    #[config(default = 0.0)]
+    start_factor: f64,
+}
+
+impl LinearWarmupLrSchedulerConfig {
+    /// Initializes a [linear warmup learning rate scheduler](LinearWarmupLrScheduler) wrapping the given
+    /// scheduler.
+    ///
+    /// # Panics
+    /// This function panics if `start_factor` is not between 0 and 1.
+    pub fn init<S: LrScheduler + Clone>(&self, scheduler: S) -> LinearWarmupLrScheduler<S> {
+        assert!(
+            (0. ..1.).contains(&self.start_factor),
+            "Start factor must be at least 0 and less than 1"
+        );
+
+        LinearWarmupLrScheduler {
+            scheduler,
+            warmup_iters: self.warmup_iters,
+            start_factor: self.start_factor,
+            current_iter: 0,
+        }
+    }
+}
+
+/// A linear warmup learning rate scheduler.
+///
+/// See [LinearWarmupLrSchedulerConfig] for more information.
+#[derive(Clone, Debug)]
+pub struct LinearWarmupLrScheduler<S> {
+    scheduler: S,
+    warmup_iters: usize,
+    start_factor: f64,
+    current_iter: usize,
+}
+
+impl<S: LrScheduler + Clone> LrScheduler for LinearWarmupLrScheduler<S> {
+    type Record<B: Backend> = (S::Record<B>, usize);
+
+    fn step(&mut self) -> LearningRate {
+        if self.current_iter >= self.warmup_iters {
+            return self.scheduler.step();
+        }
+
+        self.current_iter += 1;
+
+        // The wrapped scheduler is stepped on a copy, so it starts at its first learning rate after the warmup.
+        let target_lr = self.scheduler.clone().step();
+        let progress = self.current_iter as f64 / self.warmup_iters as f64;
+
+        target_lr * (self.start_factor + (1.0 - self.start_factor) * progress)
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        (self.scheduler.to_record(), self.current_iter)
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        let (scheduler, current_iter) = record;
+        self.scheduler = self.scheduler.load_record(scheduler);
+        self.current_iter = current_iter;
+        self
+    }
+
+    fn monitored_metric(&self) -> Option<MonitoredMetric> {
+        self.scheduler.monitored_metric()
+    }
+
+    fn on_evaluation_end(&mut self, step: usize, metric: Option<f64>) {
+        self.scheduler.on_evaluation_end(step, metric);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lr_scheduler::cosine::CosineAnnealingLrSchedulerConfig;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Start factor must be at least 0 and less than 1"]
+    fn config_start_factor_too_high() {
+        LinearWarmupLrSchedulerConfig::new(10)
+            .with_start_factor(1.0)
+            .init(0.1);
+    }
+
+    #[test]
+    fn test_lr_change() {
+        let mut scheduler = LinearWarmupLrSchedulerConfig::new(4).init(1.0);
+
+        let lrs = (0..6).map(|_| scheduler.step()).collect::<Vec<_>>();
+
+        assert_eq!(lrs, vec![0.25, 0.5, 0.75, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_wrapped_scheduler_starts_after_warmup() {
+        let cosine = CosineAnnealingLrSchedulerConfig::new(0.5, 10);
+        let mut scheduler = LinearWarmupLrSchedulerConfig::new(5)
+            .with_start_factor(0.1)
+            .init(cosine.init());
+        let mut reference = cosine.init();
+
+        let first_lr = reference.clone().step();
+        let mut previous_lr = 0.0;
+        for _ in 0..5 {
+            let lr = scheduler.step();
+            assert!(
+                lr > previous_lr,
+                "Learning rate should increase during warmup"
+            );
+            previous_lr = lr;
+        }
+        assert_eq!(previous_lr, first_lr);
+
+        for _ in 0..10 {
+            assert_eq!(scheduler.step(), reference.step());
+        }
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let cosine = CosineAnnealingLrSchedulerConfig::new(0.5, 10);
+        let config = LinearWarmupLrSchedulerConfig::new(5);
+        let mut scheduler = config.init(cosine.init());
+        for _ in 0..7 {
+            scheduler.step();
+        }
+
+        let record = scheduler.to_record::<TestBackend>();
+        let mut loaded = config
+            .init(cosine.init())
+            .load_record::<TestBackend>(record);
+
+        for _ in 0..10 {
+            assert_eq!(loaded.step(), scheduler.step());
+        }
+    }
+}
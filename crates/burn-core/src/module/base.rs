@@ -1,4 +1,4 @@
-use super::{ParamId, Quantizer};
+use super::{FakeQuantizer, HalfPrecision, ParamId, Quantizer};
 use crate::{
     record::Record,
     tensor::backend::{AutodiffBackend, Backend},
@@ -207,10 +207,33 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
     fn quantize_weights<C: Calibration>(self, quantizer: &mut Quantizer<C>) -> Self {
         self.map(quantizer)
     }
+
+    /// Fake quantize the weights of the module for quantization-aware training.
+    fn fake_quantize_weights<C: Calibration>(self, quantizer: &mut FakeQuantizer<C>) -> Self {
+        self.map(quantizer)
+    }
+
+    /// Round the weights of the module to the given [half precision](HalfPrecision), keeping
+    /// their ids so the gradients can be applied to the full precision module.
+    ///
+    /// The rounding is [emulated](HalfPrecision::round_emulated), the weights keep the element
+    /// type of the backend.
+    fn half_precision(self, mut precision: HalfPrecision) -> Self {
+        self.map(&mut precision)
+    }
 }
 
 /// Module visitor trait.
 pub trait ModuleVisitor<B: Backend> {
+    /// Called before visiting a field of a module, an item of a container module (with its index
+    /// as name) or a variant of an enum module.
+    ///
+    /// Along with [exit_module](ModuleVisitor::exit_module), this can be used to track the path of
+    /// the visited parameters, e.g. `encoder.layers.0.linear.weight`.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after visiting a field of a module, an item of a container module or a variant of an
+    /// enum module.
+    fn exit_module(&mut self, _name: &str) {}
     /// Visit a float tensor in the module.
     fn visit_float<const D: usize>(&mut self, _id: &ParamId, _tensor: &Tensor<B, D>) {}
     /// Visit an int tensor in the module.
@@ -0,0 +1,257 @@
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, container::TensorContainer, Tensor};
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+
+/// Configuration to create a [model EMA](ModelEma).
+#[derive(Config)]
+pub struct ModelEmaConfig {
+    /// The decay of the moving average, the weight of the previous average at each update.
+    #[config(default = 0.9999)]
+    pub decay: f64,
+    /// The number of updates during which the decay increases linearly from 0, so that the
+    /// average follows the model closely at the beginning of the training.
+    #[config(default = 0)]
+    pub warmup_iters: usize,
+}
+
+impl ModelEmaConfig {
+    /// Initialize a [model EMA](ModelEma) starting from the weights of the given module.
+    ///
+    /// # Panics
+    /// This function panics if `decay` is not between 0 and 1.
+    pub fn init<B: Backend, M: Module<B>>(&self, module: &M) -> ModelEma<B, M> {
+        assert!(
+            (0.0..=1.0).contains(&self.decay),
+            "Decay must be between 0 and 1"
+        );
+
+        ModelEma {
+            module: module.clone().no_grad(),
+            decay: self.decay,
+            warmup_iters: self.warmup_iters,
+            num_updates: 0,
+            _backend: PhantomData,
+        }
+    }
+}
+
+/// Record of a [model EMA](ModelEma): the averaged parameters and the number of updates.
+pub type ModelEmaRecord<B, M> = (<M as Module<B>>::Record, usize);
+
+/// Exponential moving average of the float parameters of a module.
+///
+/// The average is kept in a shadow copy of the module, which doesn't require gradients. It is
+/// updated after each optimizer step with [update](ModelEma::update):
+///
+/// `average = decay * average + (1 - decay) * parameter`
+///
+/// Parameters are matched using their [id](ParamId), so the module given to
+/// [update](ModelEma::update) must be the module the EMA was initialized with, or a version of it
+/// returned by an optimizer.
+#[derive(Clone, Debug)]
+pub struct ModelEma<B: Backend, M> {
+    module: M,
+    decay: f64,
+    warmup_iters: usize,
+    num_updates: usize,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend, M: Module<B>> ModelEma<B, M> {
+    /// Update the moving average with the current parameters of the given module.
+    pub fn update(&mut self, module: &M) {
+        let decay = self.current_decay();
+
+        let mut collector = ParamsCollector::<B>::new();
+        module.visit(&mut collector);
+
+        let mut mapper = EmaMapper::<B> {
+            params: collector.params,
+            decay,
+            _backend: PhantomData,
+        };
+        self.module = self.module.clone().map(&mut mapper);
+        self.num_updates += 1;
+    }
+
+    /// The decay used by the next update, taking the warmup into account.
+    pub fn current_decay(&self) -> f64 {
+        if self.num_updates >= self.warmup_iters {
+            return self.decay;
+        }
+
+        self.decay * self.num_updates as f64 / self.warmup_iters as f64
+    }
+
+    /// The number of updates done so far.
+    pub fn num_updates(&self) -> usize {
+        self.num_updates
+    }
+
+    /// The module holding the averaged parameters.
+    pub fn module(&self) -> &M {
+        &self.module
+    }
+
+    /// Consume the EMA and return the module holding the averaged parameters.
+    pub fn into_module(self) -> M {
+        self.module
+    }
+
+    /// Get the averaged parameters and the number of updates as a [record](ModelEmaRecord), so
+    /// the average can be resumed.
+    pub fn to_record(&self) -> ModelEmaRecord<B, M> {
+        (self.module.clone().into_record(), self.num_updates)
+    }
+
+    /// Load the averaged parameters and the number of updates from a [record](ModelEmaRecord).
+    pub fn load_record(mut self, record: ModelEmaRecord<B, M>) -> Self {
+        let (module, num_updates) = record;
+        self.module = self.module.load_record(module);
+        self.num_updates = num_updates;
+        self
+    }
+}
+
+struct ParamsCollector<B: Backend> {
+    params: TensorContainer<ParamId>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> ParamsCollector<B> {
+    fn new() -> Self {
+        Self {
+            params: TensorContainer::new(),
+            _backend: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamsCollector<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params
+            .register::<B>(id.clone(), tensor.clone().detach().into_primitive());
+    }
+}
+
+struct EmaMapper<B: Backend> {
+    params: TensorContainer<ParamId>,
+    decay: f64,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleMapper<B> for EmaMapper<B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let param = match self.params.remove::<B>(id) {
+            Some(param) => Tensor::<B, D>::from_primitive(param),
+            None => return tensor,
+        };
+
+        tensor
+            .detach()
+            .mul_scalar(self.decay)
+            .add(param.mul_scalar(1.0 - self.decay))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::TensorData;
+    use crate::TestAutodiffBackend;
+
+    type TestLinear = Linear<TestAutodiffBackend>;
+
+    fn linear_with_weights(mut linear: TestLinear, value: f32) -> TestLinear {
+        linear.weight = linear
+            .weight
+            .map(|weight| weight.ones_like().mul_scalar(value));
+        linear
+    }
+
+    #[test]
+    fn should_average_the_parameters() {
+        let device = Default::default();
+        let linear = LinearConfig::new(2, 2).with_bias(false).init(&device);
+        let linear = linear_with_weights(linear, 1.0);
+        let mut ema = ModelEmaConfig::new().with_decay(0.5).init(&linear);
+
+        let linear = linear_with_weights(linear, 3.0);
+        ema.update(&linear);
+        let linear = linear_with_weights(linear, 5.0);
+        ema.update(&linear);
+
+        // 0.5 * (0.5 * 1 + 0.5 * 3) + 0.5 * 5
+        ema.module()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[3.5, 3.5], [3.5, 3.5]]), 5);
+        assert!(!ema.module().weight.is_require_grad());
+        assert_eq!(ema.num_updates(), 2);
+    }
+
+    #[test]
+    fn should_increase_the_decay_during_warmup() {
+        let device = Default::default();
+        let linear: TestLinear = LinearConfig::new(2, 2).init(&device);
+        let mut ema = ModelEmaConfig::new()
+            .with_decay(0.5)
+            .with_warmup_iters(4)
+            .init(&linear);
+
+        let mut decays = Vec::new();
+        for _ in 0..6 {
+            decays.push(ema.current_decay());
+            ema.update(&linear);
+        }
+
+        assert_eq!(decays, vec![0.0, 0.125, 0.25, 0.375, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn first_update_during_warmup_should_copy_the_parameters() {
+        let device = Default::default();
+        let linear = LinearConfig::new(2, 2).with_bias(false).init(&device);
+        let linear = linear_with_weights(linear, 1.0);
+        let mut ema = ModelEmaConfig::new().with_warmup_iters(10).init(&linear);
+
+        let linear = linear_with_weights(linear, 3.0);
+        ema.update(&linear);
+
+        ema.module()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[3.0, 3.0], [3.0, 3.0]]), 5);
+    }
+
+    #[test]
+    fn should_resume_from_the_record() {
+        let device = Default::default();
+        let linear = LinearConfig::new(2, 2).with_bias(false).init(&device);
+        let mut ema = ModelEmaConfig::new()
+            .with_decay(0.5)
+            .init(&linear_with_weights(linear.clone(), 1.0));
+        ema.update(&linear_with_weights(linear.clone(), 3.0));
+
+        let restored = ModelEmaConfig::new()
+            .with_decay(0.5)
+            .init(&linear)
+            .load_record(ema.to_record());
+
+        restored
+            .module()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.0, 2.0], [2.0, 2.0]]), 5);
+        assert!(!restored.module().weight.is_require_grad());
+        assert_eq!(restored.num_updates(), 1);
+    }
+}
@@ -1,9 +1,13 @@
 mod base;
 mod display;
+mod ema;
 mod param;
+mod precision;
 mod quantize;
 
 pub use base::*;
 pub use display::*;
+pub use ema::*;
 pub use param::*;
+pub use precision::*;
 pub use quantize::*;
@@ -156,6 +156,11 @@ impl<T: Parameter> Param<T> {
         }
     }
 
+    /// Whether the parameter value has been initialized.
+    pub(crate) fn is_initialized(&self) -> bool {
+        self.state.get().is_some()
+    }
+
     /// The device on which the parameter is or will be initialized.
     ///
     /// This should be used instead of [crate::tensor::Tensor::device], since using the tensor
@@ -3,7 +3,7 @@ use crate::module::{
     ModuleVisitor,
 };
 
-use alloc::{format, vec::Vec};
+use alloc::{format, string::ToString, vec::Vec};
 
 use burn_tensor::backend::{AutodiffBackend, Backend};
 use core::fmt::Debug;
@@ -97,8 +97,11 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(index, module)| {
+            let name = index.to_string();
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
@@ -196,8 +199,11 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(index, module)| {
+            let name = index.to_string();
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
@@ -285,7 +291,11 @@ macro_rules! impl_module_tuple {
             }
 
             fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-                $(self.$i.visit(visitor);)*
+                $(
+                    visitor.enter_module(stringify!($i));
+                    self.$i.visit(visitor);
+                    visitor.exit_module(stringify!($i));
+                )*
             }
 
             fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
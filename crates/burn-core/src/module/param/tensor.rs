@@ -58,6 +58,33 @@ impl<B: Backend, const D: usize> Parameter for Tensor<B, D, Bool> {
     }
 }
 
+/// Load the record value with the given device and gradient requirement.
+///
+/// When the record isn't initialized yet, e.g. when its tensor is read from a memory-mapped file,
+/// the loaded parameter stays uninitialized until it is first used.
+fn load_param<T, F>(record: Param<T>, device: T::Device, is_require_grad: bool, load: F) -> Param<T>
+where
+    T: Parameter + 'static,
+    T::Device: Send,
+    F: Fn(T, &T::Device, bool) -> T + Send + 'static,
+{
+    if record.is_initialized() {
+        let (id, value) = record.consume();
+        let value = load(value, &device, is_require_grad);
+
+        return Param::initialized(id, value);
+    }
+
+    let id = record.id.clone();
+
+    Param::uninitialized(
+        id,
+        move |device, is_require_grad| load(record.val(), device, is_require_grad),
+        device,
+        is_require_grad,
+    )
+}
+
 impl<B: Backend, const D: usize> Param<Tensor<B, D>> {
     /// Create a new parameter from a float tensor.
     ///
@@ -103,20 +130,23 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
     }
 
     fn load_record(self, record: Self::Record) -> Self {
-        let (new_id, mut new_value) = record.consume();
-
         let expected_device = self.lazy_device();
         let expected_require_grad = self.lazy_is_require_grad();
 
-        // Make sure we load the record into the same module device.
-        if new_value.device() != expected_device {
-            new_value = new_value.to_device(&expected_device).detach();
-        }
+        load_param(
+            record,
+            expected_device,
+            expected_require_grad,
+            |mut value, device, require_grad| {
+                // Make sure we load the record into the same module device.
+                if value.device() != *device {
+                    value = value.to_device(device).detach();
+                }
 
-        // Make sure we load the record with the same autodiff setting.
-        new_value = new_value.set_require_grad(expected_require_grad);
-
-        Self::initialized(new_id, new_value)
+                // Make sure we load the record with the same autodiff setting.
+                value.set_require_grad(require_grad)
+            },
+        )
     }
 
     fn to_device(self, device: &<B as Backend>::Device) -> Self {
@@ -183,16 +213,16 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D, Int>> {
     }
 
     fn load_record(self, record: Self::Record) -> Self {
-        let (new_id, mut new_value) = record.consume();
-
         let expected_device = self.lazy_device();
 
-        // Make sure we load the record into the same module device.
-        if new_value.device() != expected_device {
-            new_value = new_value.to_device(&expected_device);
-        }
+        load_param(record, expected_device, false, |mut value, device, _| {
+            // Make sure we load the record into the same module device.
+            if value.device() != *device {
+                value = value.to_device(device);
+            }
 
-        Self::initialized(new_id, new_value)
+            value
+        })
     }
 
     fn to_device(self, device: &<B as Backend>::Device) -> Self {
@@ -250,16 +280,16 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D, Bool>> {
     }
 
     fn load_record(self, record: Self::Record) -> Self {
-        let (new_id, mut new_value) = record.consume();
-
         let expected_device = self.lazy_device();
 
-        // Make sure we load the record into the same module device.
-        if new_value.device() != expected_device {
-            new_value = new_value.to_device(&expected_device);
-        }
+        load_param(record, expected_device, false, |mut value, device, _| {
+            // Make sure we load the record into the same module device.
+            if value.device() != *device {
+                value = value.to_device(device);
+            }
 
-        Self::initialized(new_id, new_value)
+            value
+        })
     }
 
     fn to_device(self, device: &<B as Backend>::Device) -> Self {
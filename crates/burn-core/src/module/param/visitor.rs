@@ -1,6 +1,6 @@
 use super::ParamId;
 use crate::module::{Module, ModuleVisitor};
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use burn_tensor::{backend::Backend, Bool, Int, Tensor};
 use core::marker::PhantomData;
 
@@ -36,3 +36,53 @@ pub fn list_param_ids<M: Module<B>, B: Backend>(module: &M) -> Vec<ParamId> {
 
     params_ids
 }
+
+struct ParamPathCollector<'a, M> {
+    path: Vec<String>,
+    param_paths: &'a mut Vec<(ParamId, String)>,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, M> ParamPathCollector<'a, M> {
+    fn collect(&mut self, id: &ParamId) {
+        self.param_paths.push((id.clone(), self.path.join(".")));
+    }
+}
+
+impl<'a, B, M> ModuleVisitor<B> for ParamPathCollector<'a, M>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.into());
+    }
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        self.collect(id);
+    }
+    fn visit_int<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D, Int>) {
+        self.collect(id);
+    }
+    fn visit_bool<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D, Bool>) {
+        self.collect(id);
+    }
+}
+
+/// List all the parameter ids in a module with their path, e.g. `encoder.layers.0.linear.weight`.
+///
+/// The path is made of the field names of the modules, the index of items in container modules
+/// and the variant name of enum modules, matching the keys of the module [record](crate::record::Record).
+pub fn list_param_paths<M: Module<B>, B: Backend>(module: &M) -> Vec<(ParamId, String)> {
+    let mut param_paths = Vec::new();
+    let mut visitor = ParamPathCollector {
+        path: Vec::new(),
+        param_paths: &mut param_paths,
+        phantom: PhantomData::<M>,
+    };
+    module.visit(&mut visitor);
+
+    param_paths
+}
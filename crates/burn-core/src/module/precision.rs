@@ -0,0 +1,97 @@
+use burn_tensor::{backend::Backend, Tensor};
+use half::{bf16, f16};
+use serde::{Deserialize, Serialize};
+
+use crate::module::{ModuleMapper, ParamId};
+
+/// Half precision floating point type used to emulate mixed precision training.
+///
+/// The weights are rounded to the closest value representable in half precision, while keeping
+/// the element type of the backend. The rounding is emulated on the host, so it reproduces the
+/// precision loss of half precision on any backend, e.g. for testing on CPU with the ndarray or
+/// candle backends, but neither the memory nor the compute of the backend are reduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HalfPrecision {
+    /// IEEE 754 half precision, with 5 exponent bits and 10 mantissa bits.
+    F16,
+    /// Brain floating point, with the 8 exponent bits of single precision and 7 mantissa bits.
+    BF16,
+}
+
+impl HalfPrecision {
+    /// Round the given tensor to half precision, emulated by converting its data on the host.
+    ///
+    /// Values out of the half precision range become infinite, and small values underflow to
+    /// zero. The tensor keeps the element type of the backend.
+    pub fn round_emulated<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let device = tensor.device();
+        let data = match self {
+            HalfPrecision::F16 => tensor.into_data().convert::<f16>(),
+            HalfPrecision::BF16 => tensor.into_data().convert::<bf16>(),
+        };
+
+        Tensor::from_data(data.convert::<B::FloatElem>(), &device)
+    }
+}
+
+/// Copy the module weights [rounded](HalfPrecision::round_emulated) to half precision.
+///
+/// The rounded weights keep the [id](ParamId) of the weights they are copied from, and require
+/// gradients if they did. The gradients computed with the copy can then be applied to the full
+/// precision weights, which act as master weights:
+///
+/// ```rust, ignore
+/// let model_half = model.clone().half_precision(HalfPrecision::F16);
+/// let loss = model_half.forward(input);
+/// let grads = GradientsParams::from_grads(loss.backward(), &model_half);
+/// model = optim.step(lr, model, grads);
+/// ```
+impl<B: Backend> ModuleMapper<B> for HalfPrecision {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let require_grad = tensor.is_require_grad();
+
+        self.round_emulated(tensor.detach())
+            .set_require_grad(require_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Module;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::optim::GradientsParams;
+    use crate::tensor::TensorData;
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn should_round_to_half_precision() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestAutodiffBackend, 1>::from_floats([1.0001, 70000.0, 1e-8], &device);
+
+        HalfPrecision::F16
+            .round_emulated(tensor.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, f32::INFINITY, 0.0]), false);
+        HalfPrecision::BF16
+            .round_emulated(tensor)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 70144.0, 1e-8]), 3);
+    }
+
+    #[test]
+    fn gradients_of_the_half_precision_copy_should_apply_to_the_module() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(2, 2).init(&device);
+        let linear_half = linear.clone().half_precision(HalfPrecision::F16);
+
+        let input = Tensor::ones([1, 2], &device);
+        let loss = linear_half.forward(input).sum();
+        let grads = GradientsParams::from_grads(loss.backward(), &linear_half);
+
+        assert!(linear_half.weight.is_require_grad());
+        assert_eq!(linear_half.weight.id, linear.weight.id);
+        assert_eq!(grads.len(), 2);
+    }
+}
@@ -1,12 +1,19 @@
 use burn_tensor::{
     backend::Backend,
-    quantization::{Calibration, QuantizationScheme},
+    quantization::{Calibration, Observer, QuantizationParameters, QuantizationScheme},
     Tensor,
 };
 
 use crate::module::{ModuleMapper, ParamId};
 
 /// Describes how to quantize a module.
+///
+/// With a per-channel scheme, weights are quantized along their output channels, i.e. axis `1`
+/// for linear weights (`[d_input, d_output]`) and axis `0` for convolution weights
+/// (`[channels_out, ...]`), whatever the axis of the scheme. Biases are quantized per-tensor.
+///
+/// Activations can also be quantized statically: their statistics are gathered over many batches
+/// with an [observer](Quantizer::observer) before being quantized with the observed range.
 pub struct Quantizer<C: Calibration> {
     /// The calibration method used in quantization.
     pub calibration: C,
@@ -14,10 +21,170 @@ pub struct Quantizer<C: Calibration> {
     pub scheme: QuantizationScheme,
 }
 
+impl<C: Calibration> Quantizer<C> {
+    /// Create an observer to gather the statistics of activations over many batches.
+    ///
+    /// With a per-channel scheme, the channels of the activations are observed along the scheme
+    /// axis.
+    pub fn observer<B: Backend>(&self) -> C::Observer<B> {
+        self.calibration.observer(self.scheme.axis())
+    }
+
+    /// Quantize the activations with the range computed from the observed statistics.
+    pub fn quantize_observed<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        observer: &C::Observer<B>,
+    ) -> Tensor<B, D> {
+        let qparams = self.scheme.compute_q_params(observer.range());
+        tensor.quantize(&self.scheme, qparams)
+    }
+}
+
+/// Simulates the quantization of the module weights for quantization-aware training.
+///
+/// Weights with two dimensions or more, such as linear and convolution weights, are replaced by
+/// their [fake quantized](Tensor::fake_quantize) values with the same per-tensor or per-channel
+/// scheme as the [Quantizer], per-channel along their output channels. Other parameters, such as
+/// biases, are kept in floating point precision. The gradients flow straight through to the
+/// original weights, so the fake quantized module should be derived from the trained module at
+/// each training step.
+///
+/// # Activations
+///
+/// Only the weights are fake quantized. A module mapper can only map the parameters of a module,
+/// so it can't insert layers around the activations of the linear and convolution layers. The
+/// activations to quantize should be fake quantized by the module itself, with a
+/// [FakeQuantize](crate::nn::FakeQuantize) layer observing their range during training:
+///
+/// ```rust, ignore
+/// let x = self.activation_quant.forward(x);
+/// let x = self.linear.forward(x);
+/// ```
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let mut fake_quantizer = FakeQuantizer {
+///     calibration: MinMaxCalibration {},
+///     scheme: QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0),
+/// };
+///
+/// let output = model.clone().fake_quantize_weights(&mut fake_quantizer).forward(input);
+/// let grads = GradientsParams::from_grads(loss.backward(), &model);
+/// model = optim.step(lr, model, grads);
+/// ```
+///
+/// Once trained, the module can be converted to quantized tensors with a [Quantizer] using the
+/// same calibration and scheme.
+pub struct FakeQuantizer<C: Calibration> {
+    /// The calibration method used in quantization.
+    pub calibration: C,
+    /// The quantization scheme.
+    pub scheme: QuantizationScheme,
+}
+
+/// Returns the scheme used to quantize a parameter of the given rank.
+///
+/// Per-channel schemes are applied along the output channels: axis `1` for linear weights
+/// (`[d_input, d_output]`) and axis `0` for convolution weights (`[channels_out, ...]`). Biases
+/// don't have channels and are quantized per-tensor.
+fn scheme_for_param(scheme: &QuantizationScheme, rank: usize) -> QuantizationScheme {
+    match (scheme.axis(), rank) {
+        (None, _) => *scheme,
+        (Some(_), 0 | 1) => scheme.per_tensor(),
+        (Some(_), 2) => scheme.with_axis(1),
+        (Some(_), _) => scheme.with_axis(0),
+    }
+}
+
+/// Computes the quantization parameters of a tensor.
+fn compute_q_params<B: Backend, C: Calibration, const D: usize>(
+    calibration: &C,
+    scheme: &QuantizationScheme,
+    tensor: &Tensor<B, D>,
+) -> QuantizationParameters<B> {
+    let range = match scheme.axis() {
+        Some(axis) => calibration.compute_range_per_channel(tensor, axis),
+        None => calibration.compute_range(tensor),
+    };
+    scheme.compute_q_params(range)
+}
+
 impl<B: Backend, C: Calibration> ModuleMapper<B> for Quantizer<C> {
     fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
-        let range = self.calibration.compute_range(&tensor);
-        let qparams = self.scheme.compute_q_params(range);
-        tensor.quantize(&self.scheme, qparams)
+        let scheme = scheme_for_param(&self.scheme, D);
+        let qparams = compute_q_params(&self.calibration, &scheme, &tensor);
+        tensor.quantize(&scheme, qparams)
+    }
+}
+
+impl<B: Backend, C: Calibration> ModuleMapper<B> for FakeQuantizer<C> {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if D < 2 {
+            return tensor;
+        }
+
+        let scheme = scheme_for_param(&self.scheme, D);
+        // The range is computed from the values, without tracking the gradients
+        let qparams = compute_q_params(&self.calibration, &scheme, &tensor.clone().detach());
+        tensor.fake_quantize(&scheme, qparams)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Module;
+    use crate::nn::LinearConfig;
+    use crate::TestAutodiffBackend;
+    use burn_tensor::quantization::{MinMaxCalibration, QuantizationType};
+
+    #[test]
+    fn per_channel_scheme_should_use_the_output_channels_axis() {
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0);
+
+        assert_eq!(
+            scheme_for_param(&scheme, 4),
+            QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0)
+        );
+        assert_eq!(
+            scheme_for_param(&scheme, 2),
+            QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 1)
+        );
+        assert_eq!(
+            scheme_for_param(&scheme, 1),
+            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8)
+        );
+    }
+
+    #[test]
+    fn fake_quantized_weights_should_backward_to_original_weights() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 2).init::<TestAutodiffBackend>(&device);
+        let mut fake_quantizer = FakeQuantizer {
+            calibration: MinMaxCalibration {},
+            scheme: QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0),
+        };
+
+        let fake_linear = linear.clone().fake_quantize_weights(&mut fake_quantizer);
+        let output = fake_linear.forward(Tensor::<TestAutodiffBackend, 2>::ones([3, 4], &device));
+        let grads = output.sum().backward();
+
+        // Biases are kept in floating point precision
+        fake_linear
+            .bias
+            .as_ref()
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&linear.bias.as_ref().unwrap().val().into_data(), true);
+        fake_linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&linear.weight.val().into_data(), 1);
+        assert!(linear.weight.grad(&grads).is_some());
     }
 }
@@ -0,0 +1,261 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, RunningState};
+use crate::tensor::backend::Backend;
+use crate::tensor::quantization::{
+    Calibration, CalibrationRange, MinMaxCalibration, QuantizationScheme,
+};
+use crate::tensor::Tensor;
+
+/// Configuration to create a [FakeQuantize](FakeQuantize) layer using the [init function](FakeQuantizeConfig::init).
+#[derive(Config, Debug)]
+pub struct FakeQuantizeConfig {
+    /// The quantization scheme of the activations.
+    pub scheme: QuantizationScheme,
+    /// The number of channels along the quantization axis, for per-channel schemes. Default: 1
+    #[config(default = 1)]
+    pub channels: usize,
+    /// Momentum used to update the observed range. Default: 0.01
+    #[config(default = 0.01)]
+    pub momentum: f64,
+}
+
+/// Observes the range of the activations and simulates their quantization, for
+/// quantization-aware training.
+///
+/// During training, the observed range is updated with a moving average of the min and max values
+/// of each batch, and the activations are [fake quantized](Tensor::fake_quantize) with the
+/// observed range so the gradients flow straight through. Otherwise, the activations are
+/// quantized and dequantized with the observed range, or passed through unchanged when no range
+/// has been observed yet.
+///
+/// Should be created using [FakeQuantizeConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct FakeQuantize<B: Backend> {
+    /// The observed minimum value.
+    pub min: RunningState<Tensor<B, 1>>,
+    /// The observed maximum value.
+    pub max: RunningState<Tensor<B, 1>>,
+    /// Momentum used to update the observed range.
+    pub momentum: f64,
+    /// The quantization scheme of the activations.
+    pub scheme: Ignored<QuantizationScheme>,
+}
+
+impl FakeQuantizeConfig {
+    /// Initializes a new [fake quantize](FakeQuantize) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> FakeQuantize<B> {
+        let channels = match self.scheme.axis() {
+            Some(_) => self.channels,
+            None => 1,
+        };
+
+        // The range is set from the first observed batch
+        let min = Tensor::full([channels], f32::INFINITY, device);
+        let max = Tensor::full([channels], f32::NEG_INFINITY, device);
+
+        FakeQuantize {
+            min: RunningState::new(min),
+            max: RunningState::new(max),
+            momentum: self.momentum,
+            scheme: Ignored(self.scheme),
+        }
+    }
+}
+
+impl<B: Backend> FakeQuantize<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [FakeQuantize](FakeQuantize) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        match B::ad_enabled() {
+            true => self.forward_train(input),
+            false => self.forward_inference(input),
+        }
+    }
+
+    /// Returns the observed range.
+    pub fn range(&self) -> CalibrationRange<B> {
+        CalibrationRange {
+            min: self.min.value_sync(),
+            max: self.max.value_sync(),
+        }
+    }
+
+    fn forward_inference<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if !self.is_observed() {
+            return input;
+        }
+
+        let device = input.device();
+        let range = CalibrationRange {
+            min: self.min.value().to_device(&device),
+            max: self.max.value().to_device(&device),
+        };
+
+        let qparams = self.scheme.compute_q_params(range);
+        input.quantize(&self.scheme, qparams).dequantize()
+    }
+
+    fn forward_train<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let device = input.device();
+        let values = input.clone().detach();
+        let batch = match self.scheme.axis() {
+            Some(axis) => MinMaxCalibration {}.compute_range_per_channel(&values, axis),
+            None => MinMaxCalibration {}.compute_range(&values),
+        };
+
+        let min = self.update(self.min.value_sync().to_device(&device), batch.min);
+        let max = self.update(self.max.value_sync().to_device(&device), batch.max);
+
+        self.min.update(min.clone());
+        self.max.update(max.clone());
+
+        let qparams = self.scheme.compute_q_params(CalibrationRange { min, max });
+        input.fake_quantize(&self.scheme, qparams)
+    }
+
+    /// Whether a range has been observed, the running range is infinite before the first batch.
+    fn is_observed(&self) -> bool {
+        !self
+            .min
+            .value()
+            .abs()
+            .equal_elem(f32::INFINITY)
+            .any()
+            .into_scalar()
+    }
+
+    /// Moving average of the observed values, initialized with the first observed batch.
+    fn update(&self, running: Tensor<B, 1>, batch: Tensor<B, 1>) -> Tensor<B, 1> {
+        let first = running.clone().abs().equal_elem(f32::INFINITY);
+        running
+            .mul_scalar(1.0 - self.momentum)
+            .add(batch.clone().mul_scalar(self.momentum))
+            .mask_where(first, batch)
+            .detach()
+    }
+}
+
+impl<B: Backend> ModuleDisplay for FakeQuantize<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("scheme", &self.scheme)
+            .add("momentum", &self.momentum)
+            .optional()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::quantization::QuantizationType;
+    use crate::tensor::TensorData;
+    use crate::{module::AutodiffModule, TestAutodiffBackend, TestBackend};
+
+    #[test]
+    fn fake_quantize_observes_moving_range() {
+        let device = Default::default();
+        let module =
+            FakeQuantizeConfig::new(QuantizationScheme::PerTensorAffine(QuantizationType::QInt8))
+                .with_momentum(0.5)
+                .init::<TestAutodiffBackend>(&device);
+
+        let _ = module.forward(Tensor::<TestAutodiffBackend, 1>::from_floats(
+            [-1.0, 0.0, 2.0],
+            &device,
+        ));
+        let _ = module.forward(Tensor::<TestAutodiffBackend, 1>::from_floats(
+            [-3.0, 0.0, 4.0],
+            &device,
+        ));
+
+        let range = module.range();
+        range
+            .min
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-2.0]), 3);
+        range
+            .max
+            .into_data()
+            .assert_approx_eq(&TensorData::from([3.0]), 3);
+    }
+
+    #[test]
+    fn fake_quantize_matches_quantization() {
+        let device = Default::default();
+        let module = FakeQuantizeConfig::new(QuantizationScheme::PerTensorSymmetric(
+            QuantizationType::QInt8,
+        ))
+        .init::<TestAutodiffBackend>(&device);
+        let input = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0], [0.5, 0.33, 1.1]],
+            &device,
+        );
+
+        let output = module.forward(input.clone()).into_data();
+        let expected = module.valid().forward(input.inner()).into_data();
+
+        output.assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn fake_quantize_passes_through_before_observation() {
+        let device = Default::default();
+        let module =
+            FakeQuantizeConfig::new(QuantizationScheme::PerTensorAffine(QuantizationType::QInt8))
+                .init::<TestAutodiffBackend>(&device)
+                .valid();
+        let input = Tensor::<TestBackend, 1>::from_floats([-1.8, 0.33, 1.1], &device);
+
+        let output = module.forward(input.clone());
+
+        output.into_data().assert_eq(&input.into_data(), true);
+    }
+
+    #[test]
+    fn fake_quantize_gradients_pass_straight_through() {
+        let device = Default::default();
+        let module = FakeQuantizeConfig::new(QuantizationScheme::PerTensorSymmetric(
+            QuantizationType::QInt8,
+        ))
+        .init::<TestAutodiffBackend>(&device);
+        let input = Tensor::<TestAutodiffBackend, 1>::from_floats([-1.8, 0.33, 1.1], &device)
+            .require_grad();
+
+        let output = module.forward(input.clone());
+        let grads = output.sum().backward();
+
+        input
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 1.0, 1.0]), false);
+    }
+
+    #[test]
+    fn display() {
+        let config =
+            FakeQuantizeConfig::new(QuantizationScheme::PerTensorAffine(QuantizationType::QInt8));
+        let layer = config.init::<TestAutodiffBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", layer),
+            "FakeQuantize {scheme: PerTensorAffine(QInt8), momentum: 0.01, params: 2}"
+        );
+    }
+}
@@ -3,6 +3,7 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Param;
 use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::tensor::module::linear;
 use crate::tensor::{backend::Backend, Tensor};
 
 use super::Initializer;
@@ -70,17 +71,11 @@ impl<B: Backend> Linear<B> {
     /// - input: `[..., d_input]`
     /// - output: `[..., d_output]`
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        if D == 1 {
-            // Insert and remove an extra batch dimension for the batch matmul to work.
-            return Self::forward::<2>(self, input.unsqueeze()).flatten(0, 1);
-        }
-
-        let output = input.matmul(self.weight.val().unsqueeze());
-
-        match &self.bias {
-            Some(bias) => output + bias.val().unsqueeze(),
-            None => output,
-        }
+        linear(
+            input,
+            self.weight.val(),
+            self.bias.as_ref().map(|bias| bias.val()),
+        )
     }
 }
 
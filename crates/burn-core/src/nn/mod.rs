@@ -21,6 +21,7 @@ pub mod interpolate;
 
 mod dropout;
 mod embedding;
+mod fake_quantize;
 mod gelu;
 mod hard_sigmoid;
 mod initializer;
@@ -40,6 +41,7 @@ mod unfold;
 
 pub use dropout::*;
 pub use embedding::*;
+pub use fake_quantize::*;
 pub use gelu::*;
 pub use hard_sigmoid::*;
 pub use initializer::*;
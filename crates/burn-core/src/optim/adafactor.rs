@@ -0,0 +1,315 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Adafactor configuration.
+#[derive(Config)]
+pub struct AdafactorConfig {
+    /// Exponent of the decay rate of the second moment, which is given by `1 - t^beta_2_decay`.
+    #[config(default = -0.8)]
+    beta_2_decay: f32,
+    /// A value required for numerical stability of the second moment.
+    #[config(default = 1.1920929e-07)]
+    epsilon_1: f32,
+    /// Lower bound of the parameter scale used to compute the step size.
+    #[config(default = 1e-3)]
+    epsilon_2: f32,
+    /// Threshold of the root mean square of the update, above which the update is scaled down.
+    #[config(default = 1.0)]
+    clip_threshold: f32,
+    /// Decoupled weight decay penalty.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Adafactor optimizer as described in the paper [Adafactor: Adaptive Learning Rates with Sublinear Memory Cost](https://arxiv.org/abs/1804.04235).
+///
+/// For tensors with at least two dimensions, the second moment is factored over the last two
+/// dimensions, so only the row and column averages are stored. The step size is relative to the
+/// scale of the parameter and capped by the learning rate.
+#[derive(Clone)]
+pub struct Adafactor<B: Backend> {
+    beta_2_decay: f32,
+    epsilon_1: f32,
+    epsilon_2: f32,
+    clip_threshold: f32,
+    weight_decay: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// Adafactor state.
+///
+/// Factored tensors have a row and a column variance, other tensors have a full variance.
+#[derive(Record, Clone, new)]
+pub struct AdafactorState<B: Backend, const D: usize> {
+    time: usize,
+    row_var: Option<Tensor<B, D>>,
+    col_var: Option<Tensor<B, D>>,
+    variance: Option<Tensor<B, D>>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Adafactor<B> {
+    type State<const D: usize> = AdafactorState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let grad_squared = grad.clone().powf_scalar(2.0);
+        let mut state = match state {
+            Some(state) => state,
+            None if D >= 2 => AdafactorState::new(
+                0,
+                Some(grad_squared.clone().mean_dim(D - 1).zeros_like()),
+                Some(grad_squared.clone().mean_dim(D - 2).zeros_like()),
+                None,
+            ),
+            None => AdafactorState::new(0, None, None, Some(grad_squared.zeros_like())),
+        };
+        state.time += 1;
+
+        let decay = (state.time as f32).powf(self.beta_2_decay);
+        let rho = f64::min(lr, 1.0 / (state.time as f64).sqrt());
+        let alpha = Self::rms(tensor.clone())
+            .clamp_min(self.epsilon_2)
+            .mul_scalar(rho);
+
+        let tensor = tensor.mul_scalar(1.0 - lr * self.weight_decay as f64);
+
+        let variance = match (state.row_var.take(), state.col_var.take()) {
+            (Some(row_var), Some(col_var)) => {
+                let row_var = Self::lerp(row_var, grad_squared.clone().mean_dim(D - 1), decay);
+                let col_var = Self::lerp(col_var, grad_squared.mean_dim(D - 2), decay);
+                let row_var_mean = row_var.clone().mean_dim(D - 2).clamp_min(self.epsilon_1);
+                let variance = row_var.clone().mul(col_var.clone()).div(row_var_mean);
+
+                state.row_var = Some(row_var);
+                state.col_var = Some(col_var);
+                variance
+            }
+            _ => {
+                let variance = match state.variance.take() {
+                    Some(variance) => Self::lerp(variance, grad_squared, decay),
+                    None => grad_squared,
+                };
+
+                state.variance = Some(variance.clone());
+                variance
+            }
+        };
+
+        let update = variance
+            .clamp_min(self.epsilon_1 * self.epsilon_1)
+            .sqrt()
+            .recip()
+            .mul(grad);
+        let denom = Self::rms(update.clone())
+            .div_scalar(self.clip_threshold)
+            .clamp_min(1.0);
+        let delta = update.mul(alpha.div(denom).unsqueeze());
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.row_var = state.row_var.map(|tensor| tensor.to_device(device));
+        state.col_var = state.col_var.map(|tensor| tensor.to_device(device));
+        state.variance = state.variance.map(|tensor| tensor.to_device(device));
+        state
+    }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: penalty as f32,
+            ..self.clone()
+        })
+    }
+}
+
+impl<B: Backend> Adafactor<B> {
+    /// Root mean square of all the elements of the tensor.
+    fn rms<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 1> {
+        tensor.powf_scalar(2.0).mean().sqrt()
+    }
+
+    /// Move the running average towards the new value by the given weight.
+    fn lerp<const D: usize>(
+        average: Tensor<B, D>,
+        value: Tensor<B, D>,
+        weight: f32,
+    ) -> Tensor<B, D> {
+        average.clone().add(value.sub(average).mul_scalar(weight))
+    }
+}
+
+impl AdafactorConfig {
+    /// Initialize Adafactor optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Adafactor<B::InnerBackend>, M, B> {
+        let optim = Adafactor {
+            beta_2_decay: self.beta_2_decay,
+            epsilon_1: self.epsilon_1,
+            epsilon_2: self.epsilon_2,
+            clip_threshold: self.clip_threshold,
+            weight_decay: self.weight_decay,
+            _phantom: PhantomData,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, Tensor, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use tempfile::TempDir;
+
+    const LEARNING_RATE: LearningRate = 0.01;
+    const ASSERT_PRECISION: usize = 5;
+
+    #[test]
+    fn test_adafactor_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_adafactor();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let temp_dir = TempDir::new().unwrap();
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(
+                optimizer.to_record(),
+                temp_dir.path().join("test_optim_adafactor"),
+            )
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_adafactor();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_adafactor_optimizer_with_numbers() {
+        let device = Default::default();
+        let linear = given_linear_layer(
+            TensorData::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            TensorData::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+                [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+            ],
+            &device,
+        )
+        .require_grad();
+        let x_2 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+                [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let mut optimizer = AdafactorConfig::new().with_weight_decay(0.5).init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let weights_expected = TensorData::from([
+            [-0.322088, 0.131343, 0.395581, 0.312122, 0.080357, 0.061745],
+            [
+                0.073653, -0.021587, -0.366314, 0.249185, 0.190278, -0.292557,
+            ],
+            [
+                -0.023529, 0.029536, -0.297964, 0.241203, -0.279946, 0.305159,
+            ],
+            [
+                -0.299823, -0.223987, -0.372590, -0.299922, -0.080137, 0.156182,
+            ],
+            [
+                0.322152, -0.220580, 0.363436, -0.175732, 0.371257, -0.034653,
+            ],
+            [-0.020435, -0.016573, 0.119852, 0.185491, 0.024315, 0.374783],
+        ]);
+        let bias_expected =
+            TensorData::from([-0.390262, 0.083861, -0.099689, 0.112770, 0.131580, 0.009213]);
+
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, ASSERT_PRECISION);
+        weight_updated.assert_approx_eq(&weights_expected, ASSERT_PRECISION);
+    }
+
+    fn given_linear_layer(weight: TensorData, bias: TensorData) -> nn::Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let record = nn::LinearRecord {
+            weight: Param::from_data(weight, &device),
+            bias: Some(Param::from_data(bias, &device)),
+        };
+
+        nn::LinearConfig::new(6, 6)
+            .init(&device)
+            .load_record(record)
+    }
+
+    fn create_adafactor() -> OptimizerAdaptor<
+        Adafactor<TestBackend>,
+        nn::Linear<TestAutodiffBackend>,
+        TestAutodiffBackend,
+    > {
+        AdafactorConfig::new().init()
+    }
+}
@@ -72,6 +72,13 @@ impl<B: Backend> SimpleOptimizer<B> for AdaGrad<B> {
         state.lr_decay = state.lr_decay.to_device(device);
         state
     }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: Some(WeightDecay::new(&WeightDecayConfig::new(penalty))),
+            ..self.clone()
+        })
+    }
 }
 
 impl AdaGradConfig {
@@ -78,6 +78,13 @@ impl<B: Backend> SimpleOptimizer<B> for Adam<B> {
         state.momentum = state.momentum.to_device(device);
         state
     }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: Some(WeightDecay::new(&WeightDecayConfig::new(penalty))),
+            ..self.clone()
+        })
+    }
 }
 
 impl AdamConfig {
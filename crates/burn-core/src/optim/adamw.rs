@@ -76,6 +76,13 @@ impl<B: Backend> SimpleOptimizer<B> for AdamW<B> {
         state.momentum = state.momentum.to_device(device);
         state
     }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: penalty as f32,
+            ..self.clone()
+        })
+    }
 }
 
 impl AdamWConfig {
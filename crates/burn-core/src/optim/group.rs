@@ -0,0 +1,367 @@
+use crate as burn;
+
+use crate::config::Config;
+use alloc::{string::String, sync::Arc};
+use core::fmt::Debug;
+
+/// Configuration of a [parameter group](ParamGroup).
+#[derive(Config, Debug, PartialEq)]
+pub struct ParamGroupConfig {
+    /// Name of the group, used to match the group when loading an optimizer record.
+    pub name: String,
+    /// Multiplier applied to the learning rate of the optimizer.
+    #[config(default = 1.0)]
+    pub lr_multiplier: f64,
+    /// Weight decay penalty replacing the one of the optimizer.
+    pub weight_decay: Option<f64>,
+    /// Whether the parameters are frozen, so they are never updated and have no optimizer state.
+    #[config(default = false)]
+    pub frozen: bool,
+}
+
+/// Selects the parameters belonging to a [parameter group](ParamGroup) from their module path.
+#[derive(Clone)]
+enum ParamSelector {
+    Prefix(String),
+    Predicate(Arc<dyn Fn(&str) -> bool + Send + Sync>),
+}
+
+/// A group of parameters optimized with their own settings.
+///
+/// Parameters are selected using their module path, which is the name of each field leading to
+/// the parameter joined by a dot (e.g. `backbone.conv1.weight`), see
+/// [list_param_paths](crate::module::list_param_paths).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let optim = AdamConfig::new()
+///     .init()
+///     .with_param_group(ParamGroup::from_prefix("backbone").with_lr_multiplier(0.1))
+///     .with_param_group(ParamGroup::from_prefix("embedding").with_frozen(true));
+/// ```
+#[derive(Clone)]
+pub struct ParamGroup {
+    selector: ParamSelector,
+    config: ParamGroupConfig,
+}
+
+impl ParamGroup {
+    /// Create a group selecting the parameters of the module at the given path, named after the
+    /// path.
+    pub fn from_prefix(prefix: impl Into<String>) -> Self {
+        let prefix = prefix.into();
+
+        Self {
+            config: ParamGroupConfig::new(prefix.clone()),
+            selector: ParamSelector::Prefix(prefix),
+        }
+    }
+
+    /// Create a group selecting the parameters whose path satisfies the given predicate.
+    pub fn from_predicate<F>(name: impl Into<String>, predicate: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            config: ParamGroupConfig::new(name.into()),
+            selector: ParamSelector::Predicate(Arc::new(predicate)),
+        }
+    }
+
+    /// Set the learning rate multiplier.
+    pub fn with_lr_multiplier(mut self, lr_multiplier: f64) -> Self {
+        self.config.lr_multiplier = lr_multiplier;
+        self
+    }
+
+    /// Set the weight decay penalty, replacing the one of the optimizer.
+    pub fn with_weight_decay(mut self, penalty: f64) -> Self {
+        self.config.weight_decay = Some(penalty);
+        self
+    }
+
+    /// Set whether the parameters are frozen.
+    pub fn with_frozen(mut self, frozen: bool) -> Self {
+        self.config.frozen = frozen;
+        self
+    }
+
+    /// The configuration of the group.
+    pub fn config(&self) -> &ParamGroupConfig {
+        &self.config
+    }
+
+    /// Whether the parameter with the given module path belongs to the group.
+    pub fn contains(&self, path: &str) -> bool {
+        match &self.selector {
+            ParamSelector::Prefix(prefix) => {
+                prefix.is_empty()
+                    || path
+                        .strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+            }
+            ParamSelector::Predicate(predicate) => predicate(path),
+        }
+    }
+
+    /// Create a group from a recorded configuration, reusing the selector of the given group.
+    ///
+    /// Without a group to take the selector from, the group doesn't select any new parameter.
+    pub(crate) fn restore(config: ParamGroupConfig, group: Option<&ParamGroup>) -> Self {
+        let selector = match group {
+            Some(group) => group.selector.clone(),
+            None => ParamSelector::Predicate(Arc::new(|_| false)),
+        };
+
+        Self { selector, config }
+    }
+}
+
+impl Debug for ParamGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut debug = f.debug_struct("ParamGroup");
+        if let ParamSelector::Prefix(prefix) = &self.selector {
+            debug.field("prefix", prefix);
+        }
+        debug.field("config", &self.config).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        optim::{decay::WeightDecayConfig, GradientsParams, Optimizer, SgdConfig},
+        record::{BinBytesRecorder, FullPrecisionSettings, NamedMpkBytesRecorder, Recorder},
+        tensor::{backend::Backend, Distribution, Tensor},
+        TestAutodiffBackend,
+    };
+
+    type TestModel = Model<TestAutodiffBackend>;
+
+    const LEARNING_RATE: f64 = 0.1;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        backbone: Linear<B>,
+        head: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                backbone: LinearConfig::new(4, 4).init(device),
+                head: LinearConfig::new(4, 2).init(device),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            self.head.forward(self.backbone.forward(input))
+        }
+    }
+
+    /// Run an optimizer step on each given model, using the same input.
+    fn step<O: Optimizer<TestModel, TestAutodiffBackend>>(
+        optims: &mut [O],
+        model: TestModel,
+    ) -> Vec<TestModel> {
+        let device = Default::default();
+        let input = Tensor::random([2, 4], Distribution::Default, &device);
+
+        optims
+            .iter_mut()
+            .map(|optim| {
+                let grads = model.forward(input.clone()).sum().backward();
+                let grads = GradientsParams::from_grads(grads, &model);
+                optim.step(LEARNING_RATE, model.clone(), grads)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn prefix_should_match_whole_path_segments() {
+        let group = ParamGroup::from_prefix("backbone.conv");
+
+        assert!(group.contains("backbone.conv"));
+        assert!(group.contains("backbone.conv.weight"));
+        assert!(!group.contains("backbone.conv2.weight"));
+        assert!(!group.contains("head.weight"));
+    }
+
+    #[test]
+    fn predicate_should_select_params() {
+        let group = ParamGroup::from_predicate("bias", |path| path.ends_with(".bias"));
+
+        assert!(group.contains("head.bias"));
+        assert!(!group.contains("head.weight"));
+    }
+
+    #[test]
+    fn frozen_group_should_not_be_updated() {
+        let device = Default::default();
+        let model = TestModel::new(&device);
+        let mut optims = [SgdConfig::new()
+            .init()
+            .with_param_group(ParamGroup::from_prefix("backbone").with_frozen(true))];
+
+        let updated = step(&mut optims, model.clone()).remove(0);
+
+        updated
+            .backbone
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&model.backbone.weight.val().into_data(), true);
+        assert!(!updated.backbone.weight.is_require_grad());
+        assert_ne!(
+            updated.head.weight.val().into_data(),
+            model.head.weight.val().into_data()
+        );
+        // Only the head weight and bias have a state.
+        assert_eq!(optims[0].to_record().len(), 2);
+    }
+
+    #[test]
+    fn lr_multiplier_should_scale_the_learning_rate() {
+        let device = Default::default();
+        let model = TestModel::new(&device);
+        let mut optims = [
+            SgdConfig::new()
+                .init()
+                .with_param_group(ParamGroup::from_prefix("head").with_lr_multiplier(0.5)),
+            SgdConfig::new().init(),
+        ];
+
+        let updated = step(&mut optims, model.clone());
+
+        // With plain SGD, the update is proportional to the learning rate.
+        let delta = |updated: &TestModel| model.head.weight.val() - updated.head.weight.val();
+        delta(&updated[0])
+            .mul_scalar(2)
+            .into_data()
+            .assert_approx_eq(&delta(&updated[1]).into_data(), 5);
+        updated[0]
+            .backbone
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&updated[1].backbone.weight.val().into_data(), 5);
+    }
+
+    #[test]
+    fn group_weight_decay_should_replace_the_optimizer_one() {
+        let device = Default::default();
+        let model = TestModel::new(&device);
+        let mut optims = [
+            SgdConfig::new().init().with_param_group(
+                ParamGroup::from_predicate("weights", |path| path.ends_with(".weight"))
+                    .with_weight_decay(0.5),
+            ),
+            SgdConfig::new()
+                .with_weight_decay(Some(WeightDecayConfig::new(0.5)))
+                .init(),
+        ];
+
+        let updated = step(&mut optims, model);
+
+        updated[0]
+            .head
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&updated[1].head.weight.val().into_data(), 5);
+        assert_ne!(
+            updated[0].head.bias.as_ref().unwrap().val().into_data(),
+            updated[1].head.bias.as_ref().unwrap().val().into_data()
+        );
+    }
+
+    #[test]
+    fn groups_should_be_restored_from_the_record() {
+        let device = Default::default();
+        let model = TestModel::new(&device);
+        let mut optims = [SgdConfig::new().init().with_param_group(
+            ParamGroup::from_prefix("backbone")
+                .with_frozen(true)
+                .with_lr_multiplier(0.1),
+        )];
+        let model = step(&mut optims, model).remove(0);
+
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(optims[0].to_record(), ()).unwrap();
+        let record = recorder.load(bytes, &device).unwrap();
+        let mut optims = [SgdConfig::new().init().load_record(record)];
+
+        let record = optims[0].to_record();
+        assert_eq!(
+            record.groups,
+            vec![ParamGroupConfig::new("backbone".to_string())
+                .with_frozen(true)
+                .with_lr_multiplier(0.1)]
+        );
+        assert_eq!(record.assignments.len(), 2);
+
+        let updated = step(&mut optims, model.clone()).remove(0);
+        updated
+            .backbone
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&model.backbone.weight.val().into_data(), true);
+    }
+
+    #[test]
+    fn record_without_groups_should_be_loaded() {
+        let device = Default::default();
+        let model = TestModel::new(&device);
+        let mut optims = [SgdConfig::new().init()];
+        let model = step(&mut optims, model).remove(0);
+
+        // Records saved before the parameter groups only contain the state of each parameter.
+        let states = optims[0].to_record().states;
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(states, ()).unwrap();
+        let record = recorder.load(bytes, &device).unwrap();
+        let mut optims = [SgdConfig::new()
+            .init()
+            .with_param_group(ParamGroup::from_prefix("backbone").with_frozen(true))
+            .load_record(record)];
+
+        let record = optims[0].to_record();
+        assert_eq!(record.len(), 4);
+        assert!(record.groups.is_empty());
+
+        // The configured groups are kept.
+        let updated = step(&mut optims, model.clone()).remove(0);
+        updated
+            .backbone
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&model.backbone.weight.val().into_data(), true);
+    }
+
+    #[test]
+    fn binary_record_without_groups_should_be_loaded() {
+        let device = Default::default();
+        let model = TestModel::new(&device);
+        let mut optims = [SgdConfig::new().init()];
+        let model = step(&mut optims, model).remove(0);
+
+        // Records saved before the parameter groups only contain the state of each parameter.
+        let states = optims[0].to_record().states;
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(states, ()).unwrap();
+        let record = recorder.load(bytes, &device).unwrap();
+        let mut optims = [SgdConfig::new().init().load_record(record)];
+        step(&mut optims, model);
+
+        let record = optims[0].to_record();
+        assert_eq!(record.len(), 4);
+        assert!(record.groups.is_empty());
+        assert!(record.assignments.is_empty());
+    }
+}
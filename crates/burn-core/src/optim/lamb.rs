@@ -0,0 +1,274 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// LAMB configuration.
+#[derive(Config)]
+pub struct LambConfig {
+    /// Parameter for LAMB.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for LAMB.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-6)]
+    epsilon: f32,
+    /// Decoupled weight decay penalty, added to the update before computing the trust ratio.
+    #[config(default = 0.01)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// LAMB optimizer as described in the paper [Large Batch Optimization for Deep Learning: Training BERT in 76 minutes](https://arxiv.org/abs/1904.00962).
+///
+/// The Adam update of each parameter tensor is scaled by a trust ratio, which is the ratio
+/// between the norm of the parameter and the norm of its update.
+#[derive(Clone)]
+pub struct Lamb<B: Backend> {
+    beta_1: f32,
+    beta_2: f32,
+    epsilon: f32,
+    weight_decay: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// LAMB state.
+#[derive(Record, Clone, new)]
+pub struct LambState<B: Backend, const D: usize> {
+    time: usize,
+    moment_1: Tensor<B, D>,
+    moment_2: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lamb<B> {
+    type State<const D: usize> = LambState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut state = match state {
+            Some(state) => state,
+            None => LambState::new(0, grad.zeros_like(), grad.zeros_like()),
+        };
+        state.time += 1;
+
+        state.moment_1 = state
+            .moment_1
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1));
+        state.moment_2 = state
+            .moment_2
+            .mul_scalar(self.beta_2)
+            .add(grad.powf_scalar(2.0).mul_scalar(1.0 - self.beta_2));
+
+        let time = state.time as i32;
+        let moment_1_corrected = state
+            .moment_1
+            .clone()
+            .div_scalar(1.0 - self.beta_1.powi(time));
+        let moment_2_corrected = state
+            .moment_2
+            .clone()
+            .div_scalar(1.0 - self.beta_2.powi(time));
+
+        let update = moment_1_corrected
+            .div(moment_2_corrected.sqrt().add_scalar(self.epsilon))
+            .add(tensor.clone().mul_scalar(self.weight_decay));
+
+        // The trust ratio falls back to 1 when either norm is zero.
+        let tensor_norm = tensor.clone().powf_scalar(2.0).sum().sqrt();
+        let update_norm = update.clone().powf_scalar(2.0).sum().sqrt();
+        let trust_ratio = tensor_norm
+            .clone()
+            .div(update_norm.clone())
+            .mask_fill(tensor_norm.equal_elem(0.0), 1.0)
+            .mask_fill(update_norm.equal_elem(0.0), 1.0);
+
+        let delta = update.mul(trust_ratio.unsqueeze()).mul_scalar(lr);
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.moment_1 = state.moment_1.to_device(device);
+        state.moment_2 = state.moment_2.to_device(device);
+        state
+    }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: penalty as f32,
+            ..self.clone()
+        })
+    }
+}
+
+impl LambConfig {
+    /// Initialize LAMB optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Lamb<B::InnerBackend>, M, B> {
+        let optim = Lamb {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            epsilon: self.epsilon,
+            weight_decay: self.weight_decay,
+            _phantom: PhantomData,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, Tensor, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use tempfile::TempDir;
+
+    const LEARNING_RATE: LearningRate = 0.01;
+    const ASSERT_PRECISION: usize = 5;
+
+    #[test]
+    fn test_lamb_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_lamb();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let temp_dir = TempDir::new().unwrap();
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(
+                optimizer.to_record(),
+                temp_dir.path().join("test_optim_lamb"),
+            )
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_lamb();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lamb_optimizer_with_numbers() {
+        let device = Default::default();
+        let linear = given_linear_layer(
+            TensorData::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            TensorData::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+                [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+            ],
+            &device,
+        )
+        .require_grad();
+        let x_2 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+                [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let mut optimizer = LambConfig::new().init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let weights_expected = TensorData::from([
+            [-0.325503, 0.132474, 0.399361, 0.315065, 0.080977, 0.062178],
+            [
+                0.073201, -0.022995, -0.371177, 0.250492, 0.190995, -0.296681,
+            ],
+            [
+                -0.023922, 0.029675, -0.301109, 0.243465, -0.282909, 0.308061,
+            ],
+            [
+                -0.302916, -0.226320, -0.376412, -0.303016, -0.081027, 0.157661,
+            ],
+            [
+                0.325085, -0.223088, 0.366783, -0.177790, 0.374683, -0.035297,
+            ],
+            [-0.020819, -0.016919, 0.120874, 0.187171, 0.024379, 0.378361],
+        ]);
+        let bias_expected =
+            TensorData::from([-0.394162, 0.084721, -0.100673, 0.113920, 0.132919, 0.009323]);
+
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, ASSERT_PRECISION);
+        weight_updated.assert_approx_eq(&weights_expected, ASSERT_PRECISION);
+    }
+
+    fn given_linear_layer(weight: TensorData, bias: TensorData) -> nn::Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let record = nn::LinearRecord {
+            weight: Param::from_data(weight, &device),
+            bias: Some(Param::from_data(bias, &device)),
+        };
+
+        nn::LinearConfig::new(6, 6)
+            .init(&device)
+            .load_record(record)
+    }
+
+    fn create_lamb(
+    ) -> OptimizerAdaptor<Lamb<TestBackend>, nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>
+    {
+        LambConfig::new().init()
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    momentum::{Momentum, MomentumConfig, MomentumState},
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// LARS configuration.
+#[derive(Config)]
+pub struct LarsConfig {
+    /// Coefficient scaling the layer-wise learning rate.
+    #[config(default = 0.001)]
+    trust_coefficient: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-8)]
+    epsilon: f32,
+    /// L2 penalty, also taken into account when computing the trust ratio.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// [Momentum](MomentumConfig) config.
+    momentum: Option<MomentumConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// LARS optimizer as described in the paper [Large Batch Training of Convolutional Networks](https://arxiv.org/abs/1708.03888).
+///
+/// The gradient of each parameter tensor is scaled by a trust ratio, which is proportional to
+/// the ratio between the norm of the parameter and the norm of its gradient.
+#[derive(Clone)]
+pub struct Lars<B: Backend> {
+    trust_coefficient: f32,
+    epsilon: f32,
+    weight_decay: f32,
+    momentum: Option<Momentum<B>>,
+}
+
+/// LARS state.
+#[derive(Record, Clone, new)]
+pub struct LarsState<B: Backend, const D: usize> {
+    momentum: Option<MomentumState<B, D>>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lars<B> {
+    type State<const D: usize> = LarsState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        // The trust ratio falls back to 1 when either norm is zero.
+        let tensor_norm = tensor.clone().powf_scalar(2.0).sum().sqrt();
+        let grad_norm = grad.clone().powf_scalar(2.0).sum().sqrt();
+        let trust_ratio = tensor_norm
+            .clone()
+            .mul_scalar(self.trust_coefficient)
+            .div(
+                grad_norm
+                    .clone()
+                    .add(tensor_norm.clone().mul_scalar(self.weight_decay))
+                    .add_scalar(self.epsilon),
+            )
+            .mask_fill(tensor_norm.equal_elem(0.0), 1.0)
+            .mask_fill(grad_norm.equal_elem(0.0), 1.0);
+
+        let mut grad = grad
+            .add(tensor.clone().mul_scalar(self.weight_decay))
+            .mul(trust_ratio.unsqueeze());
+
+        let mut state_momentum = state.and_then(|state| state.momentum);
+        if let Some(momentum) = &self.momentum {
+            let (grad_out, state) = momentum.transform(grad, state_momentum);
+            state_momentum = Some(state);
+            grad = grad_out;
+        }
+
+        (
+            tensor - grad.mul_scalar(lr),
+            Some(LarsState::new(state_momentum)),
+        )
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.map(|state| state.to_device(device));
+        state
+    }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: penalty as f32,
+            ..self.clone()
+        })
+    }
+}
+
+impl LarsConfig {
+    /// Initialize LARS optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Lars<B::InnerBackend>, M, B> {
+        let optim = Lars {
+            trust_coefficient: self.trust_coefficient,
+            epsilon: self.epsilon,
+            weight_decay: self.weight_decay,
+            momentum: self.momentum.as_ref().map(Momentum::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, Tensor, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use tempfile::TempDir;
+
+    const LEARNING_RATE: LearningRate = 0.01;
+    const ASSERT_PRECISION: usize = 5;
+
+    #[test]
+    fn test_lars_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_lars();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let temp_dir = TempDir::new().unwrap();
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(
+                optimizer.to_record(),
+                temp_dir.path().join("test_optim_lars"),
+            )
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_lars();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lars_optimizer_with_numbers() {
+        let device = Default::default();
+        let linear = given_linear_layer(
+            TensorData::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            TensorData::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+                [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+            ],
+            &device,
+        )
+        .require_grad();
+        let x_2 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+                [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let mut optimizer = LarsConfig::new()
+            .with_trust_coefficient(0.02)
+            .with_weight_decay(0.5)
+            .with_momentum(Some(MomentumConfig::new().with_dampening(0.0)))
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let weights_expected = TensorData::from([
+            [-0.320715, 0.137262, 0.404148, 0.319852, 0.085764, 0.066965],
+            [
+                0.077619, -0.018576, -0.366758, 0.254910, 0.195413, -0.292262,
+            ],
+            [
+                -0.019158, 0.034440, -0.296343, 0.248229, -0.278144, 0.312825,
+            ],
+            [
+                -0.298132, -0.221536, -0.371628, -0.298232, -0.076243, 0.162445,
+            ],
+            [
+                0.329878, -0.218294, 0.371576, -0.172996, 0.379476, -0.030503,
+            ],
+            [-0.016028, -0.012129, 0.125664, 0.191961, 0.029169, 0.383151],
+        ]);
+        let bias_expected =
+            TensorData::from([-0.390592, 0.088296, -0.097099, 0.117495, 0.136495, 0.012898]);
+
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, ASSERT_PRECISION);
+        weight_updated.assert_approx_eq(&weights_expected, ASSERT_PRECISION);
+    }
+
+    fn given_linear_layer(weight: TensorData, bias: TensorData) -> nn::Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let record = nn::LinearRecord {
+            weight: Param::from_data(weight, &device),
+            bias: Some(Param::from_data(bias, &device)),
+        };
+
+        nn::LinearConfig::new(6, 6)
+            .init(&device)
+            .load_record(record)
+    }
+
+    fn create_lars(
+    ) -> OptimizerAdaptor<Lars<TestBackend>, nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>
+    {
+        LarsConfig::new().init()
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Lion configuration.
+#[derive(Config)]
+pub struct LionConfig {
+    /// Coefficient used to interpolate the momentum and the gradient when computing the update.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Coefficient used to update the momentum.
+    #[config(default = 0.99)]
+    beta_2: f32,
+    /// Decoupled weight decay penalty.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Lion optimizer as described in the paper [Symbolic Discovery of Optimization Algorithms](https://arxiv.org/abs/2302.06675).
+///
+/// The update is the sign of an interpolation between the momentum and the gradient, so it
+/// usually requires a smaller learning rate than Adam.
+#[derive(Clone)]
+pub struct Lion<B: Backend> {
+    beta_1: f32,
+    beta_2: f32,
+    weight_decay: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// Lion state.
+#[derive(Record, Clone, new)]
+pub struct LionState<B: Backend, const D: usize> {
+    momentum: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lion<B> {
+    type State<const D: usize> = LionState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let momentum = match state {
+            Some(state) => state.momentum,
+            None => grad.zeros_like(),
+        };
+
+        let update = momentum
+            .clone()
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1))
+            .sign();
+        let momentum = momentum
+            .mul_scalar(self.beta_2)
+            .add(grad.mul_scalar(1.0 - self.beta_2));
+
+        let tensor = tensor.clone() - tensor.mul_scalar(lr).mul_scalar(self.weight_decay);
+
+        (
+            tensor - update.mul_scalar(lr),
+            Some(LionState::new(momentum)),
+        )
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: penalty as f32,
+            ..self.clone()
+        })
+    }
+}
+
+impl LionConfig {
+    /// Initialize Lion optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Lion<B::InnerBackend>, M, B> {
+        let optim = Lion {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            weight_decay: self.weight_decay,
+            _phantom: PhantomData,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, Tensor, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use tempfile::TempDir;
+
+    const LEARNING_RATE: LearningRate = 0.01;
+    const ASSERT_PRECISION: usize = 5;
+
+    #[test]
+    fn test_lion_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_lion();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let temp_dir = TempDir::new().unwrap();
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(
+                optimizer.to_record(),
+                temp_dir.path().join("test_optim_lion"),
+            )
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_lion();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lion_optimizer_with_numbers() {
+        let device = Default::default();
+        let linear = given_linear_layer(
+            TensorData::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            TensorData::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+                [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+            ],
+            &device,
+        )
+        .require_grad();
+        let x_2 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+                [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let mut optimizer = LionConfig::new().with_weight_decay(0.5).init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let weights_expected = TensorData::from([
+            [-0.337352, 0.116079, 0.380317, 0.296858, 0.065093, 0.046481],
+            [
+                0.056975, -0.038265, -0.382992, 0.232506, 0.173600, -0.309235,
+            ],
+            [
+                -0.038760, 0.014305, -0.313195, 0.225972, -0.295177, 0.289928,
+            ],
+            [
+                -0.314977, -0.239142, -0.387744, -0.315076, -0.095291, 0.141028,
+            ],
+            [
+                0.306758, -0.235973, 0.348042, -0.191125, 0.355863, -0.050047,
+            ],
+            [-0.035691, -0.031830, 0.104595, 0.170234, 0.009058, 0.359527],
+        ]);
+        let bias_expected = TensorData::from([
+            -0.406555, 0.067568, -0.115982, 0.096477, 0.115287, -0.007080,
+        ]);
+
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, ASSERT_PRECISION);
+        weight_updated.assert_approx_eq(&weights_expected, ASSERT_PRECISION);
+    }
+
+    fn given_linear_layer(weight: TensorData, bias: TensorData) -> nn::Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let record = nn::LinearRecord {
+            weight: Param::from_data(weight, &device),
+            bias: Some(Param::from_data(bias, &device)),
+        };
+
+        nn::LinearConfig::new(6, 6)
+            .init(&device)
+            .load_record(record)
+    }
+
+    fn create_lion(
+    ) -> OptimizerAdaptor<Lion<TestBackend>, nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>
+    {
+        LionConfig::new().init()
+    }
+}
@@ -4,23 +4,41 @@ pub mod decay;
 /// Momentum module for optimizers.
 pub mod momentum;
 
+mod adafactor;
 mod adagrad;
 mod adam;
 mod adamw;
 mod base;
 mod grad_accum;
 mod grads;
+mod group;
+mod lamb;
+mod lars;
+mod lion;
+mod nadam;
+mod radam;
 mod rmsprop;
+mod scaler;
 mod sgd;
+mod sharded;
 mod simple;
 mod visitor;
 
+pub use adafactor::*;
 pub use adagrad::*;
 pub use adam::*;
 pub use adamw::*;
 pub use base::*;
 pub use grad_accum::*;
 pub use grads::*;
+pub use group::*;
+pub use lamb::*;
+pub use lars::*;
+pub use lion::*;
+pub use nadam::*;
+pub use radam::*;
 pub use rmsprop::*;
+pub use scaler::*;
 pub use sgd::*;
+pub use sharded::*;
 pub use simple::*;
@@ -0,0 +1,286 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    decay::{WeightDecay, WeightDecayConfig},
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// NAdam configuration.
+#[derive(Config)]
+pub struct NAdamConfig {
+    /// Parameter for NAdam.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for NAdam.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-8)]
+    epsilon: f32,
+    /// Momentum decay used to compute the momentum schedule.
+    #[config(default = 4e-3)]
+    momentum_decay: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// NAdam optimizer, which is Adam with Nesterov momentum, as described in the paper
+/// [Incorporating Nesterov Momentum into Adam](https://openreview.net/forum?id=OM0jvwB8jIp57ZJjtNEZ).
+#[derive(Clone)]
+pub struct NAdam<B: Backend> {
+    beta_1: f32,
+    beta_2: f32,
+    epsilon: f32,
+    momentum_decay: f32,
+    weight_decay: Option<WeightDecay<B>>,
+}
+
+/// NAdam state.
+#[derive(Record, Clone, new)]
+pub struct NAdamState<B: Backend, const D: usize> {
+    time: usize,
+    /// Product of the momentum coefficients of every step so far.
+    mu_product: f32,
+    moment_1: Tensor<B, D>,
+    moment_2: Tensor<B, D>,
+}
+
+impl<B: Backend> NAdam<B> {
+    /// Momentum coefficient of the given step.
+    fn mu(&self, time: usize) -> f32 {
+        self.beta_1 * (1.0 - 0.5 * 0.96f32.powf(time as f32 * self.momentum_decay))
+    }
+}
+
+impl<B: Backend> SimpleOptimizer<B> for NAdam<B> {
+    type State<const D: usize> = NAdamState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        if let Some(weight_decay) = &self.weight_decay {
+            grad = weight_decay.transform(grad, tensor.clone());
+        }
+
+        let mut state = match state {
+            Some(state) => state,
+            None => NAdamState::new(0, 1.0, grad.zeros_like(), grad.zeros_like()),
+        };
+        state.time += 1;
+
+        let mu = self.mu(state.time);
+        let mu_next = self.mu(state.time + 1);
+        state.mu_product *= mu;
+
+        state.moment_1 = state
+            .moment_1
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1));
+        state.moment_2 = state
+            .moment_2
+            .mul_scalar(self.beta_2)
+            .add(grad.clone().powf_scalar(2.0).mul_scalar(1.0 - self.beta_2));
+
+        let bias_correction_2 = 1.0 - self.beta_2.powi(state.time as i32);
+        let denominator = state
+            .moment_2
+            .clone()
+            .div_scalar(bias_correction_2)
+            .sqrt()
+            .add_scalar(self.epsilon);
+
+        let grad_factor = (1.0 - mu) / (1.0 - state.mu_product);
+        let moment_factor = mu_next / (1.0 - state.mu_product * mu_next);
+        let delta = grad
+            .mul_scalar(grad_factor)
+            .add(state.moment_1.clone().mul_scalar(moment_factor))
+            .div(denominator);
+
+        (tensor - delta.mul_scalar(lr), Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.moment_1 = state.moment_1.to_device(device);
+        state.moment_2 = state.moment_2.to_device(device);
+        state
+    }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: Some(WeightDecay::new(&WeightDecayConfig::new(penalty))),
+            ..self.clone()
+        })
+    }
+}
+
+impl NAdamConfig {
+    /// Initialize NAdam optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<NAdam<B::InnerBackend>, M, B> {
+        let optim = NAdam {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            epsilon: self.epsilon,
+            momentum_decay: self.momentum_decay,
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, Tensor, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use tempfile::TempDir;
+
+    const LEARNING_RATE: LearningRate = 0.01;
+    const ASSERT_PRECISION: usize = 5;
+
+    #[test]
+    fn test_nadam_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_nadam();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let temp_dir = TempDir::new().unwrap();
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(
+                optimizer.to_record(),
+                temp_dir.path().join("test_optim_nadam"),
+            )
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_nadam();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_nadam_optimizer_with_numbers() {
+        let device = Default::default();
+        let linear = given_linear_layer(
+            TensorData::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            TensorData::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+                [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+            ],
+            &device,
+        )
+        .require_grad();
+        let x_2 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+                [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let mut optimizer = NAdamConfig::new()
+            .with_weight_decay(Some(WeightDecayConfig::new(0.5)))
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let weights_expected = TensorData::from([
+            [-0.338429, 0.119469, 0.386325, 0.302038, 0.067979, 0.049182],
+            [
+                0.063873, -0.032005, -0.378656, 0.240668, 0.181326, -0.304548,
+            ],
+            [
+                -0.037014, 0.016579, -0.314173, 0.230354, -0.295976, 0.294947,
+            ],
+            [
+                -0.316258, -0.239662, -0.389753, -0.316357, -0.094369, 0.144320,
+            ],
+            [
+                0.312389, -0.235552, 0.354074, -0.190279, 0.361972, -0.047858,
+            ],
+            [-0.033824, -0.029925, 0.107849, 0.174137, 0.011367, 0.365307],
+        ]);
+        let bias_expected = TensorData::from([
+            -0.408891, 0.070008, -0.115392, 0.099208, 0.118208, -0.005392,
+        ]);
+
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, ASSERT_PRECISION);
+        weight_updated.assert_approx_eq(&weights_expected, ASSERT_PRECISION);
+    }
+
+    fn given_linear_layer(weight: TensorData, bias: TensorData) -> nn::Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let record = nn::LinearRecord {
+            weight: Param::from_data(weight, &device),
+            bias: Some(Param::from_data(bias, &device)),
+        };
+
+        nn::LinearConfig::new(6, 6)
+            .init(&device)
+            .load_record(record)
+    }
+
+    fn create_nadam(
+    ) -> OptimizerAdaptor<NAdam<TestBackend>, nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>
+    {
+        NAdamConfig::new().init()
+    }
+}
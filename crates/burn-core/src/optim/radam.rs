@@ -0,0 +1,288 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    decay::{WeightDecay, WeightDecayConfig},
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// RAdam configuration.
+#[derive(Config)]
+pub struct RAdamConfig {
+    /// Parameter for RAdam.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for RAdam.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-8)]
+    epsilon: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// RAdam optimizer as described in the paper [On the Variance of the Adaptive Learning Rate and Beyond](https://arxiv.org/abs/1908.03265).
+///
+/// The adaptive learning rate is only used once the variance of the second moment estimate is
+/// tractable; before that, the update uses the momentum only.
+#[derive(Clone)]
+pub struct RAdam<B: Backend> {
+    beta_1: f32,
+    beta_2: f32,
+    epsilon: f32,
+    weight_decay: Option<WeightDecay<B>>,
+}
+
+/// RAdam state.
+#[derive(Record, Clone, new)]
+pub struct RAdamState<B: Backend, const D: usize> {
+    time: usize,
+    moment_1: Tensor<B, D>,
+    moment_2: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for RAdam<B> {
+    type State<const D: usize> = RAdamState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        if let Some(weight_decay) = &self.weight_decay {
+            grad = weight_decay.transform(grad, tensor.clone());
+        }
+
+        let mut state = match state {
+            Some(state) => state,
+            None => RAdamState::new(0, grad.zeros_like(), grad.zeros_like()),
+        };
+        state.time += 1;
+
+        state.moment_1 = state
+            .moment_1
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1));
+        state.moment_2 = state
+            .moment_2
+            .mul_scalar(self.beta_2)
+            .add(grad.powf_scalar(2.0).mul_scalar(1.0 - self.beta_2));
+
+        // Computed in double precision, since the rectification term is sensitive to rounding.
+        let time = state.time as i32;
+        let beta_2 = self.beta_2 as f64;
+        let beta_2_t = beta_2.powi(time);
+        let bias_correction_1 = 1.0 - (self.beta_1 as f64).powi(time);
+        let bias_correction_2 = 1.0 - beta_2_t;
+        let moment_1_corrected = state.moment_1.clone().div_scalar(bias_correction_1);
+
+        // Length of the approximated simple moving average.
+        let rho_inf = 2.0 / (1.0 - beta_2) - 1.0;
+        let rho_t = rho_inf - 2.0 * time as f64 * beta_2_t / bias_correction_2;
+
+        let delta = if rho_t > 5.0 {
+            let rectification = ((rho_t - 4.0) * (rho_t - 2.0) * rho_inf
+                / ((rho_inf - 4.0) * (rho_inf - 2.0) * rho_t))
+                .sqrt();
+            let adaptive_lr = state
+                .moment_2
+                .clone()
+                .sqrt()
+                .add_scalar(self.epsilon)
+                .recip()
+                .mul_scalar(bias_correction_2.sqrt());
+
+            moment_1_corrected
+                .mul(adaptive_lr)
+                .mul_scalar(rectification)
+        } else {
+            moment_1_corrected
+        };
+
+        (tensor - delta.mul_scalar(lr), Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.moment_1 = state.moment_1.to_device(device);
+        state.moment_2 = state.moment_2.to_device(device);
+        state
+    }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: Some(WeightDecay::new(&WeightDecayConfig::new(penalty))),
+            ..self.clone()
+        })
+    }
+}
+
+impl RAdamConfig {
+    /// Initialize RAdam optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<RAdam<B::InnerBackend>, M, B> {
+        let optim = RAdam {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            epsilon: self.epsilon,
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, Tensor, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use tempfile::TempDir;
+
+    const LEARNING_RATE: LearningRate = 0.01;
+    const ASSERT_PRECISION: usize = 5;
+
+    #[test]
+    fn test_radam_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_radam();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let temp_dir = TempDir::new().unwrap();
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(
+                optimizer.to_record(),
+                temp_dir.path().join("test_optim_radam"),
+            )
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_radam();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_radam_optimizer_with_numbers() {
+        let device = Default::default();
+        let linear = given_linear_layer(
+            TensorData::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            TensorData::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+                [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+            ],
+            &device,
+        )
+        .require_grad();
+        let x_2 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+                [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let mut optimizer = RAdamConfig::new()
+            .with_weight_decay(Some(WeightDecayConfig::new(0.5)))
+            .init();
+
+        // Enough steps for the variance of the adaptive learning rate to be tractable.
+        let mut linear = linear;
+        for x in [x_1, x_2].into_iter().cycle().take(8) {
+            let grads = linear.forward(x).backward();
+            let grads = GradientsParams::from_grads(grads, &linear);
+            linear = optimizer.step(LEARNING_RATE, linear, grads);
+        }
+
+        let state_updated = linear.into_record();
+        let weights_expected = TensorData::from([
+            [-0.377466, 0.069145, 0.329408, 0.247204, 0.018925, 0.000593],
+            [
+                0.036906, -0.056886, -0.396342, 0.209774, 0.151760, -0.323717,
+            ],
+            [
+                -0.096576, -0.044309, -0.366884, 0.164175, -0.349136, 0.227168,
+            ],
+            [
+                -0.362872, -0.288177, -0.434545, -0.362970, -0.146490, 0.086275,
+            ],
+            [
+                0.269644, -0.264920, 0.310307, -0.220747, 0.318011, -0.081792,
+            ],
+            [
+                -0.079272, -0.075469, 0.058905, 0.123556, -0.035196, 0.310002,
+            ],
+        ]);
+        let bias_expected = TensorData::from([
+            -0.481219, -0.014226, -0.195017, 0.014248, 0.032775, -0.087752,
+        ]);
+
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, ASSERT_PRECISION);
+        weight_updated.assert_approx_eq(&weights_expected, ASSERT_PRECISION);
+    }
+
+    fn given_linear_layer(weight: TensorData, bias: TensorData) -> nn::Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let record = nn::LinearRecord {
+            weight: Param::from_data(weight, &device),
+            bias: Some(Param::from_data(bias, &device)),
+        };
+
+        nn::LinearConfig::new(6, 6)
+            .init(&device)
+            .load_record(record)
+    }
+
+    fn create_radam(
+    ) -> OptimizerAdaptor<RAdam<TestBackend>, nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>
+    {
+        RAdamConfig::new().init()
+    }
+}
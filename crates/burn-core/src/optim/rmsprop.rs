@@ -134,6 +134,13 @@ impl<B: Backend> SimpleOptimizer<B> for RmsProp<B> {
         state.momentum = state.momentum.map(|momentum| momentum.to_device(device));
         state
     }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: Some(WeightDecay::new(&WeightDecayConfig::new(penalty))),
+            ..self.clone()
+        })
+    }
 }
 
 /// State of [RmsProp](RmsProp)
@@ -0,0 +1,326 @@
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::AutodiffBackend, Tensor};
+
+use super::GradientsParams;
+use crate as burn;
+use crate::config::Config;
+use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
+
+/// Configuration to create a [gradient scaler](GradScaler).
+#[derive(Config)]
+pub struct GradScalerConfig {
+    /// The initial scale of the loss.
+    #[config(default = 65536.0)]
+    pub init_scale: f64,
+    /// The factor multiplying the scale after `growth_interval` steps without overflow.
+    #[config(default = 2.0)]
+    pub growth_factor: f64,
+    /// The factor multiplying the scale when the gradients overflow.
+    #[config(default = 0.5)]
+    pub backoff_factor: f64,
+    /// The number of consecutive steps without overflow before the scale is increased.
+    #[config(default = 2000)]
+    pub growth_interval: usize,
+}
+
+impl GradScalerConfig {
+    /// Initialize a [gradient scaler](GradScaler).
+    ///
+    /// # Panics
+    /// This function panics if the scale or the factors are not valid.
+    pub fn init(&self) -> GradScaler {
+        assert!(self.init_scale > 0.0, "The initial scale must be positive");
+        assert!(
+            self.growth_factor > 1.0,
+            "The growth factor must be greater than 1"
+        );
+        assert!(
+            self.backoff_factor > 0.0 && self.backoff_factor < 1.0,
+            "The backoff factor must be between 0 and 1"
+        );
+
+        GradScaler {
+            scale: self.init_scale,
+            growth_factor: self.growth_factor,
+            backoff_factor: self.backoff_factor,
+            growth_interval: self.growth_interval,
+            num_steps_finite: 0,
+        }
+    }
+}
+
+/// Record of a [gradient scaler](GradScaler): the scale and the number of consecutive steps
+/// without overflow.
+pub type GradScalerRecord = (f64, usize);
+
+/// Dynamic loss scaling for mixed precision training.
+///
+/// Small gradients underflow to zero in half precision. To preserve them, the loss is multiplied
+/// by a large [scale](GradScaler::scale_loss) before the backward pass, and the gradients are
+/// divided by the same scale with [unscale](GradScaler::unscale) before the optimizer step.
+///
+/// When the scaled gradients overflow, they contain infinite or NaN values: the step must be
+/// skipped and the scale is reduced with [update](GradScaler::update). After a number of steps
+/// without overflow, the scale is increased again to use the full range of half precision.
+///
+/// ```rust, ignore
+/// let loss = model.forward(input);
+/// let grads = GradientsParams::from_grads(scaler.scale_loss(loss).backward(), &model);
+/// let grads = scaler.unscale(grads, &model);
+/// scaler.update(grads.is_some());
+///
+/// if let Some(grads) = grads {
+///     model = optim.step(lr, model, grads);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GradScaler {
+    scale: f64,
+    growth_factor: f64,
+    backoff_factor: f64,
+    growth_interval: usize,
+    num_steps_finite: usize,
+}
+
+impl GradScaler {
+    /// The current scale of the loss.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Multiply the loss by the current scale.
+    pub fn scale_loss<B: AutodiffBackend, const D: usize>(
+        &self,
+        loss: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        loss.mul_scalar(self.scale)
+    }
+
+    /// Divide the gradients of the given module by the current scale.
+    ///
+    /// # Returns
+    ///
+    /// The unscaled gradients, or `None` if any of them is infinite or NaN, in which case the
+    /// optimizer step should be skipped.
+    pub fn unscale<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        grads: GradientsParams,
+        module: &M,
+    ) -> Option<GradientsParams> {
+        let (grads, finite) = Self::multiply(grads, module, 1.0 / self.scale);
+
+        match finite {
+            true => Some(grads),
+            false => None,
+        }
+    }
+
+    /// Multiply the gradients of the given module by the current scale.
+    ///
+    /// This gives the gradients of the [scaled loss](GradScaler::scale_loss) when the loss
+    /// couldn't be scaled before the backward pass, which doesn't prevent their underflow.
+    pub fn scale_gradients<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        grads: GradientsParams,
+        module: &M,
+    ) -> GradientsParams {
+        Self::multiply(grads, module, self.scale).0
+    }
+
+    fn multiply<B: AutodiffBackend, M: AutodiffModule<B>>(
+        grads: GradientsParams,
+        module: &M,
+        factor: f64,
+    ) -> (GradientsParams, bool) {
+        let mut visitor = GradientsMultiplier::<B> {
+            grads,
+            factor,
+            finite: true,
+            _backend: PhantomData,
+        };
+        module.visit(&mut visitor);
+
+        (visitor.grads, visitor.finite)
+    }
+
+    /// Get the scale and the number of steps without overflow as a [record](GradScalerRecord), so
+    /// the scaling can be resumed.
+    pub fn to_record(&self) -> GradScalerRecord {
+        (self.scale, self.num_steps_finite)
+    }
+
+    /// Load the scale and the number of steps without overflow from a
+    /// [record](GradScalerRecord).
+    pub fn load_record(mut self, record: GradScalerRecord) -> Self {
+        (self.scale, self.num_steps_finite) = record;
+        self
+    }
+
+    /// Update the scale after a step, given whether its gradients were finite.
+    ///
+    /// The scale is reduced when the gradients overflowed, and increased after `growth_interval`
+    /// consecutive steps without overflow.
+    pub fn update(&mut self, finite: bool) {
+        if !finite {
+            self.scale *= self.backoff_factor;
+            self.num_steps_finite = 0;
+            return;
+        }
+
+        self.num_steps_finite += 1;
+        if self.num_steps_finite >= self.growth_interval {
+            self.scale *= self.growth_factor;
+            self.num_steps_finite = 0;
+        }
+    }
+}
+
+struct GradientsMultiplier<B: AutodiffBackend> {
+    grads: GradientsParams,
+    factor: f64,
+    finite: bool,
+    _backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradientsMultiplier<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) else {
+            return;
+        };
+
+        // Infinite and NaN values are the only ones not equal to zero once subtracted from
+        // themselves.
+        if self.finite && grad.clone().sub(grad.clone()).is_nan().any().into_scalar() {
+            self.finite = false;
+        }
+
+        self.grads
+            .register::<B::InnerBackend, D>(id.clone(), grad.mul_scalar(self.factor));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::TensorData;
+    use crate::TestAutodiffBackend;
+
+    type TestInnerBackend = <TestAutodiffBackend as AutodiffBackend>::InnerBackend;
+
+    fn grads(linear: &Linear<TestAutodiffBackend>, value: f32) -> GradientsParams {
+        let device = Default::default();
+        let mut grads = GradientsParams::new();
+        grads.register::<TestInnerBackend, 2>(
+            linear.weight.id.clone(),
+            Tensor::full([2, 2], value, &device),
+        );
+        grads
+    }
+
+    fn linear() -> Linear<TestAutodiffBackend> {
+        LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init(&Default::default())
+    }
+
+    #[test]
+    fn should_unscale_the_gradients() {
+        let linear = linear();
+        let scaler = GradScalerConfig::new().with_init_scale(4.0).init();
+
+        let grads = scaler.unscale(grads(&linear, 2.0), &linear).unwrap();
+
+        grads
+            .get::<TestInnerBackend, 2>(&linear.weight.id)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[0.5, 0.5], [0.5, 0.5]]), false);
+    }
+
+    #[test]
+    fn should_detect_overflowing_gradients() {
+        let linear = linear();
+        let scaler = GradScalerConfig::new().init();
+
+        assert!(scaler
+            .unscale(grads(&linear, f32::INFINITY), &linear)
+            .is_none());
+        assert!(scaler.unscale(grads(&linear, f32::NAN), &linear).is_none());
+    }
+
+    #[test]
+    fn should_reduce_the_scale_when_the_gradients_overflow() {
+        let mut scaler = GradScalerConfig::new().with_init_scale(4.0).init();
+
+        scaler.update(false);
+        assert_eq!(scaler.scale(), 2.0);
+        scaler.update(false);
+        assert_eq!(scaler.scale(), 1.0);
+    }
+
+    #[test]
+    fn should_grow_the_scale_after_the_growth_interval() {
+        let mut scaler = GradScalerConfig::new()
+            .with_init_scale(4.0)
+            .with_growth_interval(2)
+            .init();
+
+        scaler.update(true);
+        assert_eq!(scaler.scale(), 4.0);
+        scaler.update(true);
+        assert_eq!(scaler.scale(), 8.0);
+
+        // An overflow resets the number of steps without overflow.
+        scaler.update(true);
+        scaler.update(false);
+        scaler.update(true);
+        assert_eq!(scaler.scale(), 4.0);
+    }
+
+    #[test]
+    fn should_resume_from_the_record() {
+        let mut scaler = GradScalerConfig::new()
+            .with_init_scale(4.0)
+            .with_growth_interval(2)
+            .init();
+        scaler.update(false);
+        scaler.update(true);
+
+        let mut resumed = GradScalerConfig::new()
+            .with_growth_interval(2)
+            .init()
+            .load_record(scaler.to_record());
+        assert_eq!(resumed.scale(), 2.0);
+
+        resumed.update(true);
+        assert_eq!(resumed.scale(), 4.0);
+    }
+
+    #[test]
+    fn should_scale_the_gradients() {
+        let linear = linear();
+        let scaler = GradScalerConfig::new().with_init_scale(4.0).init();
+
+        let grads = scaler.scale_gradients(grads(&linear, 2.0), &linear);
+
+        grads
+            .get::<TestInnerBackend, 2>(&linear.weight.id)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[8.0, 8.0], [8.0, 8.0]]), false);
+    }
+
+    #[test]
+    fn should_scale_the_loss() {
+        let device = Default::default();
+        let scaler = GradScalerConfig::new().with_init_scale(8.0).init();
+        let loss = Tensor::<TestAutodiffBackend, 1>::from_floats([0.5], &device);
+
+        scaler
+            .scale_loss(loss)
+            .into_data()
+            .assert_eq(&TensorData::from([4.0]), false);
+    }
+}
@@ -92,6 +92,13 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
         state.momentum = state.momentum.map(|state| state.to_device(device));
         state
     }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: Some(WeightDecay::new(&WeightDecayConfig::new(penalty))),
+            ..self.clone()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,466 @@
+use super::{GradientsParams, Optimizer};
+use crate::{
+    module::{AutodiffModule, ModuleMapper, ModuleVisitor, ParamId},
+    LearningRate,
+};
+use alloc::vec::Vec;
+use burn_tensor::{backend::AutodiffBackend, Tensor};
+use core::marker::PhantomData;
+use hashbrown::HashMap;
+
+/// Wrapper partitioning the parameters of a module and their optimizer state across devices, as
+/// done by the first stage of [ZeRO](https://arxiv.org/abs/1910.02054).
+///
+/// Each parameter is assigned to a shard, balancing the number of elements between the shards.
+/// Each shard has its own optimizer on its own device: on each step, the gradients of the shard
+/// are moved to its device, where its optimizer updates the parameters of the shard, so their
+/// state is only kept on that device. The updated parameters are then moved back to their
+/// original device.
+///
+/// The multi-device training of the learner doesn't shard its optimizer by itself: the gradients
+/// of all devices are aggregated on the first one before the optimizer step. Wrapping the
+/// optimizer given to the learner with the devices of the training shards its state.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let optim = ShardedOptimizer::new(AdamConfig::new().init(), devices.clone());
+/// ```
+#[derive(Clone)]
+pub struct ShardedOptimizer<O, M, B>
+where
+    O: Optimizer<M, B>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    shards: Vec<O>,
+    devices: Vec<B::Device>,
+    /// The index of the shard of each parameter, resolved on the first step.
+    assignments: Option<HashMap<ParamId, usize>>,
+    module: PhantomData<M>,
+}
+
+impl<O, M, B> ShardedOptimizer<O, M, B>
+where
+    O: Optimizer<M, B> + Clone,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    /// Create a sharded optimizer with one shard per device, each optimized by a copy of the given
+    /// optimizer.
+    ///
+    /// # Panics
+    ///
+    /// If no device is provided.
+    pub fn new(optim: O, devices: Vec<B::Device>) -> Self {
+        assert!(!devices.is_empty(), "At least one device is required");
+
+        Self {
+            shards: devices.iter().map(|_| optim.clone()).collect(),
+            devices,
+            assignments: None,
+            module: PhantomData,
+        }
+    }
+}
+
+impl<O, M, B> ShardedOptimizer<O, M, B>
+where
+    O: Optimizer<M, B>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    /// Assigns each parameter to the shard with the fewest elements so far, in the order the
+    /// parameters are visited.
+    fn assign_shards(&self, module: &M) -> HashMap<ParamId, usize> {
+        let mut visitor = ParamSizeCollector::<B>::default();
+        module.visit(&mut visitor);
+
+        let mut sizes = alloc::vec![0; self.shards.len()];
+        visitor
+            .params
+            .into_iter()
+            .map(|(id, num_elements)| {
+                let (index, size) = sizes
+                    .iter_mut()
+                    .enumerate()
+                    .min_by_key(|(_, size)| **size)
+                    .unwrap();
+                *size += num_elements;
+                (id, index)
+            })
+            .collect()
+    }
+}
+
+impl<O, M, B> Optimizer<M, B> for ShardedOptimizer<O, M, B>
+where
+    O: Optimizer<M, B>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    type Record = Vec<O::Record>;
+
+    fn step(&mut self, lr: LearningRate, mut module: M, mut grads: GradientsParams) -> M {
+        if self.assignments.is_none() {
+            self.assignments = Some(self.assign_shards(&module));
+        }
+        let assignments = self.assignments.as_ref().unwrap();
+
+        let mut splitter = GradientsSplitter::<B> {
+            grads: &mut grads,
+            shards: self
+                .devices
+                .iter()
+                .map(|_| GradientsParams::new())
+                .collect(),
+            assignments,
+            devices: &self.devices,
+        };
+        module.visit(&mut splitter);
+        let shards_grads = splitter.shards;
+
+        for (index, (optim, grads)) in self.shards.iter_mut().zip(shards_grads).enumerate() {
+            if grads.is_empty() {
+                continue;
+            }
+
+            let mut mover = ShardMover::<B> {
+                shard: index,
+                assignments,
+                device: &self.devices[index],
+                origins: HashMap::new(),
+            };
+            module = module.map(&mut mover);
+            module = optim.step(lr, module, grads);
+            module = module.map(&mut ShardRestorer::<B> {
+                origins: mover.origins,
+            });
+        }
+
+        module
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.shards.iter().map(Optimizer::to_record).collect()
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        assert_eq!(
+            record.len(),
+            self.shards.len(),
+            "The record should have the same number of shards as the optimizer"
+        );
+
+        self.shards = self
+            .shards
+            .into_iter()
+            .zip(record)
+            .map(|(optim, record)| optim.load_record(record))
+            .collect();
+        self
+    }
+}
+
+/// Move a tensor to the given device, keeping it as a leaf of the autodiff graph.
+fn move_param<B: AutodiffBackend, const D: usize>(
+    tensor: Tensor<B, D>,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let is_require_grad = tensor.is_require_grad();
+    tensor
+        .to_device(device)
+        .detach()
+        .set_require_grad(is_require_grad)
+}
+
+#[derive(Default)]
+struct ParamSizeCollector<B: AutodiffBackend> {
+    params: Vec<(ParamId, usize)>,
+    phantom: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for ParamSizeCollector<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params
+            .push((id.clone(), tensor.shape().num_elements()));
+    }
+}
+
+struct GradientsSplitter<'a, B: AutodiffBackend> {
+    grads: &'a mut GradientsParams,
+    shards: Vec<GradientsParams>,
+    assignments: &'a HashMap<ParamId, usize>,
+    devices: &'a [B::Device],
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientsSplitter<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            // Parameters added after the assignment belong to the first shard.
+            let index = self.assignments.get(id).copied().unwrap_or(0);
+            self.shards[index]
+                .register::<B::InnerBackend, D>(id.clone(), grad.to_device(&self.devices[index]));
+        }
+    }
+}
+
+struct ShardMover<'a, B: AutodiffBackend> {
+    shard: usize,
+    assignments: &'a HashMap<ParamId, usize>,
+    device: &'a B::Device,
+    /// The original device of each moved parameter.
+    origins: HashMap<ParamId, B::Device>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleMapper<B> for ShardMover<'a, B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let index = self.assignments.get(id).copied().unwrap_or(0);
+        let device = tensor.device();
+
+        if index != self.shard || device == *self.device {
+            return tensor;
+        }
+
+        self.origins.insert(id.clone(), device);
+        move_param(tensor, self.device)
+    }
+}
+
+struct ShardRestorer<B: AutodiffBackend> {
+    origins: HashMap<ParamId, B::Device>,
+}
+
+impl<B: AutodiffBackend> ModuleMapper<B> for ShardRestorer<B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.origins.remove(id) {
+            Some(device) => move_param(tensor, &device),
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        optim::AdamConfig,
+        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+        tensor::{backend::Backend, Distribution},
+        TestAutodiffBackend, TestBackend,
+    };
+    use std::sync::{Arc, Mutex};
+
+    type TestDevice = <TestAutodiffBackend as Backend>::Device;
+
+    type TestModel = Model<TestAutodiffBackend>;
+
+    const LEARNING_RATE: LearningRate = 0.01;
+    const NUM_DEVICES: usize = 3;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        backbone: Linear<B>,
+        head: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                backbone: LinearConfig::new(4, 4).init(device),
+                head: LinearConfig::new(4, 2).init(device),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            self.head.forward(self.backbone.forward(input))
+        }
+    }
+
+    fn train<O: Optimizer<TestModel, TestAutodiffBackend>>(
+        optim: &mut O,
+        mut model: TestModel,
+        inputs: &[Tensor<TestAutodiffBackend, 2>],
+    ) -> TestModel {
+        for input in inputs {
+            let grads = model.forward(input.clone()).sum().backward();
+            let grads = GradientsParams::from_grads(grads, &model);
+            model = optim.step(LEARNING_RATE, model, grads);
+        }
+        model
+    }
+
+    fn devices() -> Vec<TestDevice> {
+        (0..NUM_DEVICES).map(|_| Default::default()).collect()
+    }
+
+    /// The device of a parameter and of its gradient, during an optimizer step.
+    type Placement = (ParamId, TestDevice, TestDevice);
+
+    /// Optimizer recording the placement of the parameters of each step, shared between the
+    /// shards, which are stepped in order.
+    #[derive(Clone, Default)]
+    struct PlacementOptimizer {
+        steps: Arc<Mutex<Vec<Vec<Placement>>>>,
+    }
+
+    struct PlacementCollector<'a> {
+        grads: &'a GradientsParams,
+        placements: Vec<Placement>,
+    }
+
+    impl<'a> ModuleVisitor<TestAutodiffBackend> for PlacementCollector<'a> {
+        fn visit_float<const D: usize>(
+            &mut self,
+            id: &ParamId,
+            tensor: &Tensor<TestAutodiffBackend, D>,
+        ) {
+            if let Some(grad) = self.grads.get::<TestBackend, D>(id) {
+                self.placements
+                    .push((id.clone(), tensor.device(), grad.device()));
+            }
+        }
+    }
+
+    impl Optimizer<TestModel, TestAutodiffBackend> for PlacementOptimizer {
+        type Record = ();
+
+        fn step(
+            &mut self,
+            _lr: LearningRate,
+            module: TestModel,
+            grads: GradientsParams,
+        ) -> TestModel {
+            let mut collector = PlacementCollector {
+                grads: &grads,
+                placements: Vec::new(),
+            };
+            module.visit(&mut collector);
+            self.steps.lock().unwrap().push(collector.placements);
+            module
+        }
+
+        fn to_record(&self) -> Self::Record {}
+
+        fn load_record(self, _record: Self::Record) -> Self {
+            self
+        }
+    }
+
+    #[test]
+    fn should_match_the_wrapped_optimizer() {
+        let device = Default::default();
+        let model = TestModel::new(&device);
+        let inputs = (0..3)
+            .map(|_| Tensor::random([2, 4], Distribution::Default, &device))
+            .collect::<Vec<_>>();
+
+        let mut optim = AdamConfig::new().init();
+        let expected = train(&mut optim, model.clone(), &inputs);
+        let mut sharded = ShardedOptimizer::new(AdamConfig::new().init(), devices());
+        let updated = train(&mut sharded, model, &inputs);
+
+        let expected = expected.into_record();
+        let updated = updated.into_record();
+        updated
+            .backbone
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.backbone.weight.to_data(), 5);
+        updated
+            .head
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected.head.bias.unwrap().to_data(), 5);
+    }
+
+    #[test]
+    fn should_balance_the_state_across_shards() {
+        let device = Default::default();
+        let model = TestModel::new(&device);
+        let input = Tensor::random([2, 4], Distribution::Default, &device);
+        let mut optim = ShardedOptimizer::new(AdamConfig::new().init(), devices());
+
+        let updated = train(&mut optim, model, &[input]);
+
+        // Parameters of 16, 4, 8 and 2 elements, each assigned to the smallest shard.
+        let sizes = optim
+            .to_record()
+            .iter()
+            .map(|record| record.len())
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, vec![1, 2, 1]);
+        assert!(updated.head.weight.is_require_grad());
+        assert_eq!(updated.devices(), vec![device]);
+    }
+
+    #[test]
+    fn should_place_each_shard_on_its_device() {
+        let device = Default::default();
+        let model = TestModel::new(&device);
+        let input = Tensor::random([2, 4], Distribution::Default, &device);
+        let devices = devices();
+        let optim = PlacementOptimizer::default();
+        let mut sharded = ShardedOptimizer::new(optim.clone(), devices.clone());
+
+        let updated = train(&mut sharded, model, &[input]);
+
+        let assignments = sharded.assignments.as_ref().unwrap();
+        let steps = optim.steps.lock().unwrap();
+        assert_eq!(steps.len(), NUM_DEVICES);
+        for (index, placements) in steps.iter().enumerate() {
+            let expected = assignments
+                .values()
+                .filter(|shard| **shard == index)
+                .count();
+            assert_eq!(placements.len(), expected);
+
+            for (id, param_device, grad_device) in placements {
+                assert_eq!(assignments[id], index);
+                assert_eq!(*param_device, devices[index]);
+                assert_eq!(*grad_device, devices[index]);
+            }
+        }
+        // The updated parameters are moved back to their original device.
+        assert_eq!(updated.devices(), vec![device]);
+    }
+
+    #[test]
+    fn should_restore_the_shards_from_the_record() {
+        let device = Default::default();
+        let model = TestModel::new(&device);
+        let inputs = (0..2)
+            .map(|_| Tensor::random([2, 4], Distribution::Default, &device))
+            .collect::<Vec<_>>();
+        let mut optim = ShardedOptimizer::new(AdamConfig::new().init(), devices());
+        let model = train(&mut optim, model, &inputs[..1]);
+
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(optim.to_record(), ()).unwrap();
+        let record = recorder.load(bytes, &device).unwrap();
+        let mut loaded =
+            ShardedOptimizer::new(AdamConfig::new().init(), devices()).load_record(record);
+
+        let expected = train(&mut optim, model.clone(), &inputs[1..]).into_record();
+        let updated = train(&mut loaded, model, &inputs[1..]).into_record();
+        updated
+            .backbone
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.backbone.weight.to_data(), 5);
+    }
+
+    #[test]
+    #[should_panic = "The record should have the same number of shards as the optimizer"]
+    fn should_panic_when_loading_a_record_with_other_shards() {
+        let optim = ShardedOptimizer::<_, TestModel, _>::new(AdamConfig::new().init(), devices());
+        let record = optim.to_record();
+
+        ShardedOptimizer::<_, TestModel, _>::new(AdamConfig::new().init(), devices()[..2].to_vec())
+            .load_record(record);
+    }
+}
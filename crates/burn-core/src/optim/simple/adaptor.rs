@@ -1,10 +1,14 @@
-use super::{record::AdaptorRecord, SimpleOptimizer};
+use super::{
+    record::{AdaptorRecord, OptimizerAdaptorRecord},
+    SimpleOptimizer,
+};
 use crate::{
     grad_clipping::GradientClipping,
-    module::{AutodiffModule, ModuleMapper, ParamId},
-    optim::{GradientsParams, Optimizer},
+    module::{list_param_paths, AutodiffModule, ModuleMapper, ParamId},
+    optim::{GradientsParams, Optimizer, ParamGroup},
     LearningRate,
 };
+use alloc::{string::String, vec::Vec};
 use burn_tensor::{backend::AutodiffBackend, Tensor};
 use core::marker::PhantomData;
 use hashbrown::HashMap;
@@ -22,6 +26,9 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    groups: Vec<GroupOptimizer<O>>,
+    /// The index of the group of each parameter, resolved on the first step.
+    assignments: Option<HashMap<ParamId, usize>>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -36,6 +43,8 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            groups: Vec::new(),
+            assignments: None,
         }
     }
 }
@@ -60,6 +69,49 @@ where
         self
     }
 
+    /// Adds a parameter group, optimizing the selected parameters with their own settings.
+    ///
+    /// When a parameter is selected by multiple groups, the first group added is used. Parameters
+    /// not selected by any group use the settings of the optimizer.
+    ///
+    /// # Panics
+    ///
+    /// If the group sets a weight decay and the optimizer doesn't support weight decay.
+    pub fn with_param_group(mut self, group: ParamGroup) -> Self {
+        let group = self.group_optimizer(group);
+        self.groups.push(group);
+        self.assignments = None;
+        self
+    }
+
+    fn group_optimizer(&self, group: ParamGroup) -> GroupOptimizer<O> {
+        let optim = match group.config().weight_decay {
+            Some(penalty) => self.optim.with_weight_decay(penalty).expect(
+                "The optimizer should support weight decay to set it for a parameter group",
+            ),
+            None => self.optim.clone(),
+        };
+
+        GroupOptimizer { group, optim }
+    }
+
+    /// Assigns each parameter of the module to the first group selecting it.
+    fn assign_groups(&self, module: &M) -> HashMap<ParamId, usize> {
+        if self.groups.is_empty() {
+            return HashMap::new();
+        }
+
+        list_param_paths(module)
+            .into_iter()
+            .filter_map(|(id, path)| {
+                self.groups
+                    .iter()
+                    .position(|group| group.group.contains(&path))
+                    .map(|index| (id, index))
+            })
+            .collect()
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
@@ -72,29 +124,97 @@ where
     M: AutodiffModule<B>,
     O: SimpleOptimizer<B::InnerBackend>,
 {
-    type Record = HashMap<ParamId, AdaptorRecord<O, B>>;
+    type Record = OptimizerAdaptorRecord<O, B>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        if self.assignments.is_none() {
+            self.assignments = Some(self.assign_groups(&module));
+        }
+
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
             &mut self.records,
             &mut grads,
             lr,
             self.grad_clipping.as_ref(),
+            &self.groups,
+            self.assignments.as_ref().unwrap(),
         );
         module.map(&mut mapper)
     }
 
     fn to_record(&self) -> Self::Record {
-        self.records.clone()
+        let assignments = match &self.assignments {
+            Some(assignments) => assignments
+                .iter()
+                .map(|(id, index)| (id.clone(), self.groups[*index].name().clone()))
+                .collect(),
+            None => HashMap::new(),
+        };
+
+        OptimizerAdaptorRecord {
+            states: self.records.clone(),
+            groups: self
+                .groups
+                .iter()
+                .map(|group| group.group.config().clone())
+                .collect(),
+            assignments,
+        }
     }
 
     fn load_record(mut self, record: Self::Record) -> Self {
-        self.records = record;
+        self.records = record.states;
+
+        // Records without groups don't change the configured groups.
+        if record.groups.is_empty() {
+            return self;
+        }
+
+        // The recorded settings and assignments are restored, keeping the selectors of the
+        // configured groups with the same name.
+        let groups = record
+            .groups
+            .into_iter()
+            .map(|config| {
+                let group = self
+                    .groups
+                    .iter()
+                    .find(|group| group.name() == &config.name);
+                self.group_optimizer(ParamGroup::restore(config, group.map(|g| &g.group)))
+            })
+            .collect::<Vec<_>>();
+
+        self.assignments = Some(
+            record
+                .assignments
+                .into_iter()
+                .filter_map(|(id, name)| {
+                    groups
+                        .iter()
+                        .position(|group| group.name() == &name)
+                        .map(|index| (id, index))
+                })
+                .collect(),
+        );
+        self.groups = groups;
         self
     }
 }
 
+/// A [parameter group](ParamGroup) with its optimizer.
+#[derive(Clone)]
+struct GroupOptimizer<O> {
+    group: ParamGroup,
+    optim: O,
+}
+
+impl<O> GroupOptimizer<O> {
+    fn name(&self) -> &String {
+        &self.group.config().name
+    }
+}
+
 #[derive(new)]
 struct SimpleOptimizerMapper<'a, M, B, O>
 where
@@ -108,6 +228,8 @@ where
     lr: LearningRate,
     phantom: PhantomData<M>,
     grad_clipping: Option<&'a GradientClipping>,
+    groups: &'a [GroupOptimizer<O>],
+    assignments: &'a HashMap<ParamId, usize>,
 }
 
 impl<'a, M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'a, M, B, O>
@@ -118,6 +240,14 @@ where
 {
     fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
         let grad = self.grads.remove(id);
+        let group = self.assignments.get(id).copied();
+
+        if let Some(index) = group {
+            if self.groups[index].group.config().frozen {
+                self.records.remove(id);
+                return tensor.set_require_grad(false);
+            }
+        }
 
         if let Some(grad) = grad {
             let device = grad.device();
@@ -130,8 +260,16 @@ where
                 grad
             };
 
-            let (tensor, state) = self.optimizer.step(
-                self.lr,
+            let (optimizer, lr) = match group {
+                Some(index) => {
+                    let group = &self.groups[index];
+                    (&group.optim, self.lr * group.group.config().lr_multiplier)
+                }
+                None => (self.optimizer, self.lr),
+            };
+
+            let (tensor, state) = optimizer.step(
+                lr,
                 tensor.inner(),
                 clipped_grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
@@ -30,4 +30,12 @@ where
     /// This function will be called accordindly to have the state on the same device as the
     /// gradient and the tensor when the [step](SimpleOptimizer::step) function is called.
     fn to_device<const D: usize>(state: Self::State<D>, device: &B::Device) -> Self::State<D>;
+
+    /// Create a copy of the optimizer using the given weight decay penalty.
+    ///
+    /// This is used by [parameter groups](crate::optim::ParamGroup) overriding the weight decay.
+    /// Returns `None` when the optimizer doesn't support weight decay, which is the default.
+    fn with_weight_decay(&self, _penalty: f64) -> Option<Self> {
+        None
+    }
 }
@@ -1,10 +1,20 @@
 use super::{AdaptorRecordItemV1, AdaptorRecordV1};
 use crate::{
-    optim::SimpleOptimizer,
+    module::ParamId,
+    optim::{ParamGroupConfig, SimpleOptimizer},
     record::{PrecisionSettings, Record},
 };
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 use burn_tensor::backend::AutodiffBackend;
-use serde::{Deserialize, Serialize};
+use core::marker::PhantomData;
+use hashbrown::HashMap;
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
 
 /// [Optimizer adaptor](crate::optim::simple::adaptor::OptimizerAdaptor) record.
 ///
@@ -91,3 +101,174 @@ where
         Self::V1(AdaptorRecordV1::from_state(state))
     }
 }
+
+/// [Optimizer adaptor](crate::optim::simple::adaptor::OptimizerAdaptor) record, containing the
+/// state of each parameter along with the [parameter groups](crate::optim::ParamGroup).
+pub struct OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    /// The state of each parameter.
+    pub states: HashMap<ParamId, AdaptorRecord<O, B>>,
+    /// The configuration of each parameter group.
+    pub groups: Vec<ParamGroupConfig>,
+    /// The name of the group of each parameter. Parameters without a group use the settings of
+    /// the optimizer.
+    pub assignments: HashMap<ParamId, String>,
+}
+
+/// [Optimizer adaptor record](OptimizerAdaptorRecord) item.
+///
+/// Records saved before the parameter groups were added only map each parameter to its state,
+/// they are loaded without any group.
+#[derive(Serialize)]
+#[serde(bound = "")]
+pub struct OptimizerAdaptorRecordItem<
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+    S: PrecisionSettings,
+> {
+    states: HashMap<String, AdaptorRecordItem<O, B, S>>,
+    groups: Vec<ParamGroupConfig>,
+    assignments: HashMap<String, String>,
+}
+
+impl<'de, O, B, S> Deserialize<'de> for OptimizerAdaptorRecordItem<O, B, S>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+    S: PrecisionSettings,
+{
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        deserializer.deserialize_struct(
+            "OptimizerAdaptorRecordItem",
+            &["states", "groups", "assignments"],
+            OptimizerAdaptorRecordItemVisitor(PhantomData),
+        )
+    }
+}
+
+struct OptimizerAdaptorRecordItemVisitor<O, B, S>(PhantomData<(O, B, S)>);
+
+impl<'de, O, B, S> Visitor<'de> for OptimizerAdaptorRecordItemVisitor<O, B, S>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+    S: PrecisionSettings,
+{
+    type Value = OptimizerAdaptorRecordItem<O, B, S>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("an optimizer adaptor record")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let states = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        // The old records only contain the states, so the end of the input is reached when
+        // reading the groups with the recorders using a binary format.
+        let (groups, assignments) = match seq.next_element() {
+            Ok(Some(groups)) => {
+                let assignments = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                (groups, assignments)
+            }
+            Ok(None) | Err(_) => (Vec::new(), HashMap::new()),
+        };
+
+        Ok(OptimizerAdaptorRecordItem {
+            states,
+            groups,
+            assignments,
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut states = HashMap::new();
+        let mut groups = Vec::new();
+        let mut assignments = HashMap::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "states" => states = map.next_value()?,
+                "groups" => groups = map.next_value()?,
+                "assignments" => assignments = map.next_value()?,
+                // The old records map each parameter to its state.
+                _ => {
+                    states.insert(key, map.next_value()?);
+                }
+            }
+        }
+
+        Ok(OptimizerAdaptorRecordItem {
+            states,
+            groups,
+            assignments,
+        })
+    }
+}
+
+impl<O, B> Record<B> for OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    type Item<S: PrecisionSettings> = OptimizerAdaptorRecordItem<O, B, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        OptimizerAdaptorRecordItem {
+            states: self.states.into_item(),
+            groups: self.groups,
+            assignments: self
+                .assignments
+                .into_iter()
+                .map(|(id, group)| (id.to_string(), group))
+                .collect(),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Self {
+            states: Record::<B>::from_item(item.states, device),
+            groups: item.groups,
+            assignments: item
+                .assignments
+                .into_iter()
+                .map(|(id, group)| (ParamId::from(id), group))
+                .collect(),
+        }
+    }
+}
+
+impl<O, B> Clone for OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    fn clone(&self) -> Self {
+        Self {
+            states: self.states.clone(),
+            groups: self.groups.clone(),
+            assignments: self.assignments.clone(),
+        }
+    }
+}
+
+impl<O, B> OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    /// The number of parameters with a recorded state.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Whether no parameter has a recorded state.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+}
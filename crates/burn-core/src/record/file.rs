@@ -6,6 +6,11 @@ use serde::{de::DeserializeOwned, Serialize};
 use std::io::{BufReader, BufWriter};
 use std::{fs::File, path::PathBuf};
 
+#[cfg(feature = "record-mmap")]
+use super::mmap;
+#[cfg(feature = "record-mmap")]
+use std::{io::Write, sync::Arc};
+
 /// Recorder trait specialized to save and load data to and from files.
 pub trait FileRecorder<B: Backend>:
     Recorder<B, RecordArgs = PathBuf, RecordOutput = (), LoadArgs = PathBuf>
@@ -53,6 +58,22 @@ pub struct NamedMpkFileRecorder<S: PrecisionSettings> {
     _settings: PhantomData<S>,
 }
 
+/// File recorder using a memory-mapped format, where tensors are loaded lazily.
+///
+/// The file starts with a [bincode](bincode) header where every tensor is replaced by its offset
+/// in the data section that follows. When loading a record, the file is memory-mapped and each
+/// parameter only reads its tensor from the file when it's first used, so loading a model doesn't
+/// require more memory than the model itself.
+///
+/// # Notes
+///
+/// The file must not be modified while a record loaded from it is still in use.
+#[cfg(feature = "record-mmap")]
+#[derive(new, Debug, Default, Clone)]
+pub struct MmapFileRecorder<S: PrecisionSettings> {
+    _settings: PhantomData<S>,
+}
+
 impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for BinGzFileRecorder<S> {
     fn file_extension() -> &'static str {
         "bin.gz"
@@ -86,6 +107,13 @@ impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for NamedMpkFileRecorder<
     }
 }
 
+#[cfg(feature = "record-mmap")]
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for MmapFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "mmap"
+    }
+}
+
 macro_rules! str2reader {
     (
         $file:expr
@@ -297,6 +325,91 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkFileRecorder<S> {
     }
 }
 
+/// Magic bytes at the start of a [memory-mapped record file](MmapFileRecorder).
+#[cfg(feature = "record-mmap")]
+const MMAP_MAGIC: &[u8; 8] = b"BURNMMAP";
+
+#[cfg(feature = "record-mmap")]
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for MmapFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let (header, data) =
+            mmap::with_writer(|| bincode::serde::encode_to_vec(&item, bin_config()));
+        let header = header.map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        let mut writer = str2writer!(file)?;
+        let mut position = MMAP_MAGIC.len() + 8 + header.len();
+        let data_start = position.next_multiple_of(mmap::ALIGNMENT);
+
+        let mut write = |bytes: &[u8]| {
+            writer
+                .write_all(bytes)
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        };
+
+        write(MMAP_MAGIC)?;
+        write(&(header.len() as u64).to_le_bytes())?;
+        write(&header)?;
+
+        for (offset, bytes) in data.tensors {
+            // Pad the previous content so that every tensor is aligned.
+            write(&vec![0; data_start + offset - position])?;
+            write(&bytes)?;
+            position = data_start + offset + bytes.len();
+        }
+
+        writer
+            .flush()
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(())
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        let reader = str2reader!(file)?;
+
+        // SAFETY: The file must not be modified while it is mapped, as documented on the recorder.
+        let mmap = unsafe { memmap2::Mmap::map(reader.get_ref()) }
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        let header_start = MMAP_MAGIC.len() + 8;
+
+        if mmap.len() < header_start || &mmap[..MMAP_MAGIC.len()] != MMAP_MAGIC {
+            return Err(RecorderError::Unknown(
+                "Invalid memory-mapped record file".to_string(),
+            ));
+        }
+
+        let header_len =
+            u64::from_le_bytes(mmap[MMAP_MAGIC.len()..header_start].try_into().unwrap());
+        let header_end = header_start + header_len as usize;
+
+        if header_end > mmap.len() {
+            return Err(RecorderError::Unknown(
+                "Invalid memory-mapped record file header".to_string(),
+            ));
+        }
+
+        let data_start = header_end.next_multiple_of(mmap::ALIGNMENT);
+        let mmap = Arc::new(mmap);
+
+        let (state, _) = mmap::with_reader(mmap.clone(), data_start, || {
+            bincode::serde::decode_from_slice(&mmap[header_start..header_end], bin_config())
+        })
+        .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -352,6 +465,38 @@ mod tests {
         test_can_save_and_load(NamedMpkFileRecorder::<FullPrecisionSettings>::default())
     }
 
+    #[cfg(feature = "record-mmap")]
+    #[test]
+    fn test_can_save_and_load_mmap_format() {
+        test_can_save_and_load(MmapFileRecorder::<FullPrecisionSettings>::default())
+    }
+
+    #[cfg(feature = "record-mmap")]
+    #[test]
+    fn test_mmap_format_loads_params_lazily() {
+        let device = Default::default();
+        let recorder = MmapFileRecorder::<FullPrecisionSettings>::default();
+        let file_path = std::env::temp_dir().join("burn_test_mmap_lazy_file_recorder");
+        let model_before = create_model(&device);
+        recorder
+            .record(model_before.clone().into_record(), file_path.clone())
+            .unwrap();
+
+        let record: ModelRecord<TestBackend> = recorder.load(file_path, &device).unwrap();
+        assert!(!record.linear1.weight.is_initialized());
+
+        let model_after = create_model(&device).load_record(record);
+        assert!(!model_after.linear1.weight.is_initialized());
+
+        model_after
+            .linear1
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&model_before.linear1.weight.val().into_data(), true);
+        assert!(model_after.linear1.weight.is_initialized());
+    }
+
     fn test_can_save_and_load<Recorder>(recorder: Recorder)
     where
         Recorder: FileRecorder<TestBackend>,
@@ -105,6 +105,36 @@ mod tests {
         assert_eq!(bytes1, bytes2_after);
     }
 
+    #[test]
+    fn test_can_save_and_load_quantized_types() {
+        use crate::module::Quantizer;
+        use burn_tensor::quantization::{MinMaxCalibration, QuantizationScheme, QuantizationType};
+
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let schemes = [
+            QuantizationScheme::PerChannelAffine(QuantizationType::QInt4, 0),
+            QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8),
+            QuantizationScheme::PerTensorSymmetric(QuantizationType::QFloat8E4M3),
+            QuantizationScheme::PerChannelSymmetric(QuantizationType::QFloat8E5M2, 0),
+        ];
+
+        for scheme in schemes {
+            let mut quantizer = Quantizer {
+                calibration: MinMaxCalibration {},
+                scheme,
+            };
+            let model = create_model::<TestBackend>(&device).quantize_weights(&mut quantizer);
+            let bytes = recorder.record(model.into_record(), ()).unwrap();
+
+            let model_after = create_model::<TestBackend>(&device)
+                .load_record(recorder.load(bytes.clone(), &device).unwrap());
+            let bytes_after = recorder.record(model_after.into_record(), ()).unwrap();
+
+            assert_eq!(bytes, bytes_after);
+        }
+    }
+
     pub fn create_model<B: Backend>(device: &B::Device) -> nn::Linear<B> {
         nn::LinearConfig::new(32, 32).with_bias(true).init(device)
     }
@@ -0,0 +1,139 @@
+use burn_tensor::{DType, TensorData};
+use core::cell::RefCell;
+use memmap2::Mmap;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Alignment of every tensor in the data section of a [memory-mapped file](super::MmapFileRecorder).
+pub(crate) const ALIGNMENT: usize = 64;
+
+thread_local! {
+    /// Tensors collected while serializing a record to a memory-mapped file.
+    static WRITER: RefCell<Option<TensorWriter>> = const { RefCell::new(None) };
+    /// Memory-mapped file and data section offset used while deserializing a record.
+    static READER: RefCell<Option<(Arc<Mmap>, usize)>> = const { RefCell::new(None) };
+}
+
+/// Location of a tensor in the data section of a memory-mapped file.
+///
+/// This is what gets serialized in the file header in place of the tensor data.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct TensorRef {
+    offset: u64,
+    len: u64,
+    shape: Vec<usize>,
+    dtype: DType,
+}
+
+/// Tensor data that is only read from a memory-mapped file when needed.
+#[derive(Clone, Debug)]
+pub(crate) struct LazyTensorData {
+    mmap: Arc<Mmap>,
+    start: usize,
+    end: usize,
+    shape: Vec<usize>,
+    dtype: DType,
+}
+
+impl LazyTensorData {
+    /// Copy the tensor data from the memory-mapped file.
+    pub(crate) fn read(&self) -> TensorData {
+        TensorData {
+            bytes: self.mmap[self.start..self.end].to_vec(),
+            shape: self.shape.clone(),
+            dtype: self.dtype,
+        }
+    }
+}
+
+/// The tensors of the data section, with their offset relative to the start of the section.
+#[derive(Default)]
+pub(crate) struct TensorWriter {
+    /// Size of the data section, including the padding between tensors.
+    pub(crate) size: usize,
+    pub(crate) tensors: Vec<(usize, Vec<u8>)>,
+}
+
+/// Resets the thread-local state when leaving a scope, even on panic.
+struct ScopeGuard<F: FnMut()>(F);
+
+impl<F: FnMut()> Drop for ScopeGuard<F> {
+    fn drop(&mut self) {
+        (self.0)()
+    }
+}
+
+/// Run the serialization function, collecting the tensors in a [writer](TensorWriter) instead of
+/// serializing their data.
+pub(crate) fn with_writer<T>(func: impl FnOnce() -> T) -> (T, TensorWriter) {
+    WRITER.with(|writer| *writer.borrow_mut() = Some(TensorWriter::default()));
+    let _guard = ScopeGuard(|| WRITER.with(|writer| *writer.borrow_mut() = None));
+
+    let output = func();
+    let writer = WRITER.with(|writer| writer.borrow_mut().take()).unwrap();
+
+    (output, writer)
+}
+
+/// Run the deserialization function, creating [lazy tensor data](LazyTensorData) pointing into
+/// the memory-mapped file.
+pub(crate) fn with_reader<T>(mmap: Arc<Mmap>, data_start: usize, func: impl FnOnce() -> T) -> T {
+    READER.with(|reader| *reader.borrow_mut() = Some((mmap, data_start)));
+    let _guard = ScopeGuard(|| READER.with(|reader| *reader.borrow_mut() = None));
+
+    func()
+}
+
+/// Add the tensor data to the current writer, returning its location in the data section.
+///
+/// Returns `None` when no memory-mapped file is being written.
+pub(crate) fn write_tensor(data: &TensorData) -> Option<TensorRef> {
+    WRITER.with(|writer| {
+        let mut writer = writer.borrow_mut();
+        let writer = writer.as_mut()?;
+
+        let offset = writer.size.next_multiple_of(ALIGNMENT);
+        writer.size = offset + data.bytes.len();
+        writer.tensors.push((offset, data.bytes.clone()));
+
+        Some(TensorRef {
+            offset: offset as u64,
+            len: data.bytes.len() as u64,
+            shape: data.shape.clone(),
+            dtype: data.dtype,
+        })
+    })
+}
+
+/// Whether a memory-mapped file is currently being read.
+pub(crate) fn is_reading() -> bool {
+    READER.with(|reader| reader.borrow().is_some())
+}
+
+/// Create the lazy tensor data from its location in the memory-mapped file being read.
+pub(crate) fn read_tensor(tensor: TensorRef) -> Result<LazyTensorData, String> {
+    READER.with(|reader| {
+        let reader = reader.borrow();
+        let (mmap, data_start) = reader
+            .as_ref()
+            .ok_or_else(|| "No memory-mapped file is being read".to_string())?;
+
+        let start = data_start + tensor.offset as usize;
+        let end = start + tensor.len as usize;
+
+        if end > mmap.len() {
+            return Err(format!(
+                "Tensor data at offset {} with length {} is out of the file bounds",
+                tensor.offset, tensor.len
+            ));
+        }
+
+        Ok(LazyTensorData {
+            mmap: mmap.clone(),
+            start,
+            end,
+            shape: tensor.shape,
+            dtype: tensor.dtype,
+        })
+    })
+}
@@ -16,7 +16,15 @@ mod file;
 #[cfg(feature = "std")]
 pub use file::*;
 
+#[cfg(feature = "record-mmap")]
+mod mmap;
+
 pub use primitive::ParamSerde;
 
 #[cfg(feature = "record-item-custom-serde")]
 pub mod serde;
+
+#[cfg(feature = "record-item-custom-serde")]
+mod partial;
+#[cfg(feature = "record-item-custom-serde")]
+pub use partial::*;
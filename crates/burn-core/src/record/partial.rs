@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+
+use super::serde::{
+    adapter::DefaultAdapter,
+    data::{remap, NestedValue},
+    de::Deserializer,
+    ser::Serializer,
+};
+use super::{Record, Recorder, RecorderError};
+use crate::module::Module;
+use burn_tensor::backend::Backend;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Loads a subset of the parameters of a saved record into a module.
+///
+/// Parameters are matched by their key, which is the path of the parameter in the module, e.g.
+/// `backbone.conv1.weight`. Keys of the saved record can be remapped with regular expressions
+/// before being matched, and parameters of the module that aren't in the record keep their
+/// current value when [missing keys are allowed](Self::with_allow_missing).
+///
+/// This is useful for transfer learning, for example to load the backbone of a checkpoint into a
+/// model with a new head.
+///
+/// # Notes
+///
+/// The record must be saved with a self-describing format, such as
+/// [named MessagePack](super::NamedMpkFileRecorder) or [JSON](super::PrettyJsonFileRecorder).
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let (model, report) = PartialLoader::new()
+///     .with_key_remap("^encoder\\.", "backbone.")
+///     .with_allow_missing(true)
+///     .with_allow_unexpected(true)
+///     .load(model, &NamedMpkFileRecorder::<FullPrecisionSettings>::new(), path.into(), &device)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PartialLoader {
+    key_remap: Vec<(Regex, String)>,
+    allow_missing: bool,
+    allow_unexpected: bool,
+}
+
+/// Report of the parameters matched when [loading a record partially](PartialLoader).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadReport {
+    /// Keys of the module parameters loaded from the record.
+    pub loaded: Vec<String>,
+    /// Keys of the module parameters not found in the record, which keep their current value.
+    pub missing: Vec<String>,
+    /// Keys of the record parameters not found in the module, after remapping.
+    pub unexpected: Vec<String>,
+}
+
+/// The item of a saved record, ignoring its metadata.
+#[derive(Deserialize)]
+struct RecordItem {
+    item: NestedValue,
+}
+
+impl PartialLoader {
+    /// Create a new partial loader, where every parameter must match.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remap the keys of the record matching the pattern with the replacement.
+    ///
+    /// See [regex::Regex::replace_all](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace_all)
+    /// for the replacement syntax. Remappings are applied in the order they are added.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Allow module parameters that aren't in the record, which keep their current value.
+    pub fn with_allow_missing(mut self, allow_missing: bool) -> Self {
+        self.allow_missing = allow_missing;
+        self
+    }
+
+    /// Allow record parameters that aren't in the module, which are ignored.
+    pub fn with_allow_unexpected(mut self, allow_unexpected: bool) -> Self {
+        self.allow_unexpected = allow_unexpected;
+        self
+    }
+
+    /// Load the record with the given recorder into the module.
+    ///
+    /// # Returns
+    ///
+    /// The module with the loaded parameters and a report of the matched keys.
+    pub fn load<B, M, R>(
+        &self,
+        module: M,
+        recorder: &R,
+        args: R::LoadArgs,
+        device: &B::Device,
+    ) -> Result<(M, LoadReport), RecorderError>
+    where
+        B: Backend,
+        M: Module<B>,
+        R: Recorder<B>,
+    {
+        let source: RecordItem = recorder.load_item(args)?;
+        let mut params = HashMap::new();
+        flatten_params(source.item, String::new(), &mut params);
+        let (mut params, _) = remap(params, self.key_remap.clone());
+
+        let mut target = module
+            .clone()
+            .into_record()
+            .into_item::<R::Settings>()
+            .serialize(Serializer::new())?;
+
+        let mut report = LoadReport::default();
+        merge_params(&mut target, String::new(), &mut params, &mut report)?;
+
+        report.unexpected = params.into_keys().collect();
+        report.loaded.sort();
+        report.missing.sort();
+        report.unexpected.sort();
+
+        if !self.allow_missing && !report.missing.is_empty() {
+            return Err(RecorderError::DeserializeError(format!(
+                "Missing keys in the record: {:?}",
+                report.missing
+            )));
+        }
+
+        if !self.allow_unexpected && !report.unexpected.is_empty() {
+            return Err(RecorderError::DeserializeError(format!(
+                "Unexpected keys in the record: {:?}",
+                report.unexpected
+            )));
+        }
+
+        let deserializer = Deserializer::<DefaultAdapter>::new(target, false);
+        let item = <M::Record as Record<B>>::Item::<R::Settings>::deserialize(deserializer)?;
+        let record = M::Record::from_item::<R::Settings>(item, device);
+
+        Ok((module.load_record(record), report))
+    }
+}
+
+/// Whether the value is a serialized [parameter](crate::module::Param).
+fn is_param(value: &NestedValue) -> bool {
+    match value {
+        NestedValue::Map(map) => {
+            map.len() == 2 && map.contains_key("id") && map.contains_key("param")
+        }
+        _ => false,
+    }
+}
+
+/// The shape of a serialized parameter.
+fn param_shape(value: &NestedValue) -> Option<Vec<u64>> {
+    let NestedValue::Map(param) = value else {
+        return None;
+    };
+    let NestedValue::Map(tensor) = param.get("param")? else {
+        return None;
+    };
+    let NestedValue::Vec(shape) = tensor.get("shape")? else {
+        return None;
+    };
+
+    shape.iter().map(|dim| dim.clone().as_u64()).collect()
+}
+
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+/// Collect the parameters of the record, keyed by their path.
+fn flatten_params(value: NestedValue, prefix: String, params: &mut HashMap<String, NestedValue>) {
+    if is_param(&value) {
+        params.insert(prefix, value);
+        return;
+    }
+
+    match value {
+        NestedValue::Map(map) => {
+            for (key, value) in map {
+                flatten_params(value, join_key(&prefix, &key), params);
+            }
+        }
+        NestedValue::Vec(vec) => {
+            for (index, value) in vec.into_iter().enumerate() {
+                flatten_params(value, join_key(&prefix, &index.to_string()), params);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replace the parameters of the target record by the ones of the source record with the same key.
+fn merge_params(
+    target: &mut NestedValue,
+    prefix: String,
+    source: &mut HashMap<String, NestedValue>,
+    report: &mut LoadReport,
+) -> Result<(), RecorderError> {
+    if is_param(target) {
+        match source.remove(&prefix) {
+            Some(param) => {
+                let (expected, actual) = (param_shape(target), param_shape(&param));
+                if expected != actual {
+                    return Err(RecorderError::DeserializeError(format!(
+                        "Shape mismatch for key {prefix}: expected {expected:?}, got {actual:?}"
+                    )));
+                }
+
+                *target = param;
+                report.loaded.push(prefix);
+            }
+            None => report.missing.push(prefix),
+        }
+
+        return Ok(());
+    }
+
+    match target {
+        NestedValue::Map(map) => {
+            for (key, value) in map.iter_mut() {
+                merge_params(value, join_key(&prefix, key), source, report)?;
+            }
+        }
+        NestedValue::Vec(vec) => {
+            for (index, value) in vec.iter_mut().enumerate() {
+                merge_params(value, join_key(&prefix, &index.to_string()), source, report)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        record::{FullPrecisionSettings, NamedMpkFileRecorder},
+        TestBackend,
+    };
+    use std::path::PathBuf;
+
+    #[derive(Module, Debug)]
+    struct Backbone<B: Backend> {
+        linear1: Linear<B>,
+        linear2: Linear<B>,
+    }
+
+    #[derive(Module, Debug)]
+    struct Classifier<B: Backend> {
+        backbone: Backbone<B>,
+        head: Linear<B>,
+    }
+
+    #[derive(Module, Debug)]
+    struct Encoder<B: Backend> {
+        encoder: Backbone<B>,
+    }
+
+    fn backbone(device: &<TestBackend as Backend>::Device) -> Backbone<TestBackend> {
+        Backbone {
+            linear1: LinearConfig::new(4, 8).init(device),
+            linear2: LinearConfig::new(8, 8).with_bias(false).init(device),
+        }
+    }
+
+    fn classifier(
+        num_classes: usize,
+        device: &<TestBackend as Backend>::Device,
+    ) -> Classifier<TestBackend> {
+        Classifier {
+            backbone: backbone(device),
+            head: LinearConfig::new(8, num_classes).init(device),
+        }
+    }
+
+    fn save<M: Module<TestBackend>>(module: M, name: &str) -> PathBuf {
+        let file_path = std::env::temp_dir().join(name);
+        NamedMpkFileRecorder::<FullPrecisionSettings>::new()
+            .record(module.into_record(), file_path.clone())
+            .unwrap();
+
+        file_path
+    }
+
+    fn assert_same_backbone(actual: &Backbone<TestBackend>, expected: &Backbone<TestBackend>) {
+        let linears = [
+            (&actual.linear1, &expected.linear1),
+            (&actual.linear2, &expected.linear2),
+        ];
+
+        for (actual, expected) in linears {
+            actual
+                .weight
+                .val()
+                .into_data()
+                .assert_eq(&expected.weight.val().into_data(), true);
+            assert_eq!(
+                actual.bias.as_ref().map(|bias| bias.val().into_data()),
+                expected.bias.as_ref().map(|bias| bias.val().into_data()),
+            );
+        }
+    }
+
+    #[test]
+    fn should_load_all_params() {
+        let device = Default::default();
+        let source = classifier(3, &device);
+        let file_path = save(source.clone(), "burn_test_partial_load_all");
+
+        let (model, report) = PartialLoader::new()
+            .load(
+                classifier(3, &device),
+                &NamedMpkFileRecorder::<FullPrecisionSettings>::new(),
+                file_path,
+                &device,
+            )
+            .unwrap();
+
+        assert_same_backbone(&model.backbone, &source.backbone);
+        model
+            .head
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&source.head.weight.val().into_data(), true);
+        assert_eq!(
+            report.loaded,
+            [
+                "backbone.linear1.bias",
+                "backbone.linear1.weight",
+                "backbone.linear2.weight",
+                "head.bias",
+                "head.weight",
+            ]
+        );
+        assert!(report.missing.is_empty());
+        assert!(report.unexpected.is_empty());
+    }
+
+    #[test]
+    fn should_load_backbone_with_key_remap() {
+        let device = Default::default();
+        let source = Encoder {
+            encoder: backbone(&device),
+        };
+        let file_path = save(source.clone(), "burn_test_partial_load_remap");
+        let target = classifier(5, &device);
+        let head = target.head.weight.val();
+
+        let (model, report) = PartialLoader::new()
+            .with_key_remap("^encoder\\.", "backbone.")
+            .with_allow_missing(true)
+            .load(
+                target,
+                &NamedMpkFileRecorder::<FullPrecisionSettings>::new(),
+                file_path,
+                &device,
+            )
+            .unwrap();
+
+        assert_same_backbone(&model.backbone, &source.encoder);
+        model
+            .head
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&head.into_data(), true);
+        assert_eq!(report.missing, ["head.bias", "head.weight"]);
+        assert_eq!(report.loaded.len(), 3);
+    }
+
+    #[test]
+    fn should_ignore_unexpected_params() {
+        let device = Default::default();
+        let source = classifier(3, &device);
+        let file_path = save(source.clone(), "burn_test_partial_load_unexpected");
+
+        let (model, report) = PartialLoader::new()
+            .with_key_remap("^backbone\\.", "")
+            .with_allow_unexpected(true)
+            .load(
+                backbone(&device),
+                &NamedMpkFileRecorder::<FullPrecisionSettings>::new(),
+                file_path,
+                &device,
+            )
+            .unwrap();
+
+        assert_same_backbone(&model, &source.backbone);
+        assert_eq!(report.unexpected, ["head.bias", "head.weight"]);
+    }
+
+    #[test]
+    fn should_fail_with_missing_params_when_not_allowed() {
+        let device = Default::default();
+        let file_path = save(backbone(&device), "burn_test_partial_load_missing");
+
+        let result = PartialLoader::new().load(
+            classifier(3, &device),
+            &NamedMpkFileRecorder::<FullPrecisionSettings>::new(),
+            file_path,
+            &device,
+        );
+
+        assert!(matches!(result, Err(RecorderError::DeserializeError(_))));
+    }
+
+    #[test]
+    fn should_fail_with_shape_mismatch() {
+        let device = Default::default();
+        let file_path = save(classifier(3, &device), "burn_test_partial_load_shape");
+
+        let result = PartialLoader::new().load(
+            classifier(5, &device),
+            &NamedMpkFileRecorder::<FullPrecisionSettings>::new(),
+            file_path,
+            &device,
+        );
+
+        match result {
+            Err(RecorderError::DeserializeError(message)) => {
+                assert!(
+                    message.contains("Shape mismatch for key head."),
+                    "{message}"
+                )
+            }
+            _ => panic!("Expected a shape mismatch error"),
+        }
+    }
+}
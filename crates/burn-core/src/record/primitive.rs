@@ -5,13 +5,16 @@ use alloc::{
 };
 use core::{fmt, marker::PhantomData};
 
-use super::tensor::{BoolTensorSerde, FloatTensorSerde, IntTensorSerde};
+use super::tensor::{
+    bool_tensor, float_tensor, int_tensor, BoolTensorSerde, FloatTensorSerde, IntTensorSerde,
+    RecordData,
+};
 use super::{PrecisionSettings, Record};
-use crate::module::{Param, ParamId};
+use crate::module::{Param, ParamId, Parameter};
 
 #[allow(deprecated)]
 use burn_tensor::DataSerialize;
-use burn_tensor::{backend::Backend, Bool, Element, Int, Tensor};
+use burn_tensor::{backend::Backend, Bool, Element, Int, Tensor, TensorData};
 
 use hashbrown::HashMap;
 use serde::{
@@ -171,6 +174,36 @@ pub struct ParamSerde<T> {
     param: T,
 }
 
+/// Create a parameter from the record data.
+///
+/// When the data is read from a memory-mapped file, the parameter is uninitialized and only
+/// reads its tensor from the file when first used.
+fn param_from_data<T>(
+    id: ParamId,
+    data: RecordData,
+    device: &T::Device,
+    is_require_grad: bool,
+    init: fn(TensorData, &T::Device) -> T,
+) -> Param<T>
+where
+    T: Parameter + 'static,
+{
+    match data {
+        RecordData::Loaded(data) => {
+            Param::initialized(id, init(data, device).set_require_grad(is_require_grad))
+        }
+        #[cfg(feature = "record-mmap")]
+        RecordData::Lazy(data) => Param::uninitialized(
+            id,
+            move |device, is_require_grad| {
+                init(data.read(), device).set_require_grad(is_require_grad)
+            },
+            device.clone(),
+            is_require_grad,
+        ),
+    }
+}
+
 impl<B, const D: usize> Record<B> for Param<Tensor<B, D>>
 where
     B: Backend,
@@ -183,10 +216,13 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
+        // Require grad is the same behavior as when we create a new Param from a tensor.
+        param_from_data(
             ParamId::from(item.id),
-            Tensor::from_item(item.param, device).require_grad(), // Same behavior as when we create a new
-                                                                  // Param from a tensor.
+            item.param.into_data(),
+            device,
+            true,
+            float_tensor,
         )
     }
 }
@@ -203,9 +239,12 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
+        param_from_data(
             ParamId::from(item.id),
-            Tensor::from_item(item.param, device),
+            item.param.into_data(),
+            device,
+            false,
+            int_tensor,
         )
     }
 }
@@ -222,9 +261,12 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
+        param_from_data(
             ParamId::from(item.id),
-            Tensor::from_item::<S>(item.param, device),
+            item.param.into_data(),
+            device,
+            false,
+            bool_tensor,
         )
     }
 }
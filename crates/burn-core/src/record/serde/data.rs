@@ -10,6 +10,7 @@ use crate::tensor::backend::Backend;
 use alloc::fmt;
 use num_traits::cast::ToPrimitive;
 use regex::Regex;
+use serde::de::{MapAccess, SeqAccess, Visitor};
 use serde::Deserialize;
 
 /// The main data structure used for deserialization.
@@ -187,6 +188,8 @@ impl NestedValue {
     pub fn as_bytes(self) -> Option<Vec<u8>> {
         match self {
             NestedValue::U8s(u) => Some(u),
+            // Formats without a byte type, such as JSON, store bytes as a sequence of integers.
+            NestedValue::Vec(vec) => vec.into_iter().map(|v| v.as_u8()).collect(),
             _ => None,
         }
     }
@@ -388,3 +391,103 @@ impl fmt::Debug for NestedValue {
         }
     }
 }
+
+/// Deserialize any self-describing format, such as named MessagePack or JSON, into a nested value.
+///
+/// Integers are stored with the widest type of their sign, since the formats don't keep the
+/// original type.
+impl<'de> Deserialize<'de> for NestedValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(NestedValueVisitor)
+    }
+}
+
+struct NestedValueVisitor;
+
+impl<'de> Visitor<'de> for NestedValueVisitor {
+    type Value = NestedValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a self-describing value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(NestedValue::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(NestedValue::I64(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(NestedValue::U64(v))
+    }
+
+    fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E> {
+        Ok(NestedValue::F32(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(NestedValue::F64(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(NestedValue::String(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+        Ok(NestedValue::String(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(NestedValue::U8s(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(NestedValue::U8s(v))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(NestedValue::Default(None))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(NestedValue::Default(None))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        NestedValue::deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(value) = seq.next_element()? {
+            vec.push(value);
+        }
+
+        Ok(NestedValue::Vec(vec))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut values = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+
+        while let Some((key, value)) = map.next_entry::<String, NestedValue>()? {
+            values.insert(key, value);
+        }
+
+        Ok(NestedValue::Map(values))
+    }
+}
@@ -688,6 +688,14 @@ where
                     _ => panic!("expected DType variant as string"),
                 }
             }
+            // Self-describing formats store unit variants as their name
+            NestedValue::String(variant) => {
+                if variant == self.current_variant {
+                    Ok(())
+                } else {
+                    Err(Error::Other("Wrong variant".to_string())) // wrong match
+                }
+            }
             _ => unimplemented!(
                 "unit variant is not implemented because it is not used in the burn module"
             ),
@@ -137,11 +137,12 @@ impl SerializerTrait for Serializer {
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+        // Units have no data, e.g. `PhantomData` fields
+        Ok(NestedValue::Default(None))
     }
 
     fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+        Ok(NestedValue::Default(None))
     }
 
     fn serialize_unit_variant(
@@ -362,6 +363,15 @@ mod tests {
         // the order of the fields is not guaranteed for HashMaps.
         assert_eq!(serialized_str.len(), 135);
     }
+    #[test]
+    fn test_serialize_unit_struct() {
+        let serialized = core::marker::PhantomData::<f32>
+            .serialize(Serializer::new())
+            .expect("Should serialize item successfully");
+
+        assert!(matches!(serialized, NestedValue::Default(None)));
+    }
+
     #[test]
     fn test_param_serde() {
         type Backend = burn_ndarray::NdArray<f32>;
@@ -4,6 +4,9 @@ use super::{PrecisionSettings, Record};
 use burn_tensor::{backend::Backend, Bool, DType, Element, Int, Tensor, TensorData};
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "record-mmap")]
+use super::mmap::{self, LazyTensorData};
+
 #[cfg(not(feature = "record-backward-compat"))]
 use alloc::format;
 #[cfg(feature = "record-backward-compat")]
@@ -52,26 +55,138 @@ where
     }
 }
 
+/// The data of a tensor record.
+#[derive(Clone, Debug)]
+pub(crate) enum RecordData {
+    /// The data is in memory.
+    Loaded(TensorData),
+    /// The data is read from a memory-mapped file when needed.
+    #[cfg(feature = "record-mmap")]
+    Lazy(LazyTensorData),
+}
+
+impl RecordData {
+    /// Returns the tensor data, reading it from the file if it isn't in memory.
+    pub(crate) fn read(self) -> TensorData {
+        match self {
+            RecordData::Loaded(data) => data,
+            #[cfg(feature = "record-mmap")]
+            RecordData::Lazy(data) => data.read(),
+        }
+    }
+}
+
+/// Deserialize the value into [`RecordData`], which is lazy when reading a memory-mapped file.
+fn deserialize_record_data<'de, E, De>(deserializer: De) -> Result<RecordData, De::Error>
+where
+    E: Element + Deserialize<'de>,
+    De: serde::Deserializer<'de>,
+{
+    #[cfg(feature = "record-mmap")]
+    if mmap::is_reading() {
+        let tensor = mmap::TensorRef::deserialize(deserializer)?;
+        let data = mmap::read_tensor(tensor).map_err(serde::de::Error::custom)?;
+
+        return Ok(RecordData::Lazy(data));
+    }
+
+    deserialize_data::<E, De>(deserializer).map(RecordData::Loaded)
+}
+
+impl Serialize for RecordData {
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: serde::Serializer,
+    {
+        match self {
+            RecordData::Loaded(data) => {
+                #[cfg(feature = "record-mmap")]
+                if let Some(tensor) = mmap::write_tensor(data) {
+                    return tensor.serialize(serializer);
+                }
+
+                data.serialize(serializer)
+            }
+            #[cfg(feature = "record-mmap")]
+            RecordData::Lazy(data) => RecordData::Loaded(data.read()).serialize(serializer),
+        }
+    }
+}
+
 /// This struct implements serde to lazily serialize and deserialize a float tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct FloatTensorSerde<S: PrecisionSettings> {
-    data: TensorData,
+    data: RecordData,
     _e: PhantomData<S::FloatElem>,
 }
 
 /// This struct implements serde to lazily serialize and deserialize an int tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct IntTensorSerde<S: PrecisionSettings> {
-    data: TensorData,
+    data: RecordData,
     _e: PhantomData<S::IntElem>,
 }
 
 /// This struct implements serde to lazily serialize and deserialize an bool tensor.
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct BoolTensorSerde {
-    data: TensorData,
+    data: RecordData,
+}
+
+impl<S: PrecisionSettings> FloatTensorSerde<S> {
+    /// Create a new record of the loaded tensor data.
+    pub fn new(data: TensorData) -> Self {
+        Self::from_record_data(RecordData::Loaded(data))
+    }
+
+    /// Create a new record of the loaded or lazily read tensor data.
+    pub(crate) fn from_record_data(data: RecordData) -> Self {
+        Self {
+            data,
+            _e: PhantomData,
+        }
+    }
+
+    pub(crate) fn into_data(self) -> RecordData {
+        self.data
+    }
+}
+
+impl<S: PrecisionSettings> IntTensorSerde<S> {
+    /// Create a new record of the loaded tensor data.
+    pub fn new(data: TensorData) -> Self {
+        Self::from_record_data(RecordData::Loaded(data))
+    }
+
+    /// Create a new record of the loaded or lazily read tensor data.
+    pub(crate) fn from_record_data(data: RecordData) -> Self {
+        Self {
+            data,
+            _e: PhantomData,
+        }
+    }
+
+    pub(crate) fn into_data(self) -> RecordData {
+        self.data
+    }
+}
+
+impl BoolTensorSerde {
+    /// Create a new record of the loaded tensor data.
+    pub fn new(data: TensorData) -> Self {
+        Self::from_record_data(RecordData::Loaded(data))
+    }
+
+    /// Create a new record of the loaded or lazily read tensor data.
+    pub(crate) fn from_record_data(data: RecordData) -> Self {
+        Self { data }
+    }
+
+    pub(crate) fn into_data(self) -> RecordData {
+        self.data
+    }
 }
 
 // --- SERDE IMPLEMENTATIONS --- //
@@ -90,9 +205,9 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for FloatTensorSerde<S> {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = deserialize_data::<S::FloatElem, De>(deserializer)?;
+        let data = deserialize_record_data::<S::FloatElem, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self::from_record_data(data))
     }
 }
 
@@ -110,9 +225,9 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for IntTensorSerde<S> {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = deserialize_data::<S::IntElem, De>(deserializer)?;
+        let data = deserialize_record_data::<S::IntElem, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self::from_record_data(data))
     }
 }
 
@@ -130,12 +245,43 @@ impl<'de> Deserialize<'de> for BoolTensorSerde {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = deserialize_data::<bool, De>(deserializer)?;
+        let data = deserialize_record_data::<bool, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self::from_record_data(data))
     }
 }
 
+// --- TENSOR CREATION --- //
+
+/// Create a float tensor from the record data, converting it to the backend precision.
+pub(crate) fn float_tensor<B: Backend, const D: usize>(
+    data: TensorData,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let data = if let DType::QFloat(_) = data.dtype {
+        data // do not convert quantized tensors
+    } else {
+        data.convert::<B::FloatElem>()
+    };
+    Tensor::from_data(data, device)
+}
+
+/// Create an int tensor from the record data, converting it to the backend precision.
+pub(crate) fn int_tensor<B: Backend, const D: usize>(
+    data: TensorData,
+    device: &B::Device,
+) -> Tensor<B, D, Int> {
+    Tensor::from_data(data.convert::<B::IntElem>(), device)
+}
+
+/// Create a bool tensor from the record data.
+pub(crate) fn bool_tensor<B: Backend, const D: usize>(
+    data: TensorData,
+    device: &B::Device,
+) -> Tensor<B, D, Bool> {
+    Tensor::from_data(data, device)
+}
+
 // --- RECORD IMPLEMENTATIONS --- //
 
 impl<B: Backend, const D: usize> Record<B> for Tensor<B, D> {
@@ -152,12 +298,7 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        let data = if let DType::QFloat(_) = item.data.dtype {
-            item.data // do not convert quantized tensors
-        } else {
-            item.data.convert::<B::FloatElem>()
-        };
-        Tensor::from_data(data, device)
+        float_tensor(item.data.read(), device)
     }
 }
 
@@ -169,7 +310,7 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Int> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data.convert::<B::IntElem>(), device)
+        int_tensor(item.data.read(), device)
     }
 }
 
@@ -181,6 +322,6 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Bool> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data, device)
+        bool_tensor(item.data.read(), device)
     }
 }
@@ -234,6 +234,57 @@ mod num_params {
     }
 }
 
+mod param_paths {
+    use super::*;
+    use burn::module::list_param_paths;
+
+    fn paths<M: Module<TestBackend>>(module: &M) -> Vec<String> {
+        list_param_paths(module)
+            .into_iter()
+            .map(|(_, path)| path)
+            .collect()
+    }
+
+    #[test]
+    fn should_list_param_paths_composed() {
+        let device = <TestBackend as Backend>::Device::default();
+        let module = ModuleComposed::<TestBackend>::new(&device);
+
+        assert_eq!(
+            paths(&module),
+            [
+                "weight",
+                "basic.weight_basic",
+                "tuple.0.weight_basic",
+                "tuple.1.weight_basic",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_list_param_paths_enum() {
+        let device = <TestBackend as Backend>::Device::default();
+        let module = ModuleEnumNested::AnotherEnum(ModuleEnum::Basic(
+            ModuleBasic::<TestBackend>::new(&device),
+        ));
+
+        assert_eq!(paths(&module), ["AnotherEnum.Basic.weight_basic"]);
+    }
+
+    #[test]
+    fn should_list_param_paths_array() {
+        let device = <TestBackend as Backend>::Device::default();
+        let module = ModuleWithConstGeneric::<TestBackend, 2> {
+            modules: [ModuleBasic::new(&device), ModuleBasic::new(&device)],
+        };
+
+        assert_eq!(
+            paths(&module),
+            ["modules.0.weight_basic", "modules.1.weight_basic"]
+        );
+    }
+}
+
 #[cfg(feature = "std")]
 mod require_grad {
     use burn_tensor::backend::AutodiffBackend;
@@ -25,9 +25,13 @@ impl ModuleCodegen for EnumModuleCodegen {
     }
 
     fn gen_visit(&self) -> TokenStream {
-        let match_body = self.gen_variants_match_fn(|_| {
+        let match_body = self.gen_variants_match_fn(|variant| {
             quote! {
-                burn::module::Module::visit(module, visitor)
+                {
+                    visitor.enter_module(stringify!(#variant));
+                    burn::module::Module::visit(module, visitor);
+                    visitor.exit_module(stringify!(#variant));
+                }
             }
         });
 
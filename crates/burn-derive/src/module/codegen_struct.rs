@@ -29,7 +29,9 @@ impl ModuleCodegen for StructModuleCodegen {
     fn gen_visit(&self) -> TokenStream {
         let body = self.gen_fields_fn(|name| {
             quote! {
+                visitor.enter_module(stringify!(#name));
                 burn::module::Module::visit(&self.#name, visitor);
+                visitor.exit_module(stringify!(#name));
             }
         });
 
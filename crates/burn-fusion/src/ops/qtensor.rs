@@ -2,7 +2,7 @@ use std::{marker::PhantomData, ops::Range};
 
 use burn_tensor::{
     ops::{FloatElem, FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
-    quantization::{QuantizationParametersPrimitive, QuantizationScheme, QuantizationStrategy},
+    quantization::{QuantizationParametersPrimitive, QuantizationScheme},
     repr::{
         DequantizeOperationDescription, FloatOperationDescription, HandleContainer,
         OperationDescription, QuantizationParametersDescription, QuantizeOperationDescription,
@@ -20,14 +20,18 @@ use crate::{
 impl<B: FusionBackend> QTensorOps<Self> for Fusion<B> {
     fn q_from_data(data: TensorData, device: &Device<Self>) -> QuantizedTensor<Self> {
         match data.dtype {
-            DType::QFloat(strategy) => {
+            DType::QFloat(scheme) => {
                 let client = get_client::<B>(device);
                 let tensor = B::q_from_data(data, device);
                 let shape = B::q_shape(&tensor);
 
+                // Quantization parameters are defined per-tensor or for each channel
+                let num_params = scheme.axis().map(|axis| shape.dims[axis]).unwrap_or(1);
+
                 let mut handles = B::quantized_tensor_handle(tensor);
-                let qparams = match strategy {
-                    QuantizationStrategy::PerTensorAffineInt8(_) => {
+                let qparams = match &scheme {
+                    QuantizationScheme::PerTensorAffine(_)
+                    | QuantizationScheme::PerChannelAffine(_, _) => {
                         let num_handles = handles.len();
                         assert_eq!(
                             num_handles, 3,
@@ -38,19 +42,20 @@ impl<B: FusionBackend> QTensorOps<Self> for Fusion<B> {
                         FusionQuantizationParameters {
                             scale: client.register_tensor(
                                 scale,
-                                vec![1],
+                                vec![num_params],
                                 StreamId::current(),
                                 B::FloatElem::dtype(),
                             ),
                             offset: Some(client.register_tensor(
                                 offset,
-                                vec![1],
+                                vec![num_params],
                                 StreamId::current(),
                                 B::IntElem::dtype(),
                             )),
                         }
                     }
-                    QuantizationStrategy::PerTensorSymmetricInt8(_) => {
+                    QuantizationScheme::PerTensorSymmetric(_)
+                    | QuantizationScheme::PerChannelSymmetric(_, _) => {
                         let num_handles = handles.len();
                         assert_eq!(
                             num_handles, 2,
@@ -60,7 +65,7 @@ impl<B: FusionBackend> QTensorOps<Self> for Fusion<B> {
                         FusionQuantizationParameters {
                             scale: client.register_tensor(
                                 scale,
-                                vec![1],
+                                vec![num_params],
                                 StreamId::current(),
                                 B::FloatElem::dtype(),
                             ),
@@ -77,7 +82,7 @@ impl<B: FusionBackend> QTensorOps<Self> for Fusion<B> {
                 QFusionTensor {
                     qtensor,
                     qparams,
-                    scheme: strategy.scheme(),
+                    scheme,
                 }
             }
             _ => panic!(
@@ -142,7 +147,7 @@ impl<B: FusionBackend> QTensorOps<Self> for Fusion<B> {
                 scale: qparams.scale.clone().into_description(),
                 offset: qparams.offset.clone().map(|x| x.into_description()),
             },
-            scheme: scheme.clone(),
+            scheme: *scheme,
             out: out.to_description_out(),
         };
 
@@ -157,7 +162,7 @@ impl<B: FusionBackend> QTensorOps<Self> for Fusion<B> {
 
         QFusionTensor {
             qtensor: out,
-            scheme: scheme.clone(),
+            scheme: *scheme,
             qparams: qparams.into(),
         }
     }
@@ -24,6 +24,7 @@ fn main() {
         .input("tests/conv_transpose2d/conv_transpose2d.onnx")
         .input("tests/conv_transpose3d/conv_transpose3d.onnx")
         .input("tests/cos/cos.onnx")
+        .input("tests/dequantize_linear/dequantize_linear.onnx")
         .input("tests/div/div.onnx")
         .input("tests/dropout/dropout_opset16.onnx")
         .input("tests/dropout/dropout_opset7.onnx")
@@ -61,6 +62,7 @@ fn main() {
         .input("tests/mask_where/mask_where_scalar_y.onnx")
         .input("tests/mask_where/mask_where_all_scalar.onnx")
         .input("tests/matmul/matmul.onnx")
+        .input("tests/matmul_integer/matmul_integer.onnx")
         .input("tests/max/max.onnx")
         .input("tests/maxpool1d/maxpool1d.onnx")
         .input("tests/maxpool2d/maxpool2d.onnx")
@@ -73,6 +75,8 @@ fn main() {
         .input("tests/pow/pow.onnx")
         .input("tests/pow/pow_int.onnx")
         .input("tests/prelu/prelu.onnx")
+        .input("tests/qlinear_conv/qlinear_conv.onnx")
+        .input("tests/qlinear_matmul/qlinear_matmul.onnx")
         .input("tests/random_normal/random_normal.onnx")
         .input("tests/random_uniform/random_uniform.onnx")
         .input("tests/range/range.onnx")
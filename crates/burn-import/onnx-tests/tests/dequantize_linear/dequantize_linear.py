@@ -0,0 +1,92 @@
+#!/usr/bin/env python3
+
+# used to generate model: dequantize_linear.onnx
+
+# The model is in the QDQ format produced by onnxruntime's quantization tooling, where the
+# quantized weights are dequantized before a float convolution, hence it is exported using
+# onnx directly
+
+import onnx
+import onnx.helper
+from onnx import TensorProto
+
+
+def build_model():
+    weight = [(i * 5) % 11 - 5 for i in range(24)]
+    x_scale = 0.05
+    w_scale = [0.02, 0.03, 0.04]
+    b_scale = [x_scale * scale for scale in w_scale]
+
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 13)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "QuantizeLinear",
+                    inputs=["x", "x_scale", "x_zero_point"],
+                    outputs=["x_quantized"],
+                    name="QuantizeLinear_0",
+                ),
+                onnx.helper.make_node(
+                    "DequantizeLinear",
+                    inputs=["x_quantized", "x_scale", "x_zero_point"],
+                    outputs=["x_dequantized"],
+                    name="DequantizeLinear_1",
+                ),
+                onnx.helper.make_node(
+                    "DequantizeLinear",
+                    inputs=["w", "w_scale", "w_zero_point"],
+                    outputs=["w_dequantized"],
+                    name="DequantizeLinear_2",
+                    axis=0,
+                ),
+                onnx.helper.make_node(
+                    "DequantizeLinear",
+                    inputs=["b", "b_scale", "b_zero_point"],
+                    outputs=["b_dequantized"],
+                    name="DequantizeLinear_3",
+                    axis=0,
+                ),
+                onnx.helper.make_node(
+                    "Conv",
+                    inputs=["x_dequantized", "w_dequantized", "b_dequantized"],
+                    outputs=["y"],
+                    name="Conv_4",
+                    kernel_shape=[2, 2],
+                    strides=[1, 1],
+                    pads=[1, 1, 1, 1],
+                    dilations=[1, 1],
+                    group=1,
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_tensor_value_info("x", TensorProto.FLOAT, [1, 2, 4, 4])
+            ],
+            outputs=[
+                onnx.helper.make_tensor_value_info("y", TensorProto.FLOAT, [1, 3, 5, 5])
+            ],
+            initializer=[
+                onnx.helper.make_tensor("x_scale", TensorProto.FLOAT, [], [x_scale]),
+                onnx.helper.make_tensor("x_zero_point", TensorProto.UINT8, [], [128]),
+                onnx.helper.make_tensor("w", TensorProto.INT8, [3, 2, 2, 2], weight),
+                onnx.helper.make_tensor("w_scale", TensorProto.FLOAT, [3], w_scale),
+                onnx.helper.make_tensor("w_zero_point", TensorProto.INT8, [3], [0, 0, 0]),
+                onnx.helper.make_tensor("b", TensorProto.INT32, [3], [100, -50, 0]),
+                onnx.helper.make_tensor("b_scale", TensorProto.FLOAT, [3], b_scale),
+                onnx.helper.make_tensor("b_zero_point", TensorProto.INT32, [3], [0, 0, 0]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "dequantize_linear.onnx"
+    onnx.save(onnx_model, file_name)
+    onnx.checker.check_model(file_name)
+
+
+if __name__ == "__main__":
+    main()
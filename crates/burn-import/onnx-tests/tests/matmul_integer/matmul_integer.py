@@ -0,0 +1,53 @@
+#!/usr/bin/env python3
+
+# used to generate model: matmul_integer.onnx
+
+# The model follows onnxruntime's dynamic quantization of a MatMul node,
+# hence it is exported using onnx directly
+
+import onnx
+import onnx.helper
+from onnx import TensorProto
+
+
+def build_model():
+    weight = [(i * 37) % 256 for i in range(12)]
+
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 13)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "DynamicQuantizeLinear",
+                    inputs=["x"],
+                    outputs=["x_quantized", "x_scale", "x_zero_point"],
+                    name="DynamicQuantizeLinear_0",
+                ),
+                onnx.helper.make_node(
+                    "MatMulInteger",
+                    inputs=["x_quantized", "b", "x_zero_point", "b_zero_point"],
+                    outputs=["y"],
+                    name="MatMulInteger_1",
+                ),
+            ],
+            inputs=[onnx.helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3])],
+            outputs=[onnx.helper.make_tensor_value_info("y", TensorProto.INT32, [2, 4])],
+            initializer=[
+                onnx.helper.make_tensor("b", TensorProto.UINT8, [3, 4], weight),
+                onnx.helper.make_tensor("b_zero_point", TensorProto.UINT8, [], [128]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "matmul_integer.onnx"
+    onnx.save(onnx_model, file_name)
+    onnx.checker.check_model(file_name)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,84 @@
+#!/usr/bin/env python3
+
+# used to generate model: qlinear_conv.onnx
+
+# The model is in the QOperator format produced by onnxruntime's quantization tooling,
+# hence it is exported using onnx directly
+
+import onnx
+import onnx.helper
+from onnx import TensorProto
+
+
+def build_model():
+    weight = [(i * 5) % 11 - 5 for i in range(24)]
+
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 13)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "QuantizeLinear",
+                    inputs=["x", "x_scale", "x_zero_point"],
+                    outputs=["x_quantized"],
+                    name="QuantizeLinear_0",
+                ),
+                onnx.helper.make_node(
+                    "QLinearConv",
+                    inputs=[
+                        "x_quantized",
+                        "x_scale",
+                        "x_zero_point",
+                        "w",
+                        "w_scale",
+                        "w_zero_point",
+                        "y_scale",
+                        "y_zero_point",
+                        "b",
+                    ],
+                    outputs=["y_quantized"],
+                    name="QLinearConv_1",
+                    kernel_shape=[2, 2],
+                    strides=[1, 1],
+                    pads=[0, 0, 0, 0],
+                    dilations=[1, 1],
+                    group=1,
+                ),
+                onnx.helper.make_node(
+                    "DequantizeLinear",
+                    inputs=["y_quantized", "y_scale", "y_zero_point"],
+                    outputs=["y"],
+                    name="DequantizeLinear_2",
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_tensor_value_info("x", TensorProto.FLOAT, [1, 2, 4, 4])
+            ],
+            outputs=[
+                onnx.helper.make_tensor_value_info("y", TensorProto.FLOAT, [1, 3, 3, 3])
+            ],
+            initializer=[
+                onnx.helper.make_tensor("x_scale", TensorProto.FLOAT, [], [0.05]),
+                onnx.helper.make_tensor("x_zero_point", TensorProto.UINT8, [], [128]),
+                onnx.helper.make_tensor("w", TensorProto.INT8, [3, 2, 2, 2], weight),
+                onnx.helper.make_tensor("w_scale", TensorProto.FLOAT, [3], [0.02, 0.03, 0.04]),
+                onnx.helper.make_tensor("w_zero_point", TensorProto.INT8, [3], [0, 0, 0]),
+                onnx.helper.make_tensor("y_scale", TensorProto.FLOAT, [], [0.01]),
+                onnx.helper.make_tensor("y_zero_point", TensorProto.UINT8, [], [128]),
+                onnx.helper.make_tensor("b", TensorProto.INT32, [3], [100, -50, 0]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "qlinear_conv.onnx"
+    onnx.save(onnx_model, file_name)
+    onnx.checker.check_model(file_name)
+
+
+if __name__ == "__main__":
+    main()
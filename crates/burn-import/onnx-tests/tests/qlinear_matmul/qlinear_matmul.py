@@ -0,0 +1,73 @@
+#!/usr/bin/env python3
+
+# used to generate model: qlinear_matmul.onnx
+
+# The model is in the QOperator format produced by onnxruntime's quantization tooling,
+# hence it is exported using onnx directly
+
+import onnx
+import onnx.helper
+from onnx import TensorProto
+
+
+def build_model():
+    weight = [(i * 7) % 9 - 4 for i in range(12)]
+
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 13)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "QuantizeLinear",
+                    inputs=["x", "x_scale", "x_zero_point"],
+                    outputs=["x_quantized"],
+                    name="QuantizeLinear_0",
+                ),
+                onnx.helper.make_node(
+                    "QLinearMatMul",
+                    inputs=[
+                        "x_quantized",
+                        "x_scale",
+                        "x_zero_point",
+                        "b",
+                        "b_scale",
+                        "b_zero_point",
+                        "y_scale",
+                        "y_zero_point",
+                    ],
+                    outputs=["y_quantized"],
+                    name="QLinearMatMul_1",
+                ),
+                onnx.helper.make_node(
+                    "DequantizeLinear",
+                    inputs=["y_quantized", "y_scale", "y_zero_point"],
+                    outputs=["y"],
+                    name="DequantizeLinear_2",
+                ),
+            ],
+            inputs=[onnx.helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3])],
+            outputs=[onnx.helper.make_tensor_value_info("y", TensorProto.FLOAT, [2, 4])],
+            initializer=[
+                onnx.helper.make_tensor("x_scale", TensorProto.FLOAT, [], [0.05]),
+                onnx.helper.make_tensor("x_zero_point", TensorProto.UINT8, [], [128]),
+                onnx.helper.make_tensor("b", TensorProto.INT8, [3, 4], weight),
+                onnx.helper.make_tensor("b_scale", TensorProto.FLOAT, [4], [0.1, 0.05, 0.02, 0.04]),
+                onnx.helper.make_tensor("b_zero_point", TensorProto.INT8, [4], [0, 0, 0, 0]),
+                onnx.helper.make_tensor("y_scale", TensorProto.FLOAT, [], [0.01]),
+                onnx.helper.make_tensor("y_zero_point", TensorProto.UINT8, [], [128]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "qlinear_matmul.onnx"
+    onnx.save(onnx_model, file_name)
+    onnx.checker.check_model(file_name)
+
+
+if __name__ == "__main__":
+    main()
@@ -33,6 +33,7 @@ include_models!(
     conv_transpose2d,
     conv_transpose3d,
     cos,
+    dequantize_linear,
     div,
     dropout_opset16,
     dropout_opset7,
@@ -70,6 +71,7 @@ include_models!(
     mask_where_scalar_y,
     mask_where_all_scalar,
     matmul,
+    matmul_integer,
     max,
     maxpool1d,
     maxpool2d,
@@ -82,6 +84,8 @@ include_models!(
     pow,
     pow_int,
     prelu,
+    qlinear_conv,
+    qlinear_matmul,
     random_normal,
     random_uniform,
     range,
@@ -325,6 +329,38 @@ mod tests {
         output_mv.to_data().assert_eq(&expected_mv, true);
     }
 
+    #[test]
+    fn matmul_integer() {
+        // Initialize the model with the quantized weights (loaded from the exported file)
+        let model: matmul_integer::Model<Backend> = matmul_integer::Model::default();
+        let device = Default::default();
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-1.28, 0.5, 0.25], [1.27, -0.31, 0.0]], &device);
+        let output = model.forward(input);
+        let expected = TensorData::from([
+            [15184i64, 13223, 11262, -3499],
+            [-16876, -13324, -9772, 1716],
+        ]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn qlinear_matmul() {
+        // Initialize the model with the quantized weights (loaded from the exported file)
+        let model: qlinear_matmul::Model<Backend> = qlinear_matmul::Model::default();
+        let device = Default::default();
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-0.3, -0.05, 0.2], [-0.2, 0.05, 0.3]], &device);
+        let output = model.forward(input);
+        let expected = TensorData::from([[0.1f32, -0.1, 0.0, 0.02], [0.01, -0.08, 0.02, 0.02]]);
+
+        // The output is quantized with a scale of 0.01, rounding may differ by one step
+        output.to_data().assert_approx_eq_diff(&expected, 0.011);
+    }
+
     #[test]
     fn concat_tensors() {
         // Initialize the model
@@ -384,6 +420,86 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn dequantize_linear() {
+        // Initialize the model with the quantized weights (loaded from the exported file)
+        let model: dequantize_linear::Model<Backend> = dequantize_linear::Model::default();
+        let device = Default::default();
+
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[
+                [
+                    [-0.4, -0.05, 0.3, -0.2],
+                    [0.15, -0.35, 0.0, 0.35],
+                    [-0.15, 0.2, -0.3, 0.05],
+                    [0.4, -0.1, 0.25, -0.25],
+                ],
+                [
+                    [0.1, -0.4, -0.05, 0.3],
+                    [-0.2, 0.15, -0.35, 0.0],
+                    [0.35, -0.15, 0.2, -0.3],
+                    [0.05, 0.4, -0.1, 0.25],
+                ],
+            ]],
+            &device,
+        );
+        let output = model.forward(input);
+
+        let expected_shape = Shape::from([1, 3, 5, 5]);
+        assert_eq!(output.shape(), expected_shape);
+
+        // The input is exactly representable, so the output only depends on the dequantized weights
+        let output_sum = output.sum().into_scalar();
+        let expected_sum = 0.635; // from the reference implementation
+        assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
+    }
+
+    #[test]
+    fn qlinear_conv() {
+        // Initialize the model with the quantized weights (loaded from the exported file)
+        let model: qlinear_conv::Model<Backend> = qlinear_conv::Model::default();
+        let device = Default::default();
+
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[
+                [
+                    [-0.4, -0.05, 0.3, -0.2],
+                    [0.15, -0.35, 0.0, 0.35],
+                    [-0.15, 0.2, -0.3, 0.05],
+                    [0.4, -0.1, 0.25, -0.25],
+                ],
+                [
+                    [0.1, -0.4, -0.05, 0.3],
+                    [-0.2, 0.15, -0.35, 0.0],
+                    [0.35, -0.15, 0.2, -0.3],
+                    [0.05, 0.4, -0.1, 0.25],
+                ],
+            ]],
+            &device,
+        );
+        let output = model.forward(input);
+        let expected = TensorData::from([[
+            [
+                [0.16f32, 0.07, 0.03],
+                [0.07, 0.17, 0.07],
+                [0.17, 0.08, 0.17],
+            ],
+            [
+                [-0.07, -0.18, -0.03],
+                [-0.06, -0.07, -0.18],
+                [-0.06, -0.06, -0.07],
+            ],
+            [
+                [-0.01, 0.11, 0.07],
+                [-0.05, -0.03, 0.1],
+                [-0.09, -0.07, -0.04],
+            ],
+        ]]);
+
+        // The output is quantized with a scale of 0.01, rounding may differ by one step
+        output.to_data().assert_approx_eq_diff(&expected, 0.011);
+    }
+
     #[test]
     fn conv3d() {
         // Initialize the model with weights (loaded from the exported file)
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    use std::path::PathBuf;
+
+    use burn::{
+        module::Module,
+        record::{FullPrecisionSettings, HalfPrecisionSettings, Recorder},
+        tensor::Tensor,
+    };
+    use burn_import::safetensors::{AdapterType, LoadArgs, SafetensorsFileRecorder};
+    use safetensors::{Dtype, SafeTensors};
+
+    use crate::linear::{Net, NetRecord};
+
+    fn load_net(file: PathBuf, adapter_type: AdapterType) -> Net<Backend> {
+        let device = Default::default();
+        let record: NetRecord<Backend> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .load(LoadArgs::new(file).with_adapter_type(adapter_type), &device)
+                .expect("Should decode state successfully");
+
+        Net::init(&device).load_record(record)
+    }
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("burn-import-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn export_stores_tensors_by_field_path() {
+        let file = temp_file("export_keys.safetensors");
+        let model = load_net(
+            "tests/linear/linear.safetensors".into(),
+            AdapterType::PyTorch,
+        );
+
+        SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .record(model.into_record(), file.clone())
+            .expect("Should save the record");
+
+        let buffer = std::fs::read(&file).unwrap();
+        std::fs::remove_file(&file).unwrap();
+        let (_, metadata) = SafeTensors::read_metadata(&buffer).unwrap();
+        let tensors = SafeTensors::deserialize(&buffer).unwrap();
+
+        let mut names = tensors.names();
+        names.sort();
+        assert_eq!(
+            names,
+            vec!["fc1.bias", "fc1.weight", "fc2.bias", "fc2.weight"]
+        );
+
+        // Linear weights are stored with Burn's [d_input, d_output] layout
+        let weight = tensors.tensor("fc2.weight").unwrap();
+        assert_eq!(weight.shape(), &[3, 4]);
+        assert_eq!(weight.dtype(), Dtype::F32);
+
+        let metadata = metadata.metadata().clone().unwrap();
+        assert!(metadata["format"].contains("SafetensorsFileRecorder"));
+        assert_eq!(metadata["version"], env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn export_half_precision() {
+        let file = temp_file("export_half.safetensors");
+        let model = load_net(
+            "tests/linear/linear.safetensors".into(),
+            AdapterType::PyTorch,
+        );
+
+        SafetensorsFileRecorder::<HalfPrecisionSettings>::default()
+            .record(model.into_record(), file.clone())
+            .expect("Should save the record");
+
+        let buffer = std::fs::read(&file).unwrap();
+        std::fs::remove_file(&file).unwrap();
+        let tensors = SafeTensors::deserialize(&buffer).unwrap();
+
+        assert_eq!(tensors.tensor("fc1.weight").unwrap().dtype(), Dtype::F16);
+    }
+
+    #[test]
+    fn export_round_trip() {
+        let device = Default::default();
+        let file = temp_file("export_round_trip.safetensors");
+        let model = load_net(
+            "tests/linear/linear.safetensors".into(),
+            AdapterType::PyTorch,
+        );
+        let input = Tensor::<Backend, 2>::from_data([[0.5, -1.0], [1.5, 2.0]], &device);
+        let expected = model.forward(input.clone());
+
+        SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .record(model.into_record(), file.clone())
+            .expect("Should save the record");
+
+        let model = load_net(file.clone(), AdapterType::NoAdapter);
+        std::fs::remove_file(&file).unwrap();
+
+        model
+            .forward(input)
+            .to_data()
+            .assert_eq(&expected.to_data(), true);
+    }
+}
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    use burn::{
+        module::Module,
+        record::{FullPrecisionSettings, Recorder},
+        tensor::Tensor,
+    };
+    use burn_import::safetensors::{LoadArgs, SafetensorsFileRecorder};
+
+    use crate::linear::Net;
+
+    #[test]
+    fn key_remap() {
+        let device = Default::default();
+        // The weights are stored as bf16 under the "encoder" prefix
+        let load_args = LoadArgs::new("tests/key_remap/key_remap.safetensors".into())
+            .with_key_remap("encoder\\.(.*)", "$1") // Remove "encoder" prefix, e.g. "encoder.fc1" -> "fc1"
+            .with_debug_print();
+
+        let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .load(load_args, &device)
+            .expect("Should decode state successfully");
+
+        let model = Net::<Backend>::init(&device).load_record(record);
+
+        let input = Tensor::<Backend, 2>::from_data([[0.5, -1.0], [1.5, 2.0]], &device);
+
+        let output = model.forward(input);
+        let expected = Tensor::<Backend, 2>::from_data(
+            [
+                [-0.062964, 0.07492, -0.087488, 0.250562],
+                [-0.205474, 0.048729, 0.002639, 0.456787],
+            ],
+            &device,
+        );
+
+        output.to_data().assert_approx_eq(&expected.to_data(), 5);
+    }
+}
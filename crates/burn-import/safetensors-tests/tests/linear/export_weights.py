@@ -0,0 +1,49 @@
+#!/usr/bin/env python3
+
+import torch
+import torch.nn as nn
+from safetensors.torch import save_file
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+        self.fc1 = nn.Linear(2, 3)
+        self.fc2 = nn.Linear(3, 4)
+        self.relu = nn.ReLU()
+
+    def forward(self, x):
+        x = self.fc1(x)
+        x = self.relu(x)
+        x = self.fc2(x)
+        return x
+
+
+def init_weights(model):
+    # Deterministic weights, so that the expected outputs don't depend on the torch version
+    with torch.no_grad():
+        model.fc1.weight.copy_(torch.arange(6).reshape(3, 2) * 0.1 - 0.25)
+        model.fc1.bias.copy_(torch.tensor([0.1, -0.2, 0.3]))
+        model.fc2.weight.copy_(torch.arange(12).reshape(4, 3) * 0.05 - 0.3)
+        model.fc2.bias.copy_(torch.tensor([0.0, 0.1, -0.1, 0.2]))
+
+
+def main():
+
+    torch.set_printoptions(precision=8)
+
+    model = Model().to(torch.device("cpu"))
+    init_weights(model)
+
+    save_file(model.state_dict(), "linear.safetensors")
+
+    input = torch.tensor([[0.5, -1.0], [1.5, 2.0]])
+    print("Input shape: {}", input.shape)
+    print("Input: {}", input)
+    output = model(input)
+    print("Output: {}", output)
+    print("Output Shape: {}", output.shape)
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,81 @@
+use burn::{
+    module::Module,
+    nn::{Linear, LinearConfig, Relu},
+    tensor::{backend::Backend, Tensor},
+};
+
+#[derive(Module, Debug)]
+pub struct Net<B: Backend> {
+    fc1: Linear<B>,
+    fc2: Linear<B>,
+    relu: Relu,
+}
+
+impl<B: Backend> Net<B> {
+    /// Create a new model.
+    pub fn init(device: &B::Device) -> Self {
+        let fc1 = LinearConfig::new(2, 3).init(device);
+        let fc2 = LinearConfig::new(3, 4).init(device);
+        let relu = Relu;
+
+        Self { fc1, fc2, relu }
+    }
+
+    /// Forward pass of the model.
+    pub fn forward(&self, x: Tensor<B, 2>) -> Tensor<B, 2> {
+        let x = self.fc1.forward(x);
+        let x = self.relu.forward(x);
+
+        self.fc2.forward(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    use burn::record::{FullPrecisionSettings, HalfPrecisionSettings, Recorder};
+    use burn_import::safetensors::SafetensorsFileRecorder;
+
+    use super::*;
+
+    fn linear_test(record: NetRecord<Backend>, precision: usize) {
+        let device = Default::default();
+        let model = Net::<Backend>::init(&device).load_record(record);
+
+        let input = Tensor::<Backend, 2>::from_data([[0.5, -1.0], [1.5, 2.0]], &device);
+
+        let output = model.forward(input);
+        let expected = Tensor::<Backend, 2>::from_data(
+            [
+                [-0.0625, 0.075, -0.0875, 0.25],
+                [-0.205, 0.04875, 0.0025, 0.45625],
+            ],
+            &device,
+        );
+
+        output
+            .to_data()
+            .assert_approx_eq(&expected.to_data(), precision);
+    }
+
+    #[test]
+    fn linear_full_precision() {
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .load("tests/linear/linear.safetensors".into(), &device)
+            .expect("Should decode state successfully");
+
+        linear_test(record, 7);
+    }
+
+    #[test]
+    fn linear_half_precision() {
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<HalfPrecisionSettings>::default()
+            .load("tests/linear/linear.safetensors".into(), &device)
+            .expect("Should decode state successfully");
+
+        linear_test(record, 3);
+    }
+}
@@ -0,0 +1,3 @@
+mod export;
+mod key_remap;
+mod linear;
@@ -4,6 +4,7 @@ use quote::quote;
 use burn::nn::PaddingConfig1d;
 use burn::nn::PaddingConfig2d;
 use burn::nn::PaddingConfig3d;
+use burn::tensor::quantization::{QuantizationScheme, QuantizationType};
 
 fn convert_primitive<T: ToString>(primitive: T) -> TokenStream {
     let value = primitive.to_string();
@@ -115,3 +116,42 @@ impl ToTokens for PaddingConfig3d {
         }
     }
 }
+
+/// Quantization data type
+impl ToTokens for QuantizationType {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Self::QInt8 => quote! { QuantizationType::QInt8 },
+            Self::QInt4 => quote! { QuantizationType::QInt4 },
+            Self::QUInt8 => quote! { QuantizationType::QUInt8 },
+            Self::QFloat8E4M3 => quote! { QuantizationType::QFloat8E4M3 },
+            Self::QFloat8E5M2 => quote! { QuantizationType::QFloat8E5M2 },
+        }
+    }
+}
+
+/// Quantization scheme
+impl ToTokens for QuantizationScheme {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Self::PerTensorAffine(dtype) => {
+                let dtype = dtype.to_tokens();
+                quote! { QuantizationScheme::PerTensorAffine(#dtype) }
+            }
+            Self::PerTensorSymmetric(dtype) => {
+                let dtype = dtype.to_tokens();
+                quote! { QuantizationScheme::PerTensorSymmetric(#dtype) }
+            }
+            Self::PerChannelAffine(dtype, axis) => {
+                let dtype = dtype.to_tokens();
+                let axis = axis.to_tokens();
+                quote! { QuantizationScheme::PerChannelAffine(#dtype, #axis) }
+            }
+            Self::PerChannelSymmetric(dtype, axis) => {
+                let dtype = dtype.to_tokens();
+                let axis = axis.to_tokens();
+                quote! { QuantizationScheme::PerChannelSymmetric(#dtype, #axis) }
+            }
+        }
+    }
+}
@@ -5,23 +5,35 @@ use super::{
     batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode, concat::ConcatNode,
     constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, conv1d::Conv1dNode,
     conv2d::Conv2dNode, conv3d::Conv3dNode, conv_transpose_2d::ConvTranspose2dNode,
-    conv_transpose_3d::ConvTranspose3dNode, dropout::DropoutNode, expand::ExpandNode,
+    conv_transpose_3d::ConvTranspose3dNode, dequantize_linear::DequantizeLinearNode,
+    dropout::DropoutNode, dynamic_quantize_linear::DynamicQuantizeLinearNode, expand::ExpandNode,
     gather::GatherNode, gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
     layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
-    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, mean::MeanNode, pad::PadNode,
-    prelu::PReluNode, random_normal::RandomNormalNode, random_uniform::RandomUniformNode,
-    range::RangeNode, reshape::ReshapeNode, resize::ResizeNode, slice::SliceNode,
-    squeeze::SqueezeNode, sum::SumNode, tile::TileNode, unary::UnaryNode, unsqueeze::UnsqueezeNode,
+    matmul_integer::MatmulIntegerNode, max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode,
+    mean::MeanNode, pad::PadNode, prelu::PReluNode, quantize_linear::QuantizeLinearNode,
+    random_normal::RandomNormalNode, random_uniform::RandomUniformNode, range::RangeNode,
+    reshape::ReshapeNode, resize::ResizeNode, slice::SliceNode, squeeze::SqueezeNode, sum::SumNode,
+    tile::TileNode, unary::UnaryNode, unsqueeze::UnsqueezeNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::backend::NdArray;
 use burn::record::PrecisionSettings;
+use burn::tensor::{DType, TensorData};
 use proc_macro2::TokenStream;
 use serde::Serialize;
 
 /// Backend used for serialization.
 pub type SerializationBackend = NdArray<f32>;
 
+/// Convert the float data to the serialization precision, leaving quantized data untouched.
+pub(crate) fn convert_float_data<PS: PrecisionSettings>(data: TensorData) -> TensorData {
+    if let DType::QFloat(_) = data.dtype {
+        data
+    } else {
+        data.convert::<PS::FloatElem>()
+    }
+}
+
 /// Codegen trait that should be implemented by all [node](Node) entries.
 pub trait NodeCodegen<PS: PrecisionSettings>: std::fmt::Debug {
     /// All types that are used as inputs during the forward pass.
@@ -94,8 +106,10 @@ pub enum Node<PS: PrecisionSettings> {
     Conv3d(Conv3dNode),
     ConvTranspose2d(ConvTranspose2dNode),
     ConvTranspose3d(ConvTranspose3dNode),
+    DequantizeLinear(DequantizeLinearNode),
     PRelu(PReluNode),
     Dropout(DropoutNode),
+    DynamicQuantizeLinear(DynamicQuantizeLinearNode),
     Expand(ExpandNode),
     Gather(GatherNode),
     GatherElements(GatherElementsNode),
@@ -103,10 +117,12 @@ pub enum Node<PS: PrecisionSettings> {
     LayerNorm(LayerNormNode),
     Linear(LinearNode),
     Matmul(MatmulNode),
+    MatmulInteger(MatmulIntegerNode),
     MaxPool1d(MaxPool1dNode),
     MaxPool2d(MaxPool2dNode),
     Mean(MeanNode),
     Pad(PadNode),
+    QuantizeLinear(QuantizeLinearNode),
     Range(RangeNode),
     Reshape(ReshapeNode),
     Resize(ResizeNode),
@@ -142,8 +158,10 @@ macro_rules! match_all {
             Node::Conv3d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
             Node::ConvTranspose3d(node) => $func(node),
+            Node::DequantizeLinear(node) => $func(node),
             Node::PRelu(node) => $func(node),
             Node::Dropout(node) => $func(node),
+            Node::DynamicQuantizeLinear(node) => $func(node),
             Node::Expand(node) => $func(node),
             Node::Gather(node) => $func(node),
             Node::GatherElements(node) => $func(node),
@@ -151,10 +169,12 @@ macro_rules! match_all {
             Node::LayerNorm(node) => $func(node),
             Node::Linear(node) => $func(node),
             Node::Matmul(node) => $func(node),
+            Node::MatmulInteger(node) => $func(node),
             Node::MaxPool1d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
             Node::Mean(node) => $func(node),
             Node::Pad(node) => $func(node),
+            Node::QuantizeLinear(node) => $func(node),
             Node::Range(node) => $func(node),
             Node::Reshape(node) => $func(node),
             Node::Resize(node) => $func(node),
@@ -198,8 +218,10 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Conv3d(_) => "conv3d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::ConvTranspose3d(_) => "conv_transpose3d",
+            Node::DequantizeLinear(_) => "dequantize_linear",
             Node::PRelu(_) => "prelu",
             Node::Dropout(_) => "dropout",
+            Node::DynamicQuantizeLinear(_) => "dynamic_quantize_linear",
             Node::Expand(_) => "expand",
             Node::Gather(_) => "gather",
             Node::GatherElements(_) => "gather_elements",
@@ -207,10 +229,12 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::LayerNorm(_) => "layer_norm",
             Node::Linear(_) => "linear",
             Node::Matmul(_) => "matmul",
+            Node::MatmulInteger(_) => "matmul_integer",
             Node::MaxPool1d(_) => "max_pool1d",
             Node::MaxPool2d(_) => "max_pool2d",
             Node::Mean(_) => "mean",
             Node::Pad(_) => "pad",
+            Node::QuantizeLinear(_) => "quantize_linear",
             Node::Range(_) => "range",
             Node::Reshape(_) => "reshape",
             Node::Resize(_) => "resize",
@@ -1,4 +1,4 @@
-use super::{Node, NodeCodegen, SerializationBackend};
+use super::{convert_float_data, Node, NodeCodegen, SerializationBackend};
 use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
@@ -84,10 +84,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Conv3dNode {
         let record = Conv3dRecord::<SerializationBackend> {
             weight: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data(
-                    self.data_weights.clone().convert::<PS::FloatElem>(),
-                    &device,
-                ),
+                Tensor::from_data(convert_float_data::<PS>(self.data_weights.clone()), &device),
             ),
             bias: self.data_bias.as_ref().map(|bias| {
                 Param::initialized(
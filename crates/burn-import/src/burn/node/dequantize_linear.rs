@@ -0,0 +1,216 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ParamId,
+    record::{ParamSerde, PrecisionSettings},
+    tensor::TensorData,
+};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use serde::Serialize;
+
+/// Dequantizes the input tensor.
+///
+/// When the input is a constant, the quantized values are stored as a parameter of the model.
+#[derive(Debug, Clone)]
+pub struct DequantizeLinearNode {
+    pub name: String,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub data: Option<TensorData>,
+}
+
+impl DequantizeLinearNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        data: Option<TensorData>,
+    ) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            input,
+            output,
+            data,
+        }
+    }
+
+    fn field(&self) -> TensorType {
+        TensorType {
+            name: Ident::new(&self.name, Span::call_site()),
+            ..self.input.clone()
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DequantizeLinearNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        match self.data {
+            Some(_) => vec![],
+            None => vec![Type::Tensor(self.input.clone())],
+        }
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        self.data.as_ref().map(|_| Type::Tensor(self.field()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        self.data.as_ref().map(|_| {
+            let field = self.field();
+            let ty = field.ty();
+            let name = &field.name;
+            let shape = field.shape.unwrap().to_tokens();
+
+            quote! {
+                let #name: burn::module::Param<#ty> = burn::nn::Initializer::Zeros.init(#shape, device).set_require_grad(false);
+            }
+        })
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let output = &self.output.name;
+
+        match self.data {
+            Some(_) => {
+                let name = Ident::new(&self.name, Span::call_site());
+                quote! {
+                    let #output = self.#name.val().dequantize();
+                }
+            }
+            None => {
+                let input = scope.tensor_use_owned(&self.input, node_position);
+                quote! {
+                    let #output = #input.dequantize();
+                }
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::DequantizeLinear(self)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Some(data) = &self.data {
+            // The quantized values are serialized as is
+            let data = ParamSerde::new(ParamId::new().into_string(), data.clone());
+            return data.serialize(serializer);
+        }
+
+        S::serialize_none(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::{record::FullPrecisionSettings, tensor::quantization::QuantizationType};
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{quantize_linear::QParams, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_dequantize_linear() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DequantizeLinearNode::new(
+            "dequantizelinear1",
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            None,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.dequantize();
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_dequantize_linear_constant() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let qparams = QParams::new(QuantizationType::QInt8, vec![0.5], vec![0], None);
+
+        graph.register(DequantizeLinearNode::new(
+            "dequantizelinear1",
+            TensorType::new_float_with_shape("weight", 2, Some(vec![2, 2])),
+            TensorType::new_float("tensor1", 2),
+            Some(qparams.quantized_data(vec![1, 2, 3, 4], vec![2, 2])),
+        ));
+
+        graph.register_input_output(vec![], vec!["tensor1".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                dequantizelinear1: burn::module::Param<Tensor<B, 2>>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let dequantizelinear1: burn::module::Param<Tensor<B, 2>> = burn::nn::Initializer::Zeros.init([2, 2], device).set_require_grad(false);
+
+                    Self {
+                        dequantizelinear1,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self) -> Tensor<B, 2> {
+                    let tensor1 = self.dequantizelinear1.val().dequantize();
+
+                    tensor1
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,134 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, ScalarType, Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Quantizes the input tensor to `uint8` with the parameters computed from its range, and
+/// outputs the scale and zero point.
+#[derive(Debug, Clone, new)]
+pub struct DynamicQuantizeLinearNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub output_scale: ScalarType,
+    pub output_zero_point: ScalarType,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DynamicQuantizeLinearNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.output.clone()),
+            Type::Scalar(self.output_scale.clone()),
+            Type::Scalar(self.output_zero_point.clone()),
+        ]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let output_scale = &self.output_scale.name;
+        let output_zero_point = &self.output_zero_point.name;
+
+        quote! {
+            let (#output, #output_scale, #output_zero_point) = {
+                let input = #input;
+                let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8);
+                let qparams = scheme.compute_q_params(MinMaxCalibration {}.compute_range(&input));
+                let scale = qparams.scale.clone().into_scalar().elem::<f32>();
+                let offset = qparams.offset.clone().unwrap().into_scalar().elem::<i32>();
+
+                (input.quantize(&scheme, qparams), scale, offset)
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::ElementConversion");
+        imports.register("burn::tensor::quantization::Calibration");
+        imports.register("burn::tensor::quantization::MinMaxCalibration");
+        imports.register("burn::tensor::quantization::QuantizationScheme");
+        imports.register("burn::tensor::quantization::QuantizationType");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::DynamicQuantizeLinear(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, ScalarKind, TensorType};
+
+    #[test]
+    fn test_codegen_dynamic_quantize_linear() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DynamicQuantizeLinearNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            ScalarType::new("scale", ScalarKind::Float32),
+            ScalarType::new("zero_point", ScalarKind::Int32),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec![
+                "tensor2".to_string(),
+                "scale".to_string(),
+                "zero_point".to_string(),
+            ],
+        );
+
+        let expected = quote! {
+            use burn::tensor::ElementConversion;
+            use burn::tensor::quantization::Calibration;
+            use burn::tensor::quantization::MinMaxCalibration;
+            use burn::tensor::quantization::QuantizationScheme;
+            use burn::tensor::quantization::QuantizationType;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, f32, i32) {
+                    let (tensor2, scale, zero_point) = {
+                        let input = tensor1;
+                        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8);
+                        let qparams = scheme.compute_q_params(MinMaxCalibration {}.compute_range(&input));
+                        let scale = qparams.scale.clone().into_scalar().elem::<f32>();
+                        let offset = qparams.offset.clone().unwrap().into_scalar().elem::<i32>();
+
+                        (input.quantize(&scheme, qparams), scale, offset)
+                    };
+
+                    (tensor2, scale, zero_point)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,201 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, ScalarType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ParamId,
+    record::{ParamSerde, PrecisionSettings},
+    tensor::TensorData,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Integer matrix product of a dynamically quantized input with constant quantized weights.
+///
+/// The weights are stored with a unit scale, so that they dequantize to the values offset by
+/// their zero point. The integer values of the input are recovered from its scale and zero point.
+#[derive(Debug, Clone)]
+pub struct MatmulIntegerNode {
+    pub field: TensorType,
+    pub input: TensorType,
+    pub input_scale: ScalarType,
+    pub input_zero_point: Option<ScalarType>,
+    pub output: TensorType,
+    pub data_weights: TensorData,
+}
+
+impl MatmulIntegerNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        input_scale: ScalarType,
+        input_zero_point: Option<ScalarType>,
+        output: TensorType,
+        data_weights: TensorData,
+    ) -> Self {
+        Self {
+            field: TensorType::new_float_with_shape(name, 2, Some(data_weights.shape.clone())),
+            input,
+            input_scale,
+            input_zero_point,
+            output,
+            data_weights,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for MatmulIntegerNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![
+            Type::Tensor(self.input.clone()),
+            Type::Scalar(self.input_scale.clone()),
+        ];
+        if let Some(zero_point) = &self.input_zero_point {
+            inputs.push(Type::Scalar(zero_point.clone()));
+        }
+
+        inputs
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Tensor(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let ty = self.field.ty();
+        let name = &self.field.name;
+        let shape = self.field.shape.clone().unwrap().to_tokens();
+
+        Some(quote! {
+            let #name: burn::module::Param<#ty> = burn::nn::Initializer::Zeros.init(#shape, device).set_require_grad(false);
+        })
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let input_scale = &self.input_scale.name;
+        let output = &self.output.name;
+        let field = &self.field.name;
+
+        // Values offset by the zero point, as integers
+        let values = match &self.input_zero_point {
+            Some(zero_point) => {
+                let zero_point = &zero_point.name;
+                quote! {
+                    let values = #input.dequantize().div_scalar(#input_scale).add_scalar(#zero_point);
+                    let values = values.clone().add(values.sign().mul_scalar(0.5)).int().sub_scalar(#zero_point);
+                }
+            }
+            None => quote! {
+                let values = #input.dequantize().div_scalar(#input_scale);
+                let values = values.clone().add(values.sign().mul_scalar(0.5)).int();
+            },
+        };
+
+        quote! {
+            let #output = {
+                #values
+                linear(values.float(), self.#field.val(), None).int()
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::module::linear");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::MatmulInteger(self)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = ParamSerde::new(ParamId::new().into_string(), self.data_weights.clone());
+        data.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::{record::FullPrecisionSettings, tensor::quantization::QuantizationType};
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{quantize_linear::QParams, test::assert_tokens},
+        ScalarKind, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_matmul_integer() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let qparams = QParams::new(QuantizationType::QUInt8, vec![1.0], vec![128], None);
+
+        graph.register(MatmulIntegerNode::new(
+            "matmulinteger1",
+            TensorType::new_float("tensor1", 3),
+            ScalarType::new("scale", ScalarKind::Float32),
+            Some(ScalarType::new("zero_point", ScalarKind::Int32)),
+            TensorType::new_int("tensor2", 3),
+            qparams.quantized_data(vec![128, 129, 130, 131, 132, 133], vec![2, 3]),
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "scale".to_string(),
+                "zero_point".to_string(),
+            ],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::module::linear;
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                matmulinteger1: burn::module::Param<Tensor<B, 2>>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let matmulinteger1: burn::module::Param<Tensor<B, 2>> = burn::nn::Initializer::Zeros.init([2, 3], device).set_require_grad(false);
+
+                    Self {
+                        matmulinteger1,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3>,
+                    scale: f32,
+                    zero_point: i32,
+                ) -> Tensor<B, 3, Int> {
+                    let tensor2 = {
+                        let values = tensor1.dequantize().div_scalar(scale).add_scalar(zero_point);
+                        let values = values.clone().add(values.sign().mul_scalar(0.5)).int().sub_scalar(zero_point);
+                        linear(values.float(), self.matmulinteger1.val(), None).int()
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -14,7 +14,9 @@ pub(crate) mod conv2d;
 pub(crate) mod conv3d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod conv_transpose_3d;
+pub(crate) mod dequantize_linear;
 pub(crate) mod dropout;
+pub(crate) mod dynamic_quantize_linear;
 pub(crate) mod expand;
 pub(crate) mod gather;
 pub(crate) mod gather_elements;
@@ -23,11 +25,13 @@ pub(crate) mod layer_norm;
 pub(crate) mod linear;
 pub(crate) mod mask_where;
 pub(crate) mod matmul;
+pub(crate) mod matmul_integer;
 pub(crate) mod max_pool1d;
 pub(crate) mod max_pool2d;
 pub(crate) mod mean;
 pub(crate) mod pad;
 pub(crate) mod prelu;
+pub(crate) mod quantize_linear;
 pub(crate) mod random_normal;
 pub(crate) mod random_uniform;
 pub(crate) mod range;
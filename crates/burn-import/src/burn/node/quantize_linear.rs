@@ -0,0 +1,258 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::{
+    record::PrecisionSettings,
+    tensor::{
+        quantization::{
+            AffineQuantization, PerChannelQuantization, QuantizationScheme, QuantizationStrategy,
+            QuantizationType,
+        },
+        TensorData,
+    },
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// Quantization parameters given by the scale and zero point initializers of an ONNX node.
+#[derive(Debug, Clone)]
+pub struct QParams {
+    /// The affine quantization scheme.
+    pub scheme: QuantizationScheme,
+    /// The scaling factor, with one value per channel for per-channel schemes.
+    pub scale: Vec<f32>,
+    /// The zero point, with one value per channel for per-channel schemes.
+    pub offset: Vec<i32>,
+}
+
+impl QParams {
+    pub fn new(
+        dtype: QuantizationType,
+        scale: Vec<f32>,
+        offset: Vec<i32>,
+        axis: Option<usize>,
+    ) -> Self {
+        assert_eq!(
+            scale.len(),
+            offset.len(),
+            "The scale and zero point should have the same number of values"
+        );
+
+        let scheme = match axis {
+            Some(axis) if scale.len() > 1 => QuantizationScheme::PerChannelAffine(dtype, axis),
+            _ => QuantizationScheme::PerTensorAffine(dtype),
+        };
+
+        Self {
+            scheme,
+            scale,
+            offset,
+        }
+    }
+
+    /// The quantization strategy of the constant values.
+    pub fn strategy(&self) -> QuantizationStrategy {
+        let affine_i8 =
+            |(scale, offset): (&f32, &i32)| AffineQuantization::init(*scale, *offset as i8);
+        let affine_u8 =
+            |(scale, offset): (&f32, &i32)| AffineQuantization::init(*scale, *offset as u8);
+        let params = self.scale.iter().zip(self.offset.iter());
+
+        match &self.scheme {
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) => {
+                QuantizationStrategy::PerTensorAffineInt8(params.map(affine_i8).next().unwrap())
+            }
+            QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8) => {
+                QuantizationStrategy::PerTensorAffineUInt8(params.map(affine_u8).next().unwrap())
+            }
+            QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, axis) => {
+                QuantizationStrategy::PerChannelAffineInt8(PerChannelQuantization::init(
+                    *axis,
+                    params.map(affine_i8).collect(),
+                ))
+            }
+            QuantizationScheme::PerChannelAffine(QuantizationType::QUInt8, axis) => {
+                QuantizationStrategy::PerChannelAffineUInt8(PerChannelQuantization::init(
+                    *axis,
+                    params.map(affine_u8).collect(),
+                ))
+            }
+            scheme => panic!("Unsupported quantization scheme {scheme:?}"),
+        }
+    }
+
+    /// Create the quantized data from the constant integer values.
+    pub fn quantized_data(&self, values: Vec<i32>, shape: Vec<usize>) -> TensorData {
+        let strategy = self.strategy();
+        match strategy.scheme() {
+            QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8)
+            | QuantizationScheme::PerChannelAffine(QuantizationType::QUInt8, _) => {
+                let values = values.into_iter().map(|v| v as u8).collect::<Vec<_>>();
+                TensorData::quantized(values, shape, strategy)
+            }
+            _ => {
+                let values = values.into_iter().map(|v| v as i8).collect::<Vec<_>>();
+                TensorData::quantized(values, shape, strategy)
+            }
+        }
+    }
+
+    /// Dequantize the constant integer values, e.g. for `int32` values which have no
+    /// corresponding quantized type.
+    pub fn dequantize(&self, values: &[i32], shape: &[usize]) -> Vec<f32> {
+        let (channels, inner) = match self.scheme.axis() {
+            Some(axis) => (shape[axis], shape[axis + 1..].iter().product()),
+            None => (1, 1),
+        };
+
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let channel = (i / inner) % channels;
+                (value - self.offset[channel]) as f32 * self.scale[channel]
+            })
+            .collect()
+    }
+
+    /// The `QuantizationParameters` initialized on the model device.
+    pub fn qparams_tokens(&self) -> TokenStream {
+        let scale = self.scale.iter().map(|v| Literal::f32_suffixed(*v));
+        let offset = self.offset.iter().map(|v| Literal::i32_suffixed(*v));
+
+        quote! {
+            QuantizationParameters {
+                scale: Tensor::from_floats([#(#scale),*], &*self.device),
+                offset: Some(Tensor::from_ints([#(#offset),*], &*self.device)),
+            }
+        }
+    }
+
+    /// Register the quantization imports.
+    pub fn register_imports(imports: &mut BurnImports) {
+        imports.register("burn::tensor::quantization::QuantizationParameters");
+        imports.register("burn::tensor::quantization::QuantizationScheme");
+        imports.register("burn::tensor::quantization::QuantizationType");
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuantizeLinearNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub qparams: QParams,
+}
+
+impl QuantizeLinearNode {
+    pub fn new(input: TensorType, output: TensorType, qparams: QParams) -> Self {
+        Self {
+            input,
+            output,
+            qparams,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for QuantizeLinearNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let scheme = self.qparams.scheme.to_tokens();
+        let qparams = self.qparams.qparams_tokens();
+
+        quote! {
+            let #output = #input.quantize(&#scheme, #qparams);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        QParams::register_imports(imports);
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::QuantizeLinear(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{quantize_linear::QuantizeLinearNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_quantize_linear() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(QuantizeLinearNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            QParams::new(QuantizationType::QUInt8, vec![0.5], vec![128], Some(1)),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::quantization::QuantizationParameters;
+            use burn::tensor::quantization::QuantizationScheme;
+            use burn::tensor::quantization::QuantizationType;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.quantize(
+                        &QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8),
+                        QuantizationParameters {
+                            scale: Tensor::from_floats([0.5f32], &*self.device),
+                            offset: Some(Tensor::from_ints([128i32], &*self.device)),
+                        }
+                    );
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_dequantize_per_channel() {
+        let qparams = QParams::new(QuantizationType::QInt8, vec![0.5, 2.0], vec![0, 1], Some(0));
+
+        assert_eq!(
+            qparams.dequantize(&[2, 4, 1, 3], &[2, 2]),
+            vec![1.0, 2.0, 0.0, 4.0]
+        );
+    }
+}
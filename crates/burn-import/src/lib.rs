@@ -29,5 +29,9 @@ pub mod burn;
 #[cfg(feature = "pytorch")]
 pub mod pytorch;
 
+/// The safetensors module for recorder.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
 mod formatter;
 pub use formatter::*;
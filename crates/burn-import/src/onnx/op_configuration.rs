@@ -7,8 +7,12 @@ use burn::nn::{
     PaddingConfig2d, PaddingConfig3d,
 };
 
-use crate::burn::node::{expand::ExpandShape, pad::PadConfig, tile::TileConfig};
-use onnx_ir::ir::{ArgType, AttributeValue, Data, ElementType, Node};
+use burn::tensor::quantization::QuantizationType;
+
+use crate::burn::node::{
+    expand::ExpandShape, pad::PadConfig, quantize_linear::QParams, tile::TileConfig,
+};
+use onnx_ir::ir::{ArgType, AttributeValue, Data, ElementType, Node, NodeType};
 
 /// Create a Conv1dConfig from the attributes of the node
 pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
@@ -1693,3 +1697,79 @@ pub fn squeeze_config(curr: &Node) -> Vec<i64> {
 
     axes
 }
+
+/// Create the quantization parameters of a QuantizeLinear or DequantizeLinear node
+pub fn quantize_linear_config(curr: &Node) -> QParams {
+    // the quantized tensor is the output of QuantizeLinear and the input of DequantizeLinear
+    let quantized = match curr.node_type {
+        NodeType::QuantizeLinear => &curr.outputs[0],
+        _ => &curr.inputs[0],
+    };
+    let tensor = match &quantized.ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("{}: only tensor input is valid", curr.node_type),
+    };
+
+    // the axis of the per-channel quantization (Default: 1 per ONNX spec)
+    let mut axis: i64 = 1;
+    if let Some(value) = curr.attrs.get("axis") {
+        axis = value.clone().into_i64();
+    }
+    if axis < 0 {
+        axis += tensor.dim as i64;
+    }
+
+    let dtype = match tensor.elem_type {
+        // int32 values (e.g., biases) have no quantized type and are only dequantized at import
+        ElementType::Int32 => QuantizationType::QInt8,
+        ref elem_type => quantization_type(elem_type),
+    };
+
+    qparams_config(curr, 1, 2, dtype, axis as usize)
+}
+
+/// Create the quantization parameters from the scale and zero point inputs of the node
+///
+/// The zero point is optional and defaults to 0. When the scale has more than one value, the
+/// parameters are applied per channel along the given axis.
+pub fn qparams_config(
+    curr: &Node,
+    scale_index: usize,
+    zero_point_index: usize,
+    dtype: QuantizationType,
+    axis: usize,
+) -> QParams {
+    let scale = match curr.inputs[scale_index].value.clone() {
+        Some(Data::Float32(scale)) => vec![scale],
+        Some(Data::Float32s(scale)) => scale,
+        Some(Data::Float16(scale)) => vec![f32::from(scale)],
+        Some(Data::Float16s(scale)) => scale.into_iter().map(f32::from).collect(),
+        Some(data) => panic!("{}: unsupported scale {:?}", curr.node_type, data),
+        None => panic!("{}: the scale must be a constant", curr.node_type),
+    };
+
+    let offset = match curr
+        .inputs
+        .get(zero_point_index)
+        .filter(|zero_point| !zero_point.name.is_empty())
+    {
+        Some(zero_point) => match zero_point.value.clone() {
+            Some(Data::Int32(offset)) => vec![offset],
+            Some(Data::Int32s(offset)) => offset,
+            Some(data) => panic!("{}: unsupported zero point {:?}", curr.node_type, data),
+            None => panic!("{}: the zero point must be a constant", curr.node_type),
+        },
+        None => vec![0; scale.len()],
+    };
+
+    QParams::new(dtype, scale, offset, Some(axis))
+}
+
+/// Get the quantization type of the quantized tensor element type
+pub fn quantization_type(elem_type: &ElementType) -> QuantizationType {
+    match elem_type {
+        ElementType::Int8 => QuantizationType::QInt8,
+        ElementType::Uint8 => QuantizationType::QUInt8,
+        _ => panic!("Unsupported quantization type {:?}", elem_type),
+    }
+}
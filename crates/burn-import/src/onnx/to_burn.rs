@@ -1,13 +1,14 @@
 use std::{
+    collections::{HashMap, HashSet},
     env,
     fs::{self, create_dir_all},
     path::{Path, PathBuf},
 };
 
 use burn::{
-    nn::PReluConfig,
+    nn::{LinearConfig, PReluConfig},
     record::{FullPrecisionSettings, HalfPrecisionSettings, PrecisionSettings},
-    tensor::{Element, TensorData},
+    tensor::{quantization::QuantizationType, Element, TensorData},
 };
 use log::warn;
 
@@ -29,7 +30,9 @@ use crate::{
             conv3d::Conv3dNode,
             conv_transpose_2d::ConvTranspose2dNode,
             conv_transpose_3d::ConvTranspose3dNode,
+            dequantize_linear::DequantizeLinearNode,
             dropout::DropoutNode,
+            dynamic_quantize_linear::DynamicQuantizeLinearNode,
             expand::{ExpandNode, ExpandShape},
             gather::GatherNode,
             gather_elements::GatherElementsNode,
@@ -38,10 +41,12 @@ use crate::{
             linear::LinearNode,
             mask_where::WhereNode,
             matmul::MatmulNode,
+            matmul_integer::MatmulIntegerNode,
             max_pool1d::MaxPool1dNode,
             max_pool2d::MaxPool2dNode,
             pad::PadNode,
             prelu::PReluNode,
+            quantize_linear::{QParams, QuantizeLinearNode},
             random_normal::RandomNormalNode,
             random_uniform::RandomUniformNode,
             range::RangeNode,
@@ -53,6 +58,7 @@ use crate::{
             tile::TileNode,
             unary::UnaryNode,
             unsqueeze::UnsqueezeNode,
+            Node as BurnNode, NodeCodegen,
         },
         ScalarKind, ScalarType, ShapeType, TensorKind, TensorType, Type,
     },
@@ -65,10 +71,10 @@ use super::op_configuration::{
     concat_config, conv1d_config, conv2d_config, conv3d_config, conv_transpose2d_config,
     conv_transpose3d_config, dropout_config, expand_config, flatten_config, gather_config,
     hard_sigmoid_config, layer_norm_config, leaky_relu_config, linear_config, log_softmax_config,
-    max_pool1d_config, max_pool2d_config, pad_config, reduce_max_config, reduce_mean_config,
-    reduce_min_config, reduce_prod_config, reduce_sum_config, reshape_config, resize_config,
-    shape_config, slice_config, softmax_config, squeeze_config, tile_config, transpose_config,
-    unsqueeze_config,
+    max_pool1d_config, max_pool2d_config, pad_config, qparams_config, quantization_type,
+    quantize_linear_config, reduce_max_config, reduce_mean_config, reduce_min_config,
+    reduce_prod_config, reduce_sum_config, reshape_config, resize_config, shape_config,
+    slice_config, softmax_config, squeeze_config, tile_config, transpose_config, unsqueeze_config,
 };
 use onnx_ir::{
     convert_constant_value,
@@ -252,6 +258,9 @@ impl ParsedOnnxGraph {
 
         let mut unsupported_ops = vec![];
 
+        let quantized = QuantizedConstants::new(&self.0);
+        let mut dynamic_scales = HashMap::new();
+
         for node in self.0.nodes {
             match node.node_type {
                 NodeType::Add => graph.register(Self::add_conversion(node)),
@@ -265,9 +274,9 @@ impl ParsedOnnxGraph {
                 NodeType::Expand => graph.register(Self::expand_conversion(node)),
                 NodeType::Clip => graph.register(Self::clip_conversion(node)),
                 NodeType::Cos => graph.register(Self::cos_conversion(node)),
-                NodeType::Conv1d => graph.register(Self::conv1d_conversion::<PS>(node)),
-                NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node)),
-                NodeType::Conv3d => graph.register(Self::conv3d_conversion::<PS>(node)),
+                NodeType::Conv1d => graph.register(Self::conv1d_conversion::<PS>(node, &quantized)),
+                NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node, &quantized)),
+                NodeType::Conv3d => graph.register(Self::conv3d_conversion::<PS>(node, &quantized)),
                 NodeType::Max => graph.register(Self::max_conversion(node)),
                 NodeType::MaxPool1d => graph.register(Self::max_pool1d_conversion(node)),
                 NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
@@ -342,6 +351,31 @@ impl ParsedOnnxGraph {
                 NodeType::ConstantOfShape => {
                     graph.register(Self::constant_of_shape_conversion(node))
                 }
+                NodeType::QuantizeLinear => graph.register(Self::quantize_linear_conversion(node)),
+                NodeType::DequantizeLinear => {
+                    // Constants folded into the weights of their consumers are skipped
+                    if !quantized.is_folded(&node.outputs[0].name) {
+                        graph.register(Self::dequantize_linear_conversion(node, &quantized))
+                    }
+                }
+                NodeType::DynamicQuantizeLinear => {
+                    // Keep track of the scale for the MatMulInteger nodes
+                    dynamic_scales.insert(node.outputs[0].name.clone(), node.outputs[1].clone());
+                    graph.register(Self::dynamic_quantize_linear_conversion(node))
+                }
+                NodeType::MatMulInteger => {
+                    graph.register(Self::matmul_integer_conversion(node, &dynamic_scales))
+                }
+                NodeType::QLinearConv => {
+                    let (conv, quantize) = Self::qlinear_conv_conversion(node);
+                    graph.register(conv);
+                    graph.register(quantize);
+                }
+                NodeType::QLinearMatMul => {
+                    let (matmul, quantize) = Self::qlinear_matmul_conversion::<PS>(node);
+                    graph.register(matmul);
+                    graph.register(quantize);
+                }
                 node_type => unsupported_ops.push(node_type),
             }
         }
@@ -911,15 +945,20 @@ impl ParsedOnnxGraph {
         LayerNormNode::new(name, input, output, gamma, beta, config, full_precision)
     }
 
-    fn conv1d_conversion<PS: PrecisionSettings>(node: Node) -> Conv1dNode {
+    fn conv1d_conversion<PS: PrecisionSettings>(
+        node: Node,
+        quantized: &QuantizedConstants,
+    ) -> Conv1dNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
         let config = conv1d_config(&node);
 
         let bias = node.inputs.len() == 3;
-        let weight = extract_data_serialize::<PS::FloatElem>(1, &node).unwrap();
+        let weight = quantized
+            .extract_data_serialize::<PS::FloatElem>(1, &node)
+            .unwrap();
         let bias = match bias {
-            true => extract_data_serialize::<PS::FloatElem>(2, &node),
+            true => quantized.extract_data_serialize::<PS::FloatElem>(2, &node),
             false => None,
         };
 
@@ -927,15 +966,20 @@ impl ParsedOnnxGraph {
         Conv1dNode::new(name, input, output, weight, bias, config)
     }
 
-    fn conv2d_conversion<PS: PrecisionSettings>(node: Node) -> Conv2dNode {
+    fn conv2d_conversion<PS: PrecisionSettings>(
+        node: Node,
+        quantized: &QuantizedConstants,
+    ) -> Conv2dNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
         let config = conv2d_config(&node);
 
         let bias = node.inputs.len() == 3;
-        let weight = extract_data_serialize::<PS::FloatElem>(1, &node).unwrap();
+        let weight = quantized
+            .extract_data_serialize::<PS::FloatElem>(1, &node)
+            .unwrap();
         let bias = match bias {
-            true => extract_data_serialize::<PS::FloatElem>(2, &node),
+            true => quantized.extract_data_serialize::<PS::FloatElem>(2, &node),
             false => None,
         };
 
@@ -943,15 +987,20 @@ impl ParsedOnnxGraph {
         Conv2dNode::new(name, input, output, weight, bias, config)
     }
 
-    fn conv3d_conversion<PS: PrecisionSettings>(node: Node) -> Conv3dNode {
+    fn conv3d_conversion<PS: PrecisionSettings>(
+        node: Node,
+        quantized: &QuantizedConstants,
+    ) -> Conv3dNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
         let config = conv3d_config(&node);
 
         let bias = node.inputs.len() == 3;
-        let weight = extract_data_serialize::<PS::FloatElem>(1, &node).unwrap();
+        let weight = quantized
+            .extract_data_serialize::<PS::FloatElem>(1, &node)
+            .unwrap();
         let bias = match bias {
-            true => extract_data_serialize::<PS::FloatElem>(2, &node),
+            true => quantized.extract_data_serialize::<PS::FloatElem>(2, &node),
             false => None,
         };
 
@@ -959,6 +1008,163 @@ impl ParsedOnnxGraph {
         Conv3dNode::new(name, input, output, weight, bias, config)
     }
 
+    fn quantize_linear_conversion(node: Node) -> QuantizeLinearNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let qparams = quantize_linear_config(&node);
+
+        QuantizeLinearNode::new(input, output, qparams)
+    }
+
+    fn dequantize_linear_conversion(
+        node: Node,
+        quantized: &QuantizedConstants,
+    ) -> DequantizeLinearNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let data = quantized.extract_data_serialize::<f32>(0, &node);
+
+        DequantizeLinearNode::new(&node.name, input, output, data)
+    }
+
+    fn dynamic_quantize_linear_conversion(node: Node) -> DynamicQuantizeLinearNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let output_scale = ScalarType::new(node.outputs[1].name.clone(), ScalarKind::Float32);
+        let output_zero_point = ScalarType::new(node.outputs[2].name.clone(), ScalarKind::Int32);
+
+        DynamicQuantizeLinearNode::new(input, output, output_scale, output_zero_point)
+    }
+
+    fn matmul_integer_conversion(
+        node: Node,
+        dynamic_scales: &HashMap<String, OnnxArgument>,
+    ) -> MatmulIntegerNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+
+        // The integer input values are recovered from the dynamically quantized tensor
+        let input_scale = dynamic_scales
+            .get(&node.inputs[0].name)
+            .map(|scale| ScalarType::new(scale.name.clone(), ScalarKind::Float32))
+            .expect("MatMulInteger: the first input must come from a DynamicQuantizeLinear node");
+        let input_zero_point = node
+            .inputs
+            .get(2)
+            .filter(|arg| !arg.name.is_empty())
+            .map(|zero_point| ScalarType::new(zero_point.name.clone(), ScalarKind::Int32));
+
+        let (weight, shape) = quantized_constant(&node.inputs[1], 2)
+            .expect("MatMulInteger: the second input must be a constant 2D tensor");
+        let zero_point = match node.inputs.get(3).and_then(|arg| arg.value.clone()) {
+            Some(Data::Int32(zero_point)) => vec![zero_point],
+            Some(Data::Int32s(zero_point)) => zero_point,
+            _ => vec![0],
+        };
+        // Unit scale, so that the weights are dequantized to `b - b_zero_point`
+        let qparams = QParams::new(
+            quantization_type(node.inputs[1].ty.elem_type()),
+            vec![1.0; zero_point.len()],
+            zero_point,
+            Some(1),
+        );
+
+        let weight = qparams.quantized_data(weight, shape);
+
+        MatmulIntegerNode::new(
+            &node.name,
+            input,
+            input_scale,
+            input_zero_point,
+            output,
+            weight,
+        )
+    }
+
+    fn qlinear_conv_conversion(node: Node) -> (Conv2dNode, QuantizeLinearNode) {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+
+        let (weight, shape) = quantized_constant(&node.inputs[3], 4)
+            .expect("QLinearConv: only 2D convolutions with constant weights are supported");
+        let weight_type = quantization_type(node.inputs[3].ty.elem_type());
+        let weight_qparams = qparams_config(&node, 4, 5, weight_type, 0);
+        let weight = weight_qparams.quantized_data(weight, shape);
+
+        // The integer bias is quantized with the product of the input and weight scales
+        let bias = node
+            .inputs
+            .get(8)
+            .filter(|arg| !arg.name.is_empty())
+            .map(|bias| {
+                let input_scale = qparams_config(&node, 1, 2, QuantizationType::QInt8, 1).scale;
+                let (values, shape) =
+                    quantized_constant(bias, 1).expect("QLinearConv: the bias must be constant");
+                let bias_qparams = QParams::new(
+                    QuantizationType::QInt8,
+                    weight_qparams
+                        .scale
+                        .iter()
+                        .map(|scale| scale * input_scale[0])
+                        .collect(),
+                    vec![0; weight_qparams.scale.len()],
+                    Some(0),
+                );
+
+                TensorData::new(bias_qparams.dequantize(&values, &shape), shape)
+            });
+
+        // Convolution of the quantized input and weights, before the output is quantized
+        let mut conv = node.clone();
+        conv.inputs = vec![node.inputs[0].clone(), node.inputs[3].clone()];
+        if let Some(bias) = node.inputs.get(8).filter(|arg| !arg.name.is_empty()) {
+            conv.inputs.push(bias.clone());
+        }
+        let config = conv2d_config(&conv);
+        let conv_output = TensorType::new_float(format!("{}_conv", node.name), output.dim);
+
+        let output_type = quantization_type(node.outputs[0].ty.elem_type());
+        let output_qparams = qparams_config(&node, 6, 7, output_type, 1);
+
+        (
+            Conv2dNode::new(&node.name, input, conv_output.clone(), weight, bias, config),
+            QuantizeLinearNode::new(conv_output, output, output_qparams),
+        )
+    }
+
+    fn qlinear_matmul_conversion<PS: PrecisionSettings>(
+        node: Node,
+    ) -> (BurnNode<PS>, QuantizeLinearNode) {
+        let lhs = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let matmul_output = TensorType::new_float(format!("{}_matmul", node.name), output.dim);
+
+        let matmul = match quantized_constant(&node.inputs[3], 2) {
+            // Constant weights are stored quantized in a linear layer
+            Some((weight, shape)) => {
+                let weight_type = quantization_type(node.inputs[3].ty.elem_type());
+                let weight_qparams = qparams_config(&node, 4, 5, weight_type, 1);
+                let config = LinearConfig::new(shape[0], shape[1]).with_bias(false);
+                let weight = weight_qparams.quantized_data(weight, shape);
+
+                LinearNode::new(&node.name, lhs, matmul_output.clone(), weight, None, config)
+                    .into_node()
+            }
+            None => {
+                let rhs = TensorType::from(node.inputs.get(3).unwrap());
+                MatmulNode::new(lhs, rhs, matmul_output.clone()).into_node()
+            }
+        };
+
+        let output_type = quantization_type(node.outputs[0].ty.elem_type());
+        let output_qparams = qparams_config(&node, 6, 7, output_type, 1);
+
+        (
+            matmul,
+            QuantizeLinearNode::new(matmul_output, output, output_qparams),
+        )
+    }
+
     fn max_pool1d_conversion(node: Node) -> MaxPool1dNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1186,6 +1392,109 @@ impl ParsedOnnxGraph {
     }
 }
 
+/// Get the integer values and shape of a constant quantized tensor with the given rank.
+fn quantized_constant(arg: &OnnxArgument, dim: usize) -> Option<(Vec<i32>, Vec<usize>)> {
+    match (&arg.value, &arg.ty) {
+        (Some(Data::Int32s(values)), ArgType::Tensor(tensor)) if tensor.dim == dim => {
+            Some((values.clone(), tensor.shape.clone().unwrap()))
+        }
+        _ => None,
+    }
+}
+
+/// Constant tensors dequantized by a DequantizeLinear node.
+///
+/// The constants only consumed as the weights or bias of a convolution are folded into the
+/// convolution, so that the weights remain quantized in the record.
+#[derive(Debug, Default)]
+struct QuantizedConstants {
+    /// The quantization parameters, values, element type and shape of the constants, indexed by
+    /// the name of the dequantized output.
+    constants: HashMap<String, (QParams, Vec<i32>, ElementType, Vec<usize>)>,
+    /// The dequantized outputs that are folded.
+    folded: HashSet<String>,
+}
+
+impl QuantizedConstants {
+    fn new(graph: &OnnxGraph) -> Self {
+        let mut constants = HashMap::new();
+
+        for node in graph.nodes.iter() {
+            if node.node_type != NodeType::DequantizeLinear {
+                continue;
+            }
+
+            let input = &node.inputs[0];
+            if let (Some(Data::Int32s(values)), ArgType::Tensor(tensor)) = (&input.value, &input.ty)
+            {
+                constants.insert(
+                    node.outputs[0].name.clone(),
+                    (
+                        quantize_linear_config(node),
+                        values.clone(),
+                        tensor.elem_type.clone(),
+                        tensor.shape.clone().unwrap(),
+                    ),
+                );
+            }
+        }
+
+        // Only fold the constants that are not used by any other node or graph output
+        let mut folded = constants.keys().cloned().collect::<HashSet<_>>();
+        for node in graph.nodes.iter() {
+            let is_conv = matches!(
+                node.node_type,
+                NodeType::Conv1d | NodeType::Conv2d | NodeType::Conv3d
+            );
+            for (i, input) in node.inputs.iter().enumerate() {
+                if !(is_conv && (i == 1 || i == 2)) {
+                    folded.remove(&input.name);
+                }
+            }
+        }
+        for output in graph.outputs.iter() {
+            folded.remove(&output.name);
+        }
+
+        Self { constants, folded }
+    }
+
+    fn is_folded(&self, name: &str) -> bool {
+        self.folded.contains(name)
+    }
+
+    /// Extract the data of the node input, with the quantized values of folded constants.
+    ///
+    /// For a DequantizeLinear node, the data of its constant input is extracted.
+    #[track_caller]
+    fn extract_data_serialize<E: Element>(
+        &self,
+        input_index: usize,
+        node: &Node,
+    ) -> Option<TensorData> {
+        let input = node.inputs.get(input_index)?;
+
+        let constant = if node.node_type == NodeType::DequantizeLinear {
+            self.constants.get(&node.outputs[0].name)
+        } else if self.is_folded(&input.name) {
+            self.constants.get(&input.name)
+        } else {
+            None
+        };
+
+        match constant {
+            Some((qparams, values, ElementType::Int8 | ElementType::Uint8, shape)) => {
+                Some(qparams.quantized_data(values.clone(), shape.clone()))
+            }
+            Some((qparams, values, _, shape)) => Some(
+                TensorData::new(qparams.dequantize(values, shape), shape.clone()).convert::<E>(),
+            ),
+            None if node.node_type == NodeType::DequantizeLinear => None,
+            None => extract_data_serialize::<E>(input_index, node),
+        }
+    }
+}
+
 /// Extract data from node states and convert it to `TensorData`.
 ///
 /// # Arguments
@@ -1239,6 +1548,13 @@ impl From<&OnnxArgument> for TensorType {
                 shape,
                 ..
             }) => TensorType::new_float_with_shape(arg.name.clone(), *dim, shape.clone()),
+            // 8-bit integer tensors are quantized float tensors
+            ArgType::Tensor(OnnxTensorType {
+                elem_type: ElementType::Int8 | ElementType::Uint8,
+                dim,
+                shape,
+                ..
+            }) => TensorType::new_float_with_shape(arg.name.clone(), *dim, shape.clone()),
             ArgType::Tensor(OnnxTensorType {
                 elem_type: ElementType::Int32 | ElementType::Int64,
                 dim,
@@ -1290,6 +1606,8 @@ impl From<&ElementType> for ScalarKind {
             ElementType::Int32 => ScalarKind::Int32,
             ElementType::Int64 => ScalarKind::Int64,
             ElementType::Bool => ScalarKind::Bool,
+            // 8-bit integer scalars (e.g., zero points) are widened
+            ElementType::Int8 | ElementType::Uint8 => ScalarKind::Int32,
             ElementType::String => panic!("String tensor unsupported"),
             ElementType::Float16 => panic!("Float16 tensor unsupported"),
         }
@@ -1304,6 +1622,7 @@ impl From<ElementType> for TensorKind {
             ElementType::Int32 => TensorKind::Int,
             ElementType::Int64 => TensorKind::Int,
             ElementType::Bool => TensorKind::Bool,
+            ElementType::Int8 | ElementType::Uint8 => TensorKind::Float,
             _ => panic!("Unsupported tensor type"),
         }
     }
@@ -15,6 +15,10 @@ pub enum Error {
     #[error("Zip error: {0}")]
     Zip(#[from] ZipError),
 
+    #[cfg(feature = "safetensors")]
+    #[error("Safetensors error: {0}")]
+    Safetensors(#[from] safetensors::SafeTensorError),
+
     // Add other kinds of errors as needed
     #[error("other error: {0}")]
     Other(String),
@@ -11,6 +11,7 @@ use burn::{
 };
 use burn::{
     record::serde::{
+        adapter::BurnModuleAdapter,
         data::{remap, unflatten, NestedValue, Serializable},
         de::Deserializer,
         error,
@@ -48,6 +49,27 @@ where
         .map(|(key, tensor)| (key, CandleTensor(tensor)))
         .collect();
 
+    from_tensors::<PS, D, PyTorchAdapter<PS, B>, _>(tensors, key_remap, debug)
+}
+
+/// Deserializes a map of named tensors into a record, using the given module adapter.
+///
+/// # Arguments
+///
+/// * `tensors` - The tensors read from a file, keyed by their name.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `debug` - Whether to print the keys and the tensor shapes.
+pub(crate) fn from_tensors<PS, D, A, T>(
+    tensors: HashMap<String, T>,
+    key_remap: Vec<(Regex, String)>,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    A: BurnModuleAdapter,
+    T: Serializable + TensorInfo,
+{
     // Remap the keys (replace the keys in the map with the new keys)
     let (tensors, remapped_keys) = remap(tensors, key_remap);
 
@@ -64,25 +86,36 @@ where
                 println!("Key: {}", new_key);
             }
 
-            let shape = tensors[&new_key].shape();
-            let dtype = tensors[&new_key].dtype();
+            let (shape, dtype) = tensors[&new_key].info();
             println!("Shape: {shape:?}");
-            println!("Dtype: {dtype:?}");
+            println!("Dtype: {dtype}");
             println!("---");
         }
     }
 
-    // Convert the vector of Candle tensors to a nested value data structure
+    // Convert the vector of tensors to a nested value data structure
     let nested_value = unflatten::<PS, _>(tensors)?;
 
-    // Create a deserializer with PyTorch adapter and nested value
-    let deserializer = Deserializer::<PyTorchAdapter<PS, B>>::new(nested_value, true);
+    // Create a deserializer with the module adapter and nested value
+    let deserializer = Deserializer::<A>::new(nested_value, true);
 
     // Deserialize the nested value into a record type
     let value = D::deserialize(deserializer)?;
     Ok(value)
 }
 
+/// The shape and data type of a tensor read from a file, printed as debug information.
+pub(crate) trait TensorInfo {
+    /// Returns the shape and the data type of the tensor.
+    fn info(&self) -> (Vec<usize>, String);
+}
+
+impl TensorInfo for CandleTensor {
+    fn info(&self) -> (Vec<usize>, String) {
+        (self.dims().to_vec(), format!("{:?}", self.dtype()))
+    }
+}
+
 /// Serializes a candle tensor.
 ///
 /// Tensors are wrapped in a `Param` struct (learnable parameters) and serialized as a `TensorData` struct.
@@ -141,11 +174,20 @@ where
         .map(ElementConversion::elem)
         .collect();
 
+    serialize_tensor_data(TensorData::new(data, shape), param_id, serializer)
+}
+
+/// Helper function to serialize tensor data as a parameter.
+pub(crate) fn serialize_tensor_data(
+    data: TensorData,
+    param_id: String,
+    serializer: Serializer,
+) -> Result<NestedValue, error::Error> {
     let TensorData {
         bytes,
         shape,
         dtype,
-    } = TensorData::new(data, shape);
+    } = data;
 
     // Manually serialize the tensor instead of using the `ParamSerde` struct, such as:
     // ParamSerde::new(param_id, TensorData::new(data, shape)).serialize(serializer)
@@ -0,0 +1,4 @@
+mod reader;
+mod recorder;
+mod writer;
+pub use recorder::{AdapterType, LoadArgs, SafetensorsFileRecorder};
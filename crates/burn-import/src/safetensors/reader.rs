@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use burn::{
+    module::ParamId,
+    record::{
+        serde::{
+            adapter::DefaultAdapter,
+            data::{NestedValue, Serializable},
+            error,
+            ser::Serializer,
+        },
+        PrecisionSettings,
+    },
+    tensor::{backend::Backend, DType, TensorData},
+};
+use regex::Regex;
+use safetensors::{tensor::TensorView, Dtype, SafeTensors};
+use serde::de::DeserializeOwned;
+
+use super::recorder::AdapterType;
+use crate::pytorch::{
+    adapter::PyTorchAdapter,
+    error::Error,
+    reader::{from_tensors, serialize_tensor_data, TensorInfo},
+};
+
+/// Deserializes a safetensors file.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `adapter_type` - The adapter used to convert the modules of the file to Burn modules.
+/// * `debug` - Whether to print the keys and the tensor shapes.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    adapter_type: AdapterType,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    let buffer = std::fs::read(path)?;
+    let tensors = SafeTensors::deserialize(&buffer)?
+        .tensors()
+        .into_iter()
+        .map(|(key, view)| Ok((key, SafetensorsTensor::try_from(view)?)))
+        .collect::<Result<HashMap<_, _>, Error>>()?;
+
+    match adapter_type {
+        AdapterType::PyTorch => {
+            from_tensors::<PS, D, PyTorchAdapter<PS, B>, _>(tensors, key_remap, debug)
+        }
+        AdapterType::NoAdapter => {
+            from_tensors::<PS, D, DefaultAdapter, _>(tensors, key_remap, debug)
+        }
+    }
+}
+
+/// A tensor read from a safetensors file.
+///
+/// The values are kept in their stored data type, and are converted to the element types of the
+/// precision settings when the record is deserialized.
+struct SafetensorsTensor(TensorData);
+
+impl TryFrom<TensorView<'_>> for SafetensorsTensor {
+    type Error = Error;
+
+    fn try_from(view: TensorView<'_>) -> Result<Self, Self::Error> {
+        let dtype = match view.dtype() {
+            Dtype::BOOL => DType::Bool,
+            Dtype::U8 => DType::U8,
+            Dtype::I8 => DType::I8,
+            Dtype::I16 => DType::I16,
+            Dtype::I32 => DType::I32,
+            Dtype::U32 => DType::U32,
+            Dtype::I64 => DType::I64,
+            Dtype::U64 => DType::U64,
+            Dtype::F16 => DType::F16,
+            Dtype::BF16 => DType::BF16,
+            Dtype::F32 => DType::F32,
+            Dtype::F64 => DType::F64,
+            dtype => {
+                return Err(Error::Other(format!(
+                    "Unsupported safetensors data type: {dtype:?}"
+                )))
+            }
+        };
+
+        Ok(Self(TensorData {
+            bytes: view.data().to_vec(),
+            shape: view.shape().to_vec(),
+            dtype,
+        }))
+    }
+}
+
+impl TensorInfo for SafetensorsTensor {
+    fn info(&self) -> (Vec<usize>, String) {
+        (self.0.shape.clone(), format!("{:?}", self.0.dtype))
+    }
+}
+
+/// Serializes a safetensors tensor.
+///
+/// Tensors are wrapped in a `Param` struct (learnable parameters) and serialized as a `TensorData` struct.
+impl Serializable for SafetensorsTensor {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let param_id = ParamId::new().into_string();
+        serialize_tensor_data(self.0.clone(), param_id, serializer)
+    }
+}
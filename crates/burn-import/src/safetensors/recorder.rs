@@ -0,0 +1,175 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{reader::from_file, writer::to_file};
+
+/// A recorder that loads safetensors files (`.safetensors`) into Burn modules, and saves Burn
+/// records as safetensors files.
+///
+/// LoadArgs can be used to remap keys, file path or the module adapter.
+/// See [LoadArgs](struct.LoadArgs.html) for more information.
+///
+/// Saved records store each tensor under the path of its field, e.g. `fc1.weight`, so that the
+/// file can be loaded back with [AdapterType::NoAdapter], or memory-mapped from Python.
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder<PS: PrecisionSettings> {
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        to_file(item, &file).map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, _file: Self::LoadArgs) -> Result<I, RecorderError> {
+        unimplemented!("load_item not implemented for SafetensorsFileRecorder")
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item = from_file::<PS, R::Item<Self::Settings>, B>(
+            &args.file,
+            args.key_remap,
+            args.adapter_type,
+            args.debug,
+        )?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+/// The adapter used to convert the modules of a safetensors file to Burn modules.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AdapterType {
+    /// Adapts the modules saved by PyTorch, e.g. transposes the weights of linear layers.
+    #[default]
+    PyTorch,
+
+    /// Loads the modules as they are, e.g. for files saved by Burn.
+    NoAdapter,
+}
+
+/// Arguments for loading a safetensors file.
+///
+/// # Fields
+///
+/// * `file` - The path to the file to load.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+///                See [regex::Regex::replace](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace)
+///                for more information.
+/// * `adapter_type` - The adapter used to convert the modules, PyTorch by default.
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::safetensors::{LoadArgs, SafetensorsFileRecorder};
+/// use burn::record::FullPrecisionSettings;
+/// use burn::record::Recorder;
+///
+/// let args = LoadArgs::new("tests/key_remap/key_remap.safetensors".into())
+///    .with_key_remap("conv\\.(.*)", "$1"); // // Remove "conv" prefix, e.g. "conv.conv1" -> "conv1"
+///
+/// let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+///   .load(args)
+///   .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LoadArgs {
+    /// The path to the file to load.
+    pub file: PathBuf,
+
+    /// A list of key remappings.
+    pub key_remap: Vec<(Regex, String)>,
+
+    /// The adapter used to convert the modules.
+    pub adapter_type: AdapterType,
+
+    /// Whether to print debug information.
+    pub debug: bool,
+}
+
+impl LoadArgs {
+    /// Creates a new `LoadArgs` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path to the file to load.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            key_remap: Vec::new(),
+            adapter_type: AdapterType::default(),
+            debug: false,
+        }
+    }
+
+    /// Sets key remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern to be replaced.
+    /// * `replacement` - The pattern to replace with.
+    ///
+    /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+    /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace) for the
+    /// replacement syntax.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets the adapter used to convert the modules.
+    ///
+    /// # Arguments
+    ///
+    /// * `adapter_type` - The adapter type.
+    pub fn with_adapter_type(mut self, adapter_type: AdapterType) -> Self {
+        self.adapter_type = adapter_type;
+        self
+    }
+
+    /// Sets printing debug information on.
+    pub fn with_debug_print(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+}
+
+impl From<PathBuf> for LoadArgs {
+    fn from(val: PathBuf) -> Self {
+        LoadArgs::new(val)
+    }
+}
+
+impl From<String> for LoadArgs {
+    fn from(val: String) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+impl From<&str> for LoadArgs {
+    fn from(val: &str) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use burn::{
+    record::serde::{
+        adapter::DefaultAdapter, data::NestedValue, de::Deserializer, ser::Serializer,
+    },
+    tensor::{DType, TensorData},
+};
+use safetensors::{tensor::TensorView, Dtype};
+use serde::{Deserialize, Serialize};
+
+use crate::pytorch::error::Error;
+
+/// Serializes a record item into a safetensors file.
+///
+/// The tensors are stored under the path of their field in the module tree, e.g. `fc1.weight`,
+/// and the record metadata is stored in the `__metadata__` header of the file.
+///
+/// # Arguments
+///
+/// * `item` - The record item to save, optionally wrapped with its metadata.
+/// * `path` - The path of the file to write.
+pub fn to_file<I: Serialize>(item: I, path: &Path) -> Result<(), Error> {
+    let (metadata, item) = match item.serialize(Serializer::new())? {
+        NestedValue::Map(mut map) if map.contains_key("metadata") && map.contains_key("item") => {
+            let metadata = map.remove("metadata").and_then(NestedValue::as_map);
+            (metadata, map.remove("item").unwrap())
+        }
+        item => (None, item),
+    };
+
+    // Only the string values of the metadata can be stored in the header
+    let metadata = metadata.map(|metadata| {
+        metadata
+            .into_iter()
+            .filter_map(|(key, value)| value.as_string().map(|value| (key, value)))
+            .collect::<HashMap<_, _>>()
+    });
+
+    let mut tensors = Vec::new();
+    collect_tensors(item, String::new(), &mut tensors)?;
+
+    let views = tensors
+        .iter()
+        .map(|(key, data)| {
+            let view = TensorView::new(
+                safetensors_dtype(&data.dtype)?,
+                data.shape.clone(),
+                &data.bytes,
+            )?;
+            Ok((key.as_str(), view))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    safetensors::serialize_to_file(views, &metadata, path)?;
+
+    Ok(())
+}
+
+/// Collects the tensors of a serialized record, keyed by their path in the record.
+fn collect_tensors(
+    value: NestedValue,
+    key: String,
+    tensors: &mut Vec<(String, TensorData)>,
+) -> Result<(), Error> {
+    match value {
+        NestedValue::Map(mut map) => {
+            // Parameters are stored under the key of their field
+            if map.len() == 2 && map.contains_key("id") {
+                if let Some(param) = map.remove("param") {
+                    return collect_tensors(param, key, tensors);
+                }
+            }
+
+            if map.contains_key("bytes") && map.contains_key("shape") && map.contains_key("dtype") {
+                let deserializer =
+                    Deserializer::<DefaultAdapter>::new(NestedValue::Map(map), false);
+                tensors.push((key, TensorData::deserialize(deserializer)?));
+                return Ok(());
+            }
+
+            for (name, value) in map {
+                collect_tensors(value, nested_key(&key, &name), tensors)?;
+            }
+        }
+        NestedValue::Vec(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                collect_tensors(value, nested_key(&key, &index.to_string()), tensors)?;
+            }
+        }
+        // Constants and missing optional fields have nothing to store
+        NestedValue::Default(_) => {}
+        value => {
+            return Err(Error::Other(format!(
+            "Only tensors can be saved in the safetensors format, found {value:?} for key '{key}'"
+        )))
+        }
+    }
+
+    Ok(())
+}
+
+/// Appends the name of a field to the key of its parent.
+fn nested_key(key: &str, name: &str) -> String {
+    if key.is_empty() {
+        name.to_string()
+    } else {
+        format!("{key}.{name}")
+    }
+}
+
+/// The safetensors data type corresponding to the tensor data type.
+fn safetensors_dtype(dtype: &DType) -> Result<Dtype, Error> {
+    let dtype = match dtype {
+        DType::F64 => Dtype::F64,
+        DType::F32 => Dtype::F32,
+        DType::F16 => Dtype::F16,
+        DType::BF16 => Dtype::BF16,
+        DType::I64 => Dtype::I64,
+        DType::I32 => Dtype::I32,
+        DType::I16 => Dtype::I16,
+        DType::I8 => Dtype::I8,
+        DType::U64 => Dtype::U64,
+        DType::U32 => Dtype::U32,
+        DType::U8 => Dtype::U8,
+        DType::Bool => Dtype::BOOL,
+        DType::QFloat(_) => {
+            return Err(Error::Other(
+                "Quantized tensors can't be saved in the safetensors format".into(),
+            ))
+        }
+    };
+
+    Ok(dtype)
+}
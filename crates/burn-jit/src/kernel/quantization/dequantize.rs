@@ -2,6 +2,8 @@ use crate::tensor::{JitTensor, QJitTensor};
 use crate::FloatElement;
 use crate::{IntElement, JitElement, JitRuntime};
 use burn_tensor::quantization::{QuantizationScheme, QuantizationType};
+
+use super::unsupported_quantization;
 use cubecl::calculate_cube_count_elemwise;
 use cubecl::prelude::*;
 
@@ -207,6 +209,10 @@ where
             QuantizationType::QInt8 => {
                 dequantize_per_tensor(tensor.qtensor, tensor.qparams.scale, tensor.qparams.offset)
             }
+            _ => unsupported_quantization(&tensor.scheme),
         },
+        QuantizationScheme::PerChannelAffine(..) | QuantizationScheme::PerChannelSymmetric(..) => {
+            unsupported_quantization(&tensor.scheme)
+        }
     }
 }
@@ -3,3 +3,14 @@ mod quantize;
 
 pub use dequantize::*;
 pub use quantize::*;
+
+use burn_tensor::quantization::QuantizationScheme;
+
+/// Panics with a clear error for the quantization schemes without kernels, only the per-tensor
+/// `int8` schemes are supported by the JIT backend.
+pub(crate) fn unsupported_quantization(scheme: &QuantizationScheme) -> ! {
+    panic!(
+        "Unsupported quantization scheme {scheme:?}: the JIT backend only supports per-tensor \
+         QInt8 quantization"
+    )
+}
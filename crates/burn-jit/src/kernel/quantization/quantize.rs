@@ -2,6 +2,8 @@ use crate::tensor::{JitQuantizationParameters, JitTensor, QJitTensor};
 use crate::FloatElement;
 use crate::{IntElement, JitElement, JitRuntime};
 use burn_tensor::quantization::{QuantizationScheme, QuantizationType};
+
+use super::unsupported_quantization;
 use cubecl::calculate_cube_count_elemwise;
 use cubecl::prelude::*;
 
@@ -209,12 +211,16 @@ where
             QuantizationType::QInt8 => {
                 quantize_per_tensor(tensor, qparams.scale.clone(), qparams.offset.clone())
             }
+            _ => unsupported_quantization(scheme),
         },
+        QuantizationScheme::PerChannelAffine(..) | QuantizationScheme::PerChannelSymmetric(..) => {
+            unsupported_quantization(scheme)
+        }
     };
 
     QJitTensor {
         qtensor,
-        scheme: scheme.clone(),
+        scheme: *scheme,
         qparams,
     }
 }
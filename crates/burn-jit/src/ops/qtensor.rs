@@ -11,21 +11,24 @@ use burn_tensor::{
 };
 
 use crate::{
-    kernel,
+    kernel::{self, quantization::unsupported_quantization},
     tensor::{JitQuantizationParameters, JitTensor, QJitTensor},
     FloatElement, IntElement, JitBackend, JitRuntime,
 };
 use cubecl::CubeElement;
 
 fn pack_i8s_to_u32s(data: &TensorData) -> Vec<u32> {
+    // The quantization parameters are stored after the values
+    let values = data.iter::<i8>().collect::<Vec<_>>();
+
     // Shift and combine groups of four 8-bit values into a u32.
     // Same as doing this:
     //     let result = (a_u8 & 0xFF) << 24 | (b_u8 & 0xFF) << 16 | (c_u8 & 0xFF) << 8 | (d_u8 & 0xFF);
-    data.as_bytes()
+    values
         .chunks(4)
         .map(|x| {
             x.iter().enumerate().fold(0u32, |acc, (i, x)| {
-                acc | (*x as i8 as u32 & 0xFF) << ((3 - i) * 8)
+                acc | (*x as u32 & 0xFF) << ((3 - i) * 8)
             })
         })
         .collect()
@@ -51,12 +54,12 @@ where
 {
     fn q_from_data(data: TensorData, device: &Device<Self>) -> QuantizedTensor<Self> {
         match data.dtype {
-            DType::QFloat(strategy) => match strategy {
+            DType::QFloat(scheme) => match data.quantization_strategy().unwrap() {
                 QuantizationStrategy::PerTensorAffineInt8(q) => {
                     // Convert quantized values to packed u32s
                     QJitTensor {
                         qtensor: packed_tensor(pack_i8s_to_u32s(&data), data.shape, device),
-                        scheme: strategy.scheme(),
+                        scheme,
                         qparams: JitQuantizationParameters::new(
                             q.scale.elem(),
                             Some(q.offset.elem()),
@@ -68,10 +71,11 @@ where
                     // Convert quantized values to packed u32s
                     QJitTensor {
                         qtensor: packed_tensor(pack_i8s_to_u32s(&data), data.shape, device),
-                        scheme: strategy.scheme(),
+                        scheme,
                         qparams: JitQuantizationParameters::new(q.scale.elem(), None, device),
                     }
                 }
+                _ => unsupported_quantization(&scheme),
             },
             _ => panic!(
                 "Invalid dtype (expected DType::QFloat, got {:?})",
@@ -148,7 +152,12 @@ where
                     qtensor.shape,
                     strategy,
                 ),
+                _ => unsupported_quantization(&tensor.scheme),
             },
+            QuantizationScheme::PerChannelAffine(..)
+            | QuantizationScheme::PerChannelSymmetric(..) => {
+                unsupported_quantization(&tensor.scheme)
+            }
         }
     }
 
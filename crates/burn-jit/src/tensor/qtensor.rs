@@ -6,7 +6,10 @@ use burn_tensor::{
     read_sync, TensorData,
 };
 
-use crate::{ops::into_data, FloatElement, IntElement, JitBackend, JitRuntime};
+use crate::{
+    kernel::quantization::unsupported_quantization, ops::into_data, FloatElement, IntElement,
+    JitBackend, JitRuntime,
+};
 
 use super::JitTensor;
 
@@ -43,6 +46,7 @@ impl<R: JitRuntime, F: FloatElement, I: IntElement> QTensorPrimitive for QJitTen
                         scale, offset,
                     ))
                 }
+                _ => unsupported_quantization(&self.scheme),
             },
             QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
                 QuantizationType::QInt8 => {
@@ -52,7 +56,10 @@ impl<R: JitRuntime, F: FloatElement, I: IntElement> QTensorPrimitive for QJitTen
                         .unwrap();
                     QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(scale))
                 }
+                _ => unsupported_quantization(&self.scheme),
             },
+            QuantizationScheme::PerChannelAffine(..)
+            | QuantizationScheme::PerChannelSymmetric(..) => unsupported_quantization(&self.scheme),
         }
     }
 }
@@ -61,7 +68,7 @@ impl<R: JitRuntime, F: FloatElement, I: IntElement> Clone for QJitTensor<R, F, I
     fn clone(&self) -> Self {
         Self {
             qtensor: self.qtensor.clone(),
-            scheme: self.scheme.clone(),
+            scheme: self.scheme,
             qparams: self.qparams.clone(),
         }
     }
@@ -70,7 +70,7 @@ where
 }
 
 #[derive(Debug, PartialEq)]
-struct Strides {
+pub(crate) struct Strides {
     strides: Vec<usize>,
 }
 impl Strides {
@@ -78,7 +78,7 @@ impl Strides {
         Strides { strides }
     }
 
-    fn unflatten(&self, linear_index: usize) -> Vec<usize> {
+    pub(crate) fn unflatten(&self, linear_index: usize) -> Vec<usize> {
         let mut coord = Vec::with_capacity(self.strides.len());
         let mut rem = linear_index;
         for stride in self.strides.iter() {
@@ -88,7 +88,7 @@ impl Strides {
         coord
     }
 
-    fn flatten(&self, index: &Vec<usize>) -> usize {
+    pub(crate) fn flatten(&self, index: &Vec<usize>) -> usize {
         assert_eq!(self.strides.len(), index.len());
         self.strides
             .iter()
@@ -110,7 +110,7 @@ impl Strides {
 /// * If the matrix multiplication dimensions (last 2) are incompatible.
 /// * If any other dimension is not the same for both tensors, or equal to 1. (Any dimension where
 ///   one dim is equal to 1 is broadcast.)
-fn output_shape(lsh: &Shape, rsh: &Shape) -> (Shape, Strides, Strides, Strides) {
+pub(crate) fn output_shape(lsh: &Shape, rsh: &Shape) -> (Shape, Strides, Strides, Strides) {
     let ndims = lsh.num_dims();
     if ndims < 2 {
         panic!("Matrix multiplication requires an array with at least 2 dimensions.");
@@ -3,6 +3,8 @@ mod base;
 mod bool_tensor;
 mod int_tensor;
 mod module;
+mod qconv;
+mod qmatmul;
 mod qtensor;
 mod tensor;
 
@@ -0,0 +1,138 @@
+use alloc::{vec, vec::Vec};
+use burn_common::{iter_range_par, run_par};
+use burn_tensor::{
+    ops::{conv::calculate_conv_output_size, ConvOptions},
+    TensorData,
+};
+use ndarray::{s, Array4, ArrayView4, Dim};
+
+use crate::{element::QuantElement, sharing::UnsafeSharedRef, NdArrayQTensor, NdArrayTensor};
+
+use super::{
+    qmatmul::{matmul_block, BLOCK_COLS},
+    qtensor::Int8Params,
+};
+
+/// Applies a 2D convolution on the `int8` values of the quantized tensors with `int32`
+/// accumulation, and requantizes the output with the per-tensor scheme of the input tensor.
+///
+/// The input patches are unfolded so the convolution is computed as a matrix multiplication
+/// with the weights of each group.
+///
+/// Returns `None` if the input tensor is not quantized to `int8` per-tensor, or if the weight
+/// tensor is not quantized to `int8` per-tensor or per-channel along its output channels.
+pub(crate) fn q_conv2d<Q: QuantElement>(
+    x: &NdArrayQTensor<Q>,
+    weight: &NdArrayQTensor<Q>,
+    bias: Option<&[f32]>,
+    options: &ConvOptions<2>,
+) -> Option<NdArrayQTensor<Q>> {
+    let x_params = Int8Params::new(&x.strategy, None)?;
+    let weight_params = Int8Params::new(&weight.strategy, Some(0))?;
+
+    let [dilation_height, dilation_width] = options.dilation;
+    let [padding_height, padding_width] = options.padding;
+    let [stride_height, stride_width] = options.stride;
+    let [batch_size, channels_in, in_height, in_width] = x.shape.dims();
+    let [out_channels, in_channels, kernel_height, kernel_width] = weight.shape.dims();
+    let channels_per_group = out_channels / options.groups;
+
+    let out_height = calculate_conv_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        dilation_height,
+        in_height,
+    );
+    let out_width = calculate_conv_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        dilation_width,
+        in_width,
+    );
+
+    // The zero-point is subtracted from the values, so the input is padded with zeros.
+    let x_values = x_params.centered_values(x);
+    let mut x = Array4::<i16>::zeros(Dim([
+        batch_size,
+        channels_in,
+        in_height + 2 * padding_height,
+        in_width + 2 * padding_width,
+    ]));
+    x.slice_mut(s![
+        ..,
+        ..,
+        padding_height..padding_height + in_height,
+        padding_width..padding_width + in_width
+    ])
+    .assign(
+        &ArrayView4::from_shape(
+            Dim([batch_size, channels_in, in_height, in_width]),
+            &x_values,
+        )
+        .unwrap(),
+    );
+
+    // Each row of the weights holds the kernel of an output channel
+    let weights = weight_params.centered_values(weight);
+    let patch_size = in_channels * kernel_height * kernel_width;
+    let positions = out_height * out_width;
+
+    let mut output = vec![0i32; batch_size * out_channels * positions];
+    run_par!(|| {
+        let unsafe_shared_output = UnsafeSharedRef::new(&mut output);
+
+        iter_range_par!(0, batch_size * options.groups).for_each(|k| {
+            let b = k / options.groups;
+            let g = k % options.groups;
+
+            // Unfold the input patches of the group, with one contiguous patch per output
+            // position.
+            let mut patches = vec![0i16; positions * patch_size];
+            for (position, patch) in patches.chunks_exact_mut(patch_size).enumerate() {
+                let oh = position / out_width;
+                let ow = position % out_width;
+                let mut values = patch.iter_mut();
+
+                for ic in (in_channels * g)..(in_channels * (g + 1)) {
+                    for kh in 0..kernel_height {
+                        let ih = oh * stride_height + kh * dilation_height;
+                        for kw in 0..kernel_width {
+                            let iw = ow * stride_width + kw * dilation_width;
+                            *values.next().unwrap() = x[[b, ic, ih, iw]];
+                        }
+                    }
+                }
+            }
+
+            let weights =
+                &weights[g * channels_per_group * patch_size..][..channels_per_group * patch_size];
+
+            unsafe {
+                let output = &mut unsafe_shared_output.get()
+                    [(b * out_channels + g * channels_per_group) * positions..]
+                    [..channels_per_group * positions];
+
+                for start in (0..positions).step_by(BLOCK_COLS) {
+                    let cols = start..usize::min(start + BLOCK_COLS, positions);
+                    matmul_block(output, weights, &patches, cols, patch_size, positions);
+                }
+            }
+        });
+    });
+
+    // Each accumulated value represents `acc * x_scale * weight_scale`
+    let scales: Vec<f32> = (0..out_channels)
+        .map(|oc| x_params.scale(0) * weight_params.scale(oc))
+        .collect();
+    let (values, strategy) = x_params.requantize(&output, out_channels, positions, &scales, bias);
+
+    Some(NdArrayQTensor::new(
+        NdArrayTensor::from_data(TensorData::new(
+            values,
+            [batch_size, out_channels, out_height, out_width],
+        )),
+        strategy,
+    ))
+}
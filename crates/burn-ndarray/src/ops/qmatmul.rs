@@ -0,0 +1,155 @@
+use alloc::{vec, vec::Vec};
+use burn_common::{iter_range_par, run_par};
+use burn_tensor::TensorData;
+use core::ops::Range;
+
+use crate::{
+    element::QuantElement, ops::matmul::output_shape, sharing::UnsafeSharedRef, NdArrayQTensor,
+    NdArrayTensor,
+};
+
+use super::qtensor::Int8Params;
+
+/// Multiplies the `int8` values of the quantized tensors with `int32` accumulation, and
+/// requantizes the output with the per-tensor scheme of the left hand side tensor.
+///
+/// The bias, if any, is added to the last dimension of the output before requantization.
+///
+/// Returns `None` if the left hand side tensor is not quantized to `int8` per-tensor, or if the
+/// right hand side tensor is not quantized to `int8` per-tensor or per-channel along its last
+/// dimension.
+pub(crate) fn q_matmul<Q: QuantElement>(
+    lhs: &NdArrayQTensor<Q>,
+    rhs: &NdArrayQTensor<Q>,
+    bias: Option<&[f32]>,
+) -> Option<NdArrayQTensor<Q>> {
+    let shape_lhs = lhs.shape.clone();
+    let shape_rhs = rhs.shape.clone();
+    let ndims = shape_lhs.num_dims();
+
+    let lhs_params = Int8Params::new(&lhs.strategy, None)?;
+    let rhs_params = Int8Params::new(&rhs.strategy, Some(ndims - 1))?;
+
+    let m = shape_lhs.dims[ndims - 2]; // # of left rows
+    let k = shape_rhs.dims[ndims - 2]; // # of left cols and right rows
+    let n = shape_rhs.dims[ndims - 1]; // # of right cols
+
+    let (out_shape, strides_lhs, strides_rhs, strides_out) = output_shape(&shape_lhs, &shape_rhs);
+    let num_out_batches = out_shape.num_elements() / (m * n);
+
+    // The columns of the right hand side are transposed to be contiguous like the rows of the
+    // left hand side.
+    let lhs_values = lhs_params.centered_values(lhs);
+    let rhs_values = transpose_matrices(&rhs_params.centered_values(rhs), k, n);
+    let num_blocks = n.div_ceil(BLOCK_COLS);
+
+    let mut acc = vec![0i32; num_out_batches * m * n];
+    run_par!(|| {
+        let unsafe_shared_acc = UnsafeSharedRef::new(&mut acc);
+
+        iter_range_par!(0, num_out_batches * num_blocks).for_each(|task| {
+            let out_batch = task / num_blocks;
+            let block = task % num_blocks;
+            let cols = block * BLOCK_COLS..usize::min((block + 1) * BLOCK_COLS, n);
+
+            let out_index = strides_out.unflatten(out_batch);
+            let l_batch = strides_lhs.flatten(&out_index);
+            let r_batch = strides_rhs.flatten(&out_index);
+
+            let lhs_mat = &lhs_values[l_batch * m * k..][..m * k];
+            let rhs_mat = &rhs_values[r_batch * k * n..][..k * n];
+
+            unsafe {
+                let acc = &mut unsafe_shared_acc.get()[out_batch * m * n..][..m * n];
+                matmul_block(acc, lhs_mat, rhs_mat, cols, k, n);
+            }
+        });
+    });
+
+    // Each accumulated value represents `acc * lhs_scale * rhs_scale`
+    let scales: Vec<f32> = (0..n)
+        .map(|j| lhs_params.scale(0) * rhs_params.scale(j))
+        .collect();
+    let (values, strategy) = lhs_params.requantize(&acc, n, 1, &scales, bias);
+
+    Some(NdArrayQTensor::new(
+        NdArrayTensor::from_data(TensorData::new(values, out_shape)),
+        strategy,
+    ))
+}
+
+/// Number of right hand side columns computed by each task, so they stay in cache while
+/// iterating over the rows of the left hand side.
+pub(crate) const BLOCK_COLS: usize = 64;
+
+/// Transposes the `[k, n]` matrices so the columns are contiguous.
+fn transpose_matrices(values: &[i16], k: usize, n: usize) -> Vec<i16> {
+    let mut transposed = vec![0; values.len()];
+
+    for (matrix, out) in values
+        .chunks_exact(k * n)
+        .zip(transposed.chunks_exact_mut(k * n))
+    {
+        for (p, row) in matrix.chunks_exact(n).enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                out[j * k + p] = *value;
+            }
+        }
+    }
+
+    transposed
+}
+
+/// Computes the given output columns for all the rows of the left hand side matrix, with the
+/// transposed right hand side matrix.
+pub(crate) fn matmul_block(
+    acc: &mut [i32],
+    lhs: &[i16],
+    rhs: &[i16],
+    cols: Range<usize>,
+    k: usize,
+    n: usize,
+) {
+    for (lhs_row, acc_row) in lhs.chunks_exact(k).zip(acc.chunks_exact_mut(n)) {
+        let mut j = cols.start;
+
+        // Four columns at a time to reuse the loaded row values
+        while j + 4 <= cols.end {
+            let rhs_cols = core::array::from_fn(|c| &rhs[(j + c) * k..][..k]);
+            acc_row[j..j + 4].copy_from_slice(&dot4(lhs_row, rhs_cols));
+            j += 4;
+        }
+
+        for j in j..cols.end {
+            acc_row[j] = dot(lhs_row, &rhs[j * k..][..k]);
+        }
+    }
+}
+
+/// Dot product of the `int16` values with `int32` accumulation.
+#[inline(always)]
+fn dot(lhs: &[i16], rhs: &[i16]) -> i32 {
+    lhs.iter()
+        .zip(rhs)
+        .map(|(&a, &b)| a as i32 * b as i32)
+        .sum()
+}
+
+/// Dot products of the `int16` values with four vectors, with `int32` accumulation.
+#[inline(always)]
+fn dot4(lhs: &[i16], rhs: [&[i16]; 4]) -> [i32; 4] {
+    // Slicing upfront lets rustc elide the bounds checks and auto-vectorize the loop below.
+    let k = lhs.len();
+    let [r0, r1, r2, r3] = rhs.map(|values| &values[..k]);
+    let (mut acc0, mut acc1, mut acc2, mut acc3) = (0i32, 0i32, 0i32, 0i32);
+
+    for p in 0..k {
+        let a = lhs[p] as i32;
+        acc0 += a * r0[p] as i32;
+        acc1 += a * r1[p] as i32;
+        acc2 += a * r2[p] as i32;
+        acc3 += a * r3[p] as i32;
+    }
+
+    [acc0, acc1, acc2, acc3]
+}
@@ -1,20 +1,26 @@
 use core::ops::Range;
 
+use burn_common::{iter_par, run_par};
 use burn_tensor::{
-    ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
+    dequant_op_quant,
+    ops::{ConvOptions, FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{
-        AffineQuantization, Quantization, QuantizationParametersPrimitive, QuantizationScheme,
-        QuantizationStrategy, QuantizationType, SymmetricQuantization,
+        AffineQuantization, Float8Quantization, PerChannelQuantization, QTensorPrimitive,
+        Quantization, QuantizationParametersPrimitive, QuantizationScheme, QuantizationStrategy,
+        QuantizationType, QuantizedStorage, SymmetricQuantization,
     },
-    DType, Shape, TensorData,
+    DType, Element, Shape, TensorData,
 };
+use num_traits::{Float, PrimInt};
 
 use crate::{
     element::{NdArrayElement, QuantElement},
     FloatNdArrayElement, NdArray, NdArrayDevice, NdArrayQTensor, NdArrayTensor,
 };
 
-use super::{NdArrayMathOps, NdArrayOps};
+use super::{
+    conv::conv2d, matmul::matmul, qconv::q_conv2d, qmatmul::q_matmul, NdArrayMathOps, NdArrayOps,
+};
 
 fn into_data<E: NdArrayElement>(tensor: NdArrayTensor<E>) -> TensorData {
     let shape = tensor.shape();
@@ -22,27 +28,254 @@ fn into_data<E: NdArrayElement>(tensor: NdArrayTensor<E>) -> TensorData {
     TensorData::new(values, shape)
 }
 
+/// Creates a quantized tensor from the quantized data.
+///
+/// The 4-bit values are packed two per byte, and unpacked by the layout operations. Unsigned and
+/// 8-bit floating point values keep their bits.
+fn from_quantized_data<Q: QuantElement>(
+    data: TensorData,
+    strategy: QuantizationStrategy,
+) -> NdArrayQTensor<Q> {
+    let values: Vec<i8> = match strategy.storage() {
+        QuantizedStorage::Int8 | QuantizedStorage::PackedInt4 => data.iter::<i8>().collect(),
+        QuantizedStorage::UInt8 | QuantizedStorage::Float8 => {
+            data.iter::<u8>().map(|x| x as i8).collect()
+        }
+    };
+    let data = TensorData::new(values, data.shape);
+    NdArrayQTensor::new(NdArrayTensor::<Q>::from_data(data), strategy)
+}
+
+/// Converts the quantized tensor back to the quantized data representation.
+fn into_quantized_data<Q: QuantElement>(tensor: NdArrayQTensor<Q>) -> TensorData {
+    let shape = tensor.shape.clone();
+    let values = tensor.values().array.into_iter().map(|x| x.elem::<i8>());
+    match tensor.strategy.storage() {
+        QuantizedStorage::Int8 | QuantizedStorage::PackedInt4 => {
+            TensorData::quantized(values.collect(), shape, tensor.strategy)
+        }
+        QuantizedStorage::UInt8 | QuantizedStorage::Float8 => {
+            TensorData::quantized(values.map(|x| x as u8).collect(), shape, tensor.strategy)
+        }
+    }
+}
+
+fn affine_channels<Q: PrimInt + Element>(
+    scale: &TensorData,
+    offset: &TensorData,
+) -> Vec<AffineQuantization<f32, Q, i32>> {
+    scale
+        .iter::<f32>()
+        .zip(offset.iter::<Q>())
+        .map(|(scale, offset)| AffineQuantization::init(scale, offset))
+        .collect()
+}
+
+fn symmetric_channels<Q: PrimInt>(scale: &TensorData) -> Vec<SymmetricQuantization<f32, Q>> {
+    scale.iter().map(SymmetricQuantization::init).collect()
+}
+
+/// Maps the channels of a per-channel quantization through a layout operation.
+fn map_per_channel<S: Clone, F>(
+    q: &PerChannelQuantization<S>,
+    f: F,
+) -> Option<PerChannelQuantization<S>>
+where
+    F: FnOnce(usize, usize) -> Option<(usize, Vec<usize>)>,
+{
+    f(q.axis, q.channels.len()).map(|(axis, indices)| {
+        PerChannelQuantization::init(
+            axis,
+            indices.into_iter().map(|i| q.channels[i].clone()).collect(),
+        )
+    })
+}
+
+/// Maps the channels of a per-channel quantization strategy through a layout operation.
+///
+/// The mapping function receives the quantization axis and number of channels, and returns the
+/// new axis with the index of the original channel for each output channel. It returns `None`
+/// when the channels cannot be tracked, in which case the values must be requantized.
+///
+/// Per-tensor strategies are left unchanged.
+fn map_channels<F>(strategy: &QuantizationStrategy, f: F) -> Option<QuantizationStrategy>
+where
+    F: FnOnce(usize, usize) -> Option<(usize, Vec<usize>)>,
+{
+    match strategy {
+        QuantizationStrategy::PerChannelAffineInt8(q) => {
+            map_per_channel(q, f).map(QuantizationStrategy::PerChannelAffineInt8)
+        }
+        QuantizationStrategy::PerChannelSymmetricInt8(q) => {
+            map_per_channel(q, f).map(QuantizationStrategy::PerChannelSymmetricInt8)
+        }
+        QuantizationStrategy::PerChannelAffineInt4(q) => {
+            map_per_channel(q, f).map(QuantizationStrategy::PerChannelAffineInt4)
+        }
+        QuantizationStrategy::PerChannelSymmetricInt4(q) => {
+            map_per_channel(q, f).map(QuantizationStrategy::PerChannelSymmetricInt4)
+        }
+        QuantizationStrategy::PerChannelAffineUInt8(q) => {
+            map_per_channel(q, f).map(QuantizationStrategy::PerChannelAffineUInt8)
+        }
+        QuantizationStrategy::PerChannelSymmetricFloat8(q) => {
+            map_per_channel(q, f).map(QuantizationStrategy::PerChannelSymmetricFloat8)
+        }
+        _ => Some(strategy.clone()),
+    }
+}
+
+/// Returns true if the stored quantized values have the same order as the values they represent.
+fn is_order_preserving(strategy: &QuantizationStrategy) -> bool {
+    match strategy.storage() {
+        QuantizedStorage::Int8 | QuantizedStorage::PackedInt4 => true,
+        // Unsigned values are stored as signed bytes, and 8-bit floating point values as raw bits
+        QuantizedStorage::UInt8 | QuantizedStorage::Float8 => false,
+    }
+}
+
+/// The parameters of a tensor quantized to `int8`, used by the integer kernels.
+///
+/// The tensor is either quantized per-tensor, with a single scale and zero-point, or per-channel
+/// along the axis with a scale and zero-point for each channel.
+pub(crate) struct Int8Params {
+    axis: Option<usize>,
+    scales: Vec<f32>,
+    offsets: Vec<i32>,
+    symmetric: bool,
+}
+
+impl Int8Params {
+    /// Returns the parameters of a tensor quantized to `int8` per-tensor, or per-channel along the
+    /// given axis. Other strategies are not supported by the integer kernels.
+    pub(crate) fn new(strategy: &QuantizationStrategy, axis: Option<usize>) -> Option<Self> {
+        match strategy {
+            QuantizationStrategy::PerTensorAffineInt8(q) => Some(Self {
+                axis: None,
+                scales: vec![q.scale],
+                offsets: vec![q.offset as i32],
+                symmetric: false,
+            }),
+            QuantizationStrategy::PerTensorSymmetricInt8(q) => Some(Self {
+                axis: None,
+                scales: vec![q.scale],
+                offsets: vec![0],
+                symmetric: true,
+            }),
+            QuantizationStrategy::PerChannelAffineInt8(q) if Some(q.axis) == axis => Some(Self {
+                axis,
+                scales: q.channels.iter().map(|q| q.scale).collect(),
+                offsets: q.channels.iter().map(|q| q.offset as i32).collect(),
+                symmetric: false,
+            }),
+            QuantizationStrategy::PerChannelSymmetricInt8(q) if Some(q.axis) == axis => {
+                Some(Self {
+                    axis,
+                    scales: q.channels.iter().map(|q| q.scale).collect(),
+                    offsets: vec![0; q.channels.len()],
+                    symmetric: true,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// The scale of the given channel.
+    pub(crate) fn scale(&self, channel: usize) -> f32 {
+        match self.axis {
+            Some(_) => self.scales[channel],
+            None => self.scales[0],
+        }
+    }
+
+    /// Returns the quantized values of the tensor in logical order, minus the zero-point of
+    /// their channel.
+    pub(crate) fn centered_values<Q: QuantElement>(&self, tensor: &NdArrayQTensor<Q>) -> Vec<i16> {
+        let shape = &tensor.shape;
+        // Number of contiguous values in each channel
+        let inner: usize = match self.axis {
+            Some(axis) => shape.dims[axis + 1..].iter().product(),
+            None => shape.num_elements(),
+        };
+        let channels = self.offsets.len();
+
+        tensor
+            .values()
+            .array
+            .iter()
+            .enumerate()
+            .map(|(i, x)| x.elem::<i8>() as i16 - self.offsets[(i / inner) % channels] as i16)
+            .collect()
+    }
+
+    /// Requantizes the `int32` accumulated values to `int8` values, with the same per-tensor
+    /// affine or symmetric scheme as these parameters.
+    ///
+    /// The value represented by each accumulated value is `acc * scales[c] + bias[c]`, where the
+    /// channel `c` of the value is its index along the axis of size `channels`, followed by
+    /// `inner` contiguous values.
+    pub(crate) fn requantize(
+        &self,
+        acc: &[i32],
+        channels: usize,
+        inner: usize,
+        scales: &[f32],
+        bias: Option<&[f32]>,
+    ) -> (Vec<i8>, QuantizationStrategy) {
+        let value = |(i, acc): (usize, &i32)| {
+            let channel = (i / inner) % channels;
+            let bias = bias.map(|bias| bias[channel]).unwrap_or(0.0);
+            *acc as f32 * scales[channel] + bias
+        };
+
+        // The quantization range always contains zero
+        let (min, max) = acc
+            .iter()
+            .enumerate()
+            .map(value)
+            .fold((0.0f32, 0.0f32), |(min, max), v| (min.min(v), max.max(v)));
+
+        let (scale, offset, range, strategy) = if self.symmetric {
+            let q = SymmetricQuantization::<f32, i8>::new(min, max);
+            let range = (-(i8::MAX as f32), i8::MAX as f32);
+            (
+                q.scale,
+                0.0,
+                range,
+                QuantizationStrategy::PerTensorSymmetricInt8(q),
+            )
+        } else {
+            let q = AffineQuantization::<f32, i8, i32>::new(min, max);
+            let range = (i8::MIN as f32, i8::MAX as f32);
+            let offset = q.offset as f32;
+            (
+                q.scale,
+                offset,
+                range,
+                QuantizationStrategy::PerTensorAffineInt8(q),
+            )
+        };
+
+        let values = run_par!(|| {
+            iter_par!(acc.iter().enumerate())
+                .map(|v| {
+                    let x_q = Float::round(value(v) / scale + offset);
+                    x_q.clamp(range.0, range.1) as i8
+                })
+                .collect()
+        });
+
+        (values, strategy)
+    }
+}
+
 impl<E: FloatNdArrayElement, Q: QuantElement> QTensorOps<Self> for NdArray<E, Q> {
     fn q_from_data(data: TensorData, _device: &NdArrayDevice) -> QuantizedTensor<Self> {
         match data.dtype {
-            DType::QFloat(strategy) => match strategy {
-                QuantizationStrategy::PerTensorAffineInt8(_) => {
-                    let data = data.convert::<i8>();
-                    NdArrayQTensor {
-                        qtensor: NdArrayTensor::<Q>::from_data(data),
-                        scheme: strategy.scheme(),
-                        strategy,
-                    }
-                }
-                QuantizationStrategy::PerTensorSymmetricInt8(_) => {
-                    let data = data.convert::<i8>();
-                    NdArrayQTensor {
-                        qtensor: NdArrayTensor::<Q>::from_data(data),
-                        scheme: strategy.scheme(),
-                        strategy,
-                    }
-                }
-            },
+            DType::QFloat(_) => {
+                let strategy = data.quantization_strategy().unwrap();
+                from_quantized_data(data, strategy)
+            }
             _ => panic!(
                 "Invalid dtype (expected DType::QFloat, got {:?})",
                 data.dtype
@@ -55,43 +288,101 @@ impl<E: FloatNdArrayElement, Q: QuantElement> QTensorOps<Self> for NdArray<E, Q>
         scheme: &QuantizationScheme,
         qparams: QuantizationParametersPrimitive<Self>,
     ) -> QuantizedTensor<Self> {
+        let scale = into_data(qparams.scale);
+        let offset = qparams.offset.map(into_data);
+
         let strategy = match scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
-                        into_data(qparams.scale).iter().next().unwrap(),
-                        into_data(qparams.offset.unwrap()).iter().next().unwrap(),
-                    ))
+            QuantizationScheme::PerTensorAffine(dtype) => {
+                let scale = scale.iter().next().unwrap();
+                let offset = offset.unwrap();
+                match dtype {
+                    QuantizationType::QInt8 => QuantizationStrategy::PerTensorAffineInt8(
+                        AffineQuantization::init(scale, offset.iter().next().unwrap()),
+                    ),
+                    QuantizationType::QInt4 => QuantizationStrategy::PerTensorAffineInt4(
+                        AffineQuantization::init(scale, offset.iter().next().unwrap()),
+                    ),
+                    QuantizationType::QUInt8 => QuantizationStrategy::PerTensorAffineUInt8(
+                        AffineQuantization::init(scale, offset.iter().next().unwrap()),
+                    ),
+                    QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2 => {
+                        panic!("Affine quantization is not supported for {dtype:?}")
+                    }
                 }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => QuantizationStrategy::PerTensorSymmetricInt8(
-                    SymmetricQuantization::init(into_data(qparams.scale).iter().next().unwrap()),
+            }
+            QuantizationScheme::PerTensorSymmetric(dtype) => {
+                let scale = scale.iter().next().unwrap();
+                match dtype {
+                    QuantizationType::QInt8 => QuantizationStrategy::PerTensorSymmetricInt8(
+                        SymmetricQuantization::init(scale),
+                    ),
+                    QuantizationType::QInt4 => QuantizationStrategy::PerTensorSymmetricInt4(
+                        SymmetricQuantization::init(scale),
+                    ),
+                    QuantizationType::QUInt8 => {
+                        panic!("Symmetric quantization is not supported for {dtype:?}")
+                    }
+                    QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2 => {
+                        QuantizationStrategy::PerTensorSymmetricFloat8(Float8Quantization::init(
+                            scale,
+                            dtype.float8_format().unwrap(),
+                        ))
+                    }
+                }
+            }
+            QuantizationScheme::PerChannelAffine(dtype, axis) => {
+                let offset = offset.unwrap();
+                match dtype {
+                    QuantizationType::QInt8 => QuantizationStrategy::PerChannelAffineInt8(
+                        PerChannelQuantization::init(*axis, affine_channels(&scale, &offset)),
+                    ),
+                    QuantizationType::QInt4 => QuantizationStrategy::PerChannelAffineInt4(
+                        PerChannelQuantization::init(*axis, affine_channels(&scale, &offset)),
+                    ),
+                    QuantizationType::QUInt8 => QuantizationStrategy::PerChannelAffineUInt8(
+                        PerChannelQuantization::init(*axis, affine_channels(&scale, &offset)),
+                    ),
+                    QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2 => {
+                        panic!("Affine quantization is not supported for {dtype:?}")
+                    }
+                }
+            }
+            QuantizationScheme::PerChannelSymmetric(dtype, axis) => match dtype {
+                QuantizationType::QInt8 => QuantizationStrategy::PerChannelSymmetricInt8(
+                    PerChannelQuantization::init(*axis, symmetric_channels(&scale)),
                 ),
+                QuantizationType::QInt4 => QuantizationStrategy::PerChannelSymmetricInt4(
+                    PerChannelQuantization::init(*axis, symmetric_channels(&scale)),
+                ),
+                QuantizationType::QUInt8 => {
+                    panic!("Symmetric quantization is not supported for {dtype:?}")
+                }
+                QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2 => {
+                    let format = dtype.float8_format().unwrap();
+                    QuantizationStrategy::PerChannelSymmetricFloat8(PerChannelQuantization::init(
+                        *axis,
+                        scale
+                            .iter()
+                            .map(|scale| Float8Quantization::init(scale, format))
+                            .collect(),
+                    ))
+                }
             },
         };
 
-        let data = into_data(tensor).with_quantization(strategy);
-        NdArrayQTensor {
-            qtensor: NdArrayTensor::<Q>::from_data(data),
-            strategy,
-            scheme: scheme.clone(),
-        }
+        let data = into_data(tensor).with_quantization(strategy.clone());
+        from_quantized_data(data, strategy)
     }
 
     fn dequantize(tensor: QuantizedTensor<Self>) -> FloatTensor<Self> {
-        let data = into_data(tensor.qtensor);
-        let values = match tensor.strategy {
-            QuantizationStrategy::PerTensorAffineInt8(s) => s.dequantize(data.as_slice().unwrap()),
-            QuantizationStrategy::PerTensorSymmetricInt8(s) => {
-                s.dequantize(data.as_slice().unwrap())
-            }
-        };
+        let strategy = tensor.strategy.clone();
+        let data = into_quantized_data(tensor);
+        let values = strategy.dequantize(&data);
         NdArrayTensor::<E>::from_data(TensorData::new(values, data.shape))
     }
 
     fn q_shape(tensor: &QuantizedTensor<Self>) -> Shape {
-        tensor.qtensor.shape()
+        tensor.shape.clone()
     }
 
     fn q_device(_tensor: &QuantizedTensor<Self>) -> NdArrayDevice {
@@ -106,17 +397,32 @@ impl<E: FloatNdArrayElement, Q: QuantElement> QTensorOps<Self> for NdArray<E, Q>
     }
 
     fn q_reshape(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
-        NdArrayQTensor {
-            qtensor: NdArrayOps::reshape(tensor.qtensor, shape),
-            scheme: tensor.scheme,
-            strategy: tensor.strategy,
+        let dims = tensor.shape.dims.clone();
+        let strategy = map_channels(&tensor.strategy, |axis, channels| {
+            // The channels are preserved when the reshape keeps the values before and along
+            // the quantization axis in the same contiguous blocks
+            let prefix: usize = dims[..axis].iter().product();
+            (0..shape.num_dims())
+                .find(|&i| {
+                    shape.dims[i] == channels && shape.dims[..i].iter().product::<usize>() == prefix
+                })
+                .map(|axis| (axis, (0..channels).collect()))
+        });
+
+        match strategy {
+            Some(strategy) => {
+                NdArrayQTensor::new(NdArrayOps::reshape(tensor.values(), shape), strategy)
+            }
+            None => dequant_op_quant!(
+                ty Self,
+                float_op |tensor| NdArrayOps::reshape(tensor, shape),
+                tensor
+            ),
         }
     }
 
     async fn q_into_data(tensor: QuantizedTensor<Self>) -> TensorData {
-        let shape = tensor.qtensor.shape();
-        let values = tensor.qtensor.array.into_iter().collect();
-        TensorData::quantized(values, shape, tensor.strategy)
+        into_quantized_data(tensor)
     }
 
     fn q_swap_dims(
@@ -124,27 +430,40 @@ impl<E: FloatNdArrayElement, Q: QuantElement> QTensorOps<Self> for NdArray<E, Q>
         dim1: usize,
         dim2: usize,
     ) -> QuantizedTensor<Self> {
-        NdArrayQTensor {
-            qtensor: NdArrayOps::swap_dims(tensor.qtensor, dim1, dim2),
-            scheme: tensor.scheme,
-            strategy: tensor.strategy,
-        }
+        let strategy = map_channels(&tensor.strategy, |axis, channels| {
+            let axis = match axis {
+                axis if axis == dim1 => dim2,
+                axis if axis == dim2 => dim1,
+                axis => axis,
+            };
+            Some((axis, (0..channels).collect()))
+        })
+        .unwrap();
+
+        NdArrayQTensor::new(NdArrayOps::swap_dims(tensor.values(), dim1, dim2), strategy)
     }
 
     fn q_permute(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
-        NdArrayQTensor {
-            qtensor: NdArrayOps::permute(tensor.qtensor, axes),
-            scheme: tensor.scheme,
-            strategy: tensor.strategy,
-        }
+        let strategy = map_channels(&tensor.strategy, |axis, channels| {
+            let axis = axes.iter().position(|&dim| dim == axis).unwrap();
+            Some((axis, (0..channels).collect()))
+        })
+        .unwrap();
+
+        NdArrayQTensor::new(NdArrayOps::permute(tensor.values(), axes), strategy)
     }
 
     fn q_flip(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
-        NdArrayQTensor {
-            qtensor: NdArrayOps::flip(tensor.qtensor, axes),
-            scheme: tensor.scheme,
-            strategy: tensor.strategy,
-        }
+        let strategy = map_channels(&tensor.strategy, |axis, channels| {
+            if axes.contains(&axis) {
+                Some((axis, (0..channels).rev().collect()))
+            } else {
+                Some((axis, (0..channels).collect()))
+            }
+        })
+        .unwrap();
+
+        NdArrayQTensor::new(NdArrayOps::flip(tensor.values(), axes), strategy)
     }
 
     fn q_gather(
@@ -152,10 +471,26 @@ impl<E: FloatNdArrayElement, Q: QuantElement> QTensorOps<Self> for NdArray<E, Q>
         tensor: QuantizedTensor<Self>,
         indices: IntTensor<Self>,
     ) -> QuantizedTensor<Self> {
-        NdArrayQTensor {
-            qtensor: NdArrayMathOps::gather(dim, tensor.qtensor, indices),
-            scheme: tensor.scheme,
-            strategy: tensor.strategy,
+        let indices_dims = indices.shape().dims;
+        let strategy = map_channels(&tensor.strategy, |axis, channels| {
+            // Gathered values only stay in their channel when gathering along another axis
+            if dim != axis && indices_dims[axis] == channels {
+                Some((axis, (0..channels).collect()))
+            } else {
+                None
+            }
+        });
+
+        match strategy {
+            Some(strategy) => NdArrayQTensor::new(
+                NdArrayMathOps::gather(dim, tensor.values(), indices),
+                strategy,
+            ),
+            None => dequant_op_quant!(
+                ty Self,
+                float_op |tensor| NdArrayMathOps::gather(dim, tensor, indices),
+                tensor
+            ),
         }
     }
 
@@ -164,34 +499,138 @@ impl<E: FloatNdArrayElement, Q: QuantElement> QTensorOps<Self> for NdArray<E, Q>
         dim: usize,
         indices: IntTensor<Self>,
     ) -> QuantizedTensor<Self> {
-        NdArrayQTensor {
-            qtensor: NdArrayMathOps::select(tensor.qtensor, dim, indices),
-            scheme: tensor.scheme,
-            strategy: tensor.strategy,
-        }
+        let strategy = map_channels(&tensor.strategy, |axis, channels| {
+            if dim == axis {
+                Some((axis, indices.array.iter().map(|i| *i as usize).collect()))
+            } else {
+                Some((axis, (0..channels).collect()))
+            }
+        })
+        .unwrap();
+
+        NdArrayQTensor::new(
+            NdArrayMathOps::select(tensor.values(), dim, indices),
+            strategy,
+        )
     }
 
     fn q_slice(tensor: QuantizedTensor<Self>, ranges: &[Range<usize>]) -> QuantizedTensor<Self> {
-        NdArrayQTensor {
-            qtensor: NdArrayOps::slice(tensor.qtensor, ranges),
-            scheme: tensor.scheme,
-            strategy: tensor.strategy,
-        }
+        let strategy = map_channels(&tensor.strategy, |axis, channels| match ranges.get(axis) {
+            Some(range) => Some((axis, range.clone().collect())),
+            None => Some((axis, (0..channels).collect())),
+        })
+        .unwrap();
+
+        NdArrayQTensor::new(NdArrayOps::slice(tensor.values(), ranges), strategy)
     }
 
     fn q_argmax(tensor: QuantizedTensor<Self>, dim: usize) -> IntTensor<Self> {
-        NdArrayMathOps::argmax(tensor.qtensor, dim)
+        // Quantized values can only be compared directly when they share the same parameters
+        // and are stored in the same order as the values they represent
+        if tensor.scheme().axis() == Some(dim) || !is_order_preserving(&tensor.strategy) {
+            NdArrayMathOps::argmax(Self::dequantize(tensor), dim)
+        } else {
+            NdArrayMathOps::argmax(tensor.values(), dim)
+        }
     }
 
     fn q_argmin(tensor: QuantizedTensor<Self>, dim: usize) -> IntTensor<Self> {
-        NdArrayMathOps::argmin(tensor.qtensor, dim)
+        // Quantized values can only be compared directly when they share the same parameters
+        // and are stored in the same order as the values they represent
+        if tensor.scheme().axis() == Some(dim) || !is_order_preserving(&tensor.strategy) {
+            NdArrayMathOps::argmin(Self::dequantize(tensor), dim)
+        } else {
+            NdArrayMathOps::argmin(tensor.values(), dim)
+        }
     }
 
-    fn q_expand(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
-        NdArrayQTensor {
-            qtensor: NdArrayOps::expand(tensor.qtensor, shape),
-            scheme: tensor.scheme,
-            strategy: tensor.strategy,
+    fn q_matmul(lhs: QuantizedTensor<Self>, rhs: QuantizedTensor<Self>) -> QuantizedTensor<Self> {
+        match q_matmul(&lhs, &rhs, None) {
+            Some(output) => output,
+            None => dequant_op_quant!(
+                ty Self,
+                float_op |lhs, rhs| matmul(lhs, rhs),
+                lhs,
+                rhs
+            ),
         }
     }
+
+    fn q_linear(
+        x: QuantizedTensor<Self>,
+        weight: QuantizedTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+    ) -> QuantizedTensor<Self> {
+        let mut dims = x.shape.dims.clone();
+        let [d_input, d_output] = weight.shape.dims();
+
+        // Flatten the batch dimensions to multiply with the weight matrix
+        let x = Self::q_reshape(
+            x,
+            Shape::new([dims.iter().product::<usize>() / d_input, d_input]),
+        );
+        let bias_values = bias.as_ref().map(|bias| {
+            bias.array
+                .iter()
+                .map(|b| b.elem::<f32>())
+                .collect::<Vec<_>>()
+        });
+
+        let output = match q_matmul(&x, &weight, bias_values.as_deref()) {
+            Some(output) => output,
+            None => {
+                let scheme = *x.scheme();
+                let output = matmul(Self::dequantize(x), Self::dequantize(weight));
+                let output = match bias {
+                    Some(bias) => NdArrayMathOps::add(output, bias),
+                    None => output,
+                };
+                Self::quantize_dynamic(output, &scheme)
+            }
+        };
+
+        *dims.last_mut().unwrap() = d_output;
+        Self::q_reshape(output, Shape::from(dims))
+    }
+
+    fn q_conv2d(
+        x: QuantizedTensor<Self>,
+        weight: QuantizedTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvOptions<2>,
+    ) -> QuantizedTensor<Self> {
+        let bias_values = bias.as_ref().map(|bias| {
+            bias.array
+                .iter()
+                .map(|b| b.elem::<f32>())
+                .collect::<Vec<_>>()
+        });
+
+        match q_conv2d(&x, &weight, bias_values.as_deref(), &options) {
+            Some(output) => output,
+            None => {
+                let scheme = *x.scheme();
+                let output =
+                    conv2d::<E, Q>(Self::dequantize(x), Self::dequantize(weight), bias, options);
+                Self::quantize_dynamic(output, &scheme)
+            }
+        }
+    }
+
+    fn q_expand(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
+        let rank = tensor.shape.num_dims();
+        let strategy = map_channels(&tensor.strategy, |axis, channels| {
+            // New dimensions are prepended to the shape
+            let axis = axis + shape.num_dims() - rank;
+            if channels == 1 {
+                // A single channel can be broadcast along the quantization axis
+                Some((axis, vec![0; shape.dims[axis]]))
+            } else {
+                Some((axis, (0..channels).collect()))
+            }
+        })
+        .unwrap();
+
+        NdArrayQTensor::new(NdArrayOps::expand(tensor.values(), shape), strategy)
+    }
 }
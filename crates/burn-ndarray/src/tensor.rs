@@ -1,6 +1,6 @@
 use burn_tensor::{
-    quantization::{QTensorPrimitive, QuantizationScheme, QuantizationStrategy},
-    Element, Shape, TensorData,
+    quantization::{QTensorPrimitive, QuantizationScheme, QuantizationStrategy, QuantizedStorage},
+    Element, ElementConversion, Shape, TensorData,
 };
 
 use ndarray::{ArcArray, Array, Dim, IxDyn};
@@ -121,20 +121,82 @@ where
 #[derive(Clone, Debug)]
 pub struct NdArrayQTensor<Q: QuantElement> {
     /// The quantized tensor.
+    ///
+    /// The `int4` values are packed two per byte (the first value in the low bits) in a
+    /// one-dimensional tensor, the [values](NdArrayQTensor::values) are unpacked.
     pub qtensor: NdArrayTensor<Q>,
+    /// The shape of the quantized tensor.
+    pub shape: Shape,
     /// The quantization scheme.
     pub scheme: QuantizationScheme,
     /// The quantization strategy.
     pub strategy: QuantizationStrategy,
 }
 
+impl<Q: QuantElement> NdArrayQTensor<Q> {
+    /// Create a quantized tensor from the quantized values, packing the `int4` values.
+    pub fn new(values: NdArrayTensor<Q>, strategy: QuantizationStrategy) -> Self {
+        let shape = values.shape();
+        let qtensor = match strategy.storage() {
+            QuantizedStorage::PackedInt4 => {
+                let values = values
+                    .array
+                    .iter()
+                    .map(|x| x.elem::<i8>())
+                    .collect::<Vec<_>>();
+                let packed = values
+                    .chunks(2)
+                    .map(|pair| {
+                        let low = pair[0] as u8 & 0x0F;
+                        let high = pair.get(1).map(|x| *x as u8 & 0x0F).unwrap_or(0);
+                        ((high << 4) | low) as i8
+                    })
+                    .map(|x| x.elem::<Q>())
+                    .collect::<Vec<_>>();
+                let len = packed.len();
+                NdArrayTensor::from_data(TensorData::new(packed, [len]))
+            }
+            _ => values,
+        };
+
+        Self {
+            qtensor,
+            shape,
+            scheme: strategy.scheme(),
+            strategy,
+        }
+    }
+
+    /// Returns the quantized values with the shape of the tensor, unpacking the `int4` values.
+    pub fn values(&self) -> NdArrayTensor<Q> {
+        match self.strategy.storage() {
+            QuantizedStorage::PackedInt4 => {
+                let values = self
+                    .qtensor
+                    .array
+                    .iter()
+                    // Sign-extend the low and high 4 bits
+                    .flat_map(|x| {
+                        let x = x.elem::<i8>() as u8;
+                        [((x << 4) as i8) >> 4, (x as i8) >> 4]
+                    })
+                    .take(self.shape.num_elements())
+                    .map(|x| x.elem::<Q>())
+                    .collect::<Vec<_>>();
+                NdArrayTensor::from_data(TensorData::new(values, self.shape.clone()))
+            }
+            _ => self.qtensor.clone(),
+        }
+    }
+}
+
 impl<Q: QuantElement> QTensorPrimitive for NdArrayQTensor<Q> {
     fn scheme(&self) -> &QuantizationScheme {
         &self.scheme
     }
 
     fn strategy(&self) -> QuantizationStrategy {
-        self.strategy
+        self.strategy.clone()
     }
 }
 
@@ -146,8 +208,11 @@ mod tests {
     use burn_common::rand::get_seeded_rng;
     use burn_tensor::{
         ops::QTensorOps,
-        quantization::{AffineQuantization, QuantizationParametersPrimitive, QuantizationType},
-        Distribution,
+        quantization::{
+            AffineQuantization, QuantizationParameters, QuantizationParametersPrimitive,
+            QuantizationType, SymmetricQuantization,
+        },
+        Distribution, Tensor,
     };
 
     #[test]
@@ -222,4 +287,96 @@ mod tests {
             QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(0.009_019_608, 72))
         );
     }
+
+    #[test]
+    fn should_pack_int4_qtensor_values() {
+        let tensor =
+            NdArrayTensor::<f32>::from_data(TensorData::from([[-1.8, -1.0, 0.0], [0.5, 1.0, 1.8]]));
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+        let qparams = QuantizationParametersPrimitive {
+            scale: NdArrayTensor::from_data(TensorData::from([0.257_142_87])),
+            offset: None,
+        };
+        let qtensor: NdArrayQTensor<i8> = NdArray::quantize(tensor, &scheme, qparams);
+
+        // Six values packed in three bytes
+        assert_eq!(qtensor.qtensor.array.len(), 3);
+        assert_eq!(qtensor.shape, Shape::new([2, 3]));
+        assert_eq!(
+            qtensor.values().array.iter().copied().collect::<Vec<_>>(),
+            vec![-7, -4, 0, 2, 4, 7]
+        );
+    }
+
+    #[test]
+    fn should_support_int4_qtensor_layout_ops() {
+        let device = Default::default();
+        let tensor = Tensor::<NdArray, 2>::from_floats([[-1.8, -1.0], [0.0, 0.5]], &device);
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.257_142_87], &device),
+            offset: None,
+        };
+
+        let data = tensor
+            .quantize(&scheme, qparams)
+            .swap_dims(0, 1)
+            .into_data();
+
+        // Quantized [[-7, 0], [-4, 2]] packed two values per byte
+        assert_eq!(data.bytes[..2], [0x09, 0x2C]);
+        data.assert_eq(
+            &TensorData::quantized(
+                vec![-7i8, 0, -4, 2],
+                [2, 2],
+                QuantizationStrategy::PerTensorSymmetricInt4(SymmetricQuantization::init(
+                    0.257_142_87,
+                )),
+            ),
+            true,
+        );
+    }
+
+    #[test]
+    fn should_support_uint8_qtensor_argmax() {
+        let device = Default::default();
+        let tensor = Tensor::<NdArray, 1>::from_floats([-1.8, 0.5, -1.0, 0.0], &device);
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.009_019_608], &device),
+            offset: Some(Tensor::from_ints([199], &device)),
+        };
+
+        let x_q = tensor.quantize(&scheme, qparams);
+
+        x_q.to_data().assert_eq(
+            &TensorData::quantized(
+                vec![0u8, 254, 88, 199],
+                [4],
+                QuantizationStrategy::PerTensorAffineUInt8(AffineQuantization::init(
+                    0.009_019_608,
+                    199,
+                )),
+            ),
+            true,
+        );
+        // Values above 127 must not be compared as signed bytes
+        x_q.argmax(0)
+            .into_data()
+            .assert_eq(&TensorData::from([1i64]), false);
+    }
+
+    #[test]
+    fn should_support_float8_qtensor() {
+        let device = Default::default();
+        let tensor = Tensor::<NdArray, 1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QFloat8E4M3);
+
+        let x_q = tensor.quantize_dynamic(&scheme);
+
+        assert_eq!(x_q.to_data().bytes[..4], [0xFE, 0xF8, 0x00, 0x70]);
+        x_q.dequantize()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.8, -1.028_571_4, 0.0, 0.514_285_7]), 4);
+    }
 }
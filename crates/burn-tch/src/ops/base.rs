@@ -1,4 +1,7 @@
-use burn_tensor::{quantization::QuantizationStrategy, Shape};
+use burn_tensor::{
+    quantization::{QuantizationScheme, QuantizationStrategy},
+    Shape,
+};
 use tch::Scalar;
 
 use crate::{LibTorchDevice, TchShape, TchTensor};
@@ -489,6 +492,53 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
                     .tensor
                     .quantize_per_tensor(q.scale.into(), 0, tch::Kind::QInt8),
             ),
+            QuantizationStrategy::PerChannelAffineInt8(ref q) => {
+                let scales: Vec<f64> = q.channels.iter().map(|c| c.scale.into()).collect();
+                let offsets: Vec<i64> = q.channels.iter().map(|c| c.offset.into()).collect();
+                TchTensor::new(tensor.tensor.quantize_per_channel(
+                    &tch::Tensor::from_slice(&scales),
+                    &tch::Tensor::from_slice(&offsets),
+                    q.axis as i64,
+                    tch::Kind::QInt8,
+                ))
+            }
+            QuantizationStrategy::PerChannelSymmetricInt8(ref q) => {
+                let scales: Vec<f64> = q.channels.iter().map(|c| c.scale.into()).collect();
+                let offsets = vec![0i64; scales.len()];
+                TchTensor::new(tensor.tensor.quantize_per_channel(
+                    &tch::Tensor::from_slice(&scales),
+                    &tch::Tensor::from_slice(&offsets),
+                    q.axis as i64,
+                    tch::Kind::QInt8,
+                ))
+            }
+            QuantizationStrategy::PerTensorAffineUInt8(ref q) => {
+                TchTensor::new(tensor.tensor.quantize_per_tensor(
+                    q.scale.into(),
+                    q.offset.into(),
+                    tch::Kind::QUInt8,
+                ))
+            }
+            QuantizationStrategy::PerChannelAffineUInt8(ref q) => {
+                let scales: Vec<f64> = q.channels.iter().map(|c| c.scale.into()).collect();
+                let offsets: Vec<i64> = q.channels.iter().map(|c| c.offset.into()).collect();
+                TchTensor::new(tensor.tensor.quantize_per_channel(
+                    &tch::Tensor::from_slice(&scales),
+                    &tch::Tensor::from_slice(&offsets),
+                    q.axis as i64,
+                    tch::Kind::QUInt8,
+                ))
+            }
+            _ => unsupported_quantization(&strategy.scheme()),
         }
     }
 }
+
+/// Panics with a clear error for the quantization types without a LibTorch equivalent, only the
+/// `int8` and `uint8` types are supported.
+pub(crate) fn unsupported_quantization(scheme: &QuantizationScheme) -> ! {
+    panic!(
+        "Unsupported quantization scheme {scheme:?}: the LibTorch backend only supports QInt8 \
+         and QUInt8 quantization"
+    )
+}
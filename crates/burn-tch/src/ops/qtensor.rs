@@ -3,15 +3,15 @@ use std::ops::Range;
 use burn_tensor::{
     ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{
-        QTensorPrimitive, Quantization, QuantizationParametersPrimitive, QuantizationScheme,
-        QuantizationStrategy, QuantizationType,
+        CalibrationRange, QTensorPrimitive, QuantizationParametersPrimitive, QuantizationScheme,
+        QuantizationType, QuantizedStorage,
     },
-    DType, Shape, TensorData,
+    DType, Shape, Tensor, TensorData, TensorPrimitive,
 };
 
 use crate::{LibTorch, LibTorchDevice, QuantElement, TchElement, TchQTensor, TchShape, TchTensor};
 
-use super::TchOps;
+use super::{unsupported_quantization, TchOps};
 
 impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
     fn q_from_data(data: TensorData, device: &LibTorchDevice) -> QuantizedTensor<Self> {
@@ -23,28 +23,17 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         // So for now we have to load the dequantized values to quantize them back since the dequantization
         // methods take the values provided when quantizing.
         let (tensor, scheme) = match data.dtype {
-            DType::QFloat(strategy) => match strategy {
-                QuantizationStrategy::PerTensorAffineInt8(q) => {
-                    let values = q.dequantize(&data.iter::<i8>().collect::<Vec<_>>());
-                    let tensor = tch::Tensor::from_slice(&values).to(device);
-                    let tensor = TchOps::<E>::quantize::<i8>(
-                        TchTensor::new(tensor.reshape(shape_tch.dims)),
-                        &strategy,
-                    )
-                    .tensor;
-                    (tensor, strategy.scheme())
-                }
-                QuantizationStrategy::PerTensorSymmetricInt8(q) => {
-                    let values = q.dequantize(&data.iter::<i8>().collect::<Vec<_>>());
-                    let tensor = tch::Tensor::from_slice(&values).to(device);
-                    let tensor = TchOps::<E>::quantize::<i8>(
-                        TchTensor::new(tensor.reshape(shape_tch.dims)),
-                        &strategy,
-                    )
-                    .tensor;
-                    (tensor, strategy.scheme())
-                }
-            },
+            DType::QFloat(scheme) => {
+                let strategy = data.quantization_strategy().unwrap();
+                let values = strategy.dequantize(&data);
+                let tensor = tch::Tensor::from_slice(&values).to(device);
+                let tensor = TchOps::<E>::quantize::<i8>(
+                    TchTensor::new(tensor.reshape(shape_tch.dims)),
+                    &strategy,
+                )
+                .tensor;
+                (tensor, scheme)
+            }
             _ => panic!(
                 "Invalid dtype (expected DType::QFloat, got {:?})",
                 data.dtype
@@ -74,19 +63,50 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                     &qparams.offset.unwrap().tensor,
                     tch::Kind::QInt8,
                 ),
+                QuantizationType::QUInt8 => tensor.tensor.quantize_per_tensor_tensor_qparams(
+                    &qparams.scale.tensor,
+                    &qparams.offset.unwrap().tensor,
+                    tch::Kind::QUInt8,
+                ),
+                _ => unsupported_quantization(scheme),
             },
-            QuantizationScheme::PerTensorSymmetric(_) => {
-                tensor.tensor.quantize_per_tensor_tensor_qparams(
+            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
+                QuantizationType::QInt8 => tensor.tensor.quantize_per_tensor_tensor_qparams(
                     &qparams.scale.tensor,
                     &tch::Tensor::zeros_like(&qparams.scale.tensor),
                     tch::Kind::QInt8,
-                )
-            }
+                ),
+                _ => unsupported_quantization(scheme),
+            },
+            QuantizationScheme::PerChannelAffine(dtype, axis) => match dtype {
+                QuantizationType::QInt8 => tensor.tensor.quantize_per_channel(
+                    &qparams.scale.tensor.to_kind(tch::Kind::Double),
+                    &qparams.offset.unwrap().tensor,
+                    *axis as i64,
+                    tch::Kind::QInt8,
+                ),
+                QuantizationType::QUInt8 => tensor.tensor.quantize_per_channel(
+                    &qparams.scale.tensor.to_kind(tch::Kind::Double),
+                    &qparams.offset.unwrap().tensor,
+                    *axis as i64,
+                    tch::Kind::QUInt8,
+                ),
+                _ => unsupported_quantization(scheme),
+            },
+            QuantizationScheme::PerChannelSymmetric(dtype, axis) => match dtype {
+                QuantizationType::QInt8 => tensor.tensor.quantize_per_channel(
+                    &qparams.scale.tensor.to_kind(tch::Kind::Double),
+                    &tch::Tensor::zeros_like(&qparams.scale.tensor).to_kind(tch::Kind::Int64),
+                    *axis as i64,
+                    tch::Kind::QInt8,
+                ),
+                _ => unsupported_quantization(scheme),
+            },
         };
 
         TchQTensor {
             qtensor: TchTensor::new(qtensor),
-            scheme: scheme.clone(),
+            scheme: *scheme,
         }
     }
 
@@ -102,6 +122,10 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                 QuantizationType::QInt8 => tensor
                     .tensor
                     .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false),
+                QuantizationType::QUInt8 => tensor
+                    .tensor
+                    .quantize_per_tensor_dynamic(tch::Kind::QUInt8, /*reduce_range*/ false),
+                _ => unsupported_quantization(scheme),
             },
             QuantizationScheme::PerTensorSymmetric(dtype) => {
                 log::warn!("LibTorch backend does not support symmetric per-tensor scheme for dynamic quantization, reverting to the default per-tensor affine quantization");
@@ -109,13 +133,35 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                     QuantizationType::QInt8 => tensor
                         .tensor
                         .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false),
+                    _ => unsupported_quantization(scheme),
+                }
+            }
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis) => {
+                // LibTorch does not provide dynamic per-channel quantization, so we compute the
+                // range of each channel along the quantization axis
+                let rank = tensor.tensor.dim();
+                if *axis >= rank {
+                    return Self::quantize_dynamic(tensor, &scheme.per_tensor());
                 }
+                let dims: Vec<i64> = (0..rank as i64).filter(|d| *d != *axis as i64).collect();
+                let range = CalibrationRange {
+                    min: Tensor::<Self, 1>::from_primitive(TensorPrimitive::Float(TchTensor::new(
+                        tensor.tensor.amin(dims.as_slice(), false),
+                    ))),
+                    max: Tensor::<Self, 1>::from_primitive(TensorPrimitive::Float(TchTensor::new(
+                        tensor.tensor.amax(dims.as_slice(), false),
+                    ))),
+                };
+                let qparams = scheme.compute_q_params(range);
+
+                return Self::quantize(tensor, scheme, qparams.into());
             }
         };
 
         TchQTensor {
             qtensor: TchTensor::new(qtensor),
-            scheme: scheme.clone(),
+            scheme: *scheme,
         }
     }
 
@@ -145,6 +191,7 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
             qtensor: TchOps::reshape(tensor.qtensor, shape),
             scheme: tensor.scheme,
         }
+        .sync_axis()
     }
 
     async fn q_into_data(tensor: QuantizedTensor<Self>) -> TensorData {
@@ -153,9 +200,17 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         let strategy = tensor.strategy();
 
         // To get the integer values we have to call `int_repr()`
-        let values: Result<Vec<i8>, tch::TchError> = tensor.qtensor.tensor.int_repr().try_into();
-
-        TensorData::quantized(values.unwrap(), shape, strategy)
+        let values = tensor.qtensor.tensor.int_repr();
+        match strategy.storage() {
+            QuantizedStorage::UInt8 => {
+                let values: Result<Vec<u8>, tch::TchError> = values.try_into();
+                TensorData::quantized(values.unwrap(), shape, strategy)
+            }
+            _ => {
+                let values: Result<Vec<i8>, tch::TchError> = values.try_into();
+                TensorData::quantized(values.unwrap(), shape, strategy)
+            }
+        }
     }
 
     fn q_swap_dims(
@@ -163,17 +218,15 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         dim1: usize,
         dim2: usize,
     ) -> QuantizedTensor<Self> {
-        // NOTE: with per-channel quantization (future), the channel axis could be impacted by this op
         let mut tensor = tensor;
         tensor.qtensor = TchOps::swap_dims(tensor.qtensor, dim1, dim2);
-        tensor
+        tensor.sync_axis()
     }
 
     fn q_permute(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
-        // NOTE: with per-channel quantization (future), the channel axis could be impacted by this op
         let mut tensor = tensor;
         tensor.qtensor = TchOps::permute(tensor.qtensor, axes);
-        tensor
+        tensor.sync_axis()
     }
 
     fn q_flip(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
@@ -265,7 +318,7 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
             .into_iter()
             .map(|x| TchQTensor {
                 qtensor: x,
-                scheme: tensor.scheme.clone(),
+                scheme: tensor.scheme,
             })
             .collect()
     }
@@ -1,8 +1,8 @@
-use crate::{LibTorchDevice, QuantElement};
+use crate::{ops::unsupported_quantization, LibTorchDevice, QuantElement};
 use burn_tensor::{
     quantization::{
-        AffineQuantization, QTensorPrimitive, QuantizationScheme, QuantizationStrategy,
-        QuantizationType, SymmetricQuantization,
+        AffineQuantization, PerChannelQuantization, QTensorPrimitive, QuantizationScheme,
+        QuantizationStrategy, QuantizationType, SymmetricQuantization,
     },
     Element, Shape, TensorData,
 };
@@ -346,6 +346,15 @@ impl<Q: QuantElement> QTensorPrimitive for TchQTensor<Q> {
                         offset as i8,
                     ))
                 }
+                QuantizationType::QUInt8 => {
+                    let scale = self.qtensor.tensor.q_scale();
+                    let offset = self.qtensor.tensor.q_zero_point();
+                    QuantizationStrategy::PerTensorAffineUInt8(AffineQuantization::init(
+                        scale as f32,
+                        offset as u8,
+                    ))
+                }
+                _ => unsupported_quantization(&self.scheme),
             },
             QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
                 QuantizationType::QInt8 => {
@@ -354,11 +363,104 @@ impl<Q: QuantElement> QTensorPrimitive for TchQTensor<Q> {
                         scale as f32,
                     ))
                 }
+                _ => unsupported_quantization(&self.scheme),
+            },
+            QuantizationScheme::PerChannelAffine(dtype, axis) => match dtype {
+                QuantizationType::QInt8 => {
+                    let scales: Vec<f64> = self
+                        .qtensor
+                        .tensor
+                        .q_per_channel_scales()
+                        .try_into()
+                        .unwrap();
+                    let offsets: Vec<i64> = self
+                        .qtensor
+                        .tensor
+                        .q_per_channel_zero_points()
+                        .try_into()
+                        .unwrap();
+                    QuantizationStrategy::PerChannelAffineInt8(PerChannelQuantization::init(
+                        *axis,
+                        scales
+                            .iter()
+                            .zip(offsets.iter())
+                            .map(|(scale, offset)| {
+                                AffineQuantization::init(*scale as f32, *offset as i8)
+                            })
+                            .collect(),
+                    ))
+                }
+                QuantizationType::QUInt8 => {
+                    let scales: Vec<f64> = self
+                        .qtensor
+                        .tensor
+                        .q_per_channel_scales()
+                        .try_into()
+                        .unwrap();
+                    let offsets: Vec<i64> = self
+                        .qtensor
+                        .tensor
+                        .q_per_channel_zero_points()
+                        .try_into()
+                        .unwrap();
+                    QuantizationStrategy::PerChannelAffineUInt8(PerChannelQuantization::init(
+                        *axis,
+                        scales
+                            .iter()
+                            .zip(offsets.iter())
+                            .map(|(scale, offset)| {
+                                AffineQuantization::init(*scale as f32, *offset as u8)
+                            })
+                            .collect(),
+                    ))
+                }
+                _ => unsupported_quantization(&self.scheme),
+            },
+            QuantizationScheme::PerChannelSymmetric(dtype, axis) => match dtype {
+                QuantizationType::QInt8 => {
+                    let scales: Vec<f64> = self
+                        .qtensor
+                        .tensor
+                        .q_per_channel_scales()
+                        .try_into()
+                        .unwrap();
+                    QuantizationStrategy::PerChannelSymmetricInt8(PerChannelQuantization::init(
+                        *axis,
+                        scales
+                            .iter()
+                            .map(|scale| SymmetricQuantization::init(*scale as f32))
+                            .collect(),
+                    ))
+                }
+                _ => unsupported_quantization(&self.scheme),
             },
         }
     }
 }
 
+impl<Q: QuantElement> TchQTensor<Q> {
+    /// Updates the quantization axis of per-channel schemes after a layout operation.
+    ///
+    /// LibTorch keeps track of the channel axis of per-channel quantized tensors, so the scheme
+    /// is synchronized with the axis of the underlying tensor.
+    pub(crate) fn sync_axis(mut self) -> Self {
+        self.scheme = match self.scheme {
+            QuantizationScheme::PerChannelAffine(dtype, _) => QuantizationScheme::PerChannelAffine(
+                dtype,
+                self.qtensor.tensor.q_per_channel_axis() as usize,
+            ),
+            QuantizationScheme::PerChannelSymmetric(dtype, _) => {
+                QuantizationScheme::PerChannelSymmetric(
+                    dtype,
+                    self.qtensor.tensor.q_per_channel_axis() as usize,
+                )
+            }
+            scheme => scheme,
+        };
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::LibTorch;
@@ -119,7 +119,7 @@ impl<H: Clone> HandleContainer<H> {
         } else {
             vec![qtensor, scale]
         };
-        B::quantized_tensor(handles, tensor.scheme.clone())
+        B::quantized_tensor(handles, tensor.scheme)
     }
 
     /// Register a new [float tensor](crate::backend::Backend::FloatTensorPrimitive) with the corresponding [tensor id](TensorId).
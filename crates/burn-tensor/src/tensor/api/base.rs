@@ -1824,7 +1824,7 @@ impl<B: Backend> BasicOps<B> for Float {
 
     fn from_data(data: TensorData, device: &B::Device) -> Self::Primitive {
         match data.dtype {
-            DType::QFloat(_strategy) => TensorPrimitive::QFloat(B::q_from_data(data, device)),
+            DType::QFloat(_) => TensorPrimitive::QFloat(B::q_from_data(data, device)),
             _ => TensorPrimitive::Float(B::float_from_data(data, device)),
         }
     }
@@ -350,6 +350,33 @@ where
         )))
     }
 
+    /// Simulate the quantization of the tensor in floating point precision, e.g. for
+    /// quantization-aware training.
+    ///
+    /// The values are quantized and dequantized in the forward pass, while the gradients are passed
+    /// straight through for the values within the quantization range.
+    ///
+    /// # Arguments
+    ///
+    /// * `scheme` - The quantization scheme.
+    /// * `qparams` - The pre-computed quantization parameters.
+    ///
+    /// # Returns
+    ///
+    /// The fake quantized tensor.
+    pub fn fake_quantize(
+        self,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParameters<B>,
+    ) -> Tensor<B, D> {
+        crate::quantization::fake_quantize(
+            self,
+            scheme,
+            qparams.scale,
+            qparams.offset.map(|offset| offset.float()),
+        )
+    }
+
     /// Convert the tensor back to a higher precision data type.
     ///
     /// If the tensor is not quantized, its value is simply returned.
@@ -7,7 +7,7 @@ use alloc::vec::Vec;
 use half::{bf16, f16};
 
 use crate::{
-    quantization::{Quantization, QuantizationStrategy},
+    quantization::{QuantizationStrategy, QuantizedStorage},
     tensor::Shape,
     DType, Distribution, Element, ElementConversion,
 };
@@ -50,12 +50,72 @@ impl TensorData {
     }
 
     /// Creates a new quantized tensor data structure.
+    ///
+    /// The values are packed according to the storage type of the quantization strategy, and
+    /// followed by the quantization parameters.
     pub fn quantized<E: Element, S: Into<Vec<usize>>>(
         value: Vec<E>,
         shape: S,
         strategy: QuantizationStrategy,
     ) -> Self {
-        Self::init(value, shape, DType::QFloat(strategy))
+        let shape = shape.into();
+        let numel = Self::numel(&shape);
+        assert_eq!(
+            numel,
+            value.len(),
+            "Shape {:?} is invalid for input of size {:?}",
+            shape,
+            value.len(),
+        );
+
+        let mut bytes: Vec<u8> = match strategy.storage() {
+            QuantizedStorage::Int8 => value.iter().map(|e| e.elem::<i8>() as u8).collect(),
+            // Two values per byte, with the first value in the low bits
+            QuantizedStorage::PackedInt4 => value
+                .chunks(2)
+                .map(|pair| {
+                    let low = pair[0].elem::<i8>() as u8 & 0x0F;
+                    let high = pair.get(1).map(|e| e.elem::<i8>() as u8 & 0x0F);
+                    low | (high.unwrap_or(0) << 4)
+                })
+                .collect(),
+            QuantizedStorage::UInt8 | QuantizedStorage::Float8 => {
+                value.iter().map(|e| e.elem::<u8>()).collect()
+            }
+        };
+        bytes.extend(strategy.params_bytes());
+
+        Self {
+            bytes,
+            shape,
+            dtype: DType::QFloat(strategy.scheme()),
+        }
+    }
+
+    /// Returns the quantization strategy of quantized data, with the quantization parameters
+    /// stored after the values, or `None` if the data is not quantized.
+    pub fn quantization_strategy(&self) -> Option<QuantizationStrategy> {
+        match self.dtype {
+            DType::QFloat(scheme) => Some(QuantizationStrategy::from_params_bytes(
+                scheme,
+                &self.shape,
+                self.quantized_bytes().1,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Splits the bytes of quantized data into the quantized values and the quantization
+    /// parameters.
+    pub(crate) fn quantized_bytes(&self) -> (&[u8], &[u8]) {
+        let num_bytes = match self.dtype {
+            DType::QFloat(scheme) => match scheme.storage() {
+                QuantizedStorage::PackedInt4 => self.num_elements().div_ceil(2),
+                _ => self.num_elements(),
+            },
+            _ => self.bytes.len(),
+        };
+        self.bytes.split_at(num_bytes.min(self.bytes.len()))
     }
 
     /// Initializes a new tensor data structure from the provided values.
@@ -187,20 +247,26 @@ impl TensorData {
                 ),
                 // bool is a byte value equal to either 0 or 1
                 DType::Bool => Box::new(self.bytes.iter().map(|e| e.elem::<E>())),
-                DType::QFloat(q) => match q {
-                    // NOTE: we do not dequantize the values to iterate over
-                    QuantizationStrategy::PerTensorAffineInt8(_strategy) => Box::new(
-                        bytemuck::checked::cast_slice(&self.bytes)
-                            .iter()
-                            .map(|e: &i8| e.elem::<E>()),
-                    ),
-
-                    QuantizationStrategy::PerTensorSymmetricInt8(_strategy) => Box::new(
-                        bytemuck::checked::cast_slice(&self.bytes)
-                            .iter()
-                            .map(|e: &i8| e.elem::<E>()),
-                    ),
-                },
+                DType::QFloat(scheme) => {
+                    let (values, _) = self.quantized_bytes();
+                    match scheme.storage() {
+                        // NOTE: we do not dequantize the values to iterate over
+                        QuantizedStorage::Int8 => {
+                            Box::new(values.iter().map(|e| (*e as i8).elem::<E>()))
+                        }
+                        QuantizedStorage::PackedInt4 => Box::new(
+                            values
+                                .iter()
+                                // Sign-extend the low and high 4 bits
+                                .flat_map(|e| [((*e << 4) as i8) >> 4, (*e as i8) >> 4])
+                                .take(self.num_elements())
+                                .map(|e| e.elem::<E>()),
+                        ),
+                        QuantizedStorage::UInt8 | QuantizedStorage::Float8 => {
+                            Box::new(values.iter().map(|e| e.elem::<E>()))
+                        }
+                    }
+                }
             }
         }
     }
@@ -294,18 +360,7 @@ impl TensorData {
             DType::F32,
             "Only f32 data type can be quantized"
         );
-        match &quantization {
-            QuantizationStrategy::PerTensorAffineInt8(strategy) => TensorData::quantized(
-                strategy.quantize(self.as_slice().unwrap()),
-                self.shape,
-                quantization,
-            ),
-            QuantizationStrategy::PerTensorSymmetricInt8(strategy) => TensorData::quantized(
-                strategy.quantize(self.as_slice().unwrap()),
-                self.shape,
-                quantization,
-            ),
-        }
+        quantization.quantize(self.as_slice().unwrap(), &self.shape)
     }
 
     /// Asserts the data is approximately equal to another data.
@@ -366,16 +421,16 @@ impl TensorData {
                 } else {
                     panic!("Quantized data differs from other not quantized data")
                 };
-                match (q, q_other) {
-                    (
-                        QuantizationStrategy::PerTensorAffineInt8(_),
-                        QuantizationStrategy::PerTensorAffineInt8(_),
-                    ) => self.assert_eq_elem::<i8>(other),
-                    (
-                        QuantizationStrategy::PerTensorSymmetricInt8(_),
-                        QuantizationStrategy::PerTensorSymmetricInt8(_),
-                    ) => self.assert_eq_elem::<i8>(other),
-                    _ => panic!("Quantization strategies differ ({:?} != {:?})", q, q_other),
+                if q != q_other {
+                    panic!("Quantization schemes differ ({:?} != {:?})", q, q_other)
+                }
+                match q.storage() {
+                    QuantizedStorage::Int8 | QuantizedStorage::PackedInt4 => {
+                        self.assert_eq_elem::<i8>(other)
+                    }
+                    QuantizedStorage::UInt8 | QuantizedStorage::Float8 => {
+                        self.assert_eq_elem::<u8>(other)
+                    }
                 }
             }
         }
@@ -623,12 +678,12 @@ impl core::fmt::Display for TensorData {
             DType::U32 => format!("{:?}", self.as_slice::<u32>().unwrap()),
             DType::U8 => format!("{:?}", self.as_slice::<u8>().unwrap()),
             DType::Bool => format!("{:?}", self.as_slice::<bool>().unwrap()),
-            DType::QFloat(q) => match &q {
-                QuantizationStrategy::PerTensorAffineInt8(_) => {
-                    format!("{:?} {q:?}", self.try_as_slice::<i8>().unwrap())
+            DType::QFloat(q) => match q.storage() {
+                QuantizedStorage::Int8 | QuantizedStorage::PackedInt4 => {
+                    format!("{:?} {q:?}", self.iter::<i8>().collect::<Vec<_>>())
                 }
-                QuantizationStrategy::PerTensorSymmetricInt8(_) => {
-                    format!("{:?} {q:?}", self.try_as_slice::<i8>().unwrap())
+                QuantizedStorage::UInt8 | QuantizedStorage::Float8 => {
+                    format!("{:?} {q:?}", self.quantized_bytes().0)
                 }
             },
         };
@@ -1021,6 +1076,37 @@ mod tests {
     use alloc::vec;
     use rand::{rngs::StdRng, SeedableRng};
 
+    #[test]
+    fn should_pack_int4_values() {
+        let strategy = QuantizationStrategy::PerTensorAffineInt4(
+            crate::quantization::AffineQuantization::init(0.1, 0),
+        );
+        let data = TensorData::quantized(vec![-8i8, 7, -1], [3], strategy);
+
+        // The first value is stored in the low bits
+        assert_eq!(data.quantized_bytes().0, [0x78, 0x0F]);
+        assert_eq!(data.iter::<i8>().collect::<Vec<_>>(), vec![-8, 7, -1]);
+    }
+
+    #[test]
+    fn should_store_the_quantization_parameters_after_the_values() {
+        let strategy = QuantizationStrategy::PerChannelAffineInt8(
+            crate::quantization::PerChannelQuantization::init(
+                1,
+                vec![
+                    crate::quantization::AffineQuantization::init(0.1, -3),
+                    crate::quantization::AffineQuantization::init(0.5, 7),
+                ],
+            ),
+        );
+        let data = TensorData::quantized(vec![1i8, 2, 3, 4], [2, 2], strategy.clone());
+
+        assert_eq!(data.dtype, DType::QFloat(strategy.scheme()));
+        assert_eq!(data.quantized_bytes().0, [1, 2, 3, 4]);
+        assert_eq!(data.quantization_strategy(), Some(strategy));
+        assert_eq!(data.iter::<i8>().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    }
+
     #[test]
     fn should_have_right_num_elements() {
         let shape = Shape::new([3, 5, 6]);
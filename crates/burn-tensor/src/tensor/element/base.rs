@@ -1,6 +1,6 @@
 use core::cmp::Ordering;
 
-use crate::{cast::ToElement, quantization::QuantizationStrategy, Distribution};
+use crate::{cast::ToElement, quantization::QuantizationScheme, Distribution};
 use half::{bf16, f16};
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
@@ -256,5 +256,5 @@ pub enum DType {
     U32,
     U8,
     Bool,
-    QFloat(QuantizationStrategy),
+    QFloat(QuantizationScheme),
 }
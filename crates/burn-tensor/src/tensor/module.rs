@@ -17,6 +17,47 @@ where
     )))
 }
 
+/// Applies a linear transformation to the input tensor, `y = x @ weight + bias`.
+///
+/// When both the input and the weight are quantized, the [quantized linear](crate::ops::QTensorOps::q_linear)
+/// operation is used.
+///
+/// # Shapes
+///
+/// - x: `[..., d_input]`
+/// - weight: `[d_input, d_output]`
+/// - bias: `[d_output]`
+/// - output: `[..., d_output]`
+pub fn linear<B, const D: usize>(
+    x: Tensor<B, D>,
+    weight: Tensor<B, 2>,
+    bias: Option<Tensor<B, 1>>,
+) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    if D == 1 {
+        // Insert and remove an extra batch dimension for the batch matmul to work.
+        return linear::<B, 2>(x.unsqueeze(), weight, bias).flatten(0, 1);
+    }
+
+    match (x.primitive, weight.primitive) {
+        (TensorPrimitive::QFloat(x), TensorPrimitive::QFloat(weight)) => Tensor::new(
+            TensorPrimitive::QFloat(B::q_linear(x, weight, bias.map(|b| b.primitive.tensor()))),
+        ),
+        (x, weight) => {
+            let x = Tensor::<B, D>::new(TensorPrimitive::Float(x.tensor()));
+            let weight = Tensor::<B, 2>::new(TensorPrimitive::Float(weight.tensor()));
+            let output = x.matmul(weight.unsqueeze());
+
+            match bias {
+                Some(bias) => output + bias.dequantize().unsqueeze(),
+                None => output,
+            }
+        }
+    }
+}
+
 /// Applies a [1D convolution](crate::ops::ModuleOps::conv2d).
 pub fn conv1d<B>(
     x: Tensor<B, 3>,
@@ -45,12 +86,22 @@ pub fn conv2d<B>(
 where
     B: Backend,
 {
-    Tensor::new(TensorPrimitive::Float(B::conv2d(
-        x.primitive.tensor(),
-        weight.primitive.tensor(),
-        bias.map(|b| b.primitive.tensor()),
-        options,
-    )))
+    match (x.primitive, weight.primitive) {
+        (TensorPrimitive::QFloat(x), TensorPrimitive::QFloat(weight)) => {
+            Tensor::new(TensorPrimitive::QFloat(B::q_conv2d(
+                x,
+                weight,
+                bias.map(|b| b.primitive.tensor()),
+                options,
+            )))
+        }
+        (x, weight) => Tensor::new(TensorPrimitive::Float(B::conv2d(
+            x.tensor(),
+            weight.tensor(),
+            bias.map(|b| b.primitive.tensor()),
+            options,
+        ))),
+    }
 }
 
 /// Applies a [3D convolution](crate::ops::ModuleOps::conv3d).
@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use core::{future::Future, ops::Range};
 
 use crate::{
@@ -7,7 +7,7 @@ use crate::{
     Device, Shape, TensorData,
 };
 
-use super::{BoolTensor, FloatElem, FloatTensor, IntElem, IntTensor, QuantizedTensor};
+use super::{BoolTensor, ConvOptions, FloatElem, FloatTensor, IntElem, IntTensor, QuantizedTensor};
 
 /// Automatically applies dequantization -> float operation -> quantization.
 #[macro_export]
@@ -17,7 +17,7 @@ macro_rules! dequant_op_quant {
         ty $ty:ty, float_op $float_op:expr, $t1:expr, $t2:expr
     ) => {{
         // Heuristic: prioritize lhs scheme
-        let scheme = $t1.scheme().clone();
+        let scheme = *$t1.scheme();
 
         let t1_f = <$ty>::dequantize($t1);
         let t2_f = <$ty>::dequantize($t2);
@@ -30,7 +30,7 @@ macro_rules! dequant_op_quant {
     (
         ty $ty:ty, float_op $float_op:expr, $tensor:expr
     ) => {{
-        let scheme = $tensor.scheme().clone();
+        let scheme = *$tensor.scheme();
 
         let tensor_f = <$ty>::dequantize($tensor);
         #[allow(clippy::redundant_closure_call)]
@@ -64,9 +64,33 @@ pub trait QTensorOps<B: Backend> {
 
     /// Dynamically convert the tensor to a lower precision data type based on the quantization scheme.
     fn quantize_dynamic(tensor: FloatTensor<B>, scheme: &QuantizationScheme) -> QuantizedTensor<B> {
+        // Reductions and reshapes can remove the quantization axis, in which case we fall back
+        // to the equivalent per-tensor scheme
+        let rank = B::float_shape(&tensor).num_dims();
+        let scheme = &match scheme.axis() {
+            Some(axis) if axis >= rank => scheme.per_tensor(),
+            _ => *scheme,
+        };
+
         // Dynamically compute min/max tensor range and qparams before quantizing
-        let min = B::float_min(tensor.clone());
-        let max = B::float_max(tensor.clone());
+        let (min, max) = match scheme.axis() {
+            Some(axis) => {
+                // Compute the range of each channel along the quantization axis
+                let shape = B::float_shape(&tensor);
+                let channels = shape.dims[axis];
+                let values = B::float_reshape(
+                    B::float_swap_dims(tensor.clone(), 0, axis),
+                    Shape::new([channels, shape.num_elements() / channels]),
+                );
+                let min = B::float_min_dim(values.clone(), 1);
+                let max = B::float_max_dim(values, 1);
+                (
+                    B::float_reshape(min, Shape::new([channels])),
+                    B::float_reshape(max, Shape::new([channels])),
+                )
+            }
+            None => (B::float_min(tensor.clone()), B::float_max(tensor.clone())),
+        };
         let qparams = scheme.compute_q_params_primitive(min, max);
         Self::quantize(tensor, scheme, qparams)
     }
@@ -198,7 +222,7 @@ pub trait QTensorOps<B: Backend> {
     ///
     /// The result of adding the scalar to the tensor.
     fn q_add_scalar(lhs: QuantizedTensor<B>, rhs: FloatElem<B>) -> QuantizedTensor<B> {
-        let scheme = lhs.scheme().clone();
+        let scheme = *lhs.scheme();
 
         let lhs_f = Self::dequantize(lhs);
         let out_f = B::float_add_scalar(lhs_f, rhs);
@@ -217,7 +241,7 @@ pub trait QTensorOps<B: Backend> {
     ///
     /// The clamped tensor.
     fn q_clamp_min(tensor: QuantizedTensor<B>, min: FloatElem<B>) -> QuantizedTensor<B> {
-        let scheme = tensor.scheme().clone();
+        let scheme = *tensor.scheme();
 
         let tensor_f = Self::dequantize(tensor);
         let out_f = B::float_clamp_min(tensor_f, min);
@@ -236,7 +260,7 @@ pub trait QTensorOps<B: Backend> {
     ///
     /// The clamped tensor.
     fn q_clamp_max(tensor: QuantizedTensor<B>, max: FloatElem<B>) -> QuantizedTensor<B> {
-        let scheme = tensor.scheme().clone();
+        let scheme = *tensor.scheme();
 
         let tensor_f = Self::dequantize(tensor);
         let out_f = B::float_clamp_max(tensor_f, max);
@@ -260,7 +284,7 @@ pub trait QTensorOps<B: Backend> {
         min: FloatElem<B>,
         max: FloatElem<B>,
     ) -> QuantizedTensor<B> {
-        let scheme = tensor.scheme().clone();
+        let scheme = *tensor.scheme();
 
         let tensor_f = Self::dequantize(tensor);
         let out_f = B::float_clamp(tensor_f, min, max);
@@ -298,7 +322,7 @@ pub trait QTensorOps<B: Backend> {
     ///
     /// The result of subtracting the scalar from the tensor.
     fn q_sub_scalar(lhs: QuantizedTensor<B>, rhs: FloatElem<B>) -> QuantizedTensor<B> {
-        let scheme = lhs.scheme().clone();
+        let scheme = *lhs.scheme();
 
         let lhs_f = Self::dequantize(lhs);
         let out_f = B::float_sub_scalar(lhs_f, rhs);
@@ -327,7 +351,7 @@ pub trait QTensorOps<B: Backend> {
     ///
     /// The result of multiplying the tensor by the scalar.
     fn q_mul_scalar(lhs: QuantizedTensor<B>, rhs: FloatElem<B>) -> QuantizedTensor<B> {
-        let scheme = lhs.scheme().clone();
+        let scheme = *lhs.scheme();
 
         let lhs_f = Self::dequantize(lhs);
         let out_f = B::float_mul_scalar(lhs_f, rhs);
@@ -365,7 +389,7 @@ pub trait QTensorOps<B: Backend> {
     ///
     /// The result of dividing the tensor by the scalar.
     fn q_div_scalar(lhs: QuantizedTensor<B>, rhs: FloatElem<B>) -> QuantizedTensor<B> {
-        let scheme = lhs.scheme().clone();
+        let scheme = *lhs.scheme();
 
         let lhs_f = Self::dequantize(lhs);
         let out_f = B::float_div_scalar(lhs_f, rhs);
@@ -383,7 +407,7 @@ pub trait QTensorOps<B: Backend> {
     ///
     /// The result of applying the modulus of the scalar to the tensor.
     fn q_remainder_scalar(lhs: QuantizedTensor<B>, rhs: FloatElem<B>) -> QuantizedTensor<B> {
-        let scheme = lhs.scheme().clone();
+        let scheme = *lhs.scheme();
 
         let lhs_f = Self::dequantize(lhs);
         let out_f = B::float_remainder_scalar(lhs_f, rhs);
@@ -410,9 +434,79 @@ pub trait QTensorOps<B: Backend> {
         )
     }
 
+    /// Applies a linear transformation to the input tensor, `y = x @ weight + bias`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The input tensor of shape `[..., d_input]`.
+    /// * `weight` - The weight tensor of shape `[d_input, d_output]`.
+    /// * `bias` - The optional bias tensor of shape `[d_output]`.
+    ///
+    /// # Returns
+    ///
+    /// The quantized output tensor of shape `[..., d_output]`, with the same quantization scheme
+    /// as the input tensor.
+    fn q_linear(
+        x: QuantizedTensor<B>,
+        weight: QuantizedTensor<B>,
+        bias: Option<FloatTensor<B>>,
+    ) -> QuantizedTensor<B> {
+        let scheme = *x.scheme();
+
+        let x_f = Self::dequantize(x);
+        let weight_f = Self::dequantize(weight);
+
+        // Broadcast the weight and bias over the batch dimensions
+        let rank = B::float_shape(&x_f).num_dims();
+        let [d_input, d_output] = B::float_shape(&weight_f).dims();
+        let mut dims = vec![1; rank];
+        dims[rank - 2] = d_input;
+        dims[rank - 1] = d_output;
+
+        let weight_f = B::float_reshape(weight_f, Shape::from(dims.clone()));
+        let out_f = B::float_matmul(x_f, weight_f);
+        let out_f = match bias {
+            Some(bias) => {
+                dims[rank - 2] = 1;
+                B::float_add(out_f, B::float_reshape(bias, Shape::from(dims)))
+            }
+            None => out_f,
+        };
+
+        Self::quantize_dynamic(out_f, &scheme)
+    }
+
+    /// Applies a 2D convolution to the input tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The input tensor of shape `[batch_size, channels_in, height, width]`.
+    /// * `weight` - The weight tensor of shape `[channels_out, channels_in / groups, kernel_size_1, kernel_size_2]`.
+    /// * `bias` - The optional bias tensor of shape `[channels_out]`.
+    /// * `options` - The convolution options.
+    ///
+    /// # Returns
+    ///
+    /// The quantized output tensor of shape `[batch_size, channels_out, height_out, width_out]`,
+    /// with the same quantization scheme as the input tensor.
+    fn q_conv2d(
+        x: QuantizedTensor<B>,
+        weight: QuantizedTensor<B>,
+        bias: Option<FloatTensor<B>>,
+        options: ConvOptions<2>,
+    ) -> QuantizedTensor<B> {
+        let scheme = *x.scheme();
+
+        let x_f = Self::dequantize(x);
+        let weight_f = Self::dequantize(weight);
+        let out_f = B::conv2d(x_f, weight_f, bias, options);
+
+        Self::quantize_dynamic(out_f, &scheme)
+    }
+
     /// Negates a tensor element-wise.
     fn q_neg(tensor: QuantizedTensor<B>) -> QuantizedTensor<B> {
-        let scheme = tensor.scheme().clone();
+        let scheme = *tensor.scheme();
 
         let tensor_f = Self::dequantize(tensor);
         let out_f = B::float_neg(tensor_f);
@@ -422,7 +516,7 @@ pub trait QTensorOps<B: Backend> {
 
     /// Calculates the reciprocals element-wise
     fn q_recip(tensor: QuantizedTensor<B>) -> QuantizedTensor<B> {
-        let scheme = tensor.scheme().clone();
+        let scheme = *tensor.scheme();
 
         let tensor_f = Self::dequantize(tensor);
         let out_f = B::float_recip(tensor_f);
@@ -997,7 +1091,7 @@ pub trait QTensorOps<B: Backend> {
     /// A tensor with the concatenated tensors along `dim`.
     fn q_cat(tensors: Vec<QuantizedTensor<B>>, dim: usize) -> QuantizedTensor<B> {
         // Heuristic: prioritize first tensor scheme
-        let scheme = tensors.first().unwrap().scheme().clone();
+        let scheme = *tensors.first().unwrap().scheme();
 
         let tensor_f = tensors
             .into_iter()
@@ -1185,7 +1279,7 @@ pub trait QTensorOps<B: Backend> {
     ///
     /// A vector of tensors
     fn q_chunk(tensor: QuantizedTensor<B>, chunks: usize, dim: usize) -> Vec<QuantizedTensor<B>> {
-        let scheme = tensor.scheme().clone();
+        let scheme = *tensor.scheme();
 
         let tensor_f = Self::dequantize(tensor);
         let out_f = B::float_chunk(tensor_f, chunks, dim);
@@ -1304,7 +1398,7 @@ pub trait QTensorOps<B: Backend> {
         descending: bool,
     ) -> (QuantizedTensor<B>, IntTensor<B>) {
         // Default implementation. Backends can sort on the int values since qparams remain the same.
-        let scheme = tensor.scheme().clone();
+        let scheme = *tensor.scheme();
 
         let tensor_f = Self::dequantize(tensor);
         let (out_f, indices) = B::float_sort_with_indices(tensor_f, dim, descending);
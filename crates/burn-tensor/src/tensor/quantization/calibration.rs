@@ -1,6 +1,13 @@
-use crate::{backend::Backend, Tensor};
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, Tensor, TensorData};
+
+use super::histogram::Histogram;
 
 /// The observed input calibration range.
+///
+/// For per-tensor quantization, the range contains a single value. For per-channel quantization,
+/// the range contains the observed values for each channel along the quantization axis.
 #[derive(Clone, Debug)]
 pub struct CalibrationRange<B: Backend> {
     /// Minimum observed value.
@@ -9,26 +16,327 @@ pub struct CalibrationRange<B: Backend> {
     pub max: Tensor<B, 1>,
 }
 
+/// Gathers the statistics of the observed tensors to compute the calibration range.
+///
+/// Observers can be updated over many batches, e.g. to calibrate the range of activations for
+/// static quantization.
+pub trait Observer<B: Backend> {
+    /// Update the statistics with the values of the tensor.
+    fn observe<const D: usize>(&mut self, tensor: &Tensor<B, D>);
+
+    /// Compute the calibration range from the statistics observed so far.
+    ///
+    /// # Panics
+    ///
+    /// If no tensor has been observed.
+    fn range(&self) -> CalibrationRange<B>;
+}
+
 /// Calibration method used to compute the quantization range mapping.
 pub trait Calibration {
+    /// The observer used to gather the statistics.
+    type Observer<B: Backend>: Observer<B>;
+
+    /// Create an observer for the per-tensor range, or for the range of each channel along the
+    /// given axis.
+    fn observer<B: Backend>(&self, axis: Option<usize>) -> Self::Observer<B>;
+
     /// Compute the input tensor range.
     fn compute_range<B: Backend, const D: usize>(
         &self,
         tensor: &Tensor<B, D>,
-    ) -> CalibrationRange<B>;
+    ) -> CalibrationRange<B> {
+        let mut observer = self.observer(None);
+        observer.observe(tensor);
+        observer.range()
+    }
+
+    /// Compute the input tensor range for each channel along the given axis.
+    fn compute_range_per_channel<B: Backend, const D: usize>(
+        &self,
+        tensor: &Tensor<B, D>,
+        axis: usize,
+    ) -> CalibrationRange<B> {
+        let mut observer = self.observer(Some(axis));
+        observer.observe(tensor);
+        observer.range()
+    }
 }
 
-/// Computes the per-tensor quantization range mapping based on the min and max values.
+/// Default number of histogram bins.
+const HISTOGRAM_BINS: usize = 2048;
+
+/// Computes the per-tensor or per-channel quantization range mapping based on the min and max values.
 pub struct MinMaxCalibration {}
 
 impl Calibration for MinMaxCalibration {
-    fn compute_range<B: Backend, const D: usize>(
-        &self,
-        tensor: &Tensor<B, D>,
-    ) -> CalibrationRange<B> {
-        let min = tensor.clone().min();
-        let max = tensor.clone().max();
+    type Observer<B: Backend> = MinMaxObserver<B>;
+
+    fn observer<B: Backend>(&self, axis: Option<usize>) -> Self::Observer<B> {
+        MinMaxObserver { axis, range: None }
+    }
+}
+
+/// Computes the quantization range mapping from the given percentiles of the observed values,
+/// which ignores the outliers.
+///
+/// The percentiles are estimated from a histogram of the observed values.
+pub struct PercentileCalibration {
+    /// The percentile of the observed values used as the maximum, e.g. `99.99`. The minimum is
+    /// taken at `100 - percentile`.
+    pub percentile: f64,
+    /// The number of histogram bins.
+    pub bins: usize,
+}
+
+impl PercentileCalibration {
+    /// Create a new percentile calibration.
+    pub fn new(percentile: f64) -> Self {
+        assert!(
+            percentile > 50.0 && percentile <= 100.0,
+            "Percentile must be in the range (50, 100], got {percentile}"
+        );
+        Self {
+            percentile,
+            bins: HISTOGRAM_BINS,
+        }
+    }
+}
+
+impl Calibration for PercentileCalibration {
+    type Observer<B: Backend> = HistogramObserver<B>;
+
+    fn observer<B: Backend>(&self, axis: Option<usize>) -> Self::Observer<B> {
+        HistogramObserver::new(
+            axis,
+            self.bins,
+            HistogramMethod::Percentile(self.percentile / 100.0),
+        )
+    }
+}
+
+/// Computes the quantization range mapping that minimizes the mean squared error between the
+/// observed values and their quantized values.
+///
+/// The error is estimated from a histogram of the observed values.
+pub struct HistogramCalibration {
+    /// The number of histogram bins.
+    pub bins: usize,
+    /// The number of quantization levels, e.g. `256` for 8-bit quantization.
+    pub levels: usize,
+}
+
+impl Default for HistogramCalibration {
+    fn default() -> Self {
+        Self {
+            bins: HISTOGRAM_BINS,
+            levels: 256,
+        }
+    }
+}
+
+impl Calibration for HistogramCalibration {
+    type Observer<B: Backend> = HistogramObserver<B>;
+
+    fn observer<B: Backend>(&self, axis: Option<usize>) -> Self::Observer<B> {
+        HistogramObserver::new(axis, self.bins, HistogramMethod::Mse(self.levels))
+    }
+}
+
+/// Computes the symmetric quantization range mapping that minimizes the KL divergence (relative
+/// entropy) between the distributions of the observed values and their quantized values, as done
+/// by TensorRT.
+pub struct EntropyCalibration {
+    /// The number of histogram bins for the absolute values.
+    pub bins: usize,
+    /// The number of quantized bins for the absolute values, e.g. `128` for 8-bit quantization.
+    pub quantized_bins: usize,
+}
+
+impl Default for EntropyCalibration {
+    fn default() -> Self {
+        Self {
+            bins: HISTOGRAM_BINS,
+            quantized_bins: 128,
+        }
+    }
+}
+
+impl Calibration for EntropyCalibration {
+    type Observer<B: Backend> = HistogramObserver<B>;
 
-        CalibrationRange { min, max }
+    fn observer<B: Backend>(&self, axis: Option<usize>) -> Self::Observer<B> {
+        HistogramObserver::new(
+            axis,
+            self.bins,
+            HistogramMethod::Entropy(self.quantized_bins),
+        )
     }
 }
+
+/// Observes the running min and max values.
+#[derive(Clone, Debug)]
+pub struct MinMaxObserver<B: Backend> {
+    axis: Option<usize>,
+    range: Option<CalibrationRange<B>>,
+}
+
+impl<B: Backend> Observer<B> for MinMaxObserver<B> {
+    fn observe<const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        let (min, max) = match self.axis {
+            Some(axis) => {
+                let values = channels_first(tensor, axis);
+                (
+                    values.clone().min_dim(1).squeeze(1),
+                    values.max_dim(1).squeeze(1),
+                )
+            }
+            None => (tensor.clone().min(), tensor.clone().max()),
+        };
+
+        let range = match self.range.take() {
+            Some(range) => CalibrationRange {
+                min: range.min.min_pair(min),
+                max: range.max.max_pair(max),
+            },
+            None => CalibrationRange { min, max },
+        };
+        self.range = Some(range);
+    }
+
+    fn range(&self) -> CalibrationRange<B> {
+        self.range
+            .clone()
+            .expect("At least one tensor should be observed to compute the range")
+    }
+}
+
+/// How the range is computed from the histogram of the observed values.
+#[derive(Clone, Debug)]
+enum HistogramMethod {
+    /// The quantile of the maximum value.
+    Percentile(f64),
+    /// The number of quantization levels.
+    Mse(usize),
+    /// The number of quantized bins.
+    Entropy(usize),
+}
+
+/// Observes the histogram of the values.
+#[derive(Clone, Debug)]
+pub struct HistogramObserver<B: Backend> {
+    axis: Option<usize>,
+    bins: usize,
+    method: HistogramMethod,
+    histograms: Vec<Histogram>,
+    device: Option<B::Device>,
+}
+
+impl<B: Backend> HistogramObserver<B> {
+    fn new(axis: Option<usize>, bins: usize, method: HistogramMethod) -> Self {
+        Self {
+            axis,
+            bins,
+            method,
+            histograms: Vec::new(),
+            device: None,
+        }
+    }
+
+    fn histogram(&self) -> Histogram {
+        match self.method {
+            HistogramMethod::Entropy(_) => Histogram::from_zero(self.bins),
+            _ => Histogram::new(self.bins),
+        }
+    }
+
+    fn channel_range(&self, histogram: &Histogram) -> (f32, f32) {
+        match self.method {
+            HistogramMethod::Percentile(q) => (histogram.quantile(1.0 - q), histogram.quantile(q)),
+            HistogramMethod::Mse(levels) => histogram.mse_range(levels),
+            HistogramMethod::Entropy(quantized_bins) => {
+                let threshold = histogram.entropy_threshold(quantized_bins);
+                (-threshold, threshold)
+            }
+        }
+    }
+}
+
+impl<B: Backend> Observer<B> for HistogramObserver<B> {
+    fn observe<const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        // An empty tensor has no values to add to the histograms
+        if tensor.shape().num_elements() == 0 {
+            return;
+        }
+
+        let tensor = match self.method {
+            HistogramMethod::Entropy(_) => tensor.clone().abs(),
+            _ => tensor.clone(),
+        };
+        let values = match self.axis {
+            Some(axis) => channels_first(&tensor, axis),
+            None => tensor.reshape([1, -1]),
+        };
+
+        let [channels, _] = values.dims();
+        if self.histograms.is_empty() {
+            self.histograms = (0..channels).map(|_| self.histogram()).collect();
+        }
+        assert_eq!(
+            self.histograms.len(),
+            channels,
+            "The number of observed channels should not change"
+        );
+
+        self.device = Some(values.device());
+        let data = values.into_data().convert::<f32>();
+        let values = data.as_slice::<f32>().unwrap();
+        assert!(
+            values.len() % channels == 0,
+            "The {} observed values should be evenly split across the {channels} channels",
+            values.len()
+        );
+
+        for (histogram, values) in self
+            .histograms
+            .iter_mut()
+            .zip(values.chunks(values.len() / channels))
+        {
+            histogram.update(values.iter().copied());
+        }
+    }
+
+    fn range(&self) -> CalibrationRange<B> {
+        let device = self
+            .device
+            .as_ref()
+            .expect("At least one tensor should be observed to compute the range");
+
+        let (min, max): (Vec<_>, Vec<_>) = self
+            .histograms
+            .iter()
+            .map(|histogram| self.channel_range(histogram))
+            .unzip();
+        let channels = min.len();
+
+        CalibrationRange {
+            min: Tensor::from_floats(TensorData::new(min, [channels]), device),
+            max: Tensor::from_floats(TensorData::new(max, [channels]), device),
+        }
+    }
+}
+
+/// Reshapes the tensor to `[channels, values]` for the channels along the given axis.
+pub(crate) fn channels_first<B: Backend, const D: usize>(
+    tensor: &Tensor<B, D>,
+    axis: usize,
+) -> Tensor<B, 2> {
+    let shape = tensor.shape();
+    let channels = shape.dims[axis];
+    let numel = shape.num_elements();
+
+    tensor
+        .clone()
+        .swap_dims(0, axis)
+        .reshape([channels, numel / channels])
+}
@@ -0,0 +1,134 @@
+use crate::{backend::Backend, Tensor};
+use num_traits::Float;
+
+use super::{Float8Format, QuantizationScheme, QuantizationType};
+
+/// Simulate the quantization of the tensor in floating point precision.
+///
+/// The values are quantized and dequantized with the given scale and offset in the forward pass.
+/// In the backward pass, rounding is treated as the identity (straight-through estimator), so
+/// the gradients flow unchanged to the values within the quantization range and are zeroed for the
+/// clipped values. With 8-bit floating point types, the values are rounded to the nearest
+/// representable value of the format instead of the nearest integer.
+///
+/// Gradients also flow to the scale and offset, which can be learned during training as with
+/// [learned step size quantization](https://arxiv.org/abs/1902.08153) (LSQ).
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to fake quantize.
+/// * `scheme` - The quantization scheme.
+/// * `scale` - The scaling factor, with one value per channel for per-channel schemes.
+/// * `offset` - The zero-point offset for affine schemes, with one value per channel for
+///   per-channel schemes.
+pub fn fake_quantize<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    scheme: &QuantizationScheme,
+    scale: Tensor<B, 1>,
+    offset: Option<Tensor<B, 1>>,
+) -> Tensor<B, D> {
+    let (a, b) = quantized_range(scheme);
+    let scale = broadcast_channels::<B, D>(scale, scheme.axis());
+    let offset = offset.map(|offset| broadcast_channels::<B, D>(offset, scheme.axis()));
+
+    // x_q = clamp(round(x / scale + offset), a, b)
+    let x = tensor.div(scale.clone());
+    let x = match &offset {
+        Some(offset) => x.add(offset.clone()),
+        None => x,
+    };
+    let x = x.clamp(a, b);
+    let x_q = match scheme.q_type().float8_format() {
+        Some(format) => round_float8_straight_through(x, format),
+        None => round_straight_through(x),
+    };
+
+    // x = scale * (x_q - offset)
+    let x_q = match offset {
+        Some(offset) => x_q.sub(offset),
+        None => x_q,
+    };
+    x_q.mul(scale)
+}
+
+/// Round to the nearest integer (ties away from zero) in the forward pass, with an identity
+/// gradient in the backward pass.
+fn round_straight_through<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let rounded = tensor
+        .clone()
+        .add(tensor.clone().sign().mul_scalar(0.5))
+        .int()
+        .float();
+
+    tensor.clone().add(rounded.sub(tensor).detach())
+}
+
+/// Round to the nearest value representable with the 8-bit floating point format in the forward
+/// pass, with an identity gradient in the backward pass.
+///
+/// The values are rounded to the nearest multiple of the spacing between the representable values
+/// around them, `2^(e - m)` with the exponent `e` of the value and the number of mantissa bits `m`.
+fn round_float8_straight_through<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    format: Float8Format,
+) -> Tensor<B, D> {
+    // Values below the smallest normal value are subnormals, with the same spacing
+    let min_normal = Float::powi(2.0f32, 1 - format.bias());
+    let log2 = tensor
+        .clone()
+        .detach()
+        .abs()
+        .clamp_min(min_normal)
+        .log()
+        .div_scalar(core::f32::consts::LN_2);
+    // Floor of the positive and negative exponents
+    let exp = log2.clone().int().float();
+    let exp = exp.clone().sub(exp.greater(log2).float());
+    let spacing = exp
+        .sub_scalar(format.mantissa_bits() as f32)
+        .mul_scalar(core::f32::consts::LN_2)
+        .exp();
+
+    round_straight_through(tensor.div(spacing.clone())).mul(spacing)
+}
+
+/// Reshape the per-tensor or per-channel parameters to broadcast over a tensor of rank `D`.
+fn broadcast_channels<B: Backend, const D: usize>(
+    values: Tensor<B, 1>,
+    axis: Option<usize>,
+) -> Tensor<B, D> {
+    let mut dims = [1; D];
+    if let Some(axis) = axis {
+        dims[axis] = values.dims()[0];
+    }
+
+    values.reshape(dims)
+}
+
+/// Returns the quantized range `[a, b]` of the scheme.
+fn quantized_range(scheme: &QuantizationScheme) -> (f32, f32) {
+    match scheme {
+        QuantizationScheme::PerTensorAffine(dtype)
+        | QuantizationScheme::PerChannelAffine(dtype, _) => match dtype {
+            QuantizationType::QInt8 => (i8::MIN as f32, i8::MAX as f32),
+            QuantizationType::QInt4 => (-8.0, 7.0),
+            QuantizationType::QUInt8 => (u8::MIN as f32, u8::MAX as f32),
+            QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2 => panic!(
+                "Affine quantization is not supported for {dtype:?}, use a symmetric scheme instead"
+            ),
+        },
+        QuantizationScheme::PerTensorSymmetric(dtype)
+        | QuantizationScheme::PerChannelSymmetric(dtype, _) => match dtype {
+            QuantizationType::QInt8 => (-(i8::MAX as f32), i8::MAX as f32),
+            QuantizationType::QInt4 => (-7.0, 7.0),
+            QuantizationType::QUInt8 => panic!(
+                "Symmetric quantization is only valid for signed types, use an affine scheme instead"
+            ),
+            QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2 => {
+                // The largest finite values of the format
+                let max = dtype.float8_format().unwrap().max_value();
+                (-max, max)
+            }
+        },
+    }
+}
@@ -0,0 +1,188 @@
+use num_traits::Float;
+use serde::{Deserialize, Serialize};
+
+use super::QuantizationType;
+
+/// 8-bit floating point format.
+///
+/// Values are stored as raw bits in a byte.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Float8Format {
+    /// 4 exponent bits and 3 mantissa bits, without infinities (`E4M3FN`).
+    E4M3,
+    /// 5 exponent bits and 2 mantissa bits (`E5M2`).
+    E5M2,
+}
+
+impl Float8Format {
+    /// Returns the number of mantissa bits.
+    pub(crate) const fn mantissa_bits(&self) -> i32 {
+        match self {
+            Float8Format::E4M3 => 3,
+            Float8Format::E5M2 => 2,
+        }
+    }
+
+    /// Returns the exponent bias.
+    pub(crate) const fn bias(&self) -> i32 {
+        match self {
+            Float8Format::E4M3 => 7,
+            Float8Format::E5M2 => 15,
+        }
+    }
+
+    /// Returns the bits of the (positive) NaN value.
+    const fn nan_bits(&self) -> u8 {
+        match self {
+            Float8Format::E4M3 => 0x7F,
+            Float8Format::E5M2 => 0x7E,
+        }
+    }
+
+    /// Returns the largest finite value that can be represented.
+    pub fn max_value(&self) -> f32 {
+        match self {
+            Float8Format::E4M3 => 448.0,
+            Float8Format::E5M2 => 57344.0,
+        }
+    }
+
+    /// Convert a value to its 8-bit representation.
+    ///
+    /// The value is rounded to the nearest representable value (ties to even), and values
+    /// outside of the representable range saturate to the largest finite value.
+    pub fn encode(&self, value: f32) -> u8 {
+        if value.is_nan() {
+            return self.nan_bits();
+        }
+
+        let sign = if value.is_sign_negative() { 0x80 } else { 0 };
+        let value = Float::abs(value).min(self.max_value());
+        if value == 0.0 {
+            return sign;
+        }
+
+        let man_bits = self.mantissa_bits();
+        let min_exp = 1 - self.bias();
+
+        // Unbiased exponent of the f32 value. Values below the smallest normal exponent are
+        // encoded as subnormals.
+        let mut exp = (((value.to_bits() >> 23) & 0xFF) as i32 - 127).max(min_exp);
+        let mut man = round_ties_even(value / Float::powi(2.0f32, exp - man_bits)) as u8;
+        if man >= 2 << man_bits {
+            // Rounding overflowed to the next power of two
+            man >>= 1;
+            exp += 1;
+        }
+
+        if man < 1 << man_bits {
+            // Subnormal
+            sign | man
+        } else {
+            let exp_bits = (exp + self.bias()) as u8;
+            sign | (exp_bits << man_bits) | (man - (1 << man_bits))
+        }
+    }
+
+    /// Convert an 8-bit representation back to its value.
+    pub fn decode(&self, bits: u8) -> f32 {
+        let man_bits = self.mantissa_bits();
+        let exp_mask = (0x7F >> man_bits) as u8;
+        let man_mask = (1u8 << man_bits) - 1;
+
+        let sign = if bits & 0x80 != 0 { -1.0 } else { 1.0 };
+        let exp = (bits >> man_bits) & exp_mask;
+        let man = bits & man_mask;
+
+        match self {
+            Float8Format::E4M3 if exp == exp_mask && man == man_mask => return f32::NAN,
+            Float8Format::E5M2 if exp == exp_mask && man == 0 => return sign * f32::INFINITY,
+            Float8Format::E5M2 if exp == exp_mask => return f32::NAN,
+            _ => {}
+        }
+
+        let value = if exp == 0 {
+            man as f32 * Float::powi(2.0f32, 1 - self.bias() - man_bits)
+        } else {
+            ((1 << man_bits) + man as i32) as f32
+                * Float::powi(2.0f32, exp as i32 - self.bias() - man_bits)
+        };
+        sign * value
+    }
+}
+
+impl From<Float8Format> for QuantizationType {
+    fn from(format: Float8Format) -> Self {
+        match format {
+            Float8Format::E4M3 => QuantizationType::QFloat8E4M3,
+            Float8Format::E5M2 => QuantizationType::QFloat8E5M2,
+        }
+    }
+}
+
+/// Round to the nearest integer, with ties rounded to the nearest even integer.
+fn round_ties_even(value: f32) -> f32 {
+    let floor = Float::floor(value);
+    let diff = value - floor;
+    if diff > 0.5 || (diff == 0.5 && floor % 2.0 != 0.0) {
+        floor + 1.0
+    } else {
+        floor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_encode_e4m3() {
+        let format = Float8Format::E4M3;
+
+        assert_eq!(format.encode(0.0), 0x00);
+        assert_eq!(format.encode(1.0), 0x38);
+        assert_eq!(format.encode(-2.0), 0xC0);
+        assert_eq!(format.encode(448.0), 0x7E);
+        // Saturate to the largest finite value
+        assert_eq!(format.encode(1000.0), 0x7E);
+        // Smallest subnormal
+        assert_eq!(format.encode(1.0 / 512.0), 0x01);
+        // Ties to even (1.0625 is halfway between 1.0 and 1.125)
+        assert_eq!(format.encode(1.0625), 0x38);
+        assert_eq!(format.encode(f32::NAN), 0x7F);
+    }
+
+    #[test]
+    fn should_encode_e5m2() {
+        let format = Float8Format::E5M2;
+
+        assert_eq!(format.encode(1.0), 0x3C);
+        assert_eq!(format.encode(-0.5), 0xB8);
+        assert_eq!(format.encode(57344.0), 0x7B);
+        assert_eq!(format.encode(f32::INFINITY), 0x7B);
+        // Smallest subnormal
+        assert_eq!(format.encode(1.0 / 65536.0), 0x01);
+    }
+
+    #[test]
+    fn should_decode_all_finite_values() {
+        for format in [Float8Format::E4M3, Float8Format::E5M2] {
+            for bits in 0..=u8::MAX {
+                let value = format.decode(bits);
+                if value.is_finite() {
+                    assert_eq!(format.encode(value), bits, "{format:?} {bits:#x}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_decode_special_values() {
+        assert!(Float8Format::E4M3.decode(0x7F).is_nan());
+        assert!(Float8Format::E4M3.decode(0xFF).is_nan());
+        assert_eq!(Float8Format::E4M3.decode(0x7E), 448.0);
+        assert_eq!(Float8Format::E5M2.decode(0x7C), f32::INFINITY);
+        assert_eq!(Float8Format::E5M2.decode(0xFC), f32::NEG_INFINITY);
+        assert!(Float8Format::E5M2.decode(0x7E).is_nan());
+    }
+}
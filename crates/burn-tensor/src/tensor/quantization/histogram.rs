@@ -0,0 +1,322 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::Float;
+
+/// Histogram of the observed values with uniform bins.
+///
+/// The histogram range grows with the observed values. When it grows, the counts of the previous
+/// bins are moved to the new bins containing their centers.
+#[derive(Clone, Debug)]
+pub(crate) struct Histogram {
+    min: f32,
+    max: f32,
+    counts: Vec<f64>,
+    total: f64,
+    from_zero: bool,
+}
+
+impl Histogram {
+    /// Create an empty histogram with the given number of bins.
+    pub fn new(bins: usize) -> Self {
+        assert!(bins > 0, "Histogram must have at least one bin");
+        Self {
+            min: 0.0,
+            max: 0.0,
+            counts: vec![0.0; bins],
+            total: 0.0,
+            from_zero: false,
+        }
+    }
+
+    /// Create an empty histogram with the given number of bins whose range always starts at zero.
+    pub fn from_zero(bins: usize) -> Self {
+        Self {
+            from_zero: true,
+            ..Self::new(bins)
+        }
+    }
+
+    /// Add the values to the histogram. Non-finite values are ignored.
+    pub fn update(&mut self, values: impl Iterator<Item = f32> + Clone) {
+        let (min, max) = values
+            .clone()
+            .filter(|v| v.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), v| {
+                (min.min(v), max.max(v))
+            });
+
+        if min > max {
+            // No finite values
+            return;
+        }
+
+        let min = if self.from_zero { min.min(0.0) } else { min };
+        if self.total == 0.0 {
+            self.min = min;
+            self.max = max;
+        } else if min < self.min || max > self.max {
+            self.rebin(min.min(self.min), max.max(self.max));
+        }
+
+        for value in values.filter(|v| v.is_finite()) {
+            let bin = self.bin(value);
+            self.counts[bin] += 1.0;
+            self.total += 1.0;
+        }
+    }
+
+    /// The width of each bin.
+    pub fn bin_width(&self) -> f32 {
+        (self.max - self.min) / self.counts.len() as f32
+    }
+
+    /// The lower edge of the given bin.
+    pub fn edge(&self, bin: usize) -> f32 {
+        self.min + bin as f32 * self.bin_width()
+    }
+
+    /// The center of the given bin.
+    pub fn center(&self, bin: usize) -> f32 {
+        self.min + (bin as f32 + 0.5) * self.bin_width()
+    }
+
+    /// Returns the value below which the given fraction of the observed values fall.
+    ///
+    /// The values are assumed to be uniformly distributed within each bin.
+    pub fn quantile(&self, q: f64) -> f32 {
+        let target = q.clamp(0.0, 1.0) * self.total;
+        let mut cumulative = 0.0;
+
+        for (bin, count) in self.counts.iter().enumerate() {
+            if *count > 0.0 && cumulative + count >= target {
+                let fraction = ((target - cumulative) / count) as f32;
+                return self.edge(bin) + fraction * self.bin_width();
+            }
+            cumulative += count;
+        }
+
+        self.max
+    }
+
+    fn bin(&self, value: f32) -> usize {
+        let width = self.bin_width();
+        if width <= 0.0 {
+            return 0;
+        }
+
+        let bin = ((value - self.min) / width) as usize;
+        bin.min(self.counts.len() - 1)
+    }
+
+    fn rebin(&mut self, min: f32, max: f32) {
+        let centers = (0..self.counts.len())
+            .map(|bin| self.center(bin))
+            .collect::<Vec<_>>();
+        let counts = core::mem::replace(&mut self.counts, vec![0.0; centers.len()]);
+
+        self.min = min;
+        self.max = max;
+        for (center, count) in centers.into_iter().zip(counts) {
+            let bin = self.bin(center);
+            self.counts[bin] += count;
+        }
+    }
+
+    /// Returns the clipping range `[min, max]` that minimizes the expected mean squared error
+    /// when the values are quantized to the given number of levels.
+    ///
+    /// The lower and upper bounds are alternately optimized over the bin edges until the range
+    /// no longer changes.
+    pub fn mse_range(&self, levels: usize) -> (f32, f32) {
+        const MAX_ITERATIONS: usize = 16;
+
+        let bins = self.counts.len();
+        let (mut start, mut end) = (0, bins);
+
+        for _ in 0..MAX_ITERATIONS {
+            let new_end = argmin(
+                (start + 1..=bins).map(|end| (end, self.quantization_error(start, end, levels))),
+            );
+            let new_start = argmin(
+                (0..new_end).map(|start| (start, self.quantization_error(start, new_end, levels))),
+            );
+
+            if (new_start, new_end) == (start, end) {
+                break;
+            }
+            (start, end) = (new_start, new_end);
+        }
+
+        (self.edge(start), self.edge(end))
+    }
+
+    /// Expected squared error of the observed values when clipped to the bins `[start, end)` and
+    /// quantized to the given number of levels.
+    fn quantization_error(&self, start: usize, end: usize, levels: usize) -> f64 {
+        let (min, max) = (self.edge(start) as f64, self.edge(end) as f64);
+        let step = (max - min) / (levels.max(2) - 1) as f64;
+        // Rounding error of values uniformly distributed within a quantization step
+        let rounding_error = step * step / 12.0;
+
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0.0)
+            .map(|(bin, count)| {
+                let center = self.center(bin) as f64;
+                let error = if center < min {
+                    (min - center) * (min - center)
+                } else if center > max {
+                    (center - max) * (center - max)
+                } else {
+                    rounding_error
+                };
+                count * error
+            })
+            .sum()
+    }
+
+    /// Returns the clipping threshold that minimizes the KL divergence between the observed
+    /// distribution and its quantized version with the given number of bins.
+    ///
+    /// This follows the entropy calibration used by TensorRT, where the histogram holds the
+    /// absolute values.
+    pub fn entropy_threshold(&self, quantized_bins: usize) -> f32 {
+        let bins = self.counts.len();
+        if bins <= quantized_bins {
+            return self.max;
+        }
+
+        let mut best = bins;
+        let mut best_divergence = f64::INFINITY;
+
+        for end in quantized_bins..=bins {
+            // Reference distribution, with the clipped outliers added to the last bin
+            let mut reference = self.counts[..end].to_vec();
+            reference[end - 1] += self.counts[end..].iter().sum::<f64>();
+
+            let candidate = expand(
+                &quantize(&self.counts[..end], quantized_bins),
+                &self.counts[..end],
+            );
+            let divergence = kl_divergence(&reference, &candidate);
+
+            if divergence < best_divergence {
+                best = end;
+                best_divergence = divergence;
+            }
+        }
+
+        self.edge(best)
+    }
+}
+
+/// Returns the bin with the smallest error.
+fn argmin(errors: impl Iterator<Item = (usize, f64)>) -> usize {
+    errors
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(bin, _)| bin)
+        .unwrap()
+}
+
+/// Merges the bins into the given number of quantized bins.
+fn quantize(counts: &[f64], quantized_bins: usize) -> Vec<f64> {
+    let merged = counts.len() / quantized_bins;
+
+    (0..quantized_bins)
+        .map(|i| {
+            let end = if i == quantized_bins - 1 {
+                counts.len()
+            } else {
+                (i + 1) * merged
+            };
+            counts[i * merged..end].iter().sum()
+        })
+        .collect()
+}
+
+/// Spreads each quantized bin uniformly over the non-empty bins it was merged from.
+fn expand(quantized: &[f64], counts: &[f64]) -> Vec<f64> {
+    let merged = counts.len() / quantized.len();
+    let mut expanded = vec![0.0; counts.len()];
+
+    for (i, total) in quantized.iter().enumerate() {
+        let end = if i == quantized.len() - 1 {
+            counts.len()
+        } else {
+            (i + 1) * merged
+        };
+        let range = i * merged..end;
+        let non_empty = counts[range.clone()].iter().filter(|c| **c > 0.0).count();
+        if non_empty == 0 {
+            continue;
+        }
+
+        for bin in range.filter(|bin| counts[*bin] > 0.0) {
+            expanded[bin] = total / non_empty as f64;
+        }
+    }
+
+    expanded
+}
+
+/// KL divergence between two unnormalized distributions.
+fn kl_divergence(p: &[f64], q: &[f64]) -> f64 {
+    // Smoothing for the bins that are empty in the candidate distribution
+    const EPSILON: f64 = 1e-10;
+
+    let p_total: f64 = p.iter().sum();
+    let q_total: f64 = q.iter().sum();
+    if p_total == 0.0 || q_total == 0.0 {
+        return f64::INFINITY;
+    }
+
+    p.iter()
+        .zip(q)
+        .filter(|(p, _)| **p > 0.0)
+        .map(|(p, q)| {
+            let p = p / p_total;
+            let q = (q / q_total).max(EPSILON);
+            p * Float::ln(p / q)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_grow_histogram_range() {
+        let mut histogram = Histogram::new(4);
+
+        histogram.update([0.0, 1.0, 2.0, 4.0].into_iter());
+        assert_eq!(histogram.counts, &[1.0, 1.0, 1.0, 1.0]);
+
+        histogram.update([-4.0, 8.0].into_iter());
+        assert_eq!(histogram.min, -4.0);
+        assert_eq!(histogram.max, 8.0);
+        assert_eq!(histogram.counts, &[1.0, 2.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn should_ignore_non_finite_values() {
+        let mut histogram = Histogram::new(4);
+
+        histogram.update([f32::NAN, f32::INFINITY].into_iter());
+        assert_eq!(histogram.total, 0.0);
+
+        histogram.update([f32::NAN, 1.0, 2.0].into_iter());
+        assert_eq!(histogram.counts.iter().sum::<f64>(), 2.0);
+    }
+
+    #[test]
+    fn should_compute_quantile() {
+        let mut histogram = Histogram::new(100);
+        histogram.update((0..100).map(|v| v as f32));
+
+        assert_eq!(histogram.quantile(0.0), 0.0);
+        assert!((histogram.quantile(0.5) - 49.5).abs() < 1.0);
+        assert!((histogram.quantile(1.0) - 99.0).abs() < 1e-4);
+    }
+}
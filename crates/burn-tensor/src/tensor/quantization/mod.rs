@@ -1,10 +1,15 @@
 mod calibration;
+mod fake_quantize;
+mod float8;
+mod histogram;
 mod parameters;
 mod primitive;
 mod scheme;
 mod strategy;
 
 pub use calibration::*;
+pub use fake_quantize::*;
+pub use float8::*;
 pub use parameters::*;
 pub use primitive::*;
 pub use scheme::*;
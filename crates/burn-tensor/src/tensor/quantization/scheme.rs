@@ -2,69 +2,175 @@ use serde::{Deserialize, Serialize};
 
 use crate::{backend::Backend, Tensor, TensorPrimitive};
 
-use super::{CalibrationRange, QuantizationParameters, QuantizationParametersPrimitive};
+use super::{
+    CalibrationRange, Float8Format, QuantizationParameters, QuantizationParametersPrimitive,
+    QuantizedStorage,
+};
 
 /// Quantization data type.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum QuantizationType {
     /// 8-bit signed integer.
     QInt8,
+    /// 4-bit signed integer, packed two values per byte.
+    QInt4,
+    /// 8-bit unsigned integer.
+    QUInt8,
+    /// 8-bit floating point with 4 exponent bits and 3 mantissa bits.
+    QFloat8E4M3,
+    /// 8-bit floating point with 5 exponent bits and 2 mantissa bits.
+    QFloat8E5M2,
+}
+
+impl QuantizationType {
+    /// Returns the 8-bit floating point format, or `None` for integer types.
+    pub fn float8_format(&self) -> Option<Float8Format> {
+        match self {
+            QuantizationType::QFloat8E4M3 => Some(Float8Format::E4M3),
+            QuantizationType::QFloat8E5M2 => Some(Float8Format::E5M2),
+            QuantizationType::QInt8 | QuantizationType::QInt4 | QuantizationType::QUInt8 => None,
+        }
+    }
+
+    /// Returns the storage type of the quantized values.
+    pub fn storage(&self) -> QuantizedStorage {
+        match self {
+            QuantizationType::QInt8 => QuantizedStorage::Int8,
+            QuantizationType::QInt4 => QuantizedStorage::PackedInt4,
+            QuantizationType::QUInt8 => QuantizedStorage::UInt8,
+            QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2 => {
+                QuantizedStorage::Float8
+            }
+        }
+    }
 }
 
 /// Quantization scheme.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum QuantizationScheme {
     /// Per-tensor affine/asymmetric quantization.
     PerTensorAffine(QuantizationType),
     /// Per-tensor symmetric quantization.
     PerTensorSymmetric(QuantizationType),
-    // /// Per-channel affine/asymmetric quantization.
-    // PerChannelAffine,
-    // /// Per-channel symmetric quantization.
-    // PerChannelSymmetric,
+    /// Per-channel affine/asymmetric quantization along the given axis.
+    PerChannelAffine(QuantizationType, usize),
+    /// Per-channel symmetric quantization along the given axis.
+    PerChannelSymmetric(QuantizationType, usize),
 }
 
 impl QuantizationScheme {
+    /// Returns the quantization data type.
+    pub fn q_type(&self) -> QuantizationType {
+        match self {
+            QuantizationScheme::PerTensorAffine(dtype)
+            | QuantizationScheme::PerTensorSymmetric(dtype)
+            | QuantizationScheme::PerChannelAffine(dtype, _)
+            | QuantizationScheme::PerChannelSymmetric(dtype, _) => *dtype,
+        }
+    }
+
+    /// Returns the storage type of the quantized values.
+    pub fn storage(&self) -> QuantizedStorage {
+        self.q_type().storage()
+    }
+
+    /// Returns the quantization axis for per-channel schemes, or `None` for per-tensor schemes.
+    pub fn axis(&self) -> Option<usize> {
+        match self {
+            QuantizationScheme::PerTensorAffine(_) | QuantizationScheme::PerTensorSymmetric(_) => {
+                None
+            }
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis) => Some(*axis),
+        }
+    }
+
+    /// Returns the equivalent per-tensor quantization scheme.
+    pub fn per_tensor(&self) -> Self {
+        match self {
+            QuantizationScheme::PerTensorAffine(dtype)
+            | QuantizationScheme::PerChannelAffine(dtype, _) => {
+                QuantizationScheme::PerTensorAffine(*dtype)
+            }
+            QuantizationScheme::PerTensorSymmetric(dtype)
+            | QuantizationScheme::PerChannelSymmetric(dtype, _) => {
+                QuantizationScheme::PerTensorSymmetric(*dtype)
+            }
+        }
+    }
+
+    /// Returns the same quantization scheme along the given axis, per-tensor schemes are kept
+    /// unchanged.
+    pub fn with_axis(&self, axis: usize) -> Self {
+        match self {
+            QuantizationScheme::PerTensorAffine(_) | QuantizationScheme::PerTensorSymmetric(_) => {
+                *self
+            }
+            QuantizationScheme::PerChannelAffine(dtype, _) => {
+                QuantizationScheme::PerChannelAffine(*dtype, axis)
+            }
+            QuantizationScheme::PerChannelSymmetric(dtype, _) => {
+                QuantizationScheme::PerChannelSymmetric(*dtype, axis)
+            }
+        }
+    }
+
     /// Compute the quantization parameters.
+    ///
+    /// For per-channel schemes, the calibration range is expected to contain the range of
+    /// each channel along the quantization axis.
     pub fn compute_q_params<B: Backend>(
         &self,
         range: CalibrationRange<B>,
     ) -> QuantizationParameters<B> {
         match self {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    // Quantized range `[a, b]`
-                    let a = i8::MIN as i32;
-                    let b = i8::MAX as i32;
-
-                    // We extend the `[min, max]` interval to ensure that it contains 0.
-                    // Otherwise, we would not meet the requirement that 0 be an exactly
-                    // representable value (zero-point).
-                    let zero = Tensor::zeros_like(&range.min);
-                    let min = range.min.min_pair(zero);
-                    let zero = Tensor::zeros_like(&range.max);
-                    let max = range.max.max_pair(zero);
-
-                    let scale = max.sub(min.clone()).div_scalar(b - a);
-                    let offset = Some(-(min.div(scale.clone()).sub_scalar(a)).int());
-                    QuantizationParameters { scale, offset }
-                }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    // Quantized range `[a, b]`
-                    let b = i8::MAX as i32;
-                    let a = -b;
-
-                    // Compute scale to convert an input value in range `[-alpha, alpha]`
-                    let values_range = range.min.abs().max_pair(range.max.abs()).mul_scalar(2);
-
-                    QuantizationParameters {
-                        scale: values_range.div_scalar(b - a),
-                        offset: None,
+            QuantizationScheme::PerTensorAffine(dtype)
+            | QuantizationScheme::PerChannelAffine(dtype, _) => {
+                // Quantized range `[a, b]`
+                let (a, b) = match dtype {
+                    QuantizationType::QInt8 => (i8::MIN as i32, i8::MAX as i32),
+                    QuantizationType::QInt4 => (-8, 7),
+                    QuantizationType::QUInt8 => (u8::MIN as i32, u8::MAX as i32),
+                    QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2 => panic!(
+                        "Affine quantization is not supported for {dtype:?}, use a symmetric scheme instead"
+                    ),
+                };
+
+                // We extend the `[min, max]` interval to ensure that it contains 0.
+                // Otherwise, we would not meet the requirement that 0 be an exactly
+                // representable value (zero-point).
+                let zero = Tensor::zeros_like(&range.min);
+                let min = range.min.min_pair(zero);
+                let zero = Tensor::zeros_like(&range.max);
+                let max = range.max.max_pair(zero);
+
+                let scale = max.sub(min.clone()).div_scalar(b - a);
+                let offset = Some(-(min.div(scale.clone()).sub_scalar(a)).int());
+                QuantizationParameters { scale, offset }
+            }
+            QuantizationScheme::PerTensorSymmetric(dtype)
+            | QuantizationScheme::PerChannelSymmetric(dtype, _) => {
+                // Quantized range `[a, b]`
+                let b = match dtype {
+                    QuantizationType::QInt8 => i8::MAX as f32,
+                    QuantizationType::QInt4 => 7.0,
+                    QuantizationType::QUInt8 => panic!(
+                        "Symmetric quantization is only valid for signed types, use an affine scheme instead"
+                    ),
+                    QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2 => {
+                        dtype.float8_format().unwrap().max_value()
                     }
+                };
+                let a = -b;
+
+                // Compute scale to convert an input value in range `[-alpha, alpha]`
+                let values_range = range.min.abs().max_pair(range.max.abs()).mul_scalar(2);
+
+                QuantizationParameters {
+                    scale: values_range.div_scalar(b - a),
+                    offset: None,
                 }
-            },
+            }
         }
     }
 
@@ -8,19 +8,48 @@ use burn_common::{iter_par, run_par};
 use num_traits::{Float, PrimInt};
 use serde::{Deserialize, Serialize};
 
-use super::{QuantizationScheme, QuantizationType};
+use super::{Float8Format, QuantizationScheme, QuantizationType};
+use crate::{DType, Element, TensorData};
 
 // NOTE: QuantizationStrategy is used for TensorData (sync).
 
 /// Quantization strategy.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// The quantization parameters are stored in the [tensor data](TensorData) bytes after the
+/// quantized values, so the data type only holds the [scheme](QuantizationScheme).
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum QuantizationStrategy {
     /// Per-tensor `int8` affine/asymmetric quantization.
     PerTensorAffineInt8(AffineQuantization<f32, i8, i32>),
     /// Per-tensor `int8` symmetric quantization.
     PerTensorSymmetricInt8(SymmetricQuantization<f32, i8>),
+    /// Per-channel `int8` affine/asymmetric quantization.
+    PerChannelAffineInt8(PerChannelQuantization<AffineQuantization<f32, i8, i32>>),
+    /// Per-channel `int8` symmetric quantization.
+    PerChannelSymmetricInt8(PerChannelQuantization<SymmetricQuantization<f32, i8>>),
+    /// Per-tensor `int4` affine/asymmetric quantization.
+    PerTensorAffineInt4(AffineQuantization<f32, i8, i32>),
+    /// Per-tensor `int4` symmetric quantization.
+    PerTensorSymmetricInt4(SymmetricQuantization<f32, i8>),
+    /// Per-channel `int4` affine/asymmetric quantization.
+    PerChannelAffineInt4(PerChannelQuantization<AffineQuantization<f32, i8, i32>>),
+    /// Per-channel `int4` symmetric quantization.
+    PerChannelSymmetricInt4(PerChannelQuantization<SymmetricQuantization<f32, i8>>),
+    /// Per-tensor `uint8` affine/asymmetric quantization.
+    PerTensorAffineUInt8(AffineQuantization<f32, u8, i32>),
+    /// Per-channel `uint8` affine/asymmetric quantization.
+    PerChannelAffineUInt8(PerChannelQuantization<AffineQuantization<f32, u8, i32>>),
+    /// Per-tensor 8-bit floating point symmetric quantization.
+    PerTensorSymmetricFloat8(Float8Quantization),
+    /// Per-channel 8-bit floating point symmetric quantization.
+    PerChannelSymmetricFloat8(PerChannelQuantization<Float8Quantization>),
 }
 
+/// The `int4` quantized range for affine quantization.
+const INT4_AFFINE_RANGE: (i8, i8) = (-8, 7);
+/// The `int4` quantized range for symmetric quantization.
+const INT4_SYMMETRIC_RANGE: (i8, i8) = (-7, 7);
+
 impl QuantizationStrategy {
     /// Returns the corresponding quantization scheme.
     pub fn scheme(&self) -> QuantizationScheme {
@@ -31,8 +60,370 @@ impl QuantizationStrategy {
             QuantizationStrategy::PerTensorSymmetricInt8(_) => {
                 QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8)
             }
+            QuantizationStrategy::PerChannelAffineInt8(q) => {
+                QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, q.axis)
+            }
+            QuantizationStrategy::PerChannelSymmetricInt8(q) => {
+                QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, q.axis)
+            }
+            QuantizationStrategy::PerTensorAffineInt4(_) => {
+                QuantizationScheme::PerTensorAffine(QuantizationType::QInt4)
+            }
+            QuantizationStrategy::PerTensorSymmetricInt4(_) => {
+                QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4)
+            }
+            QuantizationStrategy::PerChannelAffineInt4(q) => {
+                QuantizationScheme::PerChannelAffine(QuantizationType::QInt4, q.axis)
+            }
+            QuantizationStrategy::PerChannelSymmetricInt4(q) => {
+                QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt4, q.axis)
+            }
+            QuantizationStrategy::PerTensorAffineUInt8(_) => {
+                QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8)
+            }
+            QuantizationStrategy::PerChannelAffineUInt8(q) => {
+                QuantizationScheme::PerChannelAffine(QuantizationType::QUInt8, q.axis)
+            }
+            QuantizationStrategy::PerTensorSymmetricFloat8(q) => {
+                QuantizationScheme::PerTensorSymmetric(q.format.into())
+            }
+            QuantizationStrategy::PerChannelSymmetricFloat8(q) => {
+                // The channels share the same format, a strategy without channels is E4M3
+                let format = q.channels.first().map_or(Float8Format::E4M3, |c| c.format);
+                QuantizationScheme::PerChannelSymmetric(format.into(), q.axis)
+            }
+        }
+    }
+
+    /// Returns the storage type of the quantized values.
+    pub fn storage(&self) -> QuantizedStorage {
+        self.scheme().storage()
+    }
+
+    /// Encodes the quantization parameters, which are stored after the quantized values in the
+    /// [tensor data](TensorData) bytes.
+    pub(crate) fn params_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(q)
+            | QuantizationStrategy::PerTensorAffineInt4(q) => q.write(&mut bytes),
+            QuantizationStrategy::PerTensorSymmetricInt8(q)
+            | QuantizationStrategy::PerTensorSymmetricInt4(q) => q.write(&mut bytes),
+            QuantizationStrategy::PerTensorAffineUInt8(q) => q.write(&mut bytes),
+            QuantizationStrategy::PerTensorSymmetricFloat8(q) => q.write(&mut bytes),
+            QuantizationStrategy::PerChannelAffineInt8(q)
+            | QuantizationStrategy::PerChannelAffineInt4(q) => q.write(&mut bytes),
+            QuantizationStrategy::PerChannelSymmetricInt8(q)
+            | QuantizationStrategy::PerChannelSymmetricInt4(q) => q.write(&mut bytes),
+            QuantizationStrategy::PerChannelAffineUInt8(q) => q.write(&mut bytes),
+            QuantizationStrategy::PerChannelSymmetricFloat8(q) => q.write(&mut bytes),
+        }
+        bytes
+    }
+
+    /// Decodes the quantization parameters of the scheme for a tensor of the given shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scheme is not supported or if the bytes are too short.
+    pub(crate) fn from_params_bytes(
+        scheme: QuantizationScheme,
+        shape: &[usize],
+        bytes: &[u8],
+    ) -> Self {
+        let dtype = scheme.q_type();
+        match scheme {
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) => {
+                QuantizationStrategy::PerTensorAffineInt8(QuantizationParams::read(bytes, dtype))
+            }
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt4) => {
+                QuantizationStrategy::PerTensorAffineInt4(QuantizationParams::read(bytes, dtype))
+            }
+            QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8) => {
+                QuantizationStrategy::PerTensorAffineUInt8(QuantizationParams::read(bytes, dtype))
+            }
+            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
+                QuantizationStrategy::PerTensorSymmetricInt8(QuantizationParams::read(bytes, dtype))
+            }
+            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4) => {
+                QuantizationStrategy::PerTensorSymmetricInt4(QuantizationParams::read(bytes, dtype))
+            }
+            QuantizationScheme::PerTensorSymmetric(
+                QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2,
+            ) => QuantizationStrategy::PerTensorSymmetricFloat8(QuantizationParams::read(
+                bytes, dtype,
+            )),
+            QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, axis) => {
+                QuantizationStrategy::PerChannelAffineInt8(PerChannelQuantization::read(
+                    axis, shape, bytes, dtype,
+                ))
+            }
+            QuantizationScheme::PerChannelAffine(QuantizationType::QInt4, axis) => {
+                QuantizationStrategy::PerChannelAffineInt4(PerChannelQuantization::read(
+                    axis, shape, bytes, dtype,
+                ))
+            }
+            QuantizationScheme::PerChannelAffine(QuantizationType::QUInt8, axis) => {
+                QuantizationStrategy::PerChannelAffineUInt8(PerChannelQuantization::read(
+                    axis, shape, bytes, dtype,
+                ))
+            }
+            QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, axis) => {
+                QuantizationStrategy::PerChannelSymmetricInt8(PerChannelQuantization::read(
+                    axis, shape, bytes, dtype,
+                ))
+            }
+            QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt4, axis) => {
+                QuantizationStrategy::PerChannelSymmetricInt4(PerChannelQuantization::read(
+                    axis, shape, bytes, dtype,
+                ))
+            }
+            QuantizationScheme::PerChannelSymmetric(
+                QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2,
+                axis,
+            ) => QuantizationStrategy::PerChannelSymmetricFloat8(PerChannelQuantization::read(
+                axis, shape, bytes, dtype,
+            )),
+            QuantizationScheme::PerTensorAffine(
+                QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2,
+            )
+            | QuantizationScheme::PerChannelAffine(
+                QuantizationType::QFloat8E4M3 | QuantizationType::QFloat8E5M2,
+                _,
+            )
+            | QuantizationScheme::PerTensorSymmetric(QuantizationType::QUInt8)
+            | QuantizationScheme::PerChannelSymmetric(QuantizationType::QUInt8, _) => {
+                panic!("Unsupported quantization scheme {scheme:?}")
+            }
         }
     }
+
+    /// Convert the values to a lower precision data type.
+    ///
+    /// The shape of the values is required to map each value to its channel when the
+    /// quantization parameters are defined per-channel.
+    pub fn quantize(&self, values: &[f32], shape: &[usize]) -> TensorData {
+        let strategy = self.clone();
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(q) => {
+                TensorData::quantized(q.quantize(values), shape, strategy)
+            }
+            QuantizationStrategy::PerTensorSymmetricInt8(q) => {
+                TensorData::quantized(q.quantize(values), shape, strategy)
+            }
+            QuantizationStrategy::PerChannelAffineInt8(q) => {
+                TensorData::quantized(q.quantize(values, shape), shape, strategy)
+            }
+            QuantizationStrategy::PerChannelSymmetricInt8(q) => {
+                TensorData::quantized(q.quantize(values, shape), shape, strategy)
+            }
+            // The int4 values are quantized with int8 precision and clamped to the int4 range
+            QuantizationStrategy::PerTensorAffineInt4(q) => TensorData::quantized(
+                clamp(q.quantize(values), INT4_AFFINE_RANGE),
+                shape,
+                strategy,
+            ),
+            QuantizationStrategy::PerTensorSymmetricInt4(q) => TensorData::quantized(
+                clamp(q.quantize(values), INT4_SYMMETRIC_RANGE),
+                shape,
+                strategy,
+            ),
+            QuantizationStrategy::PerChannelAffineInt4(q) => TensorData::quantized(
+                clamp(q.quantize(values, shape), INT4_AFFINE_RANGE),
+                shape,
+                strategy,
+            ),
+            QuantizationStrategy::PerChannelSymmetricInt4(q) => TensorData::quantized(
+                clamp(q.quantize(values, shape), INT4_SYMMETRIC_RANGE),
+                shape,
+                strategy,
+            ),
+            QuantizationStrategy::PerTensorAffineUInt8(q) => {
+                TensorData::quantized(q.quantize(values), shape, strategy)
+            }
+            QuantizationStrategy::PerChannelAffineUInt8(q) => {
+                TensorData::quantized(q.quantize(values, shape), shape, strategy)
+            }
+            QuantizationStrategy::PerTensorSymmetricFloat8(q) => {
+                TensorData::quantized(q.quantize(values), shape, strategy)
+            }
+            QuantizationStrategy::PerChannelSymmetricFloat8(q) => TensorData::quantized(
+                q.map_channels(values, shape, |q, values| q.quantize(values)),
+                shape,
+                strategy,
+            ),
+        }
+    }
+
+    /// Convert the quantized values back to a higher precision data type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data was not quantized with this strategy's storage type.
+    pub fn dequantize(&self, data: &TensorData) -> Vec<f32> {
+        assert!(
+            matches!(data.dtype, DType::QFloat(scheme) if scheme.storage() == self.storage()),
+            "Expected data quantized with {:?} storage, got {:?}",
+            self.storage(),
+            data.dtype
+        );
+        let shape = &data.shape;
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(q)
+            | QuantizationStrategy::PerTensorAffineInt4(q) => q.dequantize(&values(data)),
+            QuantizationStrategy::PerTensorSymmetricInt8(q)
+            | QuantizationStrategy::PerTensorSymmetricInt4(q) => q.dequantize(&values(data)),
+            QuantizationStrategy::PerChannelAffineInt8(q)
+            | QuantizationStrategy::PerChannelAffineInt4(q) => {
+                q.dequantize(&values::<i8>(data), shape)
+            }
+            QuantizationStrategy::PerChannelSymmetricInt8(q)
+            | QuantizationStrategy::PerChannelSymmetricInt4(q) => {
+                q.dequantize(&values::<i8>(data), shape)
+            }
+            QuantizationStrategy::PerTensorAffineUInt8(q) => q.dequantize(&values(data)),
+            QuantizationStrategy::PerChannelAffineUInt8(q) => {
+                q.dequantize(&values::<u8>(data), shape)
+            }
+            QuantizationStrategy::PerTensorSymmetricFloat8(q) => q.dequantize(&values(data)),
+            QuantizationStrategy::PerChannelSymmetricFloat8(q) => {
+                q.map_channels(&values::<u8>(data), shape, |q, values| q.dequantize(values))
+            }
+        }
+    }
+}
+
+/// Storage type of the quantized values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantizedStorage {
+    /// Signed 8-bit integer values.
+    Int8,
+    /// Signed 4-bit integer values, packed two per byte (the first value in the low bits).
+    PackedInt4,
+    /// Unsigned 8-bit integer values.
+    UInt8,
+    /// Raw bits of 8-bit floating point values.
+    Float8,
+}
+
+/// Encoding of the quantization parameters in the [tensor data](TensorData) bytes.
+///
+/// Each parameter set is a little-endian `f32` scale, followed by the offset byte for affine
+/// quantization.
+trait QuantizationParams: Sized {
+    /// The number of bytes of the encoded parameters.
+    const SIZE: usize;
+
+    /// Appends the encoded parameters to the bytes.
+    fn write(&self, bytes: &mut Vec<u8>);
+
+    /// Decodes the parameters of the quantization type from the first [SIZE](Self::SIZE) bytes.
+    fn read(bytes: &[u8], dtype: QuantizationType) -> Self;
+}
+
+fn read_scale(bytes: &[u8]) -> f32 {
+    assert!(
+        bytes.len() >= 4,
+        "Expected the quantization parameters after the quantized values"
+    );
+    f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn read_offset(bytes: &[u8]) -> u8 {
+    assert!(
+        bytes.len() >= 5,
+        "Expected the quantization parameters after the quantized values"
+    );
+    bytes[4]
+}
+
+impl QuantizationParams for AffineQuantization<f32, i8, i32> {
+    const SIZE: usize = 5;
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.scale.to_le_bytes());
+        bytes.push(self.offset as u8);
+    }
+
+    fn read(bytes: &[u8], _dtype: QuantizationType) -> Self {
+        Self::init(read_scale(bytes), read_offset(bytes) as i8)
+    }
+}
+
+impl QuantizationParams for AffineQuantization<f32, u8, i32> {
+    const SIZE: usize = 5;
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.scale.to_le_bytes());
+        bytes.push(self.offset);
+    }
+
+    fn read(bytes: &[u8], _dtype: QuantizationType) -> Self {
+        Self::init(read_scale(bytes), read_offset(bytes))
+    }
+}
+
+impl QuantizationParams for SymmetricQuantization<f32, i8> {
+    const SIZE: usize = 4;
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.scale.to_le_bytes());
+    }
+
+    fn read(bytes: &[u8], _dtype: QuantizationType) -> Self {
+        Self::init(read_scale(bytes))
+    }
+}
+
+impl QuantizationParams for Float8Quantization {
+    const SIZE: usize = 4;
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.scale.to_le_bytes());
+    }
+
+    fn read(bytes: &[u8], dtype: QuantizationType) -> Self {
+        let format = dtype
+            .float8_format()
+            .expect("8-bit floating point quantization requires a floating point type");
+        Self::init(read_scale(bytes), format)
+    }
+}
+
+impl<S: QuantizationParams> PerChannelQuantization<S> {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.channels.iter().for_each(|q| q.write(bytes));
+    }
+
+    fn read(axis: usize, shape: &[usize], bytes: &[u8], dtype: QuantizationType) -> Self {
+        assert!(
+            axis < shape.len(),
+            "Quantization axis {axis} is out of bounds for a tensor of rank {}",
+            shape.len()
+        );
+        let num_channels = shape[axis];
+        assert!(
+            bytes.len() >= num_channels * S::SIZE,
+            "Expected the quantization parameters of {num_channels} channels after the quantized values"
+        );
+        let channels = bytes
+            .chunks_exact(S::SIZE)
+            .take(num_channels)
+            .map(|bytes| S::read(bytes, dtype))
+            .collect();
+        Self::init(axis, channels)
+    }
+}
+
+fn clamp(values: Vec<i8>, range: (i8, i8)) -> Vec<i8> {
+    values
+        .into_iter()
+        .map(|x| x.clamp(range.0, range.1))
+        .collect()
+}
+
+fn values<Q: Element>(data: &TensorData) -> Vec<Q> {
+    data.iter::<Q>().collect()
 }
 
 /// Quantization scheme to convert elements of a higher precision data type `E` to a lower precision
@@ -188,6 +579,146 @@ impl<E: Float, Q: PrimInt> Quantization<E, Q> for SymmetricQuantization<E, Q> {
     }
 }
 
+/// Per-channel quantization scheme.
+///
+/// Each channel along the quantization axis has its own quantization parameters `S`
+/// (e.g., [AffineQuantization] or [SymmetricQuantization]).
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerChannelQuantization<S> {
+    /// The axis along which the channels are quantized.
+    pub axis: usize,
+    /// The quantization parameters for each channel.
+    pub channels: Vec<S>,
+}
+
+impl<S> PerChannelQuantization<S> {
+    /// Initialize a per-channel quantization scheme with the given parameters for each channel.
+    pub fn init(axis: usize, channels: Vec<S>) -> Self {
+        Self { axis, channels }
+    }
+
+    /// Create a new per-channel quantization scheme for the input ranges `[alpha, beta]`
+    /// of each channel.
+    pub fn new<E: Float, Q: PrimInt>(axis: usize, alpha: &[E], beta: &[E]) -> Self
+    where
+        S: Quantization<E, Q>,
+    {
+        assert_eq!(
+            alpha.len(),
+            beta.len(),
+            "Expected the same number of channels for the input range bounds"
+        );
+        let channels = alpha
+            .iter()
+            .zip(beta.iter())
+            .map(|(alpha, beta)| S::new(*alpha, *beta))
+            .collect();
+        Self::init(axis, channels)
+    }
+
+    /// Returns the number of contiguous values that share the same channel for a row-major
+    /// tensor of the given shape.
+    fn channel_stride(&self, shape: &[usize]) -> usize {
+        assert!(
+            self.axis < shape.len(),
+            "Quantization axis {} is out of bounds for a tensor of rank {}",
+            self.axis,
+            shape.len()
+        );
+        assert_eq!(
+            shape[self.axis],
+            self.channels.len(),
+            "Expected {} channels along the quantization axis, got {}",
+            self.channels.len(),
+            shape[self.axis]
+        );
+        shape[self.axis + 1..].iter().product()
+    }
+
+    /// Convert the values of a tensor with the given shape to a lower precision data type.
+    pub fn quantize<E: Float, Q: PrimInt>(&self, values: &[E], shape: &[usize]) -> Vec<Q>
+    where
+        S: Quantization<E, Q>,
+    {
+        self.map_channels(values, shape, |q, block| q.quantize(block))
+    }
+
+    /// Convert the values of a tensor with the given shape back to a higher precision data type.
+    pub fn dequantize<E: Float, Q: PrimInt>(&self, values: &[Q], shape: &[usize]) -> Vec<E>
+    where
+        S: Quantization<E, Q>,
+    {
+        self.map_channels(values, shape, |q, block| q.dequantize(block))
+    }
+
+    /// Applies the function to the values of each channel with the channel's quantization
+    /// parameters.
+    pub(crate) fn map_channels<T, U, F>(&self, values: &[T], shape: &[usize], f: F) -> Vec<U>
+    where
+        F: Fn(&S, &[T]) -> Vec<U>,
+    {
+        let stride = self.channel_stride(shape);
+        if stride == 0 {
+            return Vec::new();
+        }
+
+        // In row-major order, each block of `stride` contiguous values belongs to the same channel
+        values
+            .chunks(stride)
+            .enumerate()
+            .flat_map(|(i, block)| f(&self.channels[i % self.channels.len()], block))
+            .collect()
+    }
+}
+
+/// 8-bit floating point symmetric quantization scheme.
+///
+/// The values are scaled to the representable range of the format before being rounded
+/// to the nearest 8-bit floating point value.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Float8Quantization {
+    /// The scaling factor.
+    pub scale: f32,
+    /// The 8-bit floating point format.
+    pub format: Float8Format,
+}
+
+impl Float8Quantization {
+    /// Initialize an 8-bit floating point quantization scheme with the given parameters.
+    pub fn init(scale: f32, format: Float8Format) -> Self {
+        // If scale is 0 (most likely due to a tensor full of zeros), we arbitrarily adjust the
+        // scale to 0.1 to avoid division by zero.
+        let scale = if scale == 0.0 { 0.1 } else { scale };
+        Self { scale, format }
+    }
+
+    /// Create a new quantization scheme for an input range `[alpha, beta]`.
+    pub fn new(alpha: f32, beta: f32, format: Float8Format) -> Self {
+        // Compute scale to convert a floating point value in range `[-alpha, alpha]` to the
+        // representable range of the format
+        let alpha = Float::abs(alpha).max(Float::abs(beta));
+        Self::init(alpha / format.max_value(), format)
+    }
+
+    /// Convert the values to their 8-bit floating point representation.
+    pub fn quantize(&self, values: &[f32]) -> Vec<u8> {
+        // x_q = fp8(x / scale)
+        values
+            .iter()
+            .map(|x| self.format.encode(x / self.scale))
+            .collect()
+    }
+
+    /// Convert the 8-bit floating point values back to a higher precision data type.
+    pub fn dequantize(&self, values: &[u8]) -> Vec<f32> {
+        // x = scale * x_q
+        values
+            .iter()
+            .map(|x_q| self.scale * self.format.decode(*x_q))
+            .collect()
+    }
+}
+
 // Masks for the parts of the IEEE 754 float
 const SIGN_MASK: u64 = 0x8000000000000000u64;
 const EXP_MASK: u64 = 0x7ff0000000000000u64;
@@ -244,6 +775,23 @@ impl<E: Float, Q: PrimInt> PartialEq for SymmetricQuantization<E, Q> {
 
 impl<E: Float, Q: PrimInt> Eq for SymmetricQuantization<E, Q> {}
 
+impl Hash for Float8Quantization {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash raw bits.
+        let bits = raw_double_bits(&canonicalize_signed_zero(self.scale));
+        bits.hash(state);
+        self.format.hash(state);
+    }
+}
+
+impl PartialEq for Float8Quantization {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale && self.format == other.format
+    }
+}
+
+impl Eq for Float8Quantization {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -299,4 +847,91 @@ mod tests {
 
         assert_eq!(d, expected_d);
     }
+
+    #[test]
+    fn test_int8_per_channel_affine_quantization() {
+        // Shape [2, 3], quantized along the first axis
+        let x: [f32; 6] = [-1.8, -1.0, 0.5, 2.0, 3.0, 5.0];
+        let expected_q = vec![-128, -40, 126, -26, 25, 127];
+
+        let per_channel = PerChannelQuantization::<AffineQuantization<f32, i8, i32>>::new(
+            0,
+            &[-1.8, 2.0],
+            &[0.5, 5.0],
+        );
+
+        let q: Vec<i8> = per_channel.quantize(&x, &[2, 3]);
+        assert_eq!(q, expected_q);
+
+        let d: Vec<f32> = per_channel.dequantize(&expected_q, &[2, 3]);
+        let expected_d = vec![-1.794902, -1.0011765, 0.49607843, 2.0, 3.0, 5.0];
+
+        assert_eq!(d, expected_d);
+    }
+
+    #[test]
+    fn test_int8_per_channel_symmetric_quantization() {
+        // Shape [3, 2], quantized along the last axis
+        let x: [f32; 6] = [-1.8, 1.0, -1.0, -1.0, 0.5, 4.0];
+        let expected_q = vec![-127, 32, -71, -32, 35, 127];
+
+        let per_channel = PerChannelQuantization::<SymmetricQuantization<f32, i8>>::new(
+            1,
+            &[-1.8, -2.0],
+            &[0.5, 4.0],
+        );
+
+        let q: Vec<i8> = per_channel.quantize(&x, &[3, 2]);
+        assert_eq!(q, expected_q);
+    }
+
+    #[test]
+    fn test_uint8_affine_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![0, 88, 199, 254];
+
+        let affine = AffineQuantization::<f32, u8, i32>::new(-1.8, 0.5);
+
+        assert_eq!(affine.offset, 199);
+
+        let q = affine.quantize(&x);
+        assert_eq!(q, expected_q);
+    }
+
+    #[test]
+    fn test_int4_symmetric_strategy() {
+        let x: [f32; 4] = [-2.0, -0.2, 0.4, 1.6];
+        let strategy =
+            QuantizationStrategy::PerTensorSymmetricInt4(SymmetricQuantization::init(0.2));
+
+        let q = strategy.quantize(&x, &[4]);
+
+        // Values are clamped to [-7, 7] and packed two per byte
+        assert_eq!(q.quantized_bytes().0, [0xF9, 0x72]);
+        assert_eq!(q.iter::<i8>().collect::<Vec<_>>(), vec![-7, -1, 2, 7]);
+
+        let d = strategy.dequantize(&q);
+
+        assert_eq!(d, vec![-1.4, -0.2, 0.4, 1.4]);
+    }
+
+    #[test]
+    fn test_float8_per_channel_strategy() {
+        // Shape [2, 2], quantized along the first axis
+        let x: [f32; 4] = [1.0, -0.5, 4.0, 8.0];
+        let strategy =
+            QuantizationStrategy::PerChannelSymmetricFloat8(PerChannelQuantization::init(
+                0,
+                vec![
+                    Float8Quantization::init(0.5, Float8Format::E5M2),
+                    Float8Quantization::init(2.0, Float8Format::E5M2),
+                ],
+            ));
+
+        let q = strategy.quantize(&x, &[2, 2]);
+        assert_eq!(q.quantized_bytes().0, [0x40, 0xBC, 0x40, 0x44]);
+
+        let d = strategy.dequantize(&q);
+        assert_eq!(d, x.to_vec());
+    }
 }
@@ -139,6 +139,7 @@ macro_rules! testgen_quantization {
         burn_tensor::testgen_q_cat!();
         burn_tensor::testgen_q_chunk!();
         burn_tensor::testgen_q_clamp!();
+        burn_tensor::testgen_q_conv2d!();
         burn_tensor::testgen_q_cos!();
         burn_tensor::testgen_q_div!();
         burn_tensor::testgen_q_erf!();
@@ -147,6 +148,7 @@ macro_rules! testgen_quantization {
         burn_tensor::testgen_q_flip!();
         burn_tensor::testgen_q_gather_scatter!();
         burn_tensor::testgen_q_log!();
+        burn_tensor::testgen_q_linear!();
         burn_tensor::testgen_q_log1p!();
         burn_tensor::testgen_q_map_comparison!();
         burn_tensor::testgen_q_mask!();
@@ -2,7 +2,10 @@
 mod tests {
     use super::*;
     use burn_tensor::{
-        quantization::{Calibration, MinMaxCalibration, QuantizationType},
+        quantization::{
+            Calibration, EntropyCalibration, HistogramCalibration, MinMaxCalibration, Observer,
+            PercentileCalibration, QuantizationType,
+        },
         Tensor, TensorData,
     };
 
@@ -23,4 +26,153 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([0.5]), false);
     }
+
+    #[test]
+    fn min_max_calibration_range_per_channel() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, 1.0], [-1.0, 0.5], [0.0, 4.0]],
+            &Default::default(),
+        );
+        let calibration = MinMaxCalibration {};
+
+        let range = calibration.compute_range_per_channel(&tensor, 1);
+
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([-1.8, 0.5]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 4.0]), false);
+    }
+
+    #[test]
+    fn min_max_observer_range_over_batches() {
+        let device = Default::default();
+        let calibration = MinMaxCalibration {};
+        let mut observer = calibration.observer::<TestBackend>(None);
+
+        observer.observe(&Tensor::<TestBackend, 1>::from_floats([-1.0, 0.5], &device));
+        observer.observe(&Tensor::<TestBackend, 1>::from_floats([0.0, 2.0], &device));
+        let range = observer.range();
+
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([-1.0]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([2.0]), false);
+    }
+
+    /// Values concentrated around zero in [-1, 1] with a single outlier.
+    fn values_with_outlier(n: usize) -> Vec<f32> {
+        let mut values = (0..n)
+            .map(|i| {
+                let x = 2.0 * i as f32 / (n - 1) as f32 - 1.0;
+                x * x * x
+            })
+            .collect::<Vec<_>>();
+        values.push(100.0);
+        values
+    }
+
+    fn range_values(range: burn_tensor::quantization::CalibrationRange<TestBackend>) -> (f32, f32) {
+        let min = range.min.into_data().iter::<f32>().next().unwrap();
+        let max = range.max.into_data().iter::<f32>().next().unwrap();
+        (min, max)
+    }
+
+    #[test]
+    fn percentile_calibration_range_ignores_outliers() {
+        let device = Default::default();
+        let calibration = PercentileCalibration::new(99.0);
+        let mut observer = calibration.observer::<TestBackend>(None);
+
+        // The range is observed over many batches
+        for batch in values_with_outlier(1000).chunks(100) {
+            observer.observe(&Tensor::<TestBackend, 1>::from_floats(batch, &device));
+        }
+        let (min, max) = range_values(observer.range());
+
+        assert!(min < -0.8 && min > -1.1, "min {min}");
+        assert!(max > 0.8 && max < 1.1, "max {max}");
+    }
+
+    #[test]
+    fn histogram_calibration_range_clips_tails() {
+        // Long tailed distribution without the outlier
+        let mut values = values_with_outlier(10_000);
+        values.pop();
+        let tensor = Tensor::<TestBackend, 1>::from_floats(values.as_slice(), &Default::default());
+        // 4-bit quantization
+        let calibration = HistogramCalibration {
+            levels: 16,
+            ..Default::default()
+        };
+
+        let (min, max) = range_values(calibration.compute_range(&tensor));
+
+        assert!(min > -0.95 && min < -0.85, "min {min}");
+        assert!(max > 0.85 && max < 0.95, "max {max}");
+    }
+
+    #[test]
+    fn entropy_calibration_range_clips_outliers() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats(
+            values_with_outlier(10_000).as_slice(),
+            &Default::default(),
+        );
+        let calibration = EntropyCalibration::default();
+
+        let (min, max) = range_values(calibration.compute_range(&tensor));
+
+        assert_eq!(min, -max);
+        assert!(max > 0.5 && max < 10.0, "max {max}");
+    }
+
+    #[test]
+    fn percentile_calibration_range_per_channel() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, 1.0], [-1.0, 0.5], [0.0, 4.0]],
+            &Default::default(),
+        );
+        let calibration = PercentileCalibration::new(100.0);
+
+        let range = calibration.compute_range_per_channel(&tensor, 1);
+
+        range
+            .min
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.8, 0.5]), 3);
+        range
+            .max
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 4.0]), 3);
+    }
+
+    #[test]
+    fn histogram_observer_should_skip_empty_tensors() {
+        let device = Default::default();
+        let calibration = PercentileCalibration::new(100.0);
+        let mut observer = calibration.observer::<TestBackend>(None);
+
+        observer.observe(&Tensor::<TestBackend, 1>::empty([0], &device));
+        observer.observe(&Tensor::<TestBackend, 1>::from_floats(
+            [-1.8, -1.0, 0.0, 0.5],
+            &device,
+        ));
+        let range = observer.range();
+
+        range
+            .min
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.8]), 3);
+        range
+            .max
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.5]), 3);
+    }
 }
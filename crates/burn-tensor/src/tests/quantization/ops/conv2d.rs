@@ -0,0 +1,116 @@
+#[burn_tensor_testgen::testgen(q_conv2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv2d;
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::quantization::{QuantizationScheme, QuantizationType};
+    use burn_tensor::{ElementConversion, Shape};
+
+    #[test]
+    fn test_conv2d_with_bias() {
+        let test = QConv2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size: [3, 3],
+            padding: [1, 1],
+            stride: [1, 1],
+            dilation: [1, 1],
+            groups: 1,
+            size: [4, 4],
+            weight_scheme: QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0),
+        };
+
+        test.assert_output();
+    }
+
+    #[test]
+    fn test_conv2d_groups_stride_dilation() {
+        let test = QConv2dTestCase {
+            batch_size: 2,
+            channels_in: 4,
+            channels_out: 2,
+            kernel_size: [2, 3],
+            padding: [2, 1],
+            stride: [2, 1],
+            dilation: [1, 2],
+            groups: 2,
+            size: [5, 6],
+            weight_scheme: QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        };
+
+        test.assert_output();
+    }
+
+    struct QConv2dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size: [usize; 2],
+        padding: [usize; 2],
+        stride: [usize; 2],
+        dilation: [usize; 2],
+        groups: usize,
+        size: [usize; 2],
+        weight_scheme: QuantizationScheme,
+    }
+
+    impl QConv2dTestCase {
+        fn assert_output(self) {
+            let device = Default::default();
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.size[0],
+                self.size[1],
+            ]);
+            let shape_weight = Shape::new([
+                self.channels_out,
+                self.channels_in / self.groups,
+                self.kernel_size[0],
+                self.kernel_size[1],
+            ]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape(shape_x)
+                    .float()
+                    .div_scalar(8.0)
+                    .sub_scalar(2.0),
+            )
+            .quantize_dynamic(&QuantizationScheme::PerTensorAffine(
+                QuantizationType::QInt8,
+            ));
+            let weight = TestTensor::from(
+                TestTensorInt::arange(0..shape_weight.num_elements() as i64, &device)
+                    .reshape(shape_weight)
+                    .float()
+                    .div_scalar(16.0)
+                    .sub_scalar(0.5),
+            )
+            .quantize_dynamic(&self.weight_scheme);
+            let bias = TestTensor::<1>::from(
+                TestTensorInt::arange(0..self.channels_out as i64, &device)
+                    .float()
+                    .sub_scalar(1.0),
+            );
+            let options = ConvOptions::new(self.stride, self.padding, self.dilation, self.groups);
+
+            let expected = conv2d(
+                x.clone().dequantize(),
+                weight.clone().dequantize(),
+                Some(bias.clone()),
+                options.clone(),
+            );
+            let output = conv2d(x, weight, Some(bias), options);
+
+            // Tolerance of the output quantization step
+            let [min, max] = [expected.clone().min(), expected.clone().max()]
+                .map(|v| v.into_scalar().elem::<f64>());
+            let tolerance = (max.max(0.0) - min.min(0.0)) / 255.0;
+            output
+                .dequantize()
+                .into_data()
+                .assert_approx_eq_diff(&expected.into_data(), tolerance);
+        }
+    }
+}
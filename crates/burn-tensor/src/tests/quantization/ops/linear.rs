@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(q_linear)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::linear;
+    use burn_tensor::quantization::{QuantizationScheme, QuantizationType};
+    use burn_tensor::Tensor;
+
+    #[test]
+    fn test_linear_with_bias() {
+        let device = Default::default();
+        let x = TestTensor::<2>::from_floats([[1.0, -2.0, 3.0], [0.5, 4.0, -1.5]], &device)
+            .quantize_dynamic(&QuantizationScheme::PerTensorAffine(
+                QuantizationType::QInt8,
+            ));
+        let weight = TestTensor::<2>::from_floats(
+            [
+                [0.5, -1.0, 2.0, 0.0],
+                [1.5, 0.25, -0.5, 1.0],
+                [-1.0, 2.0, 0.75, -0.25],
+            ],
+            &device,
+        )
+        .quantize_dynamic(&QuantizationScheme::PerChannelSymmetric(
+            QuantizationType::QInt8,
+            1,
+        ));
+        let bias = TestTensor::<1>::from_floats([0.5, -0.5, 1.0, 0.0], &device);
+
+        let expected = linear(
+            x.clone().dequantize(),
+            weight.clone().dequantize(),
+            Some(bias.clone()),
+        );
+        let output = linear(x, weight, Some(bias));
+
+        // Tolerance of the output quantization step
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), 0.1);
+    }
+
+    #[test]
+    fn test_linear_batched() {
+        let device = Default::default();
+        let x = TestTensor::<3>::from_floats(
+            [[[1.0, -2.0], [3.0, 0.5]], [[4.0, -1.5], [-0.5, 2.0]]],
+            &device,
+        )
+        .quantize_dynamic(&QuantizationScheme::PerTensorSymmetric(
+            QuantizationType::QInt8,
+        ));
+        let weight = TestTensor::<2>::from_floats([[0.5, -1.0, 2.0], [1.5, 0.25, -0.5]], &device)
+            .quantize_dynamic(&QuantizationScheme::PerTensorSymmetric(
+                QuantizationType::QInt8,
+            ));
+
+        let expected = linear(x.clone().dequantize(), weight.clone().dequantize(), None);
+        let output = linear(x, weight, None);
+
+        assert_eq!(output.dims(), [2, 2, 3]);
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), 0.1);
+    }
+}
@@ -7,6 +7,7 @@ mod arg;
 mod cat;
 mod chunk;
 mod clamp;
+mod conv2d;
 mod cos;
 mod div;
 mod erf;
@@ -14,6 +15,7 @@ mod exp;
 mod expand;
 mod flip;
 mod gather_scatter;
+mod linear;
 mod log;
 mod log1p;
 mod map_comparison;
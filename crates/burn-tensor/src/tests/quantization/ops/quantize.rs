@@ -3,8 +3,8 @@ mod tests {
     use super::*;
     use burn_tensor::ops::QTensorOps;
     use burn_tensor::quantization::{
-        AffineQuantization, QuantizationParameters, QuantizationScheme, QuantizationStrategy,
-        QuantizationType, SymmetricQuantization,
+        AffineQuantization, PerChannelQuantization, QuantizationParameters, QuantizationScheme,
+        QuantizationStrategy, QuantizationType, SymmetricQuantization,
     };
     use burn_tensor::{Tensor, TensorData};
 
@@ -52,6 +52,30 @@ mod tests {
         x_q.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn should_support_quantize_affine_uint8() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.009_019_608], &device),
+            offset: Some(Tensor::from_ints([199], &device)),
+        };
+
+        let x_q = tensor.quantize(&scheme, qparams);
+
+        let expected = TensorData::quantized(
+            vec![0u8, 88, 199, 254],
+            [4],
+            QuantizationStrategy::PerTensorAffineUInt8(AffineQuantization::init(
+                0.009_019_608,
+                199,
+            )),
+        );
+
+        x_q.to_data().assert_eq(&expected, true);
+    }
+
     #[test]
     fn should_support_dequantize() {
         let device = Default::default();
@@ -90,4 +114,141 @@ mod tests {
 
         x_q.to_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn should_support_quantize_per_channel_symmetric_int8() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[-1.8, 1.0], [-1.0, -1.0], [0.5, 4.0]], &device);
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 1);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.014_173_228, 0.031_496_063], &device),
+            offset: None,
+        };
+
+        let x_q = tensor.quantize(&scheme, qparams);
+
+        let expected = TensorData::quantized(
+            vec![-127i8, 32, -71, -32, 35, 127],
+            [3, 2],
+            QuantizationStrategy::PerChannelSymmetricInt8(PerChannelQuantization::init(
+                1,
+                vec![
+                    SymmetricQuantization::init(0.014_173_228),
+                    SymmetricQuantization::init(0.031_496_063),
+                ],
+            )),
+        );
+
+        x_q.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn should_support_dequantize_per_channel() {
+        let device = Default::default();
+        // Quantized [[-1.8, 1.0], [-1.0, -1.0], [0.5, 4.0]]
+        let data = TensorData::quantized(
+            vec![-127i8, 32, -71, -32, 35, 127],
+            [3, 2],
+            QuantizationStrategy::PerChannelSymmetricInt8(PerChannelQuantization::init(
+                1,
+                vec![
+                    SymmetricQuantization::init(0.014_173_228),
+                    SymmetricQuantization::init(0.031_496_063),
+                ],
+            )),
+        );
+        let x_q = Tensor::<TestBackend, 2>::from_data(data, &device);
+
+        let x = x_q.dequantize();
+
+        // Precision 1 for dequantization errors
+        x.to_data().assert_approx_eq(
+            &TensorData::from([[-1.8, 1.0], [-1.0, -1.0], [0.5, 4.0]]),
+            1,
+        );
+    }
+
+    #[test]
+    fn should_support_quantize_dynamic_per_channel_int8() {
+        let device = Default::default();
+        // NOTE: we use fully representable values since different backend implementations could differ slightly
+        // due to rounding discrepancies
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[-12.7, 0., 5., 10.], [0., 2.54, -1., 0.5]],
+            &device,
+        );
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0);
+
+        let x_q = tensor.quantize_dynamic(&scheme);
+
+        let expected = TensorData::quantized(
+            vec![-127i8, 0, 50, 100, 0, 127, -50, 25],
+            [2, 4],
+            QuantizationStrategy::PerChannelSymmetricInt8(PerChannelQuantization::init(
+                0,
+                vec![
+                    SymmetricQuantization::init(0.1),
+                    SymmetricQuantization::init(0.02),
+                ],
+            )),
+        );
+
+        x_q.to_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_fake_quantize_like_quantize_dequantize() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.009_019_608], &device),
+            offset: Some(Tensor::from_ints([72], &device)),
+        };
+
+        let x = tensor.clone().fake_quantize(&scheme, qparams.clone());
+        let expected = tensor.quantize(&scheme, qparams).dequantize();
+
+        x.into_data().assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn should_fake_quantize_per_channel() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0, 0.5], [0.2, -0.3, 4.0, -4.0]],
+            &device,
+        );
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.014_173_228, 0.031_496_063], &device),
+            offset: None,
+        };
+
+        let x = tensor.fake_quantize(&scheme, qparams);
+
+        // Values are rounded to the nearest multiple of the channel scale
+        let expected = TensorData::from([
+            [-1.8, -1.006_299_2, 0.0, 0.496_063],
+            [0.188_976_38, -0.314_960_63, 4.0, -4.0],
+        ]);
+        x.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_fake_quantize_float8_like_quantize_dequantize() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([-4.0, -1.0, 0.0, 0.3, 1.7], &device);
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QFloat8E4M3);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([4.0 / 448.0], &device),
+            offset: None,
+        };
+
+        let x = tensor.clone().fake_quantize(&scheme, qparams.clone());
+        let expected = tensor.quantize(&scheme, qparams).dequantize();
+
+        x.into_data().assert_approx_eq(&expected.into_data(), 4);
+    }
 }
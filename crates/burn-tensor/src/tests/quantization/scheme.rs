@@ -45,4 +45,103 @@ mod tests {
             .assert_approx_eq(&TensorData::from([0.014_173_228]), 8);
         assert!(qparams.offset.is_none());
     }
+
+    #[test]
+    fn per_channel_affine_int8() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, 0);
+        let range = CalibrationRange {
+            min: Tensor::<TestBackend, 1>::from_floats([-1.8, -1.0], &device),
+            max: Tensor::<TestBackend, 1>::from_floats([0.5, 4.0], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.009_019_608, 0.019_607_844]), 8);
+        qparams
+            .offset
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([71, -77]), false);
+    }
+
+    #[test]
+    fn per_channel_symmetric_int8() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0);
+        let range = CalibrationRange {
+            min: Tensor::<TestBackend, 1>::from_floats([-1.8, -1.0], &device),
+            max: Tensor::<TestBackend, 1>::from_floats([0.5, 4.0], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.014_173_228, 0.031_496_063]), 8);
+        assert!(qparams.offset.is_none());
+    }
+
+    #[test]
+    fn per_tensor_affine_uint8() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8);
+        let range = CalibrationRange {
+            min: Tensor::<TestBackend, 1>::from_floats([-1.8], &device),
+            max: Tensor::<TestBackend, 1>::from_floats([0.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.009_019_608]), 8);
+        qparams
+            .offset
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([199]), false);
+    }
+
+    #[test]
+    fn per_tensor_symmetric_int4() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+        let range = CalibrationRange {
+            min: Tensor::<TestBackend, 1>::from_floats([-1.8], &device),
+            max: Tensor::<TestBackend, 1>::from_floats([0.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.257_142_84]), 8);
+        assert!(qparams.offset.is_none());
+    }
+
+    #[test]
+    fn per_tensor_symmetric_float8() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QFloat8E4M3);
+        let range = CalibrationRange {
+            min: Tensor::<TestBackend, 1>::from_floats([-1.8], &device),
+            max: Tensor::<TestBackend, 1>::from_floats([0.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        // The range is scaled to the largest finite E4M3 value (448)
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.004_017_857]), 8);
+        assert!(qparams.offset.is_none());
+    }
 }
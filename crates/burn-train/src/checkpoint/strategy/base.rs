@@ -12,6 +12,9 @@ pub enum CheckpointingAction {
 }
 
 /// Define when checkpoint should be saved and deleted.
+///
+/// The checkpoints are indexed by epochs, or by steps when the epochs are divided by intermediate
+/// evaluations, see [validate_every](crate::LearnerBuilder::validate_every).
 pub trait CheckpointingStrategy {
     /// Based on the epoch, determine if the checkpoint should be saved.
     fn checkpointing(
@@ -1,13 +1,21 @@
+use crate::checkpoint::AsyncCheckpointer;
 use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
 use crate::components::LearnerComponents;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::{
+    EarlyStoppingStrategy, EpochPosition, EvaluationSchedule, LearnerCallbacks, MixedPrecision,
+};
+use crate::metric::processor::EventProcessor;
 use crate::metric::store::EventStoreClient;
 use crate::LearnerSummaryConfig;
+use burn_core as burn;
+use burn_core::data::dataloader::Progress;
 use burn_core::lr_scheduler::LrScheduler;
-use burn_core::module::Module;
-use burn_core::optim::Optimizer;
+use burn_core::module::{ModelEma, ModelEmaConfig, ModelEmaRecord, Module};
+use burn_core::optim::{GradScalerRecord, Optimizer};
+use burn_core::record::Record;
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::Device;
+use burn_core::LearningRate;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -22,33 +30,199 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) model_ema: Option<ModelEmaConfig>,
+    pub(crate) mixed_precision: Option<MixedPrecision>,
+    pub(crate) seed: Option<u64>,
+    pub(crate) gradient_stats: bool,
+    pub(crate) evaluation: EvaluationSchedule,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
     pub(crate) early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
     pub(crate) event_processor: LC::EventProcessor,
+    pub(crate) callbacks: LearnerCallbacks<<LC::EventProcessor as EventProcessor>::ItemTrain>,
     pub(crate) event_store: Rc<EventStoreClient>,
     pub(crate) summary: Option<LearnerSummaryConfig>,
 }
 
+/// Record of the [training state](TrainingState).
+#[derive(Record)]
+pub(crate) struct TrainingStateRecord {
+    /// The epoch of the checkpoint.
+    epoch: usize,
+    /// Whether the step of the checkpoint is completed.
+    step_completed: bool,
+    /// Whether the epoch is completed, the position in the epoch is only kept otherwise.
+    epoch_completed: bool,
+    /// The number of iterations done in the epoch.
+    iteration: usize,
+    /// The number of items processed in the epoch.
+    items_processed: usize,
+    /// The total number of items of the epoch.
+    items_total: usize,
+    /// The number of items processed and the total number of items of each data loader worker.
+    progress_workers: Vec<(usize, usize)>,
+    /// The number of validations done.
+    num_validations: usize,
+    /// The number of training iterations done.
+    num_iterations: usize,
+    /// The learning rate overriding the one of the scheduler.
+    learning_rate: Option<LearningRate>,
+    /// The state of the gradient scaler of mixed precision training.
+    grad_scaler: Option<GradScalerRecord>,
+    /// The seed of the backend.
+    seed: Option<u64>,
+}
+
+/// The state of the training loop saved with the checkpoints, allowing to resume an interrupted
+/// epoch where it stopped.
+#[derive(new, Clone, Debug)]
+pub(crate) struct TrainingState {
+    /// The epoch of the checkpoint.
+    pub(crate) epoch: usize,
+    /// The position in the epoch of the checkpoint, none when the epoch is completed.
+    #[new(default)]
+    pub(crate) position: Option<EpochPosition>,
+    /// Whether the step of the checkpoint is completed, which isn't the case when the training
+    /// was interrupted.
+    pub(crate) step_completed: bool,
+    /// The number of validations done, which is the number of iterations of the validation
+    /// data loader.
+    pub(crate) num_validations: usize,
+    /// The number of training iterations done since the start of the training, logged with the
+    /// metrics of each iteration.
+    pub(crate) num_iterations: usize,
+    /// The learning rate set by the [callbacks](crate::LearnerCallback), overriding the one of
+    /// the scheduler.
+    #[new(default)]
+    pub(crate) learning_rate: Option<LearningRate>,
+    /// The state of the [gradient scaler](burn_core::optim::GradScaler), when training with
+    /// mixed precision.
+    #[new(default)]
+    pub(crate) grad_scaler: Option<GradScalerRecord>,
+    /// The seed of the backend.
+    #[new(default)]
+    pub(crate) seed: Option<u64>,
+}
+
+impl TrainingState {
+    /// Set the position in the epoch of the checkpoint.
+    pub(crate) fn with_position(mut self, position: EpochPosition) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Set the learning rate overriding the one of the scheduler.
+    pub(crate) fn with_learning_rate(mut self, learning_rate: Option<LearningRate>) -> Self {
+        self.learning_rate = learning_rate;
+        self
+    }
+
+    /// Set the state of the gradient scaler.
+    pub(crate) fn with_grad_scaler(mut self, grad_scaler: Option<GradScalerRecord>) -> Self {
+        self.grad_scaler = grad_scaler;
+        self
+    }
+
+    /// Set the seed of the backend.
+    pub(crate) fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    fn into_record(self) -> TrainingStateRecord {
+        let epoch_completed = self.position.is_none();
+        let (iteration, progress, progress_workers) = match self.position {
+            Some(position) => (
+                position.iteration,
+                position.progress,
+                position.progress_workers,
+            ),
+            None => (0, Progress::new(0, 0), Vec::new()),
+        };
+
+        TrainingStateRecord {
+            epoch: self.epoch,
+            step_completed: self.step_completed,
+            epoch_completed,
+            iteration,
+            items_processed: progress.items_processed,
+            items_total: progress.items_total,
+            progress_workers: progress_workers
+                .into_iter()
+                .map(|progress| (progress.items_processed, progress.items_total))
+                .collect(),
+            num_validations: self.num_validations,
+            num_iterations: self.num_iterations,
+            learning_rate: self.learning_rate,
+            grad_scaler: self.grad_scaler,
+            seed: self.seed,
+        }
+    }
+
+    fn from_record(record: TrainingStateRecord) -> Self {
+        let position = match record.epoch_completed {
+            true => None,
+            false => Some(EpochPosition::new(
+                record.iteration,
+                Progress::new(record.items_processed, record.items_total),
+                record
+                    .progress_workers
+                    .into_iter()
+                    .map(|(items_processed, items_total)| {
+                        Progress::new(items_processed, items_total)
+                    })
+                    .collect(),
+            )),
+        };
+
+        Self {
+            epoch: record.epoch,
+            position,
+            step_completed: record.step_completed,
+            num_validations: record.num_validations,
+            num_iterations: record.num_iterations,
+            learning_rate: record.learning_rate,
+            grad_scaler: record.grad_scaler,
+            seed: record.seed,
+        }
+    }
+}
+
+/// The checkpointer of the moving average, saved separately from the trained model.
+pub(crate) type ModelEmaCheckpointer<LC> = AsyncCheckpointer<
+    ModelEmaRecord<<LC as LearnerComponents>::Backend, <LC as LearnerComponents>::Model>,
+    <LC as LearnerComponents>::Backend,
+>;
+
+/// The moving average of the model weights.
+pub(crate) type LearnerModelEma<LC> =
+    ModelEma<<LC as LearnerComponents>::Backend, <LC as LearnerComponents>::Model>;
+
 #[derive(new)]
 pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     model: LC::CheckpointerModel,
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
+    state: AsyncCheckpointer<TrainingStateRecord, LC::Backend>,
+    ema: ModelEmaCheckpointer<LC>,
     strategy: LC::CheckpointerStrategy,
 }
 
 impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn checkpoint(
         &mut self,
         model: &LC::Model,
+        ema: Option<&LearnerModelEma<LC>>,
         optim: &LC::Optimizer,
         scheduler: &LC::LrScheduler,
+        state: TrainingState,
         epoch: usize,
         store: &EventStoreClient,
-    ) {
+    ) -> bool {
         let actions = self.strategy.checkpointing(epoch, store);
+        let mut saved = false;
 
         for action in actions {
             match action {
@@ -62,20 +236,51 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
                     self.lr_scheduler
                         .delete(epoch)
                         .expect("Can delete learning rate scheduler checkpoint.");
+                    self.state
+                        .delete(epoch)
+                        .expect("Can delete training state checkpoint.");
+                    self.ema
+                        .delete(epoch)
+                        .expect("Can delete model EMA checkpoint.");
                 }
                 CheckpointingAction::Save => {
-                    self.model
-                        .save(epoch, model.clone().into_record())
-                        .expect("Can save model checkpoint.");
-                    self.optim
-                        .save(epoch, optim.to_record())
-                        .expect("Can save optimizer checkpoint.");
-                    self.lr_scheduler
-                        .save(epoch, scheduler.to_record())
-                        .expect("Can save learning rate scheduler checkpoint.");
+                    self.save(model, ema, optim, scheduler, state.clone(), epoch);
+                    saved = true;
                 }
             }
         }
+
+        saved
+    }
+
+    /// Save a checkpoint regardless of the checkpointing strategy, e.g. when the training is
+    /// interrupted during an epoch.
+    pub(crate) fn save(
+        &self,
+        model: &LC::Model,
+        ema: Option<&LearnerModelEma<LC>>,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        state: TrainingState,
+        epoch: usize,
+    ) {
+        self.model
+            .save(epoch, model.clone().into_record())
+            .expect("Can save model checkpoint.");
+        self.optim
+            .save(epoch, optim.to_record())
+            .expect("Can save optimizer checkpoint.");
+        self.lr_scheduler
+            .save(epoch, scheduler.to_record())
+            .expect("Can save learning rate scheduler checkpoint.");
+        self.state
+            .save(epoch, state.into_record())
+            .expect("Can save training state checkpoint.");
+        if let Some(ema) = ema {
+            self.ema
+                .save(epoch, ema.to_record())
+                .expect("Can save model EMA checkpoint.");
+        }
     }
 
     pub(crate) fn load_checkpoint(
@@ -85,7 +290,7 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
         scheduler: LC::LrScheduler,
         device: &Device<LC::Backend>,
         epoch: usize,
-    ) -> (LC::Model, LC::Optimizer, LC::LrScheduler) {
+    ) -> (LC::Model, LC::Optimizer, LC::LrScheduler, TrainingState) {
         let record = self
             .model
             .restore(epoch, device)
@@ -104,7 +309,34 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
             .expect("Can load learning rate scheduler checkpoint.");
         let scheduler = scheduler.load_record(record);
 
-        (model, optim, scheduler)
+        // Checkpoints saved without the training state are resumed at the next epoch, after one
+        // validation per epoch.
+        let state = match self.state.restore(epoch, device) {
+            Ok(record) => TrainingState::from_record(record),
+            Err(err) => {
+                log::warn!("Can't load training state checkpoint: {err:?}");
+                TrainingState::new(epoch, true, epoch, 0)
+            }
+        };
+
+        (model, optim, scheduler, state)
+    }
+
+    /// Restore the moving average of the model, which restarts from the model when the checkpoint
+    /// was saved without it.
+    pub(crate) fn load_ema(
+        &self,
+        ema: LearnerModelEma<LC>,
+        device: &Device<LC::Backend>,
+        epoch: usize,
+    ) -> LearnerModelEma<LC> {
+        match self.ema.restore(epoch, device) {
+            Ok(record) => ema.load_record(record),
+            Err(err) => {
+                log::warn!("Can't load model EMA checkpoint: {err:?}");
+                ema
+            }
+        }
     }
 }
 
@@ -130,3 +362,50 @@ impl TrainingInterrupter {
         self.state.load(Ordering::Relaxed)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn training_state_should_be_restored_from_record() {
+        let position = EpochPosition::new(
+            3,
+            Progress::new(12, 40),
+            vec![Progress::new(8, 20), Progress::new(4, 20)],
+        );
+        let state = TrainingState::new(2, true, 3, 120)
+            .with_position(position)
+            .with_learning_rate(Some(0.25))
+            .with_grad_scaler(Some((1024.0, 7)))
+            .with_seed(Some(42));
+
+        let restored = TrainingState::from_record(state.into_record());
+
+        assert_eq!(restored.epoch, 2);
+        assert!(restored.step_completed);
+        let position = restored.position.unwrap();
+        assert_eq!(position.iteration, 3);
+        assert_eq!(position.progress.items_processed, 12);
+        assert_eq!(position.progress.items_total, 40);
+        assert_eq!(position.progress_workers.len(), 2);
+        assert_eq!(position.progress_workers[1].items_processed, 4);
+        assert_eq!(position.progress_workers[1].items_total, 20);
+        assert_eq!(restored.num_validations, 3);
+        assert_eq!(restored.num_iterations, 120);
+        assert_eq!(restored.learning_rate, Some(0.25));
+        assert_eq!(restored.grad_scaler, Some((1024.0, 7)));
+        assert_eq!(restored.seed, Some(42));
+    }
+
+    #[test]
+    fn completed_epoch_should_have_no_position() {
+        let state = TrainingState::new(4, true, 4, 160);
+
+        let restored = TrainingState::from_record(state.into_record());
+
+        assert_eq!(restored.epoch, 4);
+        assert!(restored.position.is_none());
+        assert!(restored.seed.is_none());
+    }
+}
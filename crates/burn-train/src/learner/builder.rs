@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
+use std::time::Duration;
 
 use super::Learner;
 use crate::checkpoint::{
@@ -9,19 +10,21 @@ use crate::checkpoint::{
 };
 use crate::components::LearnerComponentsMarker;
 use crate::learner::base::TrainingInterrupter;
-use crate::learner::EarlyStoppingStrategy;
-use crate::logger::{FileMetricLogger, MetricLogger};
+use crate::learner::{
+    EarlyStoppingStrategy, EvaluationSchedule, LearnerCallback, LearnerCallbacks, MixedPrecision,
+};
+use crate::logger::{FileMetricLogger, MetricLogger, TensorBoardMetricLogger};
 use crate::metric::processor::{FullEventProcessor, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
 use crate::metric::{Adaptor, LossMetric, Metric};
 use crate::renderer::{default_renderer, MetricsRenderer};
 use crate::{
     ApplicationLoggerInstaller, FileApplicationLoggerInstaller, LearnerCheckpointer,
-    LearnerSummaryConfig,
+    LearnerSummaryConfig, TrainingStateRecord,
 };
 use burn_core::lr_scheduler::LrScheduler;
-use burn_core::module::AutodiffModule;
-use burn_core::optim::Optimizer;
+use burn_core::module::{AutodiffModule, HalfPrecision, ModelEmaConfig, ModelEmaRecord};
+use burn_core::optim::{GradScalerConfig, Optimizer};
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::AutodiffBackend;
 
@@ -43,11 +46,18 @@ where
         AsyncCheckpointer<M::Record, B>,
         AsyncCheckpointer<O::Record, B>,
         AsyncCheckpointer<S::Record<B>, B>,
+        AsyncCheckpointer<TrainingStateRecord, B>,
+        AsyncCheckpointer<ModelEmaRecord<B, M>, B>,
     )>,
     num_epochs: usize,
     checkpoint: Option<usize>,
     directory: PathBuf,
     grad_accumulation: Option<usize>,
+    model_ema: Option<ModelEmaConfig>,
+    mixed_precision: Option<MixedPrecision>,
+    seed: Option<u64>,
+    gradient_stats: bool,
+    evaluation: EvaluationSchedule,
     devices: Vec<B::Device>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
@@ -57,8 +67,10 @@ where
     num_loggers: usize,
     checkpointer_strategy: Box<dyn CheckpointingStrategy>,
     early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    callbacks: LearnerCallbacks<T>,
     summary_metrics: HashSet<String>,
     summary: bool,
+    tensorboard: bool,
 }
 
 impl<B, T, V, M, O, S> LearnerBuilder<B, T, V, M, O, S>
@@ -88,6 +100,7 @@ where
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
             renderer: None,
+            callbacks: LearnerCallbacks::new(),
             interrupter: TrainingInterrupter::new(),
             tracing_logger: Some(Box::new(FileApplicationLoggerInstaller::new(
                 experiment_log_file,
@@ -104,8 +117,14 @@ where
                     .build(),
             ),
             early_stopping: None,
+            model_ema: None,
+            mixed_precision: None,
+            seed: None,
+            gradient_stats: false,
+            evaluation: EvaluationSchedule::default(),
             summary_metrics: HashSet::new(),
             summary: false,
+            tensorboard: false,
         }
     }
 
@@ -205,6 +224,30 @@ where
         self
     }
 
+    /// Register an [epoch](crate::metric::EpochMetric) training [metric](Metric), whose value is
+    /// computed once for the whole epoch instead of being the mean of the batches.
+    pub fn metric_train_epoch<Me>(mut self, metric: Me) -> Self
+    where
+        Me: crate::metric::EpochMetric + 'static,
+        T: Adaptor<Me::Input>,
+    {
+        self.summary_metrics.insert(Me::NAME.to_string());
+        self.metrics.register_train_metric_epoch(metric);
+        self
+    }
+
+    /// Register an [epoch](crate::metric::EpochMetric) validation [metric](Metric), whose value
+    /// is computed once for the whole epoch instead of being the mean of the batches.
+    pub fn metric_valid_epoch<Me>(mut self, metric: Me) -> Self
+    where
+        Me: crate::metric::EpochMetric + 'static,
+        V: Adaptor<Me::Input>,
+    {
+        self.summary_metrics.insert(Me::NAME.to_string());
+        self.metrics.register_valid_metric_epoch(metric);
+        self
+    }
+
     /// The number of epochs the training should last.
     pub fn num_epochs(mut self, num_epochs: usize) -> Self {
         self.num_epochs = num_epochs;
@@ -217,12 +260,56 @@ where
         self
     }
 
-    /// The epoch from which the training must resume.
+    /// The epoch from which the training must resume, or the step when the epochs are divided in
+    /// multiple steps by intermediate evaluations.
+    ///
+    /// When the training was interrupted during the epoch, the epoch is resumed where it stopped,
+    /// with the data loaders in the same random state. With a [seed](Self::seed), the resumed
+    /// training replays the same random operations on a single device, as long as the gradients
+    /// aren't accumulated across the interruption.
     pub fn checkpoint(mut self, checkpoint: usize) -> Self {
         self.checkpoint = Some(checkpoint);
         self
     }
 
+    /// Seed the backend before each training step, with a seed derived from the given one, the
+    /// epoch and the iteration.
+    ///
+    /// The seed is saved with the checkpoints, and the saved one is used when resuming the training.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Validate the model every given number of training iterations, in addition to the end of
+    /// each epoch.
+    ///
+    /// Each intermediate evaluation ends a step of the training: the metrics are aggregated, and
+    /// the [checkpointing](CheckpointingStrategy) and [early stopping](EarlyStoppingStrategy)
+    /// strategies act, per step instead of per epoch. The checkpoints are also indexed by steps.
+    pub fn validate_every(mut self, num_iterations: usize) -> Self {
+        self.evaluation.valid_every = Some(num_iterations);
+        self
+    }
+
+    /// Save a checkpoint every given number of training iterations, in addition to the end of
+    /// each epoch, following the [checkpointing strategy](CheckpointingStrategy).
+    ///
+    /// The training steps are divided as with [validate_every](Self::validate_every).
+    pub fn checkpoint_every(mut self, num_iterations: usize) -> Self {
+        self.evaluation.checkpoint_every = Some(num_iterations);
+        self
+    }
+
+    /// Save a checkpoint when the given duration has elapsed since the previous one, in addition
+    /// to the end of each epoch, following the [checkpointing strategy](CheckpointingStrategy).
+    ///
+    /// The training steps are divided as with [validate_every](Self::validate_every).
+    pub fn checkpoint_interval(mut self, interval: Duration) -> Self {
+        self.evaluation.checkpoint_interval = Some(interval);
+        self
+    }
+
     /// Provides a handle that can be used to interrupt training.
     pub fn interrupter(&self) -> TrainingInterrupter {
         self.interrupter.clone()
@@ -238,6 +325,52 @@ where
         self
     }
 
+    /// Register a [callback](LearnerCallback) called on the training events, after the ones
+    /// previously registered.
+    pub fn callback<C>(mut self, callback: C) -> Self
+    where
+        C: LearnerCallback<T> + 'static,
+    {
+        self.callbacks.register(callback);
+        self
+    }
+
+    /// Keep an [exponential moving average](burn_core::module::ModelEma) of the model weights,
+    /// updated after each optimizer step.
+    ///
+    /// The averaged weights are used for validation, while the model checkpoints keep the trained
+    /// weights. The average is saved in its own checkpoints and restored when resuming the
+    /// training. The model returned by `.fit()` is still the trained one.
+    pub fn model_ema(mut self, config: ModelEmaConfig) -> Self {
+        self.model_ema = Some(config);
+        self
+    }
+
+    /// Train with mixed precision: the training steps compute with the model weights rounded to
+    /// the given [half precision](HalfPrecision), while the optimizer updates the full precision
+    /// weights. The rounding is [emulated](HalfPrecision::round_emulated), so the weights keep
+    /// the element type of the backend.
+    ///
+    /// The loss is scaled dynamically with the given [scaler](GradScalerConfig) by
+    /// [step_scaled](crate::TrainStep::step_scaled), whose state is saved with the checkpoints.
+    /// The optimizer steps where the gradients overflow are skipped, including all the
+    /// accumulated gradients of the step.
+    pub fn mixed_precision(mut self, precision: HalfPrecision, scaler: GradScalerConfig) -> Self {
+        self.mixed_precision = Some(MixedPrecision::new(precision, scaler.init()));
+        self
+    }
+
+    /// Compute the statistics of the gradients of each training iteration, used by the
+    /// [gradient norm](crate::metric::GradientNormMetric),
+    /// [module gradient norm](crate::metric::ModuleGradientNormMetric) and
+    /// [update ratio](crate::metric::UpdateRatioMetric) metrics.
+    ///
+    /// The norms are read from the device at each iteration, which slows down the training.
+    pub fn gradient_stats(mut self) -> Self {
+        self.gradient_stats = true;
+        self
+    }
+
     /// By default, Rust logs are captured and written into
     /// `experiment.log`. If disabled, standard Rust log handling
     /// will apply.
@@ -250,7 +383,8 @@ where
     }
 
     /// Register a checkpointer that will save the [optimizer](Optimizer), the
-    /// [model](AutodiffModule) and the [scheduler](LrScheduler) to different files.
+    /// [model](AutodiffModule), the [scheduler](LrScheduler), the state of the training loop and
+    /// the [moving average](LearnerBuilder::model_ema) of the model to different files.
     pub fn with_file_checkpointer<FR>(mut self, recorder: FR) -> Self
     where
         FR: FileRecorder<B> + 'static,
@@ -264,12 +398,18 @@ where
         let checkpointer_optimizer =
             FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "optim");
         let checkpointer_scheduler: FileCheckpointer<FR> =
-            FileCheckpointer::new(recorder, &checkpoint_dir, "scheduler");
+            FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "scheduler");
+        let checkpointer_state: FileCheckpointer<FR> =
+            FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "state");
+        let checkpointer_ema: FileCheckpointer<FR> =
+            FileCheckpointer::new(recorder, &checkpoint_dir, "ema");
 
         self.checkpointers = Some((
             AsyncCheckpointer::new(checkpointer_model),
             AsyncCheckpointer::new(checkpointer_optimizer),
             AsyncCheckpointer::new(checkpointer_scheduler),
+            AsyncCheckpointer::new(checkpointer_state),
+            AsyncCheckpointer::new(checkpointer_ema),
         ));
 
         self
@@ -283,6 +423,15 @@ where
         self
     }
 
+    /// Log the metrics to TensorBoard event files, in the `tensorboard` directory of the
+    /// artifacts with a `train` and a `valid` run.
+    ///
+    /// When the training summary report is enabled, it is also logged as text.
+    pub fn tensorboard(mut self) -> Self {
+        self.tensorboard = true;
+        self
+    }
+
     /// Create the [learner](Learner) from a [model](AutodiffModule) and an [optimizer](Optimizer).
     /// The [learning rate scheduler](LrScheduler) can also be a simple
     /// [learning rate](burn_core::LearningRate).
@@ -327,17 +476,39 @@ where
                 .register_logger_valid(FileMetricLogger::new(self.directory.join("valid")));
         }
 
+        let tensorboard_directory = self.directory.join("tensorboard");
+        if self.tensorboard {
+            self.event_store
+                .register_logger_train(TensorBoardMetricLogger::new(
+                    tensorboard_directory.join("train"),
+                ));
+            self.event_store
+                .register_logger_valid(TensorBoardMetricLogger::new(
+                    tensorboard_directory.join("valid"),
+                ));
+        }
+
         let event_store = Rc::new(EventStoreClient::new(self.event_store));
         let event_processor = FullEventProcessor::new(self.metrics, renderer, event_store.clone());
 
-        let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
-            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointer_strategy)
-        });
+        let checkpointer = self
+            .checkpointers
+            .map(|(model, optim, scheduler, state, ema)| {
+                LearnerCheckpointer::new(
+                    model,
+                    optim,
+                    scheduler,
+                    state,
+                    ema,
+                    self.checkpointer_strategy,
+                )
+            });
 
         let summary = if self.summary {
             Some(LearnerSummaryConfig {
                 directory: self.directory,
                 metrics: self.summary_metrics.into_iter().collect::<Vec<_>>(),
+                tensorboard: self.tensorboard.then_some(tensorboard_directory),
             })
         } else {
             None
@@ -353,9 +524,15 @@ where
             event_store,
             checkpoint: self.checkpoint,
             grad_accumulation: self.grad_accumulation,
+            model_ema: self.model_ema,
+            mixed_precision: self.mixed_precision,
+            seed: self.seed,
+            gradient_stats: self.gradient_stats,
+            evaluation: self.evaluation,
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
+            callbacks: self.callbacks,
             summary,
         }
     }
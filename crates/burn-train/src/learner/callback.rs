@@ -0,0 +1,244 @@
+use burn_core::LearningRate;
+
+use crate::metric::store::EventStoreClient;
+use crate::TrainOutput;
+
+/// Hooks called by the [learner](crate::Learner) during the training.
+///
+/// All the hooks do nothing by default. They can act on the training loop using the
+/// [callback context](CallbackContext), e.g. to stop the training or to change the learning rate.
+pub trait LearnerCallback<TO> {
+    /// Called once before the first training epoch.
+    fn on_train_begin(&mut self, _context: &mut CallbackContext) {}
+
+    /// Called before each training epoch.
+    fn on_epoch_start(&mut self, _epoch: usize, _context: &mut CallbackContext) {}
+
+    /// Called after the training step of each iteration, before the gradients are applied with
+    /// the given learning rate.
+    fn on_iteration_end(
+        &mut self,
+        _iteration: usize,
+        _output: &TrainOutput<TO>,
+        _lr: LearningRate,
+        _context: &mut CallbackContext,
+    ) {
+    }
+
+    /// Called after each validation, with the step indexing its metrics in the store.
+    fn on_validation_end(
+        &mut self,
+        _step: usize,
+        _store: &EventStoreClient,
+        _context: &mut CallbackContext,
+    ) {
+    }
+
+    /// Called after a checkpoint has been saved for the given step.
+    fn on_checkpoint(&mut self, _step: usize, _context: &mut CallbackContext) {}
+
+    /// Called after each epoch, once validated and checkpointed.
+    fn on_epoch_end(&mut self, _epoch: usize, _context: &mut CallbackContext) {}
+}
+
+/// Let the [callbacks](LearnerCallback) act on the training loop.
+#[derive(Default, Debug)]
+pub struct CallbackContext {
+    stop: bool,
+    lr: Option<LearningRate>,
+}
+
+impl CallbackContext {
+    /// Request the training to stop, as with an
+    /// [early stopping strategy](crate::EarlyStoppingStrategy).
+    ///
+    /// Unlike an [interrupted](crate::TrainingInterrupter) training, no checkpoint is saved to
+    /// resume the current epoch: the training stops at the end of the current iteration, or once
+    /// the current step or epoch is completed when requested after it.
+    pub fn stop(&mut self) {
+        self.stop = true;
+    }
+
+    /// Override the learning rate given by the scheduler for the next iterations, until it is
+    /// reset with `None`.
+    pub fn set_learning_rate(&mut self, lr: Option<LearningRate>) {
+        self.lr = lr;
+    }
+
+    /// The learning rate overriding the one given by the scheduler, if any.
+    pub fn learning_rate(&self) -> Option<LearningRate> {
+        self.lr
+    }
+}
+
+/// The [callbacks](LearnerCallback) called by the [learner](crate::Learner), sharing the same
+/// [context](CallbackContext).
+pub struct LearnerCallbacks<TO> {
+    callbacks: Vec<Box<dyn LearnerCallback<TO>>>,
+    context: CallbackContext,
+}
+
+impl<TO> Default for LearnerCallbacks<TO> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TO> LearnerCallbacks<TO> {
+    /// Create an empty list of callbacks.
+    pub fn new() -> Self {
+        Self {
+            callbacks: Vec::new(),
+            context: CallbackContext::default(),
+        }
+    }
+
+    /// Register a new callback, called after the previous ones.
+    pub fn register<C: LearnerCallback<TO> + 'static>(&mut self, callback: C) {
+        self.callbacks.push(Box::new(callback));
+    }
+
+    /// The learning rate to use, given the one of the scheduler.
+    pub fn learning_rate(&self, lr: LearningRate) -> LearningRate {
+        self.context.lr.unwrap_or(lr)
+    }
+
+    /// The learning rate overriding the one given by the scheduler, if any.
+    pub fn learning_rate_override(&self) -> Option<LearningRate> {
+        self.context.lr
+    }
+
+    /// Restore the learning rate overriding the one given by the scheduler, e.g. when resuming
+    /// from a checkpoint.
+    pub fn restore_learning_rate_override(&mut self, lr: Option<LearningRate>) {
+        self.context.lr = lr;
+    }
+
+    /// Whether a callback requested the training to stop.
+    pub fn should_stop(&self) -> bool {
+        self.context.stop
+    }
+
+    /// Call [on_train_begin](LearnerCallback::on_train_begin) on each callback.
+    pub fn on_train_begin(&mut self) {
+        self.call(|callback, context| callback.on_train_begin(context));
+    }
+
+    /// Call [on_epoch_start](LearnerCallback::on_epoch_start) on each callback.
+    pub fn on_epoch_start(&mut self, epoch: usize) {
+        self.call(|callback, context| callback.on_epoch_start(epoch, context));
+    }
+
+    /// Call [on_iteration_end](LearnerCallback::on_iteration_end) on each callback.
+    pub fn on_iteration_end(
+        &mut self,
+        iteration: usize,
+        output: &TrainOutput<TO>,
+        lr: LearningRate,
+    ) {
+        self.call(|callback, context| callback.on_iteration_end(iteration, output, lr, context));
+    }
+
+    /// Call [on_validation_end](LearnerCallback::on_validation_end) on each callback.
+    pub fn on_validation_end(&mut self, step: usize, store: &EventStoreClient) {
+        self.call(|callback, context| callback.on_validation_end(step, store, context));
+    }
+
+    /// Call [on_checkpoint](LearnerCallback::on_checkpoint) on each callback.
+    pub fn on_checkpoint(&mut self, step: usize) {
+        self.call(|callback, context| callback.on_checkpoint(step, context));
+    }
+
+    /// Call [on_epoch_end](LearnerCallback::on_epoch_end) on each callback.
+    pub fn on_epoch_end(&mut self, epoch: usize) {
+        self.call(|callback, context| callback.on_epoch_end(epoch, context));
+    }
+
+    fn call<F>(&mut self, mut hook: F)
+    where
+        F: FnMut(&mut dyn LearnerCallback<TO>, &mut CallbackContext),
+    {
+        for callback in self.callbacks.iter_mut() {
+            hook(callback.as_mut(), &mut self.context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::optim::GradientsParams;
+
+    struct StopAtEpoch(usize);
+
+    impl LearnerCallback<()> for StopAtEpoch {
+        fn on_epoch_end(&mut self, epoch: usize, context: &mut CallbackContext) {
+            if epoch == self.0 {
+                context.stop();
+            }
+        }
+    }
+
+    struct HalveLearningRate;
+
+    impl LearnerCallback<()> for HalveLearningRate {
+        fn on_iteration_end(
+            &mut self,
+            _iteration: usize,
+            _output: &TrainOutput<()>,
+            lr: LearningRate,
+            context: &mut CallbackContext,
+        ) {
+            context.set_learning_rate(Some(lr / 2.0));
+        }
+    }
+
+    fn output() -> TrainOutput<()> {
+        TrainOutput {
+            grads: GradientsParams::new(),
+            item: (),
+        }
+    }
+
+    #[test]
+    fn should_stop_the_training_when_requested() {
+        let mut callbacks = LearnerCallbacks::new();
+        callbacks.register(StopAtEpoch(2));
+
+        callbacks.on_epoch_end(1);
+        assert!(!callbacks.should_stop());
+
+        callbacks.on_epoch_end(2);
+        assert!(callbacks.should_stop());
+    }
+
+    #[test]
+    fn should_override_the_learning_rate() {
+        let mut callbacks = LearnerCallbacks::new();
+        callbacks.register(HalveLearningRate);
+
+        assert_eq!(callbacks.learning_rate(0.5), 0.5);
+
+        let lr = callbacks.learning_rate(0.5);
+        callbacks.on_iteration_end(1, &output(), lr);
+        assert_eq!(callbacks.learning_rate(0.5), 0.25);
+
+        let lr = callbacks.learning_rate(0.5);
+        callbacks.on_iteration_end(2, &output(), lr);
+        assert_eq!(callbacks.learning_rate(0.5), 0.125);
+    }
+
+    #[test]
+    fn should_restore_the_learning_rate_override() {
+        let mut callbacks = LearnerCallbacks::<()>::new();
+        callbacks.register(HalveLearningRate);
+
+        callbacks.on_iteration_end(1, &output(), 0.5);
+        let lr = callbacks.learning_rate_override();
+        assert_eq!(lr, Some(0.25));
+
+        let mut resumed = LearnerCallbacks::<()>::new();
+        resumed.restore_learning_rate_override(lr);
+        assert_eq!(resumed.learning_rate(0.5), 0.25);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::metric::{AccuracyInput, Adaptor, HammingScoreInput, LossInput};
+use crate::metric::{AccuracyInput, Adaptor, ClassificationInput, HammingScoreInput, LossInput};
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::{Int, Tensor};
 
@@ -27,6 +27,12 @@ impl<B: Backend> Adaptor<LossInput<B>> for ClassificationOutput<B> {
     }
 }
 
+impl<B: Backend> Adaptor<ClassificationInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> ClassificationInput<B> {
+        ClassificationInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
 /// Multi-label classification output adapted for multiple metrics.
 #[derive(new)]
 pub struct MultiLabelClassificationOutput<B: Backend> {
@@ -51,3 +57,9 @@ impl<B: Backend> Adaptor<LossInput<B>> for MultiLabelClassificationOutput<B> {
         LossInput::new(self.loss.clone())
     }
 }
+
+impl<B: Backend> Adaptor<ClassificationInput<B>> for MultiLabelClassificationOutput<B> {
+    fn adapt(&self) -> ClassificationInput<B> {
+        ClassificationInput::multilabel(self.output.clone(), self.targets.clone())
+    }
+}
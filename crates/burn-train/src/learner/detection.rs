@@ -0,0 +1,154 @@
+use crate::metric::{Adaptor, BoundingBox, Detection, DetectionInput, LossInput};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Bool, Int, Tensor};
+
+/// Object detection output adapted for multiple metrics.
+///
+/// The boxes are given as `[x_min, y_min, x_max, y_max]`.
+#[derive(new)]
+pub struct DetectionOutput<B: Backend> {
+    /// The loss.
+    pub loss: Tensor<B, 1>,
+
+    /// The predicted boxes of shape `[batch_size, num_detections, 4]`.
+    pub boxes: Tensor<B, 3>,
+
+    /// The confidence scores of the predicted boxes of shape `[batch_size, num_detections]`.
+    pub scores: Tensor<B, 2>,
+
+    /// The predicted classes of shape `[batch_size, num_detections]`, negative for the padding
+    /// detections of the images with fewer predictions, which are ignored.
+    pub labels: Tensor<B, 2, Int>,
+
+    /// The target boxes of shape `[batch_size, num_targets, 4]`.
+    pub target_boxes: Tensor<B, 3>,
+
+    /// The target classes of shape `[batch_size, num_targets]`, the boxes with a negative class
+    /// are ignored as the padding boxes.
+    pub target_labels: Tensor<B, 2, Int>,
+
+    /// The padding mask of the targets of shape `[batch_size, num_targets]`, `true` for the
+    /// padding boxes of the images with fewer targets.
+    pub mask_pad: Option<Tensor<B, 2, Bool>>,
+}
+
+/// The boxes of each image with their index in the batch, without the padding boxes and the boxes
+/// with a negative label.
+fn bounding_boxes<B: Backend>(
+    boxes: Tensor<B, 3>,
+    labels: Tensor<B, 2, Int>,
+    mask_pad: Option<Tensor<B, 2, Bool>>,
+) -> Vec<Vec<(usize, BoundingBox)>> {
+    let [batch_size, num_boxes] = labels.dims();
+    let coordinates = boxes.into_data().iter::<f64>().collect::<Vec<_>>();
+    let labels = labels.into_data().iter::<i64>().collect::<Vec<_>>();
+    let mask_pad = match mask_pad {
+        Some(mask_pad) => mask_pad.into_data().iter::<bool>().collect(),
+        None => vec![false; batch_size * num_boxes],
+    };
+
+    (0..batch_size)
+        .map(|image| {
+            (image * num_boxes..(image + 1) * num_boxes)
+                .filter(|index| !mask_pad[*index] && labels[*index] >= 0)
+                .map(|index| {
+                    let coordinates = coordinates[4 * index..4 * index + 4].try_into().unwrap();
+                    (index, BoundingBox::new(coordinates, labels[index] as usize))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+impl<B: Backend> Adaptor<LossInput<B>> for DetectionOutput<B> {
+    fn adapt(&self) -> LossInput<B> {
+        LossInput::new(self.loss.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<DetectionInput> for DetectionOutput<B> {
+    fn adapt(&self) -> DetectionInput {
+        let scores = self
+            .scores
+            .clone()
+            .into_data()
+            .iter::<f64>()
+            .collect::<Vec<_>>();
+
+        let predictions = bounding_boxes(self.boxes.clone(), self.labels.clone(), None)
+            .into_iter()
+            .map(|boxes| {
+                boxes
+                    .into_iter()
+                    .map(|(index, bbox)| Detection::new(bbox, scores[index]))
+                    .collect()
+            })
+            .collect();
+        let targets = bounding_boxes(
+            self.target_boxes.clone(),
+            self.target_labels.clone(),
+            self.mask_pad.clone(),
+        )
+        .into_iter()
+        .map(|boxes| boxes.into_iter().map(|(_index, bbox)| bbox).collect())
+        .collect();
+
+        DetectionInput::new(predictions, targets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_skip_the_padding_predictions_and_targets() {
+        let device = Default::default();
+        let output = DetectionOutput::<TestBackend>::new(
+            Tensor::zeros([1], &device),
+            Tensor::from_floats(
+                [
+                    [[0.0, 0.0, 1.0, 1.0], [0.0, 0.0, 0.0, 0.0]],
+                    [[1.0, 1.0, 2.0, 2.0], [2.0, 2.0, 3.0, 3.0]],
+                ],
+                &device,
+            ),
+            Tensor::from_floats([[0.9, 0.0], [0.8, 0.7]], &device),
+            Tensor::from_ints([[1, -1], [0, 2]], &device),
+            Tensor::from_floats(
+                [
+                    [[0.0, 0.0, 1.0, 1.0], [0.0, 0.0, 0.0, 0.0]],
+                    [[1.0, 1.0, 2.0, 2.0], [0.0, 0.0, 0.0, 0.0]],
+                ],
+                &device,
+            ),
+            Tensor::from_ints([[1, 0], [0, -1]], &device),
+            Some(Tensor::from_data([[false, true], [false, false]], &device)),
+        );
+
+        let input: DetectionInput = output.adapt();
+        let (predictions, targets) = input.images();
+
+        assert_eq!(
+            predictions,
+            [
+                vec![Detection::new(
+                    BoundingBox::new([0.0, 0.0, 1.0, 1.0], 1),
+                    0.9_f32 as f64
+                )],
+                vec![
+                    Detection::new(BoundingBox::new([1.0, 1.0, 2.0, 2.0], 0), 0.8_f32 as f64),
+                    Detection::new(BoundingBox::new([2.0, 2.0, 3.0, 3.0], 2), 0.7_f32 as f64),
+                ],
+            ]
+        );
+        assert_eq!(
+            targets,
+            [
+                vec![BoundingBox::new([0.0, 0.0, 1.0, 1.0], 1)],
+                vec![BoundingBox::new([1.0, 1.0, 2.0, 2.0], 0)],
+            ]
+        );
+    }
+}
@@ -13,6 +13,10 @@ pub enum StoppingCondition {
 }
 
 /// A strategy that checks if the training should be stopped.
+///
+/// The strategy is checked after each validation, with the step of the validation when the epochs
+/// are divided by intermediate evaluations, see
+/// [validate_every](crate::LearnerBuilder::validate_every).
 pub trait EarlyStoppingStrategy {
     /// Update its current state and returns if the training should be stopped.
     fn should_stop(&mut self, epoch: usize, store: &EventStoreClient) -> bool;
@@ -1,12 +1,17 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    data::dataloader::{DataLoader, DataLoaderIterator, Progress},
+    lr_scheduler::LrScheduler,
+    module::{AutodiffModule, ModelEma},
+    optim::{GradientsAccumulator, GradientsParams},
+    tensor::backend::{AutodiffBackend, Backend},
 };
 use std::sync::Arc;
+use std::time::Instant;
 
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
+use crate::metric::GradientStats;
 use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
-use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
+use crate::{LearnerCallbacks, MixedPrecision, MultiDevicesTrainStep, TrainStep, ValidStep};
 
 /// A validation epoch.
 #[derive(new)]
@@ -14,6 +19,8 @@ pub struct ValidEpoch<VI> {
     dataloader: Arc<dyn DataLoader<VI>>,
     epoch: usize,
     epoch_total: usize,
+    #[new(default)]
+    step: Option<usize>,
 }
 
 /// A training epoch.
@@ -23,9 +30,78 @@ pub struct TrainEpoch<TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    #[new(default)]
+    position: Option<EpochPosition>,
+    #[new(default)]
+    seed: Option<u64>,
+    #[new(default)]
+    gradient_stats: bool,
+}
+
+/// The position of the training loop in an epoch, used to resume an interrupted epoch.
+#[derive(new, Clone, Debug)]
+pub struct EpochPosition {
+    /// The number of iterations done.
+    pub iteration: usize,
+    /// The progress of the data loader.
+    pub progress: Progress,
+    /// The progress of each worker of the data loader, empty when unknown.
+    pub progress_workers: Vec<Progress>,
+}
+
+/// The iterator of a [training epoch](TrainEpoch), kept between the runs of the epoch so that it
+/// can be paused for intermediate evaluations.
+pub struct TrainEpochIterator<'a, TI> {
+    iterator: Box<dyn DataLoaderIterator<TI> + 'a>,
+    iteration: usize,
+    step: usize,
+    pause: Option<usize>,
+    deadline: Option<Instant>,
+    finished: bool,
+}
+
+impl<TI> TrainEpochIterator<'_, TI> {
+    /// Start a new step, whose metrics are indexed by the given number.
+    ///
+    /// The next run of the epoch stops at the end of the epoch, or after the given iteration or
+    /// time, as soon as the accumulated gradients are applied.
+    pub fn start_step(&mut self, step: usize, pause: Option<usize>, deadline: Option<Instant>) {
+        self.step = step;
+        self.pause = pause;
+        self.deadline = deadline;
+    }
+
+    /// The number of iterations done.
+    pub fn iteration(&self) -> usize {
+        self.iteration
+    }
+
+    /// If all the items of the epoch have been processed.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn should_pause(&self, progress: &Progress) -> bool {
+        // The end of the epoch is handled by the learner.
+        if progress.items_processed >= progress.items_total {
+            return false;
+        }
+
+        self.pause.is_some_and(|pause| self.iteration >= pause)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
 }
 
 impl<VI> ValidEpoch<VI> {
+    /// Index the metrics of the validation by the given step instead of the epoch, when the
+    /// epoch is divided in multiple steps.
+    pub fn with_step(mut self, step: usize) -> Self {
+        self.step = Some(step);
+        self
+    }
+
     /// Runs the validation epoch.
     ///
     /// # Arguments
@@ -68,65 +144,180 @@ impl<VI> ValidEpoch<VI> {
                 break;
             }
         }
-        processor.process_valid(Event::EndEpoch(self.epoch));
+        processor.process_valid(Event::EndEpoch(self.step.unwrap_or(self.epoch)));
+    }
+}
+
+impl<TI> TrainEpoch<TI> {
+    /// Resume the epoch at the given position.
+    ///
+    /// The training data loader should be in the same random state as when the interrupted epoch
+    /// started, see [restore_iterations](DataLoader::restore_iterations).
+    pub fn resume(mut self, position: EpochPosition) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Seed the backend before each training step, with a seed derived from the given one, the
+    /// epoch and the iteration, so that a resumed epoch replays the same random operations.
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Compute the [statistics of the gradients](GradientStats) of each iteration, reported to
+    /// the metrics with the training items.
+    pub fn with_gradient_stats(mut self, enabled: bool) -> Self {
+        self.gradient_stats = enabled;
+        self
+    }
+
+    /// Creates the iterator of the epoch, starting at the resumed position if any.
+    ///
+    /// By default, the epoch is run in a single step indexed by the epoch number.
+    pub fn iterator(&self) -> TrainEpochIterator<'_, TI> {
+        let (iterator, iteration) = match &self.position {
+            Some(position) if position.progress_workers.is_empty() => (
+                self.dataloader.iter_from(&position.progress),
+                position.iteration,
+            ),
+            Some(position) => (
+                self.dataloader
+                    .iter_from_workers(&position.progress_workers),
+                position.iteration,
+            ),
+            None => (self.dataloader.iter(), 0),
+        };
+
+        TrainEpochIterator {
+            iterator,
+            iteration,
+            step: self.epoch,
+            pause: None,
+            deadline: None,
+            finished: false,
+        }
+    }
+
+    fn seed_iteration<B: Backend>(&self, iteration: usize) {
+        if let Some(seed) = self.seed {
+            B::seed(
+                seed.wrapping_add((self.epoch as u64) << 32)
+                    .wrapping_add(iteration as u64),
+            );
+        }
+    }
+
+    fn gradient_stats<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        grads: &GradientsParams,
+        model: &M,
+    ) -> Option<(GradientStats, M)> {
+        // The model is kept to compute the update ratio after the optimizer step.
+        self.gradient_stats
+            .then(|| (GradientStats::from_grads(grads, model), model.clone()))
     }
 }
 
 impl<TI> TrainEpoch<TI> {
-    /// Runs the training epoch.
+    /// Runs the current step of the training epoch.
     ///
     /// # Arguments
     ///
+    /// * `iterator` - The iterator of the epoch, see [iterator](Self::iterator).
     /// * `model` - The model to train.
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
+    /// * `ema` - The moving average of the model weights to update after each optimizer step.
+    /// * `precision` - The mixed precision used to compute the training steps, if enabled.
     /// * `processor` - The event processor to use.
+    /// * `callbacks` - The callbacks to call after each iteration.
     ///
     /// # Returns
     ///
-    /// The trained model and the optimizer.
+    /// The trained model, the optimizer and the position where the epoch stopped.
+    #[allow(clippy::too_many_arguments)]
     pub fn run<LC: LearnerComponents, TO>(
         &self,
+        iterator: &mut TrainEpochIterator<'_, TI>,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
         scheduler: &mut LC::LrScheduler,
+        mut ema: Option<&mut ModelEma<LC::Backend, LC::Model>>,
+        mut precision: Option<&mut MixedPrecision>,
         processor: &mut LC::EventProcessor,
+        callbacks: &mut LearnerCallbacks<TO>,
         interrupter: &TrainingInterrupter,
-    ) -> (LC::Model, LC::Optimizer)
+    ) -> (LC::Model, LC::Optimizer, EpochPosition)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
         LC::Model: TrainStep<TI, TO>,
     {
         log::info!("Executing training step for epoch {}", self.epoch,);
 
-        let mut iterator = self.dataloader.iter();
-        let mut iteration = 0;
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
+        // Whether the gradients of the current optimizer step overflowed, which skips the step.
+        let mut overflow = false;
 
-        while let Some(item) = iterator.next() {
-            iteration += 1;
-            let lr = scheduler.step();
+        loop {
+            let item = match iterator.iterator.next() {
+                Some(item) => item,
+                None => {
+                    iterator.finished = true;
+                    break;
+                }
+            };
+            iterator.iteration += 1;
+            let iteration = iterator.iteration;
+            let lr = callbacks.learning_rate(scheduler.step());
             log::info!("Iteration {}", iteration);
 
-            let progress = iterator.progress();
-            let item = model.step(item);
-
-            match self.grad_accumulation {
+            let progress = iterator.iterator.progress();
+            self.seed_iteration::<LC::Backend>(iteration);
+            let (item, finite) = match precision.as_deref() {
+                Some(precision) => precision.step(&model, item),
+                None => (model.step(item), true),
+            };
+            overflow |= !finite;
+            callbacks.on_iteration_end(iteration, &item, lr);
+            let stats = self.gradient_stats::<LC::Backend, _>(&item.grads, &model);
+            let mut stepped = false;
+
+            let grads = match self.grad_accumulation {
                 Some(accumulation) => {
                     accumulator.accumulate(&model, item.grads);
                     accumulation_current += 1;
 
-                    if accumulation <= accumulation_current {
-                        let grads = accumulator.grads();
-                        model = model.optimize(&mut optim, lr, grads);
-                        accumulation_current = 0;
+                    match accumulation <= accumulation_current {
+                        true => {
+                            accumulation_current = 0;
+                            Some(accumulator.grads())
+                        }
+                        false => None,
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+                None => Some(item.grads),
+            };
+
+            if let Some(grads) = grads {
+                // The accumulated gradients are dropped as a whole when any of them overflowed.
+                if !overflow {
+                    model = model.optimize(&mut optim, lr, grads);
+                    stepped = true;
+
+                    if let Some(ema) = ema.as_deref_mut() {
+                        ema.update(&model);
+                    }
+                }
+
+                if let Some(precision) = precision.as_deref_mut() {
+                    precision.update(!overflow);
+                }
+                overflow = false;
             }
 
-            let item = LearnerItem::new(
+            let mut item = LearnerItem::new(
                 item.item,
                 progress,
                 self.epoch,
@@ -134,6 +325,10 @@ impl<TI> TrainEpoch<TI> {
                 iteration,
                 Some(lr),
             );
+            item.gradients = stats.map(|(stats, before)| match stepped {
+                true => stats.with_update_ratio::<LC::Backend, _>(&before, &model),
+                false => stats,
+            });
 
             processor.process_train(Event::ProcessedItem(item));
 
@@ -141,36 +336,59 @@ impl<TI> TrainEpoch<TI> {
                 log::info!("Training interrupted.");
                 break;
             }
+
+            if callbacks.should_stop() {
+                log::info!("Training stopped by a callback.");
+                break;
+            }
+
+            if accumulation_current == 0 && iterator.should_pause(&iterator.iterator.progress()) {
+                break;
+            }
         }
-        processor.process_train(Event::EndEpoch(self.epoch));
+        processor.process_train(Event::EndEpoch(iterator.step));
+        let position = EpochPosition::new(
+            iterator.iteration,
+            iterator.iterator.progress(),
+            iterator.iterator.progress_workers(),
+        );
 
-        (model, optim)
+        (model, optim, position)
     }
 }
 
 impl<TI> TrainEpoch<TI> {
-    /// Runs the training epoch on multiple devices.
+    /// Runs the current step of the training epoch on multiple devices.
     ///
     /// # Arguments
     ///
+    /// * `iterator` - The iterator of the epoch, see [iterator](Self::iterator).
     /// * `model` - The model to train.
     /// * `optim` - The optimizer to use.
     /// * `lr_scheduler` - The learning rate scheduler to use.
+    /// * `ema` - The moving average of the model weights to update after each optimizer step.
+    /// * `precision` - The mixed precision used to compute the training steps, if enabled.
     /// * `processor` - The event processor to use.
+    /// * `callbacks` - The callbacks to call after each iteration.
     /// * `devices` - The devices to use.
     ///
     /// # Returns
     ///
-    /// The trained model and the optimizer.
+    /// The trained model, the optimizer and the position where the epoch stopped.
+    #[allow(clippy::too_many_arguments)]
     pub fn run_multi_device<LC: LearnerComponents, TO>(
         &self,
+        iterator: &mut TrainEpochIterator<'_, TI>,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
         lr_scheduler: &mut LC::LrScheduler,
+        mut ema: Option<&mut ModelEma<LC::Backend, LC::Model>>,
+        mut precision: Option<&mut MixedPrecision>,
         processor: &mut LC::EventProcessor,
+        callbacks: &mut LearnerCallbacks<TO>,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
-    ) -> (LC::Model, LC::Optimizer)
+    ) -> (LC::Model, LC::Optimizer, EpochPosition)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
         LC::Model: TrainStep<TI, TO>,
@@ -183,8 +401,6 @@ impl<TI> TrainEpoch<TI> {
             devices
         );
 
-        let mut iterator = self.dataloader.iter();
-        let mut iteration = 0;
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
 
@@ -193,31 +409,74 @@ impl<TI> TrainEpoch<TI> {
 
         // The main device is always the first in the list.
         let device_main = devices.first().expect("A minimum of one device.").clone();
-        let mut interrupted = false;
+        let mut stopped = false;
+        // Whether the gradients of the current optimizer step overflowed, which skips the step.
+        let mut overflow = false;
 
         loop {
-            let items = step.step(&mut iterator, &model);
+            // The workers run concurrently, so the random operations may not be replayed the same
+            // way.
+            self.seed_iteration::<LC::Backend>(iterator.iteration + 1);
+            let items = match precision.as_deref() {
+                Some(precision) => step.step(
+                    &mut iterator.iterator,
+                    &precision.model(&model),
+                    Some(precision.scaler()),
+                ),
+                None => step.step(&mut iterator.iterator, &model, None),
+            };
             if items.is_empty() {
+                iterator.finished = true;
                 break;
             }
 
-            for item in items {
-                iteration += 1;
-                let lr = lr_scheduler.step();
-                let progress = iterator.progress();
-
-                let grads = item.grads.to_device(&device_main, &model);
+            // The outputs of the workers are computed with the same loss scale, which is only
+            // updated once all of them are unscaled.
+            let mut finite_step = None;
+
+            for mut item in items {
+                iterator.iteration += 1;
+                let iteration = iterator.iteration;
+                let lr = callbacks.learning_rate(lr_scheduler.step());
+                let progress = iterator.iterator.progress();
+
+                item.grads = item.grads.to_device(&device_main, &model);
+                if let Some(precision) = precision.as_deref() {
+                    item.grads = match precision.unscale(item.grads, &model) {
+                        Some(grads) => grads,
+                        None => {
+                            overflow = true;
+                            GradientsParams::new()
+                        }
+                    };
+                }
+                callbacks.on_iteration_end(iteration, &item, lr);
+                let stats = self.gradient_stats::<LC::Backend, _>(&item.grads, &model);
 
-                accumulator.accumulate(&model, grads);
+                accumulator.accumulate(&model, item.grads);
                 accumulation_current += 1;
+                let mut stepped = false;
 
                 if accumulation <= accumulation_current {
                     let grads = accumulator.grads();
-                    model = model.optimize(&mut optim, lr, grads);
                     accumulation_current = 0;
+
+                    // The accumulated gradients are dropped as a whole when any of them
+                    // overflowed.
+                    if !overflow {
+                        model = model.optimize(&mut optim, lr, grads);
+                        stepped = true;
+
+                        if let Some(ema) = ema.as_deref_mut() {
+                            ema.update(&model);
+                        }
+                    }
+
+                    finite_step = Some(!overflow);
+                    overflow = false;
                 }
 
-                let item = LearnerItem::new(
+                let mut item = LearnerItem::new(
                     item.item,
                     progress,
                     self.epoch,
@@ -225,23 +484,46 @@ impl<TI> TrainEpoch<TI> {
                     iteration,
                     Some(lr),
                 );
+                item.gradients = stats.map(|(stats, before)| match stepped {
+                    true => stats.with_update_ratio::<LC::Backend, _>(&before, &model),
+                    false => stats,
+                });
 
                 processor.process_train(Event::ProcessedItem(item));
 
                 if interrupter.should_stop() {
                     log::info!("Training interrupted.");
-                    interrupted = true;
+                    stopped = true;
                     break;
                 }
+
+                if callbacks.should_stop() {
+                    log::info!("Training stopped by a callback.");
+                    stopped = true;
+                    break;
+                }
+            }
+
+            if let (Some(precision), Some(finite)) = (precision.as_deref_mut(), finite_step) {
+                precision.update(finite);
             }
 
-            if interrupted {
+            if stopped {
+                break;
+            }
+
+            if accumulation_current == 0 && iterator.should_pause(&iterator.iterator.progress()) {
                 break;
             }
         }
 
-        processor.process_train(Event::EndEpoch(self.epoch));
+        processor.process_train(Event::EndEpoch(iterator.step));
+        let position = EpochPosition::new(
+            iterator.iteration,
+            iterator.iterator.progress(),
+            iterator.iterator.progress_workers(),
+        );
 
-        (model, optim)
+        (model, optim, position)
     }
 }
@@ -0,0 +1,130 @@
+use std::time::{Duration, Instant};
+
+/// Define when the training pauses during an epoch to validate the model or to save a checkpoint.
+///
+/// Each pause ends a step of the training: the metrics, the checkpoints and the strategies acting
+/// on them are indexed by steps. Without any intermediate evaluation, a step is an epoch.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EvaluationSchedule {
+    /// The number of training iterations between two validations.
+    pub(crate) valid_every: Option<usize>,
+    /// The number of training iterations between two checkpoints.
+    pub(crate) checkpoint_every: Option<usize>,
+    /// The duration between two checkpoints.
+    pub(crate) checkpoint_interval: Option<Duration>,
+}
+
+impl EvaluationSchedule {
+    /// If the epochs are divided in multiple steps.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.valid_every.is_some()
+            || self.checkpoint_every.is_some()
+            || self.checkpoint_interval.is_some()
+    }
+
+    /// The iteration following the given one at which the epoch should pause.
+    pub(crate) fn next_iteration(&self, iteration: usize) -> Option<usize> {
+        let next = |every: usize| (iteration / every + 1) * every;
+
+        [self.valid_every, self.checkpoint_every]
+            .into_iter()
+            .flatten()
+            .map(next)
+            .min()
+    }
+
+    /// The time at which the epoch should pause to save a checkpoint.
+    pub(crate) fn deadline(&self, last_checkpoint: Instant) -> Option<Instant> {
+        self.checkpoint_interval
+            .map(|interval| last_checkpoint + interval)
+    }
+
+    /// If the model should be validated when the epoch pauses at the given iteration, after
+    /// pausing at the `previous` one.
+    pub(crate) fn should_validate(&self, previous: usize, iteration: usize) -> bool {
+        is_crossed(previous, iteration, self.valid_every)
+    }
+
+    /// If a checkpoint should be saved when the epoch pauses at the given iteration, after
+    /// pausing at the `previous` one.
+    pub(crate) fn should_checkpoint(
+        &self,
+        previous: usize,
+        iteration: usize,
+        last_checkpoint: Instant,
+    ) -> bool {
+        if is_crossed(previous, iteration, self.checkpoint_every) {
+            return true;
+        }
+
+        match self.deadline(last_checkpoint) {
+            Some(deadline) => Instant::now() >= deadline,
+            None => false,
+        }
+    }
+}
+
+/// The pause can be delayed until the gradients are no longer accumulated, so a multiple of the
+/// interval may have been passed instead of reached.
+fn is_crossed(previous: usize, iteration: usize, every: Option<usize>) -> bool {
+    match every {
+        Some(every) => iteration / every > previous / every,
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_pause_at_the_closest_iteration() {
+        let schedule = EvaluationSchedule {
+            valid_every: Some(4),
+            checkpoint_every: Some(6),
+            checkpoint_interval: None,
+        };
+
+        assert_eq!(schedule.next_iteration(0), Some(4));
+        assert_eq!(schedule.next_iteration(4), Some(6));
+        assert_eq!(schedule.next_iteration(6), Some(8));
+        assert_eq!(schedule.next_iteration(11), Some(12));
+        assert!(schedule.should_validate(11, 12));
+        assert!(schedule.should_checkpoint(11, 12, Instant::now()));
+        assert!(!schedule.should_checkpoint(6, 8, Instant::now()));
+    }
+
+    #[test]
+    fn should_evaluate_when_the_pause_is_delayed() {
+        let schedule = EvaluationSchedule {
+            valid_every: Some(4),
+            ..Default::default()
+        };
+
+        assert!(schedule.should_validate(3, 5));
+        assert!(!schedule.should_validate(5, 7));
+    }
+
+    #[test]
+    fn should_checkpoint_when_the_interval_elapsed() {
+        let schedule = EvaluationSchedule {
+            checkpoint_interval: Some(Duration::from_secs(60)),
+            ..Default::default()
+        };
+        let now = Instant::now();
+
+        assert_eq!(schedule.next_iteration(10), None);
+        assert!(!schedule.should_checkpoint(0, 10, now));
+        assert!(schedule.should_checkpoint(0, 10, now - Duration::from_secs(61)));
+        assert!(!schedule.should_validate(0, 10));
+    }
+
+    #[test]
+    fn should_never_pause_by_default() {
+        let schedule = EvaluationSchedule::default();
+
+        assert!(!schedule.is_enabled());
+        assert_eq!(schedule.next_iteration(10), None);
+        assert_eq!(schedule.deadline(Instant::now()), None);
+    }
+}
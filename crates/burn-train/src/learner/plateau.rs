@@ -0,0 +1,293 @@
+use crate::metric::{
+    store::{Direction, Split},
+    Metric,
+};
+use burn_core::{
+    lr_scheduler::{LrScheduler, MetricAggregate, MonitoredMetric},
+    tensor::backend::Backend,
+    LearningRate,
+};
+
+/// A [learning rate scheduler](LrScheduler) reducing the learning rate when a metric collected
+/// during training or validation stops improving.
+///
+/// The learning rate is multiplied by `factor` once the metric hasn't improved for more than
+/// `patience` evaluation steps, without going below `min_lr`. After a reduction, the scheduler
+/// waits `cooldown` evaluation steps before counting the steps without improvement again.
+///
+/// The mean value of the [monitored metric](LrScheduler::monitored_metric) over each evaluation
+/// step is read by the [learner](crate::Learner), which calls
+/// [on_evaluation_end](LrScheduler::on_evaluation_end) with it. An evaluation step is a whole
+/// epoch unless intermediate evaluations are scheduled, in which case the patience and cooldown
+/// count the intermediate evaluations.
+///
+/// # Notes
+///
+/// The metric should be registered for the scheduler to work, otherwise no data is collected.
+#[derive(Clone)]
+pub struct PlateauLrScheduler {
+    metric_name: String,
+    direction: Direction,
+    split: Split,
+    factor: f64,
+    patience: usize,
+    cooldown: usize,
+    min_lr: LearningRate,
+    lr: LearningRate,
+    best_value: f64,
+    num_bad_steps: usize,
+    cooldown_counter: usize,
+}
+
+impl PlateauLrScheduler {
+    /// Create a new [plateau learning rate scheduler](PlateauLrScheduler) starting at the given
+    /// learning rate, based on a metric collected during training or validation.
+    ///
+    /// By default, the learning rate is divided by 10 after 10 evaluation steps without
+    /// improvement, without cooldown or minimum learning rate.
+    pub fn new<Me: Metric>(initial_lr: LearningRate, direction: Direction, split: Split) -> Self {
+        Self {
+            metric_name: Me::NAME.to_string(),
+            direction,
+            split,
+            factor: 0.1,
+            patience: 10,
+            cooldown: 0,
+            min_lr: 0.0,
+            lr: initial_lr,
+            best_value: Self::init_value(direction),
+            num_bad_steps: 0,
+            cooldown_counter: 0,
+        }
+    }
+
+    /// Set the factor the learning rate is multiplied by when reduced.
+    ///
+    /// # Panics
+    /// This function panics if `factor` is not between 0 and 1.
+    pub fn with_factor(mut self, factor: f64) -> Self {
+        assert!(
+            factor > 0. && factor < 1.,
+            "Factor must be greater than 0 and less than 1"
+        );
+        self.factor = factor;
+        self
+    }
+
+    /// Set the number of evaluation steps without improvement allowed before reducing the learning
+    /// rate.
+    pub fn with_patience(mut self, patience: usize) -> Self {
+        self.patience = patience;
+        self
+    }
+
+    /// Set the number of evaluation steps to wait after a reduction before resuming normal
+    /// operation.
+    pub fn with_cooldown(mut self, cooldown: usize) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Set the minimum learning rate.
+    pub fn with_min_lr(mut self, min_lr: LearningRate) -> Self {
+        self.min_lr = min_lr;
+        self
+    }
+
+    fn init_value(direction: Direction) -> f64 {
+        match direction {
+            Direction::Lowest => f64::MAX,
+            Direction::Highest => f64::MIN,
+        }
+    }
+
+    fn update(&mut self, current_value: f64) {
+        let is_best = match self.direction {
+            Direction::Lowest => current_value < self.best_value,
+            Direction::Highest => current_value > self.best_value,
+        };
+
+        if is_best {
+            self.best_value = current_value;
+            self.num_bad_steps = 0;
+        } else {
+            self.num_bad_steps += 1;
+        }
+
+        if self.cooldown_counter > 0 {
+            self.cooldown_counter -= 1;
+            self.num_bad_steps = 0;
+        }
+
+        if self.num_bad_steps > self.patience {
+            let lr = f64::max(self.lr * self.factor, self.min_lr);
+
+            if lr < self.lr {
+                log::info!(
+                    "Reducing learning rate from {} to {}, no improvement since {} steps, {}: {}",
+                    self.lr,
+                    lr,
+                    self.num_bad_steps,
+                    self.metric_name,
+                    current_value
+                );
+                self.lr = lr;
+            }
+
+            self.cooldown_counter = self.cooldown;
+            self.num_bad_steps = 0;
+        }
+    }
+}
+
+impl LrScheduler for PlateauLrScheduler {
+    type Record<B: Backend> = (LearningRate, f64, usize, usize);
+
+    fn step(&mut self) -> LearningRate {
+        self.lr
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        (
+            self.lr,
+            self.best_value,
+            self.num_bad_steps,
+            self.cooldown_counter,
+        )
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        (
+            self.lr,
+            self.best_value,
+            self.num_bad_steps,
+            self.cooldown_counter,
+        ) = record;
+        self
+    }
+
+    fn monitored_metric(&self) -> Option<MonitoredMetric> {
+        Some(MonitoredMetric {
+            name: self.metric_name.clone(),
+            valid: matches!(self.split, Split::Valid),
+            aggregate: MetricAggregate::Mean,
+        })
+    }
+
+    fn on_evaluation_end(&mut self, _step: usize, metric: Option<f64>) {
+        match metric {
+            Some(value) => self.update(value),
+            None => log::warn!("Can't find metric for the plateau learning rate scheduler."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{metric::LossMetric, TestBackend};
+
+    use super::*;
+
+    #[test]
+    fn reduce_lr_when_no_improvement_after_patience() {
+        let scheduler = scheduler().with_patience(1);
+
+        test_plateau(
+            scheduler,
+            &[
+                (0.5, 0.8, "Should not reduce first step"),
+                (0.4, 0.8, "Should not reduce when improving"),
+                (0.6, 0.8, "Should not reduce within the patience"),
+                (0.6, 0.4, "Should reduce after the patience"),
+                (0.6, 0.4, "Should not reduce right after a reduction"),
+                (0.6, 0.2, "Should reduce again after the patience"),
+            ],
+        );
+    }
+
+    #[test]
+    fn wait_cooldown_after_reduction() {
+        let scheduler = scheduler().with_patience(0).with_cooldown(2);
+
+        test_plateau(
+            scheduler,
+            &[
+                (0.5, 0.8, "Should not reduce first step"),
+                (0.6, 0.4, "Should reduce without patience"),
+                (0.6, 0.4, "Should not reduce during cooldown"),
+                (0.6, 0.4, "Should not reduce during cooldown"),
+                (0.6, 0.2, "Should reduce after cooldown"),
+            ],
+        );
+    }
+
+    #[test]
+    fn never_go_below_min_lr() {
+        let scheduler = scheduler().with_patience(0).with_min_lr(0.3);
+
+        test_plateau(
+            scheduler,
+            &[
+                (0.5, 0.8, "Should not reduce first step"),
+                (0.6, 0.4, "Should reduce without patience"),
+                (0.6, 0.3, "Should reduce down to the minimum"),
+                (0.6, 0.3, "Should stay at the minimum"),
+            ],
+        );
+    }
+
+    #[test]
+    fn restore_state_from_record() {
+        let mut scheduler = scheduler().with_patience(0);
+        scheduler.lr = 0.4;
+        scheduler.best_value = 0.5;
+        scheduler.num_bad_steps = 1;
+
+        let record = scheduler.to_record::<TestBackend>();
+        let mut restored = self::scheduler().load_record::<TestBackend>(record);
+
+        assert_eq!(restored.step(), 0.4);
+        assert_eq!(restored.best_value, 0.5);
+        assert_eq!(restored.num_bad_steps, 1);
+    }
+
+    #[test]
+    fn monitor_the_configured_metric() {
+        let scheduler = PlateauLrScheduler::new::<LossMetric<TestBackend>>(
+            0.8,
+            Direction::Lowest,
+            Split::Valid,
+        );
+
+        assert_eq!(
+            scheduler.monitored_metric(),
+            Some(MonitoredMetric {
+                name: "Loss".to_string(),
+                valid: true,
+                aggregate: MetricAggregate::Mean,
+            })
+        );
+    }
+
+    #[test]
+    fn keep_lr_when_the_metric_is_missing() {
+        let mut scheduler = scheduler().with_patience(0);
+
+        scheduler.on_evaluation_end(1, Some(0.5));
+        scheduler.on_evaluation_end(2, None);
+
+        assert_eq!(scheduler.step(), 0.8);
+    }
+
+    fn scheduler() -> PlateauLrScheduler {
+        PlateauLrScheduler::new::<LossMetric<TestBackend>>(0.8, Direction::Lowest, Split::Train)
+            .with_factor(0.5)
+    }
+
+    fn test_plateau(mut scheduler: PlateauLrScheduler, data: &[(f64, LearningRate, &str)]) {
+        for (step, (point, lr, comment)) in (1..).zip(data) {
+            scheduler.on_evaluation_end(step, Some(*point));
+            assert_eq!(*lr, scheduler.step(), "{comment}");
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use burn_core::module::{AutodiffModule, HalfPrecision, Module};
+use burn_core::optim::{GradScaler, GradScalerRecord, GradientsParams};
+use burn_core::tensor::backend::AutodiffBackend;
+
+use crate::{TrainOutput, TrainStep};
+
+/// Mixed precision training, computing with the model weights
+/// [rounded](HalfPrecision::round_emulated) to half precision while the optimizer updates the
+/// full precision weights.
+///
+/// The loss is scaled by a [gradient scaler](GradScaler) to avoid the underflow of small
+/// gradients, the optimizer steps where the gradients overflow are skipped.
+#[derive(new, Clone, Debug)]
+pub struct MixedPrecision {
+    precision: HalfPrecision,
+    scaler: GradScaler,
+}
+
+impl MixedPrecision {
+    /// The copy of the model computing in half precision.
+    pub fn model<B: AutodiffBackend, M: AutodiffModule<B>>(&self, model: &M) -> M {
+        model.clone().half_precision(self.precision)
+    }
+
+    /// The scaler of the loss, used by [step_scaled](TrainStep::step_scaled).
+    pub fn scaler(&self) -> &GradScaler {
+        &self.scaler
+    }
+
+    /// The state of the scaler as a [record](GradScalerRecord), saved with the checkpoints.
+    pub fn scaler_record(&self) -> GradScalerRecord {
+        self.scaler.to_record()
+    }
+
+    /// Load the state of the scaler from a [record](GradScalerRecord), e.g. when resuming from a
+    /// checkpoint.
+    pub fn load_scaler_record(&mut self, record: GradScalerRecord) {
+        self.scaler = self.scaler.clone().load_record(record);
+    }
+
+    /// Runs the training step of the half precision copy of the model, returning the unscaled
+    /// gradients and whether they are finite.
+    ///
+    /// When the gradients overflow, they are all removed. The scale isn't updated, which must be
+    /// done once per optimizer step with [update](Self::update).
+    pub fn step<B, M, TI, TO>(&self, model: &M, item: TI) -> (TrainOutput<TO>, bool)
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B> + TrainStep<TI, TO>,
+    {
+        let output = self.model(model).step_scaled::<B>(item, &self.scaler);
+        let grads = self.unscale(output.grads, model);
+        let finite = grads.is_some();
+
+        let output = TrainOutput {
+            grads: grads.unwrap_or_default(),
+            item: output.item,
+        };
+
+        (output, finite)
+    }
+
+    /// Unscale the gradients of the given model, see [unscale](GradScaler::unscale).
+    pub fn unscale<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        grads: GradientsParams,
+        model: &M,
+    ) -> Option<GradientsParams> {
+        self.scaler.unscale(grads, model)
+    }
+
+    /// Update the scale after an optimizer step, given whether all its gradients were finite.
+    pub fn update(&mut self, finite: bool) {
+        self.scaler.update(finite);
+
+        if !finite {
+            log::warn!(
+                "Skipping the optimizer step with overflowing gradients, the loss scale is \
+                 reduced to {}",
+                self.scaler.scale()
+            );
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::metric::{Adaptor, LossInput, SegmentationInput};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// Semantic segmentation output adapted for multiple metrics.
+#[derive(new)]
+pub struct SegmentationOutput<B: Backend> {
+    /// The loss.
+    pub loss: Tensor<B, 1>,
+
+    /// The output of shape `[batch_size, num_classes, height, width]`.
+    pub output: Tensor<B, 4>,
+
+    /// The target classes of shape `[batch_size, height, width]`.
+    pub targets: Tensor<B, 3, Int>,
+}
+
+impl<B: Backend> Adaptor<LossInput<B>> for SegmentationOutput<B> {
+    fn adapt(&self) -> LossInput<B> {
+        LossInput::new(self.loss.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<SegmentationInput<B>> for SegmentationOutput<B> {
+    fn adapt(&self) -> SegmentationInput<B> {
+        SegmentationInput::new(self.output.clone(), self.targets.clone())
+    }
+}
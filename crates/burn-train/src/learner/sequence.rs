@@ -0,0 +1,127 @@
+use crate::metric::{Adaptor, LossInput, PerplexityInput, SequenceInput};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Bool, Int, Tensor};
+
+/// Sequence output adapted for multiple metrics, e.g. of language or speech recognition models.
+#[derive(new)]
+pub struct SequenceOutput<B: Backend> {
+    /// The loss.
+    pub loss: Tensor<B, 1>,
+
+    /// The logits of shape `[batch_size, seq_length, vocab_size]`.
+    pub output: Tensor<B, 3>,
+
+    /// The target tokens of shape `[batch_size, seq_length]`.
+    pub targets: Tensor<B, 2, Int>,
+
+    /// The padding mask of shape `[batch_size, seq_length]`, `true` for the padding tokens.
+    pub mask_pad: Option<Tensor<B, 2, Bool>>,
+
+    /// The generated tokens of each sequence, compared to the targets instead of the most
+    /// probable tokens of the output.
+    #[new(default)]
+    pub generated: Option<Vec<Vec<usize>>>,
+}
+
+impl<B: Backend> SequenceOutput<B> {
+    /// Sets the generated tokens of each sequence, e.g. decoded autoregressively, without padding.
+    pub fn with_generated(mut self, generated: Vec<Vec<usize>>) -> Self {
+        self.generated = Some(generated);
+        self
+    }
+
+    /// The tokens of each sequence of the given tensor, without padding.
+    fn sequences(&self, tokens: Tensor<B, 2, Int>) -> Vec<Vec<usize>> {
+        let [batch_size, seq_length] = tokens.dims();
+        let tokens = tokens
+            .into_data()
+            .iter::<i64>()
+            .map(|token| token as usize)
+            .collect::<Vec<_>>();
+        let mask_pad = match &self.mask_pad {
+            Some(mask_pad) => mask_pad.clone().into_data().iter::<bool>().collect(),
+            None => vec![false; batch_size * seq_length],
+        };
+
+        tokens
+            .chunks(seq_length.max(1))
+            .zip(mask_pad.chunks(seq_length.max(1)))
+            .map(|(tokens, mask_pad)| {
+                tokens
+                    .iter()
+                    .zip(mask_pad)
+                    .filter(|(_, pad)| !**pad)
+                    .map(|(token, _)| *token)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<B: Backend> Adaptor<LossInput<B>> for SequenceOutput<B> {
+    fn adapt(&self) -> LossInput<B> {
+        LossInput::new(self.loss.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<PerplexityInput<B>> for SequenceOutput<B> {
+    fn adapt(&self) -> PerplexityInput<B> {
+        PerplexityInput::new(
+            self.output.clone(),
+            self.targets.clone(),
+            self.mask_pad.clone(),
+        )
+    }
+}
+
+impl<B: Backend> Adaptor<SequenceInput> for SequenceOutput<B> {
+    fn adapt(&self) -> SequenceInput {
+        let references = self.sequences(self.targets.clone());
+        let predictions = match &self.generated {
+            Some(generated) => generated.clone(),
+            None => {
+                let [batch_size, seq_length, _vocab_size] = self.output.dims();
+                let predictions = self
+                    .output
+                    .clone()
+                    .argmax(2)
+                    .reshape([batch_size, seq_length]);
+                self.sequences(predictions)
+            }
+        };
+
+        SequenceInput::new(predictions, references)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_remove_the_padding_of_the_sequences() {
+        let device = Default::default();
+        let output = SequenceOutput::<TestBackend>::new(
+            Tensor::zeros([2], &device),
+            Tensor::from_data(
+                [
+                    [[0.9, 0.1, 0.0], [0.1, 0.9, 0.0], [0.0, 0.0, 1.0]],
+                    [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+                ],
+                &device,
+            ),
+            Tensor::from_data([[0, 2, 0], [2, 0, 0]], &device),
+            Some(Tensor::from_data(
+                [[false, false, true], [false, true, true]],
+                &device,
+            )),
+        );
+
+        let input: SequenceInput = output.adapt();
+
+        let pairs = input.pairs().collect::<Vec<_>>();
+        assert_eq!(pairs[0], (&[0, 1][..], &[0, 2][..]));
+        assert_eq!(pairs[1], (&[2][..], &[2][..]));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{TrainOutput, TrainStep};
 use burn_core::{
-    data::dataloader::DataLoaderIterator, module::AutodiffModule, tensor::backend::AutodiffBackend,
+    data::dataloader::DataLoaderIterator, module::AutodiffModule, optim::GradScaler,
+    tensor::backend::AutodiffBackend,
 };
 use std::sync::mpsc::{Receiver, Sender};
 use std::thread::spawn;
@@ -14,6 +15,7 @@ pub struct MultiDevicesTrainStep<B: AutodiffBackend, M, TI, TO> {
 struct Message<M, TI> {
     item: TI,
     model: M,
+    scaler: Option<GradScaler>,
 }
 
 struct Worker<B: AutodiffBackend, M, TI> {
@@ -26,10 +28,11 @@ where
     B: AutodiffBackend,
     M: AutodiffModule<B>,
 {
-    fn register(&self, item: TI, model: &M) {
+    fn register(&self, item: TI, model: &M, scaler: Option<&GradScaler>) {
         let message = Message {
             item,
             model: model.clone(),
+            scaler: scaler.cloned(),
         };
         self.sender_input.send(message).unwrap();
     }
@@ -49,7 +52,10 @@ where
             match receiver_input.recv() {
                 Ok(item) => {
                     let step = item.model.fork(&device);
-                    let output = step.step(item.item);
+                    let output = match &item.scaler {
+                        Some(scaler) => step.step_scaled::<B>(item.item, scaler),
+                        None => step.step(item.item),
+                    };
 
                     sender_output.send(output).unwrap();
                 }
@@ -109,6 +115,7 @@ where
     ///
     /// * `dataloader` - Dataloader.
     /// * `model` - Model.
+    /// * `scaler` - The scaler of the loss, when training with mixed precision.
     ///
     /// # Returns
     ///
@@ -117,12 +124,13 @@ where
         &self,
         dataloader: &mut Box<dyn DataLoaderIterator<TI> + 'a>,
         model: &M,
+        scaler: Option<&GradScaler>,
     ) -> Vec<TrainOutput<TO>> {
         let mut num_send = 0;
 
         for worker in self.workers.iter() {
             if let Some(item) = dataloader.next() {
-                worker.register(item, model);
+                worker.register(item, model, scaler);
                 num_send += 1;
             }
         }
@@ -5,13 +5,14 @@ use std::{
 };
 
 use crate::{
-    logger::FileMetricLogger,
+    logger::{FileMetricLogger, TensorBoardWriter},
     metric::store::{Aggregate, EventStore, LogEventStore, Split},
 };
 
 /// Contains the metric value at a given time.
 pub struct MetricEntry {
-    /// The step at which the metric was recorded (i.e., epoch).
+    /// The step at which the metric was recorded (i.e., epoch, unless the epochs are divided by
+    /// intermediate evaluations).
     pub step: usize,
     /// The metric value.
     pub value: f64,
@@ -59,14 +60,30 @@ pub struct SummaryMetrics {
     pub valid: Vec<MetricSummary>,
 }
 
+/// The position in the training of a step ended by an intermediate evaluation or by the end of
+/// an epoch.
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct SummaryStep {
+    /// The step number.
+    pub step: usize,
+    /// The epoch of the step.
+    pub epoch: usize,
+    /// The training iteration in the epoch at which the step ended.
+    pub iteration: usize,
+}
+
 /// Detailed training summary.
 pub struct LearnerSummary {
-    /// The number of epochs completed.
+    /// The number of epochs completed, or of steps when the epochs are divided by intermediate
+    /// evaluations.
     pub epochs: usize,
     /// The summary of recorded metrics during training.
     pub metrics: SummaryMetrics,
     /// The model name (only recorded within the learner).
     pub(crate) model: Option<String>,
+    /// The steps of the epochs divided by intermediate evaluations (only recorded within the
+    /// learner).
+    pub(crate) steps: Vec<SummaryStep>,
 }
 
 impl LearnerSummary {
@@ -125,6 +142,7 @@ impl LearnerSummary {
                 valid: valid_summary,
             },
             model: None,
+            steps: Vec::new(),
         })
     }
 
@@ -132,6 +150,11 @@ impl LearnerSummary {
         self.model = Some(name);
         self
     }
+
+    pub(crate) fn with_steps(mut self, steps: Vec<SummaryStep>) -> Self {
+        self.steps = steps;
+        self
+    }
 }
 
 impl Display for LearnerSummary {
@@ -160,12 +183,26 @@ impl Display for LearnerSummary {
         if let Some(model) = &self.model {
             writeln!(f, "Model:\n{model}")?;
         }
-        writeln!(f, "Total Epochs: {epochs}\n\n", epochs = self.epochs)?;
+        let step_name = match self.steps.last() {
+            Some(last) => {
+                writeln!(
+                    f,
+                    "Total Epochs: {epochs}\nTotal Steps: {steps}\n\n",
+                    epochs = last.epoch,
+                    steps = self.epochs
+                )?;
+                "Step"
+            }
+            None => {
+                writeln!(f, "Total Epochs: {epochs}\n\n", epochs = self.epochs)?;
+                "Epoch"
+            }
+        };
 
         // Metrics table header
         writeln!(
             f,
-            "| {:<width_split$} | {:<width_metric$} | Min.     | Epoch    | Max.     | Epoch    |\n|{:->width_split$}--|{:->width_metric$}--|----------|----------|----------|----------|",
+            "| {:<width_split$} | {:<width_metric$} | Min.     | {step_name:<9}| Max.     | {step_name:<9}|\n|{:->width_split$}--|{:->width_metric$}--|----------|----------|----------|----------|",
             "Split", "Metric", "", "",
             width_split = max_split_len,
             width_metric = max_metric_len,
@@ -229,6 +266,21 @@ impl Display for LearnerSummary {
         write_metrics_summary(&self.metrics.train, split_train)?;
         write_metrics_summary(&self.metrics.valid, split_valid)?;
 
+        // Position of each step in the training
+        if !self.steps.is_empty() {
+            writeln!(
+                f,
+                "\n| Step     | Epoch    | Iteration |\n|----------|----------|-----------|"
+            )?;
+            for step in self.steps.iter() {
+                writeln!(
+                    f,
+                    "| {:<9}| {:<9}| {:<10}|",
+                    step.step, step.epoch, step.iteration
+                )?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -236,12 +288,23 @@ impl Display for LearnerSummary {
 pub(crate) struct LearnerSummaryConfig {
     pub(crate) directory: PathBuf,
     pub(crate) metrics: Vec<String>,
+    /// The TensorBoard directory where the summary is logged.
+    pub(crate) tensorboard: Option<PathBuf>,
 }
 
 impl LearnerSummaryConfig {
     pub fn init(&self) -> Result<LearnerSummary, String> {
         LearnerSummary::new(&self.directory, &self.metrics[..])
     }
+
+    /// Logs the summary as text in the TensorBoard training run, if enabled.
+    pub(crate) fn log(&self, summary: &LearnerSummary) {
+        if let Some(directory) = &self.tensorboard {
+            let mut writer = TensorBoardWriter::new(directory.join("train"));
+            // Displayed as a code block to keep the alignment of the table.
+            writer.add_text("summary", &format!("```\n{summary}\n```"), summary.epochs);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -319,4 +382,32 @@ mod tests {
 
         std::fs::remove_dir_all(dir).unwrap();
     }
+
+    #[test]
+    fn test_summary_should_display_the_steps() {
+        let dir = Path::new("/tmp/test-learner-summary-steps");
+        for step in 1..=3 {
+            let train_dir = dir.join(format!("train/epoch-{step}"));
+            std::fs::create_dir_all(&train_dir).unwrap();
+            std::fs::write(train_dir.join("Loss.log"), format!("{step}.0"))
+                .expect("Unable to write file");
+        }
+        std::fs::create_dir_all(dir.join("valid")).unwrap();
+
+        let summary = LearnerSummary::new(dir.to_str().unwrap(), &["Loss"])
+            .expect("Summary artifacts should exist")
+            .with_steps(vec![
+                SummaryStep::new(1, 1, 100),
+                SummaryStep::new(2, 1, 150),
+                SummaryStep::new(3, 2, 100),
+            ]);
+        let output = summary.to_string();
+
+        assert_eq!(summary.epochs, 3);
+        assert!(output.contains("Total Epochs: 2\nTotal Steps: 3"));
+        assert!(output.contains("| Min.     | Step     | Max.     | Step     |"));
+        assert!(output.contains("| 2        | 1        | 150       |"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
 }
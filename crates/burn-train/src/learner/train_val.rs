@@ -1,11 +1,14 @@
 use crate::components::LearnerComponents;
 use crate::metric::processor::EventProcessor;
-use crate::{Learner, TrainEpoch, ValidEpoch};
+use crate::metric::store::{self, Aggregate, Split};
+use crate::{Learner, MixedPrecision, SummaryStep, TrainEpoch, TrainingState, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
-use burn_core::module::{AutodiffModule, Module};
-use burn_core::optim::{GradientsParams, Optimizer};
+use burn_core::lr_scheduler::{LrScheduler, MetricAggregate};
+use burn_core::module::{AutodiffModule, ModelEma, Module};
+use burn_core::optim::{GradScaler, GradientsParams, Optimizer};
 use burn_core::tensor::backend::AutodiffBackend;
 use std::sync::Arc;
+use std::time::Instant;
 
 /// A training output.
 pub struct TrainOutput<TO> {
@@ -62,6 +65,50 @@ pub trait TrainStep<TI, TO> {
     ///
     /// The training output containing the model output and the gradients.
     fn step(&self, item: TI) -> TrainOutput<TO>;
+    /// Runs the training step for mixed precision training, where the loss must be multiplied
+    /// by the [scaler](GradScaler) before the backward pass.
+    ///
+    /// It is called on a copy of the model with half precision weights when training with
+    /// [mixed precision](crate::LearnerBuilder::mixed_precision). By default, the gradients of
+    /// [step](TrainStep::step) are [scaled](GradScaler::scale_gradients) after the backward pass,
+    /// which doesn't prevent their underflow. It should be overridden to scale the loss instead:
+    ///
+    /// ```rust, ignore
+    /// fn step_scaled<BA: AutodiffBackend>(
+    ///     &self,
+    ///     item: MnistBatch<B>,
+    ///     scaler: &GradScaler,
+    /// ) -> TrainOutput<ClassificationOutput<B>>
+    /// where
+    ///     Self: AutodiffModule<BA>,
+    /// {
+    ///     let item = self.forward_classification(item.images, item.targets);
+    ///     let grads = scaler.scale_loss(item.loss.clone()).backward();
+    ///
+    ///     TrainOutput::new(self, grads, item)
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The training input for the model.
+    /// * `scaler` - The scaler of the loss.
+    ///
+    /// # Returns
+    ///
+    /// The training output containing the model output and the scaled gradients.
+    fn step_scaled<B>(&self, item: TI, scaler: &GradScaler) -> TrainOutput<TO>
+    where
+        B: AutodiffBackend,
+        Self: AutodiffModule<B>,
+    {
+        let output = self.step(item);
+
+        TrainOutput {
+            grads: scaler.scale_gradients(output.grads, self),
+            item: output.item,
+        }
+    }
     /// Optimize the current module with the provided gradients and learning rate.
     ///
     /// # Arguments
@@ -128,85 +175,333 @@ impl<LC: LearnerComponents> Learner<LC> {
             self.model = self.model.fork(device);
         }
 
-        let starting_epoch = match self.checkpoint {
+        let mut state = TrainingState::new(0, true, 0, 0).with_seed(self.seed);
+        let (starting_epoch, starting_step) = match self.checkpoint {
             Some(checkpoint) => {
+                state.epoch = checkpoint;
                 if let Some(checkpointer) = &mut self.checkpointer {
-                    (self.model, self.optim, self.lr_scheduler) = checkpointer.load_checkpoint(
-                        self.model,
-                        self.optim,
-                        self.lr_scheduler,
-                        &Default::default(), // Load the checkpoint on the default device.
-                        checkpoint,
-                    );
+                    let state_loaded;
+                    (self.model, self.optim, self.lr_scheduler, state_loaded) = checkpointer
+                        .load_checkpoint(
+                            self.model,
+                            self.optim,
+                            self.lr_scheduler,
+                            &Default::default(), // Load the checkpoint on the default device.
+                            checkpoint,
+                        );
+                    state.epoch = state_loaded.epoch;
+                    state.position = state_loaded.position;
+                    state.step_completed = state_loaded.step_completed;
+                    state.num_validations = state_loaded.num_validations;
+                    state.num_iterations = state_loaded.num_iterations;
+                    state.learning_rate = state_loaded.learning_rate;
+                    state.grad_scaler = state_loaded.grad_scaler;
+                    state.seed = state_loaded.seed.or(state.seed);
                 }
-                checkpoint + 1
+
+                // An interrupted epoch is resumed, otherwise the training starts at the next one.
+                let epoch = match state.position {
+                    Some(_) => state.epoch,
+                    None => state.epoch + 1,
+                };
+                // The same goes for the steps, which are the epochs without intermediate
+                // evaluations.
+                let step = match state.step_completed {
+                    true => checkpoint + 1,
+                    false => checkpoint,
+                };
+
+                (epoch, step)
             }
-            None => 1,
+            None => (1, 1),
         };
 
-        for epoch in starting_epoch..self.num_epochs + 1 {
-            let epoch_train = TrainEpoch::new(
+        // The data loaders create one iteration per epoch or validation, their random state is
+        // restored accordingly.
+        if starting_epoch > 1 {
+            dataloader_train.restore_iterations(starting_epoch - 1);
+        }
+        if state.num_validations > 0 {
+            dataloader_valid.restore_iterations(state.num_validations);
+        }
+
+        // The moving average is restored with the checkpoint, otherwise it starts from the
+        // weights the training resumes from.
+        let mut ema = self
+            .model_ema
+            .as_ref()
+            .map(|config| config.init(&self.model));
+        if let (Some(checkpoint), Some(checkpointer)) = (self.checkpoint, &self.checkpointer) {
+            ema = ema.map(|ema| checkpointer.load_ema(ema, &Default::default(), checkpoint));
+        }
+
+        let mut step = starting_step;
+        let mut steps = Vec::new();
+        let mut last_checkpoint = Instant::now();
+
+        self.callbacks
+            .restore_learning_rate_override(state.learning_rate);
+        if let (Some(precision), Some(record)) = (&mut self.mixed_precision, state.grad_scaler) {
+            precision.load_scaler_record(record);
+        }
+        self.callbacks.on_train_begin();
+
+        'epochs: for epoch in starting_epoch..self.num_epochs + 1 {
+            self.callbacks.on_epoch_start(epoch);
+            if self.callbacks.should_stop() {
+                break;
+            }
+
+            let mut epoch_train = TrainEpoch::new(
                 dataloader_train.clone(),
                 epoch,
                 self.num_epochs,
                 self.grad_accumulation,
-            );
-
-            if self.devices.len() > 1 {
-                (self.model, self.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
-                    self.model,
-                    self.optim,
-                    &mut self.lr_scheduler,
-                    &mut self.event_processor,
-                    self.devices.clone(),
-                    &self.interrupter,
-                )
-            } else {
-                (self.model, self.optim) = epoch_train.run::<LC, OutputTrain>(
-                    self.model,
-                    self.optim,
-                    &mut self.lr_scheduler,
-                    &mut self.event_processor,
-                    &self.interrupter,
-                );
+            )
+            .with_seed(state.seed)
+            .with_gradient_stats(self.gradient_stats);
+            if let Some(position) = state.position.take() {
+                epoch_train = epoch_train.resume(position);
             }
+            let mut iterator = epoch_train.iterator();
 
-            if self.interrupter.should_stop() {
-                break;
-            }
+            // The epoch is run in steps, pausing for the intermediate evaluations.
+            let position = loop {
+                let previous = iterator.iteration();
+                // The metrics of each step are logged with its index, and the metrics of each
+                // iteration with the number of iterations done since the start of the training.
+                self.event_store
+                    .add_event_train(store::Event::StartEpoch(step, state.num_iterations));
+                iterator.start_step(
+                    step,
+                    self.evaluation.next_iteration(previous),
+                    self.evaluation.deadline(last_checkpoint),
+                );
+
+                let position;
+                if self.devices.len() > 1 {
+                    (self.model, self.optim, position) = epoch_train
+                        .run_multi_device::<LC, OutputTrain>(
+                            &mut iterator,
+                            self.model,
+                            self.optim,
+                            &mut self.lr_scheduler,
+                            ema.as_mut(),
+                            self.mixed_precision.as_mut(),
+                            &mut self.event_processor,
+                            &mut self.callbacks,
+                            self.devices.clone(),
+                            &self.interrupter,
+                        )
+                } else {
+                    (self.model, self.optim, position) = epoch_train.run::<LC, OutputTrain>(
+                        &mut iterator,
+                        self.model,
+                        self.optim,
+                        &mut self.lr_scheduler,
+                        ema.as_mut(),
+                        self.mixed_precision.as_mut(),
+                        &mut self.event_processor,
+                        &mut self.callbacks,
+                        &self.interrupter,
+                    );
+                }
+                state.num_iterations += position.iteration - previous;
+
+                // When enabled, the moving average replaces the model for validation.
+                let model = ema.as_ref().map(ModelEma::module).unwrap_or(&self.model);
+
+                if self.interrupter.should_stop() {
+                    // Save where the epoch stopped, so it can be resumed.
+                    if let Some(checkpointer) = &self.checkpointer {
+                        let state = TrainingState::new(
+                            epoch,
+                            false,
+                            state.num_validations,
+                            state.num_iterations,
+                        )
+                        .with_position(position)
+                        .with_learning_rate(self.callbacks.learning_rate_override())
+                        .with_grad_scaler(
+                            self.mixed_precision
+                                .as_ref()
+                                .map(MixedPrecision::scaler_record),
+                        )
+                        .with_seed(state.seed);
+                        checkpointer.save(
+                            &self.model,
+                            ema.as_ref(),
+                            &self.optim,
+                            &self.lr_scheduler,
+                            state,
+                            step,
+                        );
+                    }
+                    break 'epochs;
+                }
 
-            let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
-            epoch_valid.run::<LC, OutputValid>(
-                &self.model,
-                &mut self.event_processor,
-                &self.interrupter,
-            );
+                if iterator.is_finished() {
+                    break position;
+                }
+
+                // Stopped by the callbacks in the middle of the step, which isn't resumed.
+                if self.callbacks.should_stop() {
+                    break 'epochs;
+                }
+
+                let validate = self
+                    .evaluation
+                    .should_validate(previous, position.iteration);
+                if validate {
+                    // The validation metrics are indexed by steps, which skip the ones without
+                    // validation.
+                    self.event_store
+                        .add_event_valid(store::Event::StartEpoch(step, state.num_iterations));
+                    state.num_validations += 1;
+                    ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs)
+                        .with_step(step)
+                        .run::<LC, OutputValid>(
+                            model,
+                            &mut self.event_processor,
+                            &self.interrupter,
+                        );
+                    self.callbacks
+                        .on_validation_end(step, self.event_store.as_ref());
+                }
+
+                let should_checkpoint = self.evaluation.should_checkpoint(
+                    previous,
+                    position.iteration,
+                    last_checkpoint,
+                );
+                steps.push(SummaryStep::new(step, epoch, position.iteration));
+
+                if should_checkpoint {
+                    if let Some(checkpointer) = &mut self.checkpointer {
+                        let state = TrainingState::new(
+                            epoch,
+                            true,
+                            state.num_validations,
+                            state.num_iterations,
+                        )
+                        .with_position(position)
+                        .with_learning_rate(self.callbacks.learning_rate_override())
+                        .with_grad_scaler(
+                            self.mixed_precision
+                                .as_ref()
+                                .map(MixedPrecision::scaler_record),
+                        )
+                        .with_seed(state.seed);
+                        let saved = checkpointer.checkpoint(
+                            &self.model,
+                            ema.as_ref(),
+                            &self.optim,
+                            &self.lr_scheduler,
+                            state,
+                            step,
+                            &self.event_store,
+                        );
+                        if saved {
+                            self.callbacks.on_checkpoint(step);
+                        }
+                    }
+                    last_checkpoint = Instant::now();
+                }
+
+                if validate {
+                    if let Some(early_stopping) = &mut self.early_stopping {
+                        if early_stopping.should_stop(step, &self.event_store) {
+                            break 'epochs;
+                        }
+                    }
+                }
+
+                // The callbacks may have requested to stop once the step is completed.
+                if self.callbacks.should_stop() {
+                    break 'epochs;
+                }
+
+                step += 1;
+            };
+
+            let model = ema.as_ref().map(ModelEma::module).unwrap_or(&self.model);
+
+            self.event_store
+                .add_event_valid(store::Event::StartEpoch(step, state.num_iterations));
+            state.num_validations += 1;
+            let epoch_valid =
+                ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs).with_step(step);
+            epoch_valid.run::<LC, OutputValid>(model, &mut self.event_processor, &self.interrupter);
+            self.callbacks
+                .on_validation_end(step, self.event_store.as_ref());
+
+            // The metrics are indexed by steps, the scheduler is updated at each evaluation step.
+            let metric = self.lr_scheduler.monitored_metric().and_then(|metric| {
+                let split = match metric.valid {
+                    true => Split::Valid,
+                    false => Split::Train,
+                };
+                let aggregate = match metric.aggregate {
+                    MetricAggregate::Mean => Aggregate::Mean,
+                };
+                self.event_store
+                    .find_metric(&metric.name, step, aggregate, split)
+            });
+            self.lr_scheduler.on_evaluation_end(step, metric);
 
             if let Some(checkpointer) = &mut self.checkpointer {
-                checkpointer.checkpoint(
+                let saved = checkpointer.checkpoint(
                     &self.model,
+                    ema.as_ref(),
                     &self.optim,
                     &self.lr_scheduler,
-                    epoch,
+                    TrainingState::new(epoch, true, state.num_validations, state.num_iterations)
+                        .with_learning_rate(self.callbacks.learning_rate_override())
+                        .with_grad_scaler(
+                            self.mixed_precision
+                                .as_ref()
+                                .map(MixedPrecision::scaler_record),
+                        )
+                        .with_seed(state.seed),
+                    step,
                     &self.event_store,
                 );
+                if saved {
+                    self.callbacks.on_checkpoint(step);
+                }
             }
+            last_checkpoint = Instant::now();
 
-            if let Some(early_stopping) = &mut self.early_stopping {
-                if early_stopping.should_stop(epoch, &self.event_store) {
-                    break;
-                }
+            self.callbacks.on_epoch_end(epoch);
+
+            if self.evaluation.is_enabled() {
+                steps.push(SummaryStep::new(step, epoch, position.iteration));
+            }
+
+            let early_stop = match &mut self.early_stopping {
+                Some(early_stopping) => early_stopping.should_stop(step, &self.event_store),
+                None => false,
+            };
+            // The training may have been interrupted or stopped by the callbacks once the epoch
+            // is completed.
+            if early_stop || self.callbacks.should_stop() || self.interrupter.should_stop() {
+                break;
             }
+
+            step += 1;
         }
 
         // Display learner summary
-        if let Some(summary) = self.summary {
-            match summary.init() {
+        if let Some(config) = self.summary {
+            match config.init() {
                 Ok(summary) => {
+                    let summary = summary.with_model(self.model.to_string()).with_steps(steps);
+                    config.log(&summary);
+
                     // Drop event processor (includes renderer) so the summary is displayed
                     // when switching back to "main" screen
                     core::mem::drop(self.event_processor);
-                    println!("{}", summary.with_model(self.model.to_string()))
+                    println!("{summary}")
                 }
                 Err(err) => log::error!("Could not retrieve learner summary:\n{err}"),
             }
@@ -24,6 +24,17 @@ pub trait MetricLogger: Send {
     /// * `epoch` - The epoch.
     fn end_epoch(&mut self, epoch: usize);
 
+    /// Starts logging the items of the given epoch.
+    ///
+    /// Items are logged to the epoch following the last ended one by default, so this is only
+    /// needed when some epochs aren't logged, e.g. when resuming the training.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The epoch.
+    /// * `iteration` - The number of training iterations done before the epoch.
+    fn start_epoch(&mut self, epoch: usize, iteration: usize);
+
     /// Read the logs for an epoch.
     fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<NumericEntry>, String>;
 }
@@ -127,6 +138,11 @@ impl MetricLogger for FileMetricLogger {
         self.epoch = epoch + 1;
     }
 
+    fn start_epoch(&mut self, epoch: usize, _iteration: usize) {
+        self.loggers.clear();
+        self.epoch = epoch;
+    }
+
     fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<NumericEntry>, String> {
         if let Some(value) = self.loggers.get(name) {
             value.sync()
@@ -167,6 +183,7 @@ impl MetricLogger for FileMetricLogger {
 #[derive(Default)]
 pub struct InMemoryMetricLogger {
     values: HashMap<String, Vec<InMemoryLogger>>,
+    last_epoch: usize,
 }
 
 impl InMemoryMetricLogger {
@@ -177,20 +194,21 @@ impl InMemoryMetricLogger {
 }
 impl MetricLogger for InMemoryMetricLogger {
     fn log(&mut self, item: &MetricEntry) {
-        if !self.values.contains_key(&item.name) {
-            self.values
-                .insert(item.name.clone(), vec![InMemoryLogger::default()]);
+        let values = self.values.entry(item.name.clone()).or_default();
+
+        if values.len() <= self.last_epoch {
+            values.resize_with(self.last_epoch + 1, InMemoryLogger::default);
         }
 
-        let values = self.values.get_mut(&item.name).unwrap();
+        values[self.last_epoch].log(item.serialize.clone());
+    }
 
-        values.last_mut().unwrap().log(item.serialize.clone());
+    fn end_epoch(&mut self, epoch: usize) {
+        self.last_epoch = epoch;
     }
 
-    fn end_epoch(&mut self, _epoch: usize) {
-        for (_, values) in self.values.iter_mut() {
-            values.push(InMemoryLogger::default());
-        }
+    fn start_epoch(&mut self, epoch: usize, _iteration: usize) {
+        self.last_epoch = epoch.saturating_sub(1);
     }
 
     fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<NumericEntry>, String> {
@@ -0,0 +1,448 @@
+use super::{InMemoryMetricLogger, MetricLogger};
+use crate::metric::{MetricEntry, NumericEntry};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Number of event files created by the process, keeping their names unique.
+static NUM_EVENT_FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// Writer of TensorBoard event files.
+///
+/// Each directory is displayed as a run by TensorBoard, all the event files of a directory being
+/// read together.
+pub struct TensorBoardWriter {
+    file: BufWriter<File>,
+    path: PathBuf,
+}
+
+impl TensorBoardWriter {
+    /// Create a new event file in the given directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory of the run.
+    ///
+    /// # Returns
+    ///
+    /// The TensorBoard writer.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory).ok();
+
+        let name = format!(
+            "events.out.tfevents.{}.burn.{}.{}",
+            wall_time() as u64,
+            std::process::id(),
+            NUM_EVENT_FILES.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = directory.join(name);
+        let file = File::create(&path).unwrap_or_else(|err| {
+            panic!(
+                "Should be able to create the new file '{}': {}",
+                path.display(),
+                err
+            )
+        });
+
+        let mut writer = Self {
+            file: BufWriter::new(file),
+            path,
+        };
+
+        let mut event = Vec::new();
+        encode_double(&mut event, 1, wall_time());
+        encode_bytes(&mut event, 3, b"brain.Event:2");
+        writer.write_record(&event);
+
+        writer
+    }
+
+    /// The path of the event file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Logs a scalar value.
+    pub fn add_scalar(&mut self, tag: &str, value: f64, step: usize) {
+        let mut summary_value = Vec::new();
+        encode_bytes(&mut summary_value, 1, tag.as_bytes());
+        encode_float(&mut summary_value, 2, value as f32);
+
+        self.write_summary(&summary_value, step);
+    }
+
+    /// Logs a text, rendered as markdown by TensorBoard.
+    pub fn add_text(&mut self, tag: &str, text: &str, step: usize) {
+        let mut plugin_data = Vec::new();
+        encode_bytes(&mut plugin_data, 1, b"text");
+        let mut metadata = Vec::new();
+        encode_bytes(&mut metadata, 1, &plugin_data);
+
+        // A scalar string tensor.
+        let mut tensor = Vec::new();
+        encode_varint_field(&mut tensor, 1, DT_STRING);
+        encode_bytes(&mut tensor, 2, &[]);
+        encode_bytes(&mut tensor, 8, text.as_bytes());
+
+        let mut summary_value = Vec::new();
+        encode_bytes(&mut summary_value, 1, tag.as_bytes());
+        encode_bytes(&mut summary_value, 8, &tensor);
+        encode_bytes(&mut summary_value, 9, &metadata);
+
+        self.write_summary(&summary_value, step);
+    }
+
+    /// Writes the buffered events to the file.
+    pub fn flush(&mut self) {
+        self.file.flush().expect("Can flush the event file.");
+    }
+
+    fn write_summary(&mut self, summary_value: &[u8], step: usize) {
+        let mut summary = Vec::new();
+        encode_bytes(&mut summary, 1, summary_value);
+
+        let mut event = Vec::new();
+        encode_double(&mut event, 1, wall_time());
+        encode_varint_field(&mut event, 2, step as u64);
+        encode_bytes(&mut event, 5, &summary);
+
+        self.write_record(&event);
+    }
+
+    /// Writes a record of the TFRecord format: the length, its checksum, the data and its
+    /// checksum.
+    fn write_record(&mut self, data: &[u8]) {
+        let length = (data.len() as u64).to_le_bytes();
+
+        let mut record = Vec::with_capacity(data.len() + 16);
+        record.extend_from_slice(&length);
+        record.extend_from_slice(&masked_crc32c(&length).to_le_bytes());
+        record.extend_from_slice(data);
+        record.extend_from_slice(&masked_crc32c(data).to_le_bytes());
+
+        self.file.write_all(&record).expect("Can log an item.");
+    }
+}
+
+impl Drop for TensorBoardWriter {
+    fn drop(&mut self) {
+        self.file.flush().ok();
+    }
+}
+
+/// The `DT_STRING` data type of the tensors.
+const DT_STRING: u64 = 7;
+
+fn wall_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64())
+        .unwrap_or_default()
+}
+
+fn encode_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn encode_key(buffer: &mut Vec<u8>, field: u64, wire_type: u64) {
+    encode_varint(buffer, (field << 3) | wire_type);
+}
+
+fn encode_varint_field(buffer: &mut Vec<u8>, field: u64, value: u64) {
+    encode_key(buffer, field, 0);
+    encode_varint(buffer, value);
+}
+
+fn encode_double(buffer: &mut Vec<u8>, field: u64, value: f64) {
+    encode_key(buffer, field, 1);
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn encode_float(buffer: &mut Vec<u8>, field: u64, value: f32) {
+    encode_key(buffer, field, 5);
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn encode_bytes(buffer: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    encode_key(buffer, field, 2);
+    encode_varint(buffer, bytes.len() as u64);
+    buffer.extend_from_slice(bytes);
+}
+
+/// The CRC-32C (Castagnoli) checksum.
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0x82F6_3B78,
+                _ => crc >> 1,
+            };
+        }
+    }
+    !crc
+}
+
+/// The checksum of the TFRecord format.
+fn masked_crc32c(data: &[u8]) -> u32 {
+    let crc = crc32c(data);
+    ((crc >> 15) | (crc << 17)).wrapping_add(0xA282_EAD8)
+}
+
+/// The TensorBoard metric logger, writing the numeric metrics to event files.
+///
+/// The value of each iteration is logged under the `iteration/` tags, and the value of each
+/// epoch, the mean of its iterations or the value of the [epoch metrics](crate::metric::EpochMetric),
+/// under the `epoch/` tags. Use a different directory for the training and the validation
+/// loggers, e.g. `train` and `valid`, so they are displayed as two runs.
+///
+/// The iterations are counted from the number of training iterations done before each
+/// [epoch](MetricLogger::start_epoch), so they continue after a resumed training and the
+/// validation iterations are aligned with the training ones.
+///
+/// The values are also kept in memory to be read by the learner.
+pub struct TensorBoardMetricLogger {
+    writer: TensorBoardWriter,
+    values: InMemoryMetricLogger,
+    /// The number of iterations done before the current epoch.
+    iteration: usize,
+    /// The number of iterations of each metric in the current epoch.
+    iterations: HashMap<String, usize>,
+    epoch_values: HashMap<String, EpochValue>,
+}
+
+/// The value of a metric over the current epoch.
+#[derive(Default)]
+struct EpochValue {
+    sum: f64,
+    num_items: usize,
+    epoch: Option<f64>,
+}
+
+impl TensorBoardMetricLogger {
+    /// Create a new TensorBoard metric logger.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory of the run.
+    ///
+    /// # Returns
+    ///
+    /// The TensorBoard metric logger.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            writer: TensorBoardWriter::new(directory),
+            values: InMemoryMetricLogger::new(),
+            iteration: 0,
+            iterations: HashMap::new(),
+            epoch_values: HashMap::new(),
+        }
+    }
+}
+
+impl MetricLogger for TensorBoardMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        self.values.log(item);
+
+        // The non-numeric metrics are not logged.
+        let Ok(entry) = NumericEntry::deserialize(&item.serialize) else {
+            return;
+        };
+        let epoch_value = self.epoch_values.entry(item.name.clone()).or_default();
+
+        let (value, num_items) = match entry {
+            NumericEntry::Value(value) => (value, 1),
+            NumericEntry::Aggregated(value, num_items) => (value, num_items),
+            NumericEntry::Epoch(value) => {
+                epoch_value.epoch = Some(value);
+                return;
+            }
+        };
+        epoch_value.sum += value * num_items as f64;
+        epoch_value.num_items += num_items;
+
+        let iteration = self.iterations.entry(item.name.clone()).or_default();
+        *iteration += 1;
+        self.writer.add_scalar(
+            &format!("iteration/{}", item.name),
+            value,
+            self.iteration + *iteration,
+        );
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        let mut epoch_values = self.epoch_values.drain().collect::<Vec<_>>();
+        epoch_values.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        for (name, value) in epoch_values {
+            let value = match value.epoch {
+                Some(value) => value,
+                None if value.num_items > 0 => value.sum / value.num_items as f64,
+                None => continue,
+            };
+            self.writer
+                .add_scalar(&format!("epoch/{name}"), value, epoch);
+        }
+
+        self.writer.flush();
+        self.values.end_epoch(epoch);
+
+        // The next epoch continues after the iterations of this one, unless it is started with
+        // the number of iterations done.
+        self.iteration += self
+            .iterations
+            .drain()
+            .map(|(_, num)| num)
+            .max()
+            .unwrap_or(0);
+    }
+
+    fn start_epoch(&mut self, epoch: usize, iteration: usize) {
+        self.epoch_values.clear();
+        self.iterations.clear();
+        self.iteration = iteration;
+        self.values.start_epoch(epoch, iteration);
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<NumericEntry>, String> {
+        self.values.read_numeric(name, epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The data of the records of an event file, checking their length and checksums.
+    fn read_records(path: &Path) -> Vec<Vec<u8>> {
+        let bytes = std::fs::read(path).unwrap();
+        let mut records = Vec::new();
+        let mut position = 0;
+
+        while position < bytes.len() {
+            let length = &bytes[position..position + 8];
+            let length_crc =
+                u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
+            assert_eq!(length_crc, masked_crc32c(length));
+
+            let length = u64::from_le_bytes(length.try_into().unwrap()) as usize;
+            let data = &bytes[position + 12..position + 12 + length];
+            let data_crc = u32::from_le_bytes(
+                bytes[position + 12 + length..position + 16 + length]
+                    .try_into()
+                    .unwrap(),
+            );
+            assert_eq!(data_crc, masked_crc32c(data));
+
+            records.push(data.to_vec());
+            position += 16 + length;
+        }
+
+        records
+    }
+
+    fn contains(data: &[u8], pattern: &[u8]) -> bool {
+        data.windows(pattern.len()).any(|window| window == pattern)
+    }
+
+    /// The step of an event, its varint field 2 following the wall time of field 1.
+    fn step(record: &[u8]) -> u64 {
+        assert_eq!(record[9], 2 << 3);
+        let mut step = 0;
+        for (index, byte) in record[10..].iter().enumerate() {
+            step |= ((byte & 0x7F) as u64) << (7 * index);
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        step
+    }
+
+    #[test]
+    fn should_compute_the_crc32c_checksum() {
+        assert_eq!(crc32c(b"123456789"), 0xE306_9283);
+    }
+
+    #[test]
+    fn should_encode_the_varints() {
+        let mut buffer = Vec::new();
+        encode_varint(&mut buffer, 300);
+
+        assert_eq!(buffer, [0xAC, 0x02]);
+    }
+
+    #[test]
+    fn should_log_the_iterations_and_the_epochs() {
+        let mut logger = TensorBoardMetricLogger::new("/tmp/burn-tensorboard-test/train");
+        let entry = |name: &str, serialize: &str| {
+            MetricEntry::new(name.to_string(), String::new(), serialize.to_string())
+        };
+
+        logger.log(&entry("Loss", "2"));
+        logger.log(&entry("Loss", "1,3"));
+        logger.log(&entry("Precision", "50"));
+        logger.log(&entry("Precision", "epoch:75"));
+        logger.end_epoch(1);
+
+        let records = read_records(logger.writer.path());
+        assert_eq!(records.len(), 6);
+        assert!(contains(&records[0], b"brain.Event:2"));
+        assert!(contains(&records[1], b"iteration/Loss"));
+        assert!(contains(&records[4], b"epoch/Loss"));
+        // The mean of the 4 items of the epoch.
+        assert!(contains(&records[4], &1.25f32.to_le_bytes()));
+        assert!(contains(&records[5], b"epoch/Precision"));
+        assert!(contains(&records[5], &75f32.to_le_bytes()));
+
+        let values = logger.read_numeric("Loss", 1).unwrap();
+        let values = values
+            .iter()
+            .map(|value| value.serialize())
+            .collect::<Vec<_>>();
+        assert_eq!(values, ["2", "1,3"]);
+    }
+
+    #[test]
+    fn should_log_the_texts() {
+        let mut writer = TensorBoardWriter::new("/tmp/burn-tensorboard-test/text");
+
+        writer.add_text("summary", "| Split | Loss |", 1);
+        writer.flush();
+
+        let records = read_records(writer.path());
+        assert_eq!(records.len(), 2);
+        assert!(contains(&records[1], b"| Split | Loss |"));
+    }
+
+    #[test]
+    fn should_count_the_iterations_from_the_started_epoch() {
+        let mut logger = TensorBoardMetricLogger::new("/tmp/burn-tensorboard-test/resumed");
+        let entry = MetricEntry::new("Loss".to_string(), String::new(), "2".to_string());
+
+        // Resumed at the third step, after 20 training iterations.
+        logger.start_epoch(3, 20);
+        logger.log(&entry);
+        logger.log(&entry);
+        logger.end_epoch(3);
+        logger.log(&entry);
+        logger.writer.flush();
+
+        let steps = read_records(logger.writer.path())
+            .into_iter()
+            .filter(|record| contains(record, b"iteration/Loss"))
+            .map(|record| step(&record))
+            .collect::<Vec<_>>();
+        assert_eq!(steps, [21, 22, 23]);
+    }
+}
@@ -0,0 +1,252 @@
+use core::marker::PhantomData;
+
+use super::classification::{batch_entry, to_vec};
+use super::state::{epoch_entry, FormatOptions};
+use super::{ClassAverage, ClassificationInput, EpochMetric, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+
+/// The area under the receiver operating characteristic curve (AUROC), the probability that a
+/// positive item of a class is ranked above a negative one.
+///
+/// The multi-class outputs are ranked by their probabilities, one class versus the others. The
+/// classes without positive or negative items are ignored.
+///
+/// It is an [epoch metric](EpochMetric): the scores are accumulated over the epoch, and the area
+/// of all the items of the epoch is computed at its end. The value of a batch is the area of its
+/// items.
+pub struct AurocMetric<B: Backend> {
+    scores: ClassScores,
+    average: ClassAverage,
+    value: f64,
+    _b: PhantomData<B>,
+}
+
+/// The scores and the targets of each class, class-major.
+#[derive(Default)]
+struct ClassScores {
+    scores: Vec<Vec<f64>>,
+    targets: Vec<Vec<bool>>,
+}
+
+impl ClassScores {
+    fn from_input<B: Backend>(input: &ClassificationInput<B>) -> Self {
+        let [batch_size, _num_classes] = input.targets().dims();
+        let scores = to_vec(input.scores().transpose());
+        let targets = to_vec(input.targets().transpose());
+
+        Self {
+            scores: scores
+                .chunks(batch_size.max(1))
+                .map(|scores| scores.to_vec())
+                .collect(),
+            targets: targets
+                .chunks(batch_size.max(1))
+                .map(|targets| targets.iter().map(|target| *target > 0.5).collect())
+                .collect(),
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        if self.scores.is_empty() {
+            *self = other;
+            return;
+        }
+        assert_eq!(
+            self.scores.len(),
+            other.scores.len(),
+            "The number of classes should be the same for all batches."
+        );
+
+        for (acc, scores) in self.scores.iter_mut().zip(other.scores) {
+            acc.extend(scores);
+        }
+        for (acc, targets) in self.targets.iter_mut().zip(other.targets) {
+            acc.extend(targets);
+        }
+    }
+
+    fn auroc(&self, average: ClassAverage) -> f64 {
+        if let ClassAverage::Micro = average {
+            let scores = self.scores.iter().flatten().copied().collect::<Vec<_>>();
+            let targets = self.targets.iter().flatten().copied().collect::<Vec<_>>();
+
+            return auroc(&scores, &targets).unwrap_or(f64::NAN);
+        }
+
+        let (sum, weights) = self
+            .scores
+            .iter()
+            .zip(&self.targets)
+            .filter_map(|(scores, targets)| {
+                let auroc = auroc(scores, targets)?;
+                let weight = match average {
+                    ClassAverage::Weighted => targets.iter().filter(|target| **target).count(),
+                    _ => 1,
+                } as f64;
+
+                Some((auroc * weight, weight))
+            })
+            .fold((0.0, 0.0), |(sum, weights), (auroc, weight)| {
+                (sum + auroc, weights + weight)
+            });
+
+        match weights > 0.0 {
+            true => sum / weights,
+            false => f64::NAN,
+        }
+    }
+}
+
+/// The area under the curve with the Mann-Whitney U statistic, the tied scores counting as half
+/// ranked above, or `None` without positive or negative items.
+fn auroc(scores: &[f64], targets: &[bool]) -> Option<f64> {
+    let mut items = scores
+        .iter()
+        .copied()
+        .zip(targets.iter().copied())
+        .collect::<Vec<_>>();
+    items.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    let num_positives = targets.iter().filter(|target| **target).count() as f64;
+    let num_negatives = targets.len() as f64 - num_positives;
+    if num_positives == 0.0 || num_negatives == 0.0 {
+        return None;
+    }
+
+    // The sum of the ranks of the positive items, starting at 1.
+    let mut rank_sum = 0.0;
+    let mut start = 0;
+    while start < items.len() {
+        let end = start
+            + items[start..]
+                .iter()
+                .take_while(|(score, _)| *score == items[start].0)
+                .count();
+        let rank = (start + end + 1) as f64 / 2.0;
+        let positives = items[start..end]
+            .iter()
+            .filter(|(_, target)| *target)
+            .count();
+
+        rank_sum += rank * positives as f64;
+        start = end;
+    }
+
+    Some((rank_sum - num_positives * (num_positives + 1.0) / 2.0) / (num_positives * num_negatives))
+}
+
+impl<B: Backend> AurocMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how the area of each class is averaged, macro by default.
+    pub fn with_average(mut self, average: ClassAverage) -> Self {
+        self.average = average;
+        self
+    }
+}
+
+impl<B: Backend> Default for AurocMetric<B> {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            scores: ClassScores::default(),
+            average: ClassAverage::default(),
+            value: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for AurocMetric<B> {
+    const NAME: &'static str = "AUROC";
+
+    type Input = ClassificationInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let scores = ClassScores::from_input(input);
+        self.value = scores.auroc(self.average);
+        self.scores.merge(scores);
+
+        batch_entry(
+            self.value,
+            None,
+            FormatOptions::new(Self::NAME).precision(4),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.scores = ClassScores::default();
+        self.value = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for AurocMetric<B> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<B: Backend> EpochMetric for AurocMetric<B> {
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        if self.scores.scores.is_empty() {
+            return None;
+        }
+
+        Some(epoch_entry(
+            self.scores.auroc(self.average),
+            FormatOptions::new(Self::NAME).precision(4),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_auroc_with_ties() {
+        let scores = [0.1, 0.4, 0.35, 0.8, 0.4];
+        let targets = [false, false, true, true, true];
+
+        // 4 of the 6 pairs are ordered, and 1 is tied.
+        assert_eq!(auroc(&scores, &targets), Some(4.5 / 6.0));
+        assert_eq!(auroc(&scores, &[true; 5]), None);
+    }
+
+    #[test]
+    fn test_auroc_accumulated_over_batches() {
+        let device = Default::default();
+        let mut metric = AurocMetric::<TestBackend>::new();
+
+        let _entry = metric.update(
+            &ClassificationInput::multilabel(
+                Tensor::from_data([[0.1], [0.9]], &device),
+                Tensor::from_data([[0], [1]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+        assert_eq!(1.0, metric.value());
+        assert_eq!(metric.epoch_entry().unwrap().serialize, "epoch:1");
+
+        let _entry = metric.update(
+            &ClassificationInput::multilabel(
+                Tensor::from_data([[0.2], [0.95]], &device),
+                Tensor::from_data([[1], [0]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+        assert_eq!(0.0, metric.value());
+        // 2 of the 4 pairs are ordered: 0.9 > 0.1 and 0.2 > 0.1.
+        assert_eq!(metric.epoch_entry().unwrap().serialize, "epoch:0.5");
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::BTreeSet;
+
+use super::classification::batch_entry;
+use super::detection::average_precision;
+use super::state::{epoch_entry, FormatOptions};
+use super::{BoundingBox, Detection, DetectionInput, EpochMetric, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+
+/// The mean average precision (mAP) of the object detections, averaged over the classes and the
+/// IoU thresholds, `0.5` to `0.95` with a step of `0.05` by default as the COCO evaluation.
+///
+/// Only the detections with the highest scores of each image are evaluated, 100 by default. The
+/// classes without target are ignored.
+///
+/// It is an [epoch metric](EpochMetric): the detections and the targets are accumulated over the
+/// epoch, and the precision of all the images of the epoch is computed at its end. The value of a
+/// batch is the precision of its images.
+pub struct MeanAveragePrecisionMetric {
+    iou_thresholds: Vec<f64>,
+    max_detections: usize,
+    predictions: Vec<Vec<Detection>>,
+    targets: Vec<Vec<BoundingBox>>,
+    value: f64,
+}
+
+impl MeanAveragePrecisionMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the IoU thresholds above which a detection matches a target, e.g. `vec![0.5]` for
+    /// the mAP@0.5 of the Pascal VOC evaluation.
+    pub fn with_iou_thresholds(mut self, iou_thresholds: Vec<f64>) -> Self {
+        assert!(
+            !iou_thresholds.is_empty(),
+            "At least one IoU threshold is required."
+        );
+        self.iou_thresholds = iou_thresholds;
+        self
+    }
+
+    /// Sets the maximum number of detections of each image.
+    pub fn with_max_detections(mut self, max_detections: usize) -> Self {
+        self.max_detections = max_detections;
+        self
+    }
+
+    fn mean_average_precision(
+        &self,
+        predictions: &[Vec<Detection>],
+        targets: &[Vec<BoundingBox>],
+    ) -> f64 {
+        let predictions = predictions
+            .iter()
+            .map(|detections| {
+                let mut detections = detections.clone();
+                detections.sort_by(|a, b| b.score.total_cmp(&a.score));
+                detections.truncate(self.max_detections);
+                detections
+            })
+            .collect::<Vec<_>>();
+        let labels = targets
+            .iter()
+            .flatten()
+            .map(|target| target.label)
+            .collect::<BTreeSet<_>>();
+
+        let (sum, count) = labels
+            .iter()
+            .flat_map(|label| {
+                self.iou_thresholds.iter().map(|iou_threshold| {
+                    average_precision(&predictions, targets, *label, *iou_threshold)
+                })
+            })
+            .fold((0.0, 0), |(sum, count), ap| (sum + ap, count + 1));
+
+        match count {
+            0 => f64::NAN,
+            _ => 100.0 * sum / count as f64,
+        }
+    }
+
+    fn format() -> FormatOptions {
+        FormatOptions::new(Self::NAME).precision(2)
+    }
+}
+
+impl Default for MeanAveragePrecisionMetric {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            iou_thresholds: (0..10).map(|step| 0.5 + 0.05 * step as f64).collect(),
+            max_detections: 100,
+            predictions: Vec::new(),
+            targets: Vec::new(),
+            value: f64::NAN,
+        }
+    }
+}
+
+impl Metric for MeanAveragePrecisionMetric {
+    const NAME: &'static str = "mAP";
+
+    type Input = DetectionInput;
+
+    fn update(&mut self, input: &DetectionInput, _metadata: &MetricMetadata) -> MetricEntry {
+        let (predictions, targets) = input.images();
+        self.value = self.mean_average_precision(predictions, targets);
+        self.predictions.extend_from_slice(predictions);
+        self.targets.extend_from_slice(targets);
+
+        batch_entry(self.value, None, Self::format())
+    }
+
+    fn clear(&mut self) {
+        self.predictions.clear();
+        self.targets.clear();
+        self.value = f64::NAN;
+    }
+}
+
+impl Numeric for MeanAveragePrecisionMetric {
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl EpochMetric for MeanAveragePrecisionMetric {
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        if self.targets.is_empty() {
+            return None;
+        }
+
+        Some(epoch_entry(
+            self.mean_average_precision(&self.predictions, &self.targets),
+            Self::format(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_average_precision() {
+        let mut metric = MeanAveragePrecisionMetric::new();
+
+        // Class 0 is detected with an IoU of 0.88 (true positive for 8 of the 10 thresholds) and
+        // class 1 is not detected.
+        let _entry = metric.update(
+            &DetectionInput::new(
+                vec![vec![Detection::new(
+                    BoundingBox::new([0.0, 0.0, 10.0, 8.8], 0),
+                    0.9,
+                )]],
+                vec![vec![
+                    BoundingBox::new([0.0, 0.0, 10.0, 10.0], 0),
+                    BoundingBox::new([20.0, 20.0, 30.0, 30.0], 1),
+                ]],
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        assert!((metric.value() - 100.0 * 0.8 / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mean_average_precision_accumulated_over_batches() {
+        let mut metric = MeanAveragePrecisionMetric::new().with_iou_thresholds(vec![0.5]);
+        let target = BoundingBox::new([0.0, 0.0, 1.0, 1.0], 0);
+        let missed = BoundingBox::new([5.0, 5.0, 6.0, 6.0], 0);
+
+        // A confident false positive, then a true positive in another batch.
+        let _entry = metric.update(
+            &DetectionInput::new(vec![vec![Detection::new(missed, 0.9)]], vec![vec![]]),
+            &MetricMetadata::fake(),
+        );
+        let _entry = metric.update(
+            &DetectionInput::new(vec![vec![Detection::new(target, 0.5)]], vec![vec![target]]),
+            &MetricMetadata::fake(),
+        );
+        assert_eq!(metric.value(), 100.0);
+
+        // Over the epoch, the true positive is ranked second so its precision is 1/2.
+        let entry = metric.epoch_entry().unwrap();
+        assert_eq!(entry.serialize, "epoch:50");
+    }
+}
@@ -1,5 +1,7 @@
 use burn_core::{data::dataloader::Progress, LearningRate};
 
+use super::GradientStats;
+
 /// Metric metadata that can be used when computing metrics.
 pub struct MetricMetadata {
     /// The current progress.
@@ -16,6 +18,9 @@ pub struct MetricMetadata {
 
     /// The current learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The statistics of the gradients of the current training iteration, if enabled.
+    pub gradients: Option<GradientStats>,
 }
 
 impl MetricMetadata {
@@ -30,6 +35,7 @@ impl MetricMetadata {
             epoch_total: 1,
             iteration: 0,
             lr: None,
+            gradients: None,
         }
     }
 }
@@ -73,6 +79,24 @@ pub trait Numeric {
     fn value(&self) -> f64;
 }
 
+/// Declare a numeric metric whose value can't be computed from the values of the batches, e.g.
+/// the [F1 score](crate::metric::FBetaScoreMetric) or the [AUROC](crate::metric::AurocMetric).
+///
+/// The metric accumulates the sufficient statistics of the epoch in its [updates](Metric::update),
+/// and its value is computed once at the end of the epoch. This value is stored and used when the
+/// metric is aggregated, e.g. by the [early stopping](crate::MetricEarlyStoppingStrategy) and
+/// [checkpointing](crate::checkpoint::MetricCheckpointingStrategy) strategies, instead of the
+/// mean of the batches.
+pub trait EpochMetric: Metric + Numeric {
+    /// Computes the entry of the epoch from the accumulated statistics, called at the end of the
+    /// epoch before the metric is cleared.
+    ///
+    /// The entry should be created with [epoch_entry](crate::metric::state::epoch_entry). No
+    /// entry is logged when `None` is returned, e.g. when the metric wasn't updated during the
+    /// epoch.
+    fn epoch_entry(&self) -> Option<MetricEntry>;
+}
+
 /// Data type that contains the current state of a metric at a given time.
 #[derive(new, Debug, Clone)]
 pub struct MetricEntry {
@@ -90,17 +114,30 @@ pub enum NumericEntry {
     Value(f64),
     /// Aggregated numeric (value, number of elements).
     Aggregated(f64, usize),
+    /// Value of a whole epoch computed by an [epoch metric](EpochMetric), replacing the other
+    /// entries of the epoch when aggregated.
+    Epoch(f64),
 }
 
+const EPOCH_PREFIX: &str = "epoch:";
+
 impl NumericEntry {
     pub(crate) fn serialize(&self) -> String {
         match self {
             Self::Value(v) => v.to_string(),
             Self::Aggregated(v, n) => format!("{v},{n}"),
+            Self::Epoch(v) => format!("{EPOCH_PREFIX}{v}"),
         }
     }
 
     pub(crate) fn deserialize(entry: &str) -> Result<Self, String> {
+        if let Some(value) = entry.strip_prefix(EPOCH_PREFIX) {
+            return match value.parse::<f64>() {
+                Ok(value) => Ok(NumericEntry::Epoch(value)),
+                Err(err) => Err(err.to_string()),
+            };
+        }
+
         // Check for comma separated values
         let values = entry.split(',').collect::<Vec<_>>();
         let num_values = values.len();
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use super::classification::batch_entry;
+use super::state::{epoch_entry, FormatOptions};
+use super::{EpochMetric, MetricEntry, MetricMetadata, SequenceInput};
+use crate::metric::{Metric, Numeric};
+
+/// The corpus-level BLEU score, the geometric mean of the modified n-gram precisions of the
+/// predicted sequences, with a penalty for the predictions shorter than their references.
+///
+/// It is an [epoch metric](EpochMetric): the n-gram matches and the lengths are accumulated over
+/// the epoch, so the value of the epoch is the score of the corpus of all its sequences and not
+/// the mean of the batches.
+pub struct BleuMetric {
+    max_order: usize,
+    stats: BleuStats,
+    value: f64,
+}
+
+/// The sufficient statistics of the BLEU score.
+#[derive(Debug, Clone, Default)]
+struct BleuStats {
+    matches: Vec<usize>,
+    totals: Vec<usize>,
+    prediction_length: usize,
+    reference_length: usize,
+}
+
+impl BleuStats {
+    fn new(max_order: usize) -> Self {
+        Self {
+            matches: vec![0; max_order],
+            totals: vec![0; max_order],
+            prediction_length: 0,
+            reference_length: 0,
+        }
+    }
+
+    fn update(&mut self, prediction: &[usize], reference: &[usize]) {
+        self.prediction_length += prediction.len();
+        self.reference_length += reference.len();
+
+        for (order, (matches, total)) in self.matches.iter_mut().zip(&mut self.totals).enumerate() {
+            let predicted = ngrams(prediction, order + 1);
+            let references = ngrams(reference, order + 1);
+
+            *total += predicted.values().sum::<usize>();
+            *matches += predicted
+                .iter()
+                .map(|(ngram, count)| (*count).min(*references.get(ngram).unwrap_or(&0)))
+                .sum::<usize>();
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (acc, value) in self.matches.iter_mut().zip(&other.matches) {
+            *acc += value;
+        }
+        for (acc, value) in self.totals.iter_mut().zip(&other.totals) {
+            *acc += value;
+        }
+        self.prediction_length += other.prediction_length;
+        self.reference_length += other.reference_length;
+    }
+
+    fn score(&self) -> f64 {
+        if self.prediction_length == 0 {
+            return 0.0;
+        }
+
+        let mut sum_log_precisions = 0.0;
+        for (matches, total) in self.matches.iter().zip(&self.totals) {
+            if *matches == 0 {
+                return 0.0;
+            }
+            sum_log_precisions += (*matches as f64 / *total as f64).ln();
+        }
+
+        let brevity_penalty = match self.prediction_length < self.reference_length {
+            true => (1.0 - self.reference_length as f64 / self.prediction_length as f64).exp(),
+            false => 1.0,
+        };
+
+        brevity_penalty * (sum_log_precisions / self.matches.len() as f64).exp()
+    }
+}
+
+/// The number of occurrences of each n-gram of the sequence.
+fn ngrams(sequence: &[usize], n: usize) -> HashMap<&[usize], usize> {
+    let mut ngrams = HashMap::new();
+    for ngram in sequence.windows(n) {
+        *ngrams.entry(ngram).or_insert(0) += 1;
+    }
+    ngrams
+}
+
+impl BleuMetric {
+    /// Creates the metric, with n-grams up to 4 tokens.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of tokens of the n-grams.
+    pub fn with_max_order(mut self, max_order: usize) -> Self {
+        assert!(max_order > 0, "The maximum order should be positive.");
+        self.max_order = max_order;
+        self.stats = BleuStats::new(max_order);
+        self
+    }
+
+    fn format() -> FormatOptions {
+        FormatOptions::new(Self::NAME).precision(2)
+    }
+}
+
+impl Default for BleuMetric {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            max_order: 4,
+            stats: BleuStats::new(4),
+            value: f64::NAN,
+        }
+    }
+}
+
+impl Metric for BleuMetric {
+    const NAME: &'static str = "BLEU";
+
+    type Input = SequenceInput;
+
+    fn update(&mut self, input: &SequenceInput, _metadata: &MetricMetadata) -> MetricEntry {
+        let mut stats = BleuStats::new(self.max_order);
+        for (prediction, reference) in input.pairs() {
+            stats.update(prediction, reference);
+        }
+
+        self.stats.merge(&stats);
+        self.value = 100.0 * stats.score();
+
+        batch_entry(self.value, Some(100.0 * self.stats.score()), Self::format())
+    }
+
+    fn clear(&mut self) {
+        self.stats = BleuStats::new(self.max_order);
+        self.value = f64::NAN;
+    }
+}
+
+impl Numeric for BleuMetric {
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl EpochMetric for BleuMetric {
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        if self.stats.prediction_length == 0 && self.stats.reference_length == 0 {
+            return None;
+        }
+
+        Some(epoch_entry(100.0 * self.stats.score(), Self::format()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bleu_perfect_match() {
+        let mut metric = BleuMetric::new();
+
+        let _entry = metric.update(
+            &SequenceInput::new(vec![vec![1, 2, 3, 4, 5]], vec![vec![1, 2, 3, 4, 5]]),
+            &MetricMetadata::fake(),
+        );
+
+        assert_eq!(metric.value(), 100.0);
+    }
+
+    #[test]
+    fn test_bleu_corpus_level() {
+        let mut metric = BleuMetric::new().with_max_order(2);
+
+        // Unigrams 2/3 and bigrams 1/2, without brevity penalty.
+        let _entry = metric.update(
+            &SequenceInput::new(vec![vec![1, 2, 5]], vec![vec![1, 2, 3]]),
+            &MetricMetadata::fake(),
+        );
+        assert!((metric.value() - 100.0 * (1.0f64 / 3.0).sqrt()).abs() < 1e-9);
+
+        // Unigrams 2/2 and bigrams 1/1, with a brevity penalty of exp(1 - 3 / 2).
+        let _entry = metric.update(
+            &SequenceInput::new(vec![vec![6, 7]], vec![vec![6, 7, 8]]),
+            &MetricMetadata::fake(),
+        );
+
+        // Unigrams 4/5 and bigrams 2/3, with a brevity penalty of exp(1 - 6 / 5).
+        let expected = 100.0 * (-0.2f64).exp() * (4.0f64 / 5.0 * 2.0 / 3.0).sqrt();
+        let entry = metric.epoch_entry().unwrap();
+        let value = entry.serialize.strip_prefix("epoch:").unwrap();
+        assert!((value.parse::<f64>().unwrap() - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,306 @@
+use super::state::FormatOptions;
+use super::{MetricEntry, NumericEntry};
+use burn_core::tensor::activation::{sigmoid, softmax};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// The input type of the classification metrics, e.g. the [precision](super::PrecisionMetric)
+/// or the [confusion matrix](super::ConfusionMatrixMetric).
+pub struct ClassificationInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 2>,
+    multilabel: bool,
+}
+
+impl<B: Backend> ClassificationInput<B> {
+    /// Creates the input of a multi-class classification, where the predicted class is the one
+    /// with the highest output.
+    ///
+    /// # Arguments
+    ///
+    /// * `outputs` - The outputs of the model of shape `[batch_size, num_classes]`.
+    /// * `targets` - The target classes of shape `[batch_size]`.
+    pub fn new(outputs: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Self {
+        let [batch_size, num_classes] = outputs.dims();
+        let device = outputs.device();
+        let targets = Tensor::zeros([batch_size, num_classes], &device).scatter(
+            1,
+            targets.reshape([batch_size, 1]),
+            Tensor::ones([batch_size, 1], &device),
+        );
+
+        Self {
+            outputs,
+            targets,
+            multilabel: false,
+        }
+    }
+
+    /// Creates the input of a multi-label classification, where a label is predicted when its
+    /// output is above the decision threshold of the metric.
+    ///
+    /// # Arguments
+    ///
+    /// * `outputs` - The outputs of the model of shape `[batch_size, num_labels]`.
+    /// * `targets` - The targets of shape `[batch_size, num_labels]`, `1` for the positive labels.
+    pub fn multilabel(outputs: Tensor<B, 2>, targets: Tensor<B, 2, Int>) -> Self {
+        Self {
+            outputs,
+            targets: targets.float(),
+            multilabel: true,
+        }
+    }
+
+    /// If the input is a multi-label classification.
+    pub fn is_multilabel(&self) -> bool {
+        self.multilabel
+    }
+
+    /// The targets, with `1` for the positive classes and `0` for the others.
+    pub(crate) fn targets(&self) -> Tensor<B, 2> {
+        self.targets.clone()
+    }
+
+    /// The predictions, with `1` for the predicted classes and `0` for the others.
+    pub(crate) fn predictions(&self, decision: &Decision) -> Tensor<B, 2> {
+        let [batch_size, _num_classes] = self.outputs.dims();
+
+        match self.multilabel {
+            true => {
+                let mut outputs = self.outputs.clone();
+                if decision.sigmoid {
+                    outputs = sigmoid(outputs);
+                }
+                outputs.greater_elem(decision.threshold).float()
+            }
+            false => self.outputs.zeros_like().scatter(
+                1,
+                self.outputs.clone().argmax(1),
+                Tensor::ones([batch_size, 1], &self.outputs.device()),
+            ),
+        }
+    }
+
+    /// The scores used to rank the items, the probabilities of the classes for a multi-class
+    /// classification.
+    pub(crate) fn scores(&self) -> Tensor<B, 2> {
+        match self.multilabel {
+            true => self.outputs.clone(),
+            false => softmax(self.outputs.clone(), 1),
+        }
+    }
+}
+
+/// How the metrics computed for each class are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClassAverage {
+    /// The metric is computed on the counts of all the classes summed together.
+    Micro,
+    /// The mean of the metric of each class.
+    #[default]
+    Macro,
+    /// The mean of the metric of each class, weighted by the number of targets of the class.
+    Weighted,
+}
+
+/// How the multi-label outputs are turned into predictions.
+#[derive(Debug, Clone)]
+pub(crate) struct Decision {
+    pub(crate) threshold: f64,
+    pub(crate) sigmoid: bool,
+}
+
+impl Default for Decision {
+    fn default() -> Self {
+        Self {
+            threshold: 0.5,
+            sigmoid: false,
+        }
+    }
+}
+
+/// The true positives, false positives and false negatives of each class, accumulated over the
+/// batches.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ClassCounts {
+    true_positives: Vec<f64>,
+    false_positives: Vec<f64>,
+    false_negatives: Vec<f64>,
+}
+
+impl ClassCounts {
+    pub(crate) fn from_input<B: Backend>(
+        input: &ClassificationInput<B>,
+        decision: &Decision,
+    ) -> Self {
+        Self::from_one_hot(input.predictions(decision), input.targets())
+    }
+
+    /// Counts the predictions and the targets of shape `[num_items, num_classes]`, with `1` for
+    /// the predicted or targeted classes and `0` for the others.
+    pub(crate) fn from_one_hot<B: Backend>(
+        predictions: Tensor<B, 2>,
+        targets: Tensor<B, 2>,
+    ) -> Self {
+        let true_positives = predictions.clone().mul(targets.clone()).sum_dim(0);
+        let false_positives = predictions.sum_dim(0).sub(true_positives.clone());
+        let false_negatives = targets.sum_dim(0).sub(true_positives.clone());
+
+        Self {
+            true_positives: to_vec(true_positives),
+            false_positives: to_vec(false_positives),
+            false_negatives: to_vec(false_negatives),
+        }
+    }
+
+    /// If no item was counted.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.true_positives.is_empty()
+    }
+
+    pub(crate) fn merge(&mut self, other: &Self) {
+        if self.true_positives.is_empty() {
+            *self = other.clone();
+            return;
+        }
+        assert_eq!(
+            self.true_positives.len(),
+            other.true_positives.len(),
+            "The number of classes should be the same for all batches."
+        );
+
+        let add = |acc: &mut Vec<f64>, values: &[f64]| {
+            acc.iter_mut()
+                .zip(values)
+                .for_each(|(acc, value)| *acc += value)
+        };
+        add(&mut self.true_positives, &other.true_positives);
+        add(&mut self.false_positives, &other.false_positives);
+        add(&mut self.false_negatives, &other.false_negatives);
+    }
+
+    pub(crate) fn precision(&self, average: ClassAverage) -> f64 {
+        self.average(average, |tp, fp, _fn| divide(tp, tp + fp))
+    }
+
+    pub(crate) fn recall(&self, average: ClassAverage) -> f64 {
+        self.average(average, |tp, _fp, fn_| divide(tp, tp + fn_))
+    }
+
+    pub(crate) fn fbeta(&self, beta: f64, average: ClassAverage) -> f64 {
+        let beta2 = beta * beta;
+        self.average(average, |tp, fp, fn_| {
+            divide((1.0 + beta2) * tp, (1.0 + beta2) * tp + beta2 * fn_ + fp)
+        })
+    }
+
+    /// The intersection over union of the predictions and the targets (Jaccard index).
+    pub(crate) fn jaccard(&self, average: ClassAverage) -> f64 {
+        self.average(average, |tp, fp, fn_| divide(tp, tp + fp + fn_))
+    }
+
+    /// Combine the metric of each class, the classes never targeted nor predicted are ignored.
+    fn average<F: Fn(f64, f64, f64) -> f64>(&self, average: ClassAverage, metric: F) -> f64 {
+        let classes = self
+            .true_positives
+            .iter()
+            .zip(&self.false_positives)
+            .zip(&self.false_negatives)
+            .map(|((tp, fp), fn_)| (*tp, *fp, *fn_))
+            .filter(|(tp, fp, fn_)| tp + fp + fn_ > 0.0);
+
+        match average {
+            ClassAverage::Micro => {
+                let (tp, fp, fn_) = classes.fold((0.0, 0.0, 0.0), |acc, (tp, fp, fn_)| {
+                    (acc.0 + tp, acc.1 + fp, acc.2 + fn_)
+                });
+                metric(tp, fp, fn_)
+            }
+            ClassAverage::Macro => {
+                let (sum, count) = classes.fold((0.0, 0.0), |(sum, count), (tp, fp, fn_)| {
+                    (sum + metric(tp, fp, fn_), count + 1.0)
+                });
+                divide(sum, count)
+            }
+            ClassAverage::Weighted => {
+                let (sum, support) = classes.fold((0.0, 0.0), |(sum, support), (tp, fp, fn_)| {
+                    (sum + metric(tp, fp, fn_) * (tp + fn_), support + tp + fn_)
+                });
+                divide(sum, support)
+            }
+        }
+    }
+}
+
+fn divide(numerator: f64, denominator: f64) -> f64 {
+    match denominator > 0.0 {
+        true => numerator / denominator,
+        false => 0.0,
+    }
+}
+
+pub(crate) fn to_vec<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Vec<f64> {
+    tensor.into_data().iter::<f64>().collect()
+}
+
+/// Creates the entry of a batch of an [epoch metric](super::EpochMetric), displaying the value
+/// accumulated over the epoch so far when it is cheap to compute.
+pub(crate) fn batch_entry(batch: f64, epoch: Option<f64>, format: FormatOptions) -> MetricEntry {
+    let formatted = match epoch {
+        Some(epoch) => format!(
+            "epoch {} - batch {}",
+            format.format(epoch),
+            format.format(batch)
+        ),
+        None => format!("batch {}", format.format(batch)),
+    };
+
+    MetricEntry::new(
+        format.name().to_string(),
+        formatted,
+        NumericEntry::Value(batch).serialize(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_count_the_multiclass_predictions() {
+        let device = Default::default();
+        let input = ClassificationInput::<TestBackend>::new(
+            Tensor::from_data(
+                [
+                    [0.8, 0.1, 0.1],
+                    [0.2, 0.7, 0.1],
+                    [0.6, 0.3, 0.1],
+                    [0.1, 0.2, 0.7],
+                ],
+                &device,
+            ),
+            Tensor::from_data([0, 1, 1, 1], &device),
+        );
+
+        let counts = ClassCounts::from_input(&input, &Decision::default());
+
+        assert_eq!(counts.true_positives, vec![1.0, 1.0, 0.0]);
+        assert_eq!(counts.false_positives, vec![1.0, 0.0, 1.0]);
+        assert_eq!(counts.false_negatives, vec![0.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn should_average_the_classes() {
+        let counts = ClassCounts {
+            true_positives: vec![8.0, 1.0, 0.0],
+            false_positives: vec![2.0, 1.0, 0.0],
+            false_negatives: vec![0.0, 3.0, 0.0],
+        };
+
+        // The last class is never targeted nor predicted.
+        assert_eq!(counts.precision(ClassAverage::Micro), 0.75);
+        assert_eq!(counts.precision(ClassAverage::Macro), 0.65);
+        assert_eq!(counts.recall(ClassAverage::Weighted), 0.75);
+    }
+}
@@ -0,0 +1,182 @@
+use core::marker::PhantomData;
+
+use super::classification::{to_vec, Decision};
+use super::{ClassificationInput, MetricEntry, MetricMetadata};
+use crate::metric::Metric;
+use burn_core::tensor::backend::Backend;
+
+/// The confusion matrix, accumulated over the epoch.
+///
+/// For a multi-class classification, the matrix has a row for each target class and a column for
+/// each predicted class. For a multi-label classification, each label has a `2x2` matrix, whose
+/// rows are the negative and positive targets and columns the negative and positive predictions.
+pub struct ConfusionMatrixMetric<B: Backend> {
+    matrices: Vec<Vec<Vec<u64>>>,
+    decision: Decision,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> ConfusionMatrixMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the decision threshold of the multi-label classifications.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.decision.threshold = threshold;
+        self
+    }
+
+    /// Sets the sigmoid activation function usage on the multi-label outputs before the
+    /// threshold.
+    pub fn with_sigmoid(mut self, sigmoid: bool) -> Self {
+        self.decision.sigmoid = sigmoid;
+        self
+    }
+
+    /// The accumulated matrices, a single one for a multi-class classification and one for each
+    /// label for a multi-label classification.
+    pub fn matrices(&self) -> &[Vec<Vec<u64>>] {
+        &self.matrices
+    }
+
+    fn accumulate(&mut self, input: &ClassificationInput<B>) {
+        let [_batch_size, num_classes] = input.targets().dims();
+        let predictions = to_vec(input.predictions(&self.decision));
+        let targets = to_vec(input.targets());
+
+        if input.is_multilabel() {
+            if self.matrices.is_empty() {
+                self.matrices = vec![vec![vec![0; 2]; 2]; num_classes];
+            }
+            for (index, (prediction, target)) in predictions.iter().zip(&targets).enumerate() {
+                let matrix = &mut self.matrices[index % num_classes];
+                matrix[(*target > 0.5) as usize][(*prediction > 0.5) as usize] += 1;
+            }
+            return;
+        }
+
+        if self.matrices.is_empty() {
+            self.matrices = vec![vec![vec![0; num_classes]; num_classes]];
+        }
+        let class = |row: &[f64]| row.iter().position(|value| *value > 0.5).unwrap_or(0);
+        for (prediction, target) in predictions
+            .chunks(num_classes)
+            .zip(targets.chunks(num_classes))
+        {
+            self.matrices[0][class(target)][class(prediction)] += 1;
+        }
+    }
+}
+
+impl<B: Backend> Default for ConfusionMatrixMetric<B> {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            matrices: Vec::new(),
+            decision: Decision::default(),
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for ConfusionMatrixMetric<B> {
+    const NAME: &'static str = "Confusion Matrix";
+
+    type Input = ClassificationInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        self.accumulate(input);
+
+        let format_matrix = |matrix: &Vec<Vec<u64>>, separator: &str| {
+            matrix
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|count| count.to_string())
+                        .collect::<Vec<_>>()
+                        .join(separator)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let formatted = self
+            .matrices
+            .iter()
+            .map(|matrix| format!("[{}]", format_matrix(matrix, " ").join(", ")))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let serialized = self
+            .matrices
+            .iter()
+            .map(|matrix| format_matrix(matrix, ",").join(";"))
+            .collect::<Vec<_>>()
+            .join("|");
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, serialized)
+    }
+
+    fn clear(&mut self) {
+        self.matrices.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_confusion_matrix_multiclass() {
+        let device = Default::default();
+        let mut metric = ConfusionMatrixMetric::<TestBackend>::new();
+
+        let entry = metric.update(
+            &ClassificationInput::new(
+                Tensor::from_data(
+                    [
+                        [0.8, 0.1, 0.1],
+                        [0.2, 0.7, 0.1],
+                        [0.6, 0.3, 0.1],
+                        [0.1, 0.2, 0.7],
+                    ],
+                    &device,
+                ),
+                Tensor::from_data([0, 1, 1, 1], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        assert_eq!(
+            metric.matrices(),
+            &[vec![vec![1, 0, 0], vec![1, 1, 1], vec![0, 0, 0]]]
+        );
+        assert_eq!(entry.formatted, "[1 0 0, 1 1 1, 0 0 0]");
+        assert_eq!(entry.serialize, "1,0,0;1,1,1;0,0,0");
+    }
+
+    #[test]
+    fn test_confusion_matrix_multilabel() {
+        let device = Default::default();
+        let mut metric = ConfusionMatrixMetric::<TestBackend>::new();
+
+        let _entry = metric.update(
+            &ClassificationInput::multilabel(
+                Tensor::from_data([[0.7, 0.2], [0.4, 0.9], [0.6, 0.1]], &device),
+                Tensor::from_data([[1, 0], [1, 1], [0, 0]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        assert_eq!(
+            metric.matrices(),
+            &[vec![vec![0, 1], vec![1, 1]], vec![vec![2, 0], vec![0, 1]]]
+        );
+    }
+}
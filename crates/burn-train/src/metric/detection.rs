@@ -0,0 +1,184 @@
+/// A bounding box of an object of a class.
+#[derive(new, Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    /// The coordinates of the box `[x_min, y_min, x_max, y_max]`.
+    pub coordinates: [f64; 4],
+    /// The class of the object.
+    pub label: usize,
+}
+
+impl BoundingBox {
+    /// The area of the box.
+    pub fn area(&self) -> f64 {
+        let [x_min, y_min, x_max, y_max] = self.coordinates;
+        (x_max - x_min).max(0.0) * (y_max - y_min).max(0.0)
+    }
+
+    /// The intersection over union of the areas of two boxes.
+    pub fn iou(&self, other: &BoundingBox) -> f64 {
+        let [x_min, y_min, x_max, y_max] = self.coordinates;
+        let [other_x_min, other_y_min, other_x_max, other_y_max] = other.coordinates;
+
+        let intersection = BoundingBox::new(
+            [
+                x_min.max(other_x_min),
+                y_min.max(other_y_min),
+                x_max.min(other_x_max),
+                y_max.min(other_y_max),
+            ],
+            self.label,
+        )
+        .area();
+        let union = self.area() + other.area() - intersection;
+
+        match union > 0.0 {
+            true => intersection / union,
+            false => 0.0,
+        }
+    }
+}
+
+/// A predicted bounding box with its confidence score.
+#[derive(new, Debug, Clone, Copy, PartialEq)]
+pub struct Detection {
+    /// The predicted box.
+    pub bbox: BoundingBox,
+    /// The confidence score of the prediction.
+    pub score: f64,
+}
+
+/// The input type of the object detection metrics, e.g. the
+/// [mean average precision](super::MeanAveragePrecisionMetric).
+#[derive(new)]
+pub struct DetectionInput {
+    /// The predicted boxes of each image.
+    predictions: Vec<Vec<Detection>>,
+    /// The target boxes of each image.
+    targets: Vec<Vec<BoundingBox>>,
+}
+
+impl DetectionInput {
+    /// The predicted and target boxes of each image.
+    pub(crate) fn images(&self) -> (&[Vec<Detection>], &[Vec<BoundingBox>]) {
+        assert_eq!(
+            self.predictions.len(),
+            self.targets.len(),
+            "Each image should have predictions and targets."
+        );
+
+        (&self.predictions, &self.targets)
+    }
+}
+
+/// The average precision of the predictions of a class, with the precision interpolated at 101
+/// recall levels as the COCO evaluation.
+///
+/// The predictions are matched by decreasing score to the unmatched target of the same image with
+/// the highest IoU above the threshold, the unmatched predictions are false positives.
+pub(crate) fn average_precision(
+    predictions: &[Vec<Detection>],
+    targets: &[Vec<BoundingBox>],
+    label: usize,
+    iou_threshold: f64,
+) -> f64 {
+    let targets = targets
+        .iter()
+        .map(|targets| {
+            targets
+                .iter()
+                .filter(|target| target.label == label)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let num_targets = targets.iter().map(|targets| targets.len()).sum::<usize>();
+    if num_targets == 0 {
+        return 0.0;
+    }
+
+    let mut detections = predictions
+        .iter()
+        .enumerate()
+        .flat_map(|(image, detections)| {
+            detections
+                .iter()
+                .filter(|detection| detection.bbox.label == label)
+                .map(move |detection| (image, detection))
+        })
+        .collect::<Vec<_>>();
+    detections.sort_by(|(_, a), (_, b)| b.score.total_cmp(&a.score));
+
+    let mut matched = targets
+        .iter()
+        .map(|targets| vec![false; targets.len()])
+        .collect::<Vec<_>>();
+    let mut true_positives = 0;
+    let mut precisions = Vec::with_capacity(detections.len());
+    let mut recalls = Vec::with_capacity(detections.len());
+
+    for (rank, (image, detection)) in detections.into_iter().enumerate() {
+        let best = targets[image]
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !matched[image][*index])
+            .map(|(index, target)| (index, detection.bbox.iou(target)))
+            .filter(|(_, iou)| *iou >= iou_threshold)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        if let Some((index, _)) = best {
+            matched[image][index] = true;
+            true_positives += 1;
+        }
+
+        precisions.push(true_positives as f64 / (rank + 1) as f64);
+        recalls.push(true_positives as f64 / num_targets as f64);
+    }
+
+    // The interpolated precision at a recall is the best precision at any higher recall.
+    for index in (0..precisions.len().saturating_sub(1)).rev() {
+        precisions[index] = precisions[index].max(precisions[index + 1]);
+    }
+
+    let sum = (0..=100)
+        .map(|level| {
+            let recall = level as f64 / 100.0;
+            let index = recalls.partition_point(|value| *value < recall);
+            precisions.get(index).copied().unwrap_or(0.0)
+        })
+        .sum::<f64>();
+
+    sum / 101.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compute_the_iou_of_the_boxes() {
+        let a = BoundingBox::new([0.0, 0.0, 2.0, 2.0], 0);
+        let b = BoundingBox::new([1.0, 1.0, 3.0, 3.0], 0);
+        let c = BoundingBox::new([5.0, 5.0, 6.0, 6.0], 0);
+
+        assert_eq!(a.iou(&b), 1.0 / 7.0);
+        assert_eq!(a.iou(&a), 1.0);
+        assert_eq!(a.iou(&c), 0.0);
+    }
+
+    #[test]
+    fn should_interpolate_the_precision() {
+        let target = |x: f64| BoundingBox::new([x, 0.0, x + 1.0, 1.0], 0);
+        let detection = |x: f64, score: f64| Detection::new(target(x), score);
+
+        // The ranked predictions are a true positive, a false positive and a true positive, so
+        // the precision is 1 up to the recall 1/2, then 2/3.
+        let predictions = vec![
+            vec![detection(0.0, 0.9), detection(5.0, 0.8)],
+            vec![detection(2.0, 0.7)],
+        ];
+        let targets = vec![vec![target(0.0)], vec![target(2.0)]];
+
+        let expected = (51.0 + 50.0 * 2.0 / 3.0) / 101.0;
+        let ap = average_precision(&predictions, &targets, 0, 0.5);
+        assert!((ap - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,136 @@
+use core::marker::PhantomData;
+
+use super::classification::{batch_entry, ClassCounts};
+use super::state::{epoch_entry, FormatOptions};
+use super::{ClassAverage, EpochMetric, MetricEntry, MetricMetadata, SegmentationInput};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+
+/// The Dice coefficient, twice the intersection of the predicted and the targeted pixels of each
+/// class divided by the sum of their areas.
+///
+/// The classes never targeted nor predicted are ignored.
+///
+/// It is an [epoch metric](EpochMetric): the intersections and the areas of each class are
+/// accumulated over the epoch, so the value of the epoch is the coefficient of all its pixels and
+/// not the mean of the batches.
+pub struct DiceMetric<B: Backend> {
+    counts: ClassCounts,
+    average: ClassAverage,
+    ignore_index: Option<usize>,
+    value: f64,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> DiceMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how the coefficient of each class is averaged, macro by default.
+    pub fn with_average(mut self, average: ClassAverage) -> Self {
+        self.average = average;
+        self
+    }
+
+    /// Sets the target class of the pixels to ignore, e.g. the boundaries or the unlabeled
+    /// pixels.
+    pub fn with_ignore_index(mut self, ignore_index: usize) -> Self {
+        self.ignore_index = Some(ignore_index);
+        self
+    }
+
+    fn dice(counts: &ClassCounts, average: ClassAverage) -> f64 {
+        // The Dice coefficient is the F1 score of the pixels.
+        100.0 * counts.fbeta(1.0, average)
+    }
+
+    fn format(&self) -> FormatOptions {
+        FormatOptions::new(Self::NAME).unit("%").precision(2)
+    }
+}
+
+impl<B: Backend> Default for DiceMetric<B> {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            counts: ClassCounts::default(),
+            average: ClassAverage::default(),
+            ignore_index: None,
+            value: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for DiceMetric<B> {
+    const NAME: &'static str = "Dice";
+
+    type Input = SegmentationInput<B>;
+
+    fn update(&mut self, input: &SegmentationInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let counts = input.counts(self.ignore_index);
+        self.counts.merge(&counts);
+        self.value = Self::dice(&self.counts, self.average);
+
+        batch_entry(
+            Self::dice(&counts, self.average),
+            Some(self.value),
+            self.format(),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.counts = ClassCounts::default();
+        self.value = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for DiceMetric<B> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<B: Backend> EpochMetric for DiceMetric<B> {
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        if self.counts.is_empty() {
+            return None;
+        }
+
+        Some(epoch_entry(
+            Self::dice(&self.counts, self.average),
+            self.format(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_dice() {
+        let device = Default::default();
+        let mut metric = DiceMetric::<TestBackend>::new();
+
+        // Predicted classes [[0, 0], [1, 1]] for the targets [[0, 1], [1, 1]].
+        let _entry = metric.update(
+            &SegmentationInput::new(
+                Tensor::from_data(
+                    [[[[0.9, 0.6], [0.2, 0.1]], [[0.1, 0.4], [0.8, 0.9]]]],
+                    &device,
+                ),
+                Tensor::from_data([[[0, 1], [1, 1]]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        // Class 0: 2 * 1 / (2 + 1), class 1: 2 * 2 / (2 + 3).
+        let expected = 100.0 * (2.0 / 3.0 + 4.0 / 5.0) / 2.0;
+        assert!((metric.value() - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,302 @@
+use super::classification::batch_entry;
+use super::sequence::{edit_distance, TokenDecoder};
+use super::state::{epoch_entry, FormatOptions};
+use super::{EpochMetric, MetricEntry, MetricMetadata, SequenceInput};
+use crate::metric::{Metric, Numeric};
+
+/// The edit distances and the reference lengths accumulated over the epoch.
+struct ErrorRateState {
+    errors: usize,
+    length: usize,
+    value: f64,
+}
+
+impl ErrorRateState {
+    fn new() -> Self {
+        Self {
+            errors: 0,
+            length: 0,
+            value: f64::NAN,
+        }
+    }
+
+    fn update<T: PartialEq>(&mut self, sequences: &[(Vec<T>, Vec<T>)]) -> f64 {
+        let (errors, length) =
+            sequences
+                .iter()
+                .fold((0, 0), |(errors, length), (prediction, reference)| {
+                    (
+                        errors + edit_distance(prediction, reference),
+                        length + reference.len(),
+                    )
+                });
+
+        self.errors += errors;
+        self.length += length;
+        self.value = rate(errors, length);
+        self.value
+    }
+
+    /// The rate of the epoch, none when no reference was accumulated.
+    fn epoch_value(&self) -> Option<f64> {
+        match self.length {
+            0 => None,
+            length => Some(rate(self.errors, length)),
+        }
+    }
+
+    fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+fn rate(errors: usize, length: usize) -> f64 {
+    100.0 * errors as f64 / length.max(1) as f64
+}
+
+/// The character error rate (CER), the number of character insertions, deletions and
+/// substitutions transforming the predictions into their references, divided by the number of
+/// characters of the references.
+///
+/// Without [decoder](Self::with_decoder), each token is a character, as with the character
+/// vocabularies of speech recognition models.
+///
+/// It is an [epoch metric](EpochMetric): the errors and the lengths are accumulated over the
+/// epoch, so the value of the epoch is the rate of all its sequences and not the mean of the
+/// batches.
+pub struct CharErrorRateMetric {
+    state: ErrorRateState,
+    decoder: Option<TokenDecoder>,
+}
+
+impl CharErrorRateMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the decoder of the tokens into text.
+    pub fn with_decoder(mut self, decoder: TokenDecoder) -> Self {
+        self.decoder = Some(decoder);
+        self
+    }
+
+    fn format() -> FormatOptions {
+        FormatOptions::new(Self::NAME).unit("%").precision(2)
+    }
+}
+
+impl Default for CharErrorRateMetric {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            state: ErrorRateState::new(),
+            decoder: None,
+        }
+    }
+}
+
+impl Metric for CharErrorRateMetric {
+    const NAME: &'static str = "CER";
+
+    type Input = SequenceInput;
+
+    fn update(&mut self, input: &SequenceInput, _metadata: &MetricMetadata) -> MetricEntry {
+        let batch = match &self.decoder {
+            Some(decoder) => {
+                let chars = |tokens: &[usize]| decoder(tokens).chars().collect::<Vec<_>>();
+                let sequences = input
+                    .pairs()
+                    .map(|(prediction, reference)| (chars(prediction), chars(reference)))
+                    .collect::<Vec<_>>();
+                self.state.update(&sequences)
+            }
+            None => {
+                let sequences = input
+                    .pairs()
+                    .map(|(prediction, reference)| (prediction.to_vec(), reference.to_vec()))
+                    .collect::<Vec<_>>();
+                self.state.update(&sequences)
+            }
+        };
+
+        batch_entry(batch, Some(self.state.epoch_value()), Self::format())
+    }
+
+    fn clear(&mut self) {
+        self.state.clear()
+    }
+}
+
+impl Numeric for CharErrorRateMetric {
+    fn value(&self) -> f64 {
+        self.state.value
+    }
+}
+
+impl EpochMetric for CharErrorRateMetric {
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        self.state
+            .epoch_value()
+            .map(|value| epoch_entry(value, Self::format()))
+    }
+}
+
+/// The word error rate (WER), the number of word insertions, deletions and substitutions
+/// transforming the predictions into their references, divided by the number of words of the
+/// references.
+///
+/// With a [decoder](Self::with_decoder), the words are the decoded text split by whitespaces,
+/// otherwise each token is a word.
+///
+/// It is an [epoch metric](EpochMetric): the errors and the lengths are accumulated over the
+/// epoch, so the value of the epoch is the rate of all its sequences and not the mean of the
+/// batches.
+pub struct WordErrorRateMetric {
+    state: ErrorRateState,
+    decoder: Option<TokenDecoder>,
+}
+
+impl WordErrorRateMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the decoder of the tokens into text.
+    pub fn with_decoder(mut self, decoder: TokenDecoder) -> Self {
+        self.decoder = Some(decoder);
+        self
+    }
+
+    fn format() -> FormatOptions {
+        FormatOptions::new(Self::NAME).unit("%").precision(2)
+    }
+}
+
+impl Default for WordErrorRateMetric {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            state: ErrorRateState::new(),
+            decoder: None,
+        }
+    }
+}
+
+impl Metric for WordErrorRateMetric {
+    const NAME: &'static str = "WER";
+
+    type Input = SequenceInput;
+
+    fn update(&mut self, input: &SequenceInput, _metadata: &MetricMetadata) -> MetricEntry {
+        let batch = match &self.decoder {
+            Some(decoder) => {
+                let words = |tokens: &[usize]| {
+                    decoder(tokens)
+                        .split_whitespace()
+                        .map(|word| word.to_string())
+                        .collect::<Vec<_>>()
+                };
+                let sequences = input
+                    .pairs()
+                    .map(|(prediction, reference)| (words(prediction), words(reference)))
+                    .collect::<Vec<_>>();
+                self.state.update(&sequences)
+            }
+            None => {
+                let sequences = input
+                    .pairs()
+                    .map(|(prediction, reference)| (prediction.to_vec(), reference.to_vec()))
+                    .collect::<Vec<_>>();
+                self.state.update(&sequences)
+            }
+        };
+
+        batch_entry(batch, Some(self.state.epoch_value()), Self::format())
+    }
+
+    fn clear(&mut self) {
+        self.state.clear()
+    }
+}
+
+impl Numeric for WordErrorRateMetric {
+    fn value(&self) -> f64 {
+        self.state.value
+    }
+}
+
+impl EpochMetric for WordErrorRateMetric {
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        self.state
+            .epoch_value()
+            .map(|value| epoch_entry(value, Self::format()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    const VOCAB: [&str; 4] = ["the", "cat", "sat", "mat"];
+
+    fn decoder() -> TokenDecoder {
+        Arc::new(|tokens: &[usize]| {
+            tokens
+                .iter()
+                .map(|token| VOCAB[*token])
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    }
+
+    #[test]
+    fn test_word_error_rate_accumulated_over_batches() {
+        let mut metric = WordErrorRateMetric::new();
+
+        // 1 substitution for 2 words.
+        let _entry = metric.update(
+            &SequenceInput::new(vec![vec![0, 1]], vec![vec![0, 3]]),
+            &MetricMetadata::fake(),
+        );
+        assert_eq!(metric.value(), 50.0);
+
+        // 1 deletion for 4 words.
+        let _entry = metric.update(
+            &SequenceInput::new(vec![vec![0, 1, 2]], vec![vec![0, 1, 2, 3]]),
+            &MetricMetadata::fake(),
+        );
+        assert_eq!(metric.value(), 25.0);
+
+        // The mean of the batches would be 37.5%.
+        let entry = metric.epoch_entry().unwrap();
+        assert_eq!(entry.serialize, format!("epoch:{}", 100.0 * 2.0 / 6.0));
+    }
+
+    #[test]
+    fn test_word_error_rate_without_references_has_no_epoch_entry() {
+        let mut metric = WordErrorRateMetric::new();
+        assert!(metric.epoch_entry().is_none());
+
+        let _entry = metric.update(
+            &SequenceInput::new(vec![vec![0, 1]], vec![vec![]]),
+            &MetricMetadata::fake(),
+        );
+        assert!(metric.epoch_entry().is_none());
+    }
+
+    #[test]
+    fn test_char_error_rate_with_decoder() {
+        let mut metric = CharErrorRateMetric::new().with_decoder(decoder());
+
+        // "the cat" -> "the mat": 1 substitution for 7 characters.
+        let _entry = metric.update(
+            &SequenceInput::new(vec![vec![0, 1]], vec![vec![0, 3]]),
+            &MetricMetadata::fake(),
+        );
+
+        assert_eq!(metric.value(), 100.0 / 7.0);
+    }
+}
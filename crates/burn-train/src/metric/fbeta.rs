@@ -0,0 +1,140 @@
+use core::marker::PhantomData;
+
+use super::classification::{batch_entry, ClassCounts, Decision};
+use super::state::{epoch_entry, FormatOptions};
+use super::{ClassAverage, ClassificationInput, EpochMetric, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+
+/// The F-beta score, the weighted harmonic mean of the [precision](super::PrecisionMetric) and
+/// the [recall](super::RecallMetric), where the recall is considered `beta` times as important
+/// as the precision.
+///
+/// It is an [epoch metric](EpochMetric): the true and false positives and negatives are
+/// accumulated over the epoch, so the value of the epoch is the score of all its items and not
+/// the mean of the batches.
+pub struct FBetaScoreMetric<B: Backend> {
+    beta: f64,
+    counts: ClassCounts,
+    average: ClassAverage,
+    decision: Decision,
+    value: f64,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> FBetaScoreMetric<B> {
+    /// Creates the metric with the given beta.
+    pub fn new(beta: f64) -> Self {
+        assert!(beta > 0.0, "Beta should be positive, got {beta}");
+
+        Self {
+            beta,
+            counts: ClassCounts::default(),
+            average: ClassAverage::default(),
+            decision: Decision::default(),
+            value: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+
+    /// Creates the F1 score metric, the harmonic mean of the precision and the recall.
+    pub fn f1() -> Self {
+        Self::new(1.0)
+    }
+
+    /// Sets how the score of each class is averaged, macro by default.
+    pub fn with_average(mut self, average: ClassAverage) -> Self {
+        self.average = average;
+        self
+    }
+
+    /// Sets the decision threshold of the multi-label classifications.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.decision.threshold = threshold;
+        self
+    }
+
+    /// Sets the sigmoid activation function usage on the multi-label outputs before the
+    /// threshold.
+    pub fn with_sigmoid(mut self, sigmoid: bool) -> Self {
+        self.decision.sigmoid = sigmoid;
+        self
+    }
+
+    fn format(&self) -> FormatOptions {
+        FormatOptions::new(Self::NAME).unit("%").precision(2)
+    }
+}
+
+impl<B: Backend> Metric for FBetaScoreMetric<B> {
+    const NAME: &'static str = "FBeta Score";
+
+    type Input = ClassificationInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let counts = ClassCounts::from_input(input, &self.decision);
+        self.counts.merge(&counts);
+        self.value = 100.0 * self.counts.fbeta(self.beta, self.average);
+
+        batch_entry(
+            100.0 * counts.fbeta(self.beta, self.average),
+            Some(self.value),
+            self.format(),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.counts = ClassCounts::default();
+        self.value = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for FBetaScoreMetric<B> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<B: Backend> EpochMetric for FBetaScoreMetric<B> {
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        if self.counts.is_empty() {
+            return None;
+        }
+
+        Some(epoch_entry(
+            100.0 * self.counts.fbeta(self.beta, self.average),
+            self.format(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_fbeta_score() {
+        let device = Default::default();
+        let outputs = Tensor::from_data([[0.7, 0.2, 0.6], [0.4, 0.9, 0.1]], &device); // [1, 0, 1], [0, 1, 0]
+        let targets = Tensor::from_data([[1, 0, 0], [1, 1, 0]], &device);
+        let input = ClassificationInput::multilabel(outputs, targets);
+
+        // Precision 2/3 and recall 2/3.
+        let mut metric = FBetaScoreMetric::<TestBackend>::f1().with_average(ClassAverage::Micro);
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 200.0 / 3.0).abs() < 1e-9);
+
+        // With a threshold of 0.65, precision 1 and recall 2/3.
+        let mut metric = FBetaScoreMetric::<TestBackend>::new(2.0)
+            .with_average(ClassAverage::Micro)
+            .with_threshold(0.65);
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 500.0 / 7.0).abs() < 1e-9);
+    }
+}
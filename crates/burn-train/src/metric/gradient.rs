@@ -0,0 +1,338 @@
+use core::marker::PhantomData;
+
+use burn_core::module::{AutodiffModule, ModuleVisitor, ParamId};
+use burn_core::optim::GradientsParams;
+use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::{container::TensorContainer, ElementConversion, Tensor};
+
+use super::state::{FormatOptions, NumericMetricState};
+use super::{MetricEntry, MetricMetadata, Numeric};
+use crate::metric::Metric;
+
+/// Statistics of the gradients of a training iteration, computed when the
+/// [gradient statistics](crate::learner::LearnerBuilder::gradient_stats) are enabled.
+#[derive(Debug, Clone, Default)]
+pub struct GradientStats {
+    /// The L2 norm of all the gradients.
+    pub norm: f64,
+    /// The L2 norm of the gradients of each top-level module of the model, by field name.
+    pub module_norms: Vec<(String, f64)>,
+    /// The ratio between the L2 norm of the parameter updates and the L2 norm of the parameters,
+    /// when the optimizer stepped during the iteration.
+    pub update_ratio: Option<f64>,
+}
+
+impl GradientStats {
+    /// Compute the norms of the gradients of the given module.
+    pub fn from_grads<B: AutodiffBackend, M: AutodiffModule<B>>(
+        grads: &GradientsParams,
+        module: &M,
+    ) -> Self {
+        let mut visitor = GradientNorms::<B> {
+            grads,
+            path: Vec::new(),
+            sum_squares: 0.0,
+            modules: Vec::new(),
+            _backend: PhantomData,
+        };
+        module.visit(&mut visitor);
+
+        Self {
+            norm: visitor.sum_squares.sqrt(),
+            module_norms: visitor
+                .modules
+                .into_iter()
+                .map(|(name, sum_squares)| (name, sum_squares.sqrt()))
+                .collect(),
+            update_ratio: None,
+        }
+    }
+
+    /// Set the ratio between the norm of the parameter updates and the norm of the parameters,
+    /// given the module before and after the optimizer step.
+    pub fn with_update_ratio<B: AutodiffBackend, M: AutodiffModule<B>>(
+        mut self,
+        before: &M,
+        after: &M,
+    ) -> Self {
+        let mut collector = ParamsCollector::<B> {
+            params: TensorContainer::new(),
+            _backend: PhantomData,
+        };
+        before.visit(&mut collector);
+
+        let mut visitor = UpdateNorms::<B> {
+            params: collector.params,
+            sum_squares_params: 0.0,
+            sum_squares_updates: 0.0,
+            _backend: PhantomData,
+        };
+        after.visit(&mut visitor);
+
+        let norm_params = visitor.sum_squares_params.sqrt();
+        self.update_ratio = match norm_params > 0.0 {
+            true => Some(visitor.sum_squares_updates.sqrt() / norm_params),
+            false => None,
+        };
+        self
+    }
+}
+
+fn sum_squares<B: AutodiffBackend, const D: usize>(tensor: Tensor<B::InnerBackend, D>) -> f64 {
+    tensor.powf_scalar(2.0).sum().into_scalar().elem::<f64>()
+}
+
+struct GradientNorms<'a, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    path: Vec<String>,
+    sum_squares: f64,
+    modules: Vec<(String, f64)>,
+    _backend: PhantomData<B>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientNorms<'a, B> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) else {
+            return;
+        };
+        let sum_squares = sum_squares::<B, D>(grad);
+        self.sum_squares += sum_squares;
+
+        let module = self.path.first().cloned().unwrap_or_default();
+        match self.modules.iter_mut().find(|(name, _)| *name == module) {
+            Some((_, sum)) => *sum += sum_squares,
+            None => self.modules.push((module, sum_squares)),
+        }
+    }
+}
+
+struct ParamsCollector<B: AutodiffBackend> {
+    params: TensorContainer<ParamId>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for ParamsCollector<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params
+            .register::<B::InnerBackend>(id.clone(), tensor.clone().inner().into_primitive());
+    }
+}
+
+struct UpdateNorms<B: AutodiffBackend> {
+    params: TensorContainer<ParamId>,
+    sum_squares_params: f64,
+    sum_squares_updates: f64,
+    _backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for UpdateNorms<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let Some(before) = self.params.remove::<B::InnerBackend>(id) else {
+            return;
+        };
+        let before = Tensor::<B::InnerBackend, D>::from_primitive(before);
+        let after = tensor.clone().inner();
+
+        self.sum_squares_updates += sum_squares::<B, D>(after.sub(before.clone()));
+        self.sum_squares_params += sum_squares::<B, D>(before);
+    }
+}
+
+/// The L2 norm of all the gradients of the model.
+#[derive(Default)]
+pub struct GradientNormMetric {
+    state: NumericMetricState,
+}
+
+impl GradientNormMetric {
+    /// Creates a new gradient norm metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Metric for GradientNormMetric {
+    const NAME: &'static str = "Gradient Norm";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let norm = metadata.gradients.as_ref().map_or(0.0, |stats| stats.norm);
+
+        self.state
+            .update(norm, 1, FormatOptions::new(Self::NAME).precision(4))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for GradientNormMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+/// The L2 norm of the gradients of a top-level module of the model.
+pub struct ModuleGradientNormMetric {
+    module: String,
+    name: String,
+    state: NumericMetricState,
+}
+
+impl ModuleGradientNormMetric {
+    /// Creates a new gradient norm metric for the module with the given field name.
+    pub fn new(module: &str) -> Self {
+        Self {
+            module: module.to_string(),
+            name: format!("Gradient Norm ({module})"),
+            state: NumericMetricState::new(),
+        }
+    }
+}
+
+impl Metric for ModuleGradientNormMetric {
+    const NAME: &'static str = "Module Gradient Norm";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let norm = metadata
+            .gradients
+            .as_ref()
+            .and_then(|stats| {
+                stats
+                    .module_norms
+                    .iter()
+                    .find(|(module, _)| *module == self.module)
+            })
+            .map_or(0.0, |(_, norm)| *norm);
+
+        self.state
+            .update(norm, 1, FormatOptions::new(&self.name).precision(4))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for ModuleGradientNormMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+/// The ratio between the L2 norm of the parameter updates of the optimizer and the L2 norm of the
+/// parameters.
+///
+/// The iterations where the optimizer doesn't step, e.g. when accumulating gradients, are not
+/// taken into account.
+#[derive(Default)]
+pub struct UpdateRatioMetric {
+    state: NumericMetricState,
+    last: f64,
+}
+
+impl UpdateRatioMetric {
+    /// Creates a new update ratio metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Metric for UpdateRatioMetric {
+    const NAME: &'static str = "Update Ratio";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let ratio = metadata
+            .gradients
+            .as_ref()
+            .and_then(|stats| stats.update_ratio);
+        let format = FormatOptions::new(Self::NAME).precision(6);
+
+        match ratio {
+            Some(ratio) => {
+                self.last = ratio;
+                self.state.update(ratio, 1, format)
+            }
+            // The last ratio is displayed without being aggregated.
+            None => self.state.update(self.last, 0, format),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.last = 0.0;
+    }
+}
+
+impl Numeric for UpdateRatioMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::backend::AutodiffBackend;
+
+    type TestInnerBackend = <TestAutodiffBackend as AutodiffBackend>::InnerBackend;
+
+    #[test]
+    fn should_compute_the_gradient_norms() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(2, 2).init(&device);
+        let mut grads = GradientsParams::new();
+        grads.register::<TestInnerBackend, 2>(
+            linear.weight.id.clone(),
+            Tensor::full([2, 2], 1.5, &device),
+        );
+        grads.register::<TestInnerBackend, 1>(
+            linear.bias.as_ref().unwrap().id.clone(),
+            Tensor::full([2], 2.0, &device),
+        );
+
+        let stats = GradientStats::from_grads::<TestAutodiffBackend, _>(&grads, &linear);
+
+        // sqrt(4 * 1.5^2 + 2 * 2^2)
+        assert!((stats.norm - 17.0f64.sqrt()).abs() < 1e-6);
+        assert_eq!(stats.module_norms.len(), 2);
+        assert_eq!(stats.module_norms[0].0, "weight");
+        assert!((stats.module_norms[0].1 - 3.0).abs() < 1e-6);
+        assert_eq!(stats.module_norms[1].0, "bias");
+        assert!((stats.module_norms[1].1 - 8.0f64.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn should_compute_the_update_ratio() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> =
+            LinearConfig::new(2, 2).with_bias(false).init(&device);
+        let mut before = linear.clone();
+        before.weight = before.weight.map(|weight| weight.ones_like());
+        let mut after = linear;
+        after.weight = after
+            .weight
+            .map(|weight| weight.ones_like().mul_scalar(3.0));
+
+        let stats =
+            GradientStats::default().with_update_ratio::<TestAutodiffBackend, _>(&before, &after);
+
+        assert_eq!(stats.update_ratio, Some(2.0));
+    }
+}
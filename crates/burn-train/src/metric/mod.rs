@@ -2,35 +2,69 @@
 pub mod state;
 
 mod acc;
+mod auroc;
+mod average_precision;
 mod base;
+mod bleu;
+mod classification;
+mod confusion_matrix;
 #[cfg(feature = "metrics")]
 mod cpu_temp;
 #[cfg(feature = "metrics")]
 mod cpu_use;
 #[cfg(feature = "metrics")]
 mod cuda;
+mod detection;
+mod dice;
+mod error_rate;
+mod fbeta;
+mod gradient;
 mod hamming;
+mod iou;
 mod learning_rate;
 mod loss;
 #[cfg(feature = "metrics")]
 mod memory_use;
+mod perplexity;
+mod precision;
+mod recall;
+mod rouge;
+mod segmentation;
+mod sequence;
 
 #[cfg(feature = "metrics")]
 mod top_k_acc;
 
 pub use acc::*;
+pub use auroc::*;
+pub use average_precision::*;
 pub use base::*;
+pub use bleu::*;
+pub use classification::{ClassAverage, ClassificationInput};
+pub use confusion_matrix::*;
 #[cfg(feature = "metrics")]
 pub use cpu_temp::*;
 #[cfg(feature = "metrics")]
 pub use cpu_use::*;
 #[cfg(feature = "metrics")]
 pub use cuda::*;
+pub use detection::{BoundingBox, Detection, DetectionInput};
+pub use dice::*;
+pub use error_rate::*;
+pub use fbeta::*;
+pub use gradient::*;
 pub use hamming::*;
+pub use iou::*;
 pub use learning_rate::*;
 pub use loss::*;
 #[cfg(feature = "metrics")]
 pub use memory_use::*;
+pub use perplexity::*;
+pub use precision::*;
+pub use recall::*;
+pub use rouge::*;
+pub use segmentation::*;
+pub use sequence::{SequenceInput, TokenDecoder};
 #[cfg(feature = "metrics")]
 pub use top_k_acc::*;
 
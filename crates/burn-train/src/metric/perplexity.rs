@@ -0,0 +1,170 @@
+use core::marker::PhantomData;
+
+use super::classification::batch_entry;
+use super::state::{epoch_entry, FormatOptions};
+use super::{EpochMetric, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::activation::log_softmax;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Bool, ElementConversion, Int, Tensor};
+
+/// The perplexity metric, the exponential of the mean negative log-likelihood of the target
+/// tokens.
+///
+/// It is an [epoch metric](EpochMetric): the negative log-likelihood and the number of tokens are
+/// accumulated over the epoch, so the value of the epoch is the perplexity of all its tokens and
+/// not the mean of the batches. The padding tokens are ignored.
+pub struct PerplexityMetric<B: Backend> {
+    sum_nll: f64,
+    num_tokens: usize,
+    value: f64,
+    _b: PhantomData<B>,
+}
+
+/// The [perplexity metric](PerplexityMetric) input type.
+#[derive(new)]
+pub struct PerplexityInput<B: Backend> {
+    /// The logits of shape `[batch_size, seq_length, vocab_size]`.
+    logits: Tensor<B, 3>,
+    /// The target tokens of shape `[batch_size, seq_length]`.
+    targets: Tensor<B, 2, Int>,
+    /// The padding mask of shape `[batch_size, seq_length]`, `true` for the padding tokens.
+    mask_pad: Option<Tensor<B, 2, Bool>>,
+}
+
+impl<B: Backend> PerplexityMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn format() -> FormatOptions {
+        FormatOptions::new(Self::NAME).precision(2)
+    }
+}
+
+impl<B: Backend> Default for PerplexityMetric<B> {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            sum_nll: 0.0,
+            num_tokens: 0,
+            value: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for PerplexityMetric<B> {
+    const NAME: &'static str = "Perplexity";
+
+    type Input = PerplexityInput<B>;
+
+    fn update(&mut self, input: &PerplexityInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, seq_length, _vocab_size] = input.logits.dims();
+
+        let nll = log_softmax(input.logits.clone(), 2)
+            .gather(
+                2,
+                input.targets.clone().reshape([batch_size, seq_length, 1]),
+            )
+            .reshape([batch_size, seq_length])
+            .neg();
+
+        let (nll, num_tokens) = match &input.mask_pad {
+            Some(mask_pad) => {
+                let num_pad = mask_pad.clone().int().sum().into_scalar().elem::<i64>() as usize;
+                (
+                    nll.mask_fill(mask_pad.clone(), 0.0),
+                    batch_size * seq_length - num_pad,
+                )
+            }
+            None => (nll, batch_size * seq_length),
+        };
+        let sum_nll = nll.sum().into_scalar().elem::<f64>();
+
+        self.sum_nll += sum_nll;
+        self.num_tokens += num_tokens;
+        self.value = (sum_nll / num_tokens as f64).exp();
+
+        batch_entry(
+            self.value,
+            Some((self.sum_nll / self.num_tokens as f64).exp()),
+            Self::format(),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.sum_nll = 0.0;
+        self.num_tokens = 0;
+        self.value = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for PerplexityMetric<B> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<B: Backend> EpochMetric for PerplexityMetric<B> {
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        if self.num_tokens == 0 {
+            return None;
+        }
+
+        Some(epoch_entry(
+            (self.sum_nll / self.num_tokens as f64).exp(),
+            Self::format(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_perplexity_with_padding() {
+        let device = Default::default();
+        let mut metric = PerplexityMetric::<TestBackend>::new();
+
+        // Uniform distributions over 4 tokens, the perplexity is 4.
+        let logits = Tensor::zeros([2, 3, 4], &device);
+        let targets = Tensor::from_data([[1, 2, 0], [3, 0, 0]], &device);
+        let mask_pad = Tensor::from_data([[false, false, true], [false, true, true]], &device);
+
+        let _entry = metric.update(
+            &PerplexityInput::new(logits, targets, Some(mask_pad)),
+            &MetricMetadata::fake(),
+        );
+
+        assert!((metric.value() - 4.0).abs() < 1e-4);
+        assert_eq!(metric.num_tokens, 3);
+    }
+
+    #[test]
+    fn test_perplexity_accumulated_over_batches() {
+        let device = Default::default();
+        let mut metric = PerplexityMetric::<TestBackend>::new();
+
+        // The probability of the targets is 1/2, then 1/8 for 3 tokens.
+        let logits = Tensor::from_data([[[0.0, 0.0]]], &device);
+        let _entry = metric.update(
+            &PerplexityInput::new(logits, Tensor::from_data([[0]], &device), None),
+            &MetricMetadata::fake(),
+        );
+        let logits = Tensor::from_data([[[0.0; 8], [0.0; 8], [0.0; 8]]], &device);
+        let _entry = metric.update(
+            &PerplexityInput::new(logits, Tensor::from_data([[0, 1, 2]], &device), None),
+            &MetricMetadata::fake(),
+        );
+
+        // exp((ln(2) + 3 ln(8)) / 4) = 2^(10 / 4)
+        let expected = 2.0f64.powf(2.5);
+        let entry = metric.epoch_entry().unwrap();
+        let value = entry.serialize.strip_prefix("epoch:").unwrap();
+        assert!((value.parse::<f64>().unwrap() - expected).abs() < 1e-4);
+    }
+}
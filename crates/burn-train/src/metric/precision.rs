@@ -0,0 +1,151 @@
+use core::marker::PhantomData;
+
+use super::classification::{batch_entry, ClassCounts, Decision};
+use super::state::{epoch_entry, FormatOptions};
+use super::{ClassAverage, ClassificationInput, EpochMetric, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+
+/// The precision metric, the proportion of the predictions that are correct.
+///
+/// It is an [epoch metric](EpochMetric): the true and false positives and negatives are
+/// accumulated over the epoch, so the value of the epoch is the precision of all its items and not
+/// the mean of the batches.
+pub struct PrecisionMetric<B: Backend> {
+    counts: ClassCounts,
+    average: ClassAverage,
+    decision: Decision,
+    value: f64,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> PrecisionMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how the precision of each class is averaged, macro by default.
+    pub fn with_average(mut self, average: ClassAverage) -> Self {
+        self.average = average;
+        self
+    }
+
+    /// Sets the decision threshold of the multi-label classifications.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.decision.threshold = threshold;
+        self
+    }
+
+    /// Sets the sigmoid activation function usage on the multi-label outputs before the
+    /// threshold.
+    pub fn with_sigmoid(mut self, sigmoid: bool) -> Self {
+        self.decision.sigmoid = sigmoid;
+        self
+    }
+
+    fn format(&self) -> FormatOptions {
+        FormatOptions::new(Self::NAME).unit("%").precision(2)
+    }
+}
+
+impl<B: Backend> Default for PrecisionMetric<B> {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            counts: ClassCounts::default(),
+            average: ClassAverage::default(),
+            decision: Decision::default(),
+            value: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for PrecisionMetric<B> {
+    const NAME: &'static str = "Precision";
+
+    type Input = ClassificationInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let counts = ClassCounts::from_input(input, &self.decision);
+        self.counts.merge(&counts);
+        self.value = 100.0 * self.counts.precision(self.average);
+
+        batch_entry(
+            100.0 * counts.precision(self.average),
+            Some(self.value),
+            self.format(),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.counts = ClassCounts::default();
+        self.value = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for PrecisionMetric<B> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<B: Backend> EpochMetric for PrecisionMetric<B> {
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        if self.counts.is_empty() {
+            return None;
+        }
+
+        Some(epoch_entry(
+            100.0 * self.counts.precision(self.average),
+            self.format(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_precision_accumulated_over_batches() {
+        let device = Default::default();
+        let mut metric = PrecisionMetric::<TestBackend>::new();
+
+        // Class 0: 1 true positive and 1 false positive, class 1: 1 false negative.
+        let _entry = metric.update(
+            &ClassificationInput::new(
+                Tensor::from_data([[0.9, 0.1], [0.8, 0.2]], &device),
+                Tensor::from_data([0, 1], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+        assert_eq!(25.0, metric.value());
+
+        // Class 1: 1 true positive.
+        let _entry = metric.update(
+            &ClassificationInput::new(
+                Tensor::from_data([[0.1, 0.9]], &device),
+                Tensor::from_data([1], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+        // The mean of the batches would be 50%.
+        assert_eq!(75.0, metric.value());
+        assert_eq!(metric.epoch_entry().unwrap().serialize, "epoch:75");
+    }
+
+    #[test]
+    fn test_precision_without_items_has_no_epoch_entry() {
+        let metric = PrecisionMetric::<TestBackend>::new();
+
+        assert!(metric.epoch_entry().is_none());
+    }
+}
@@ -1,6 +1,8 @@
 use burn_core::data::dataloader::Progress;
 use burn_core::LearningRate;
 
+use crate::metric::GradientStats;
+
 /// Event happening during the training/validation process.
 pub enum Event<T> {
     /// Signal that an item have been processed.
@@ -42,4 +44,8 @@ pub struct LearnerItem<T> {
 
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The statistics of the gradients, only computed for training items when enabled.
+    #[new(default)]
+    pub gradients: Option<GradientStats>,
 }
@@ -57,7 +57,9 @@ impl<T, V> EventProcessor for FullEventProcessor<T, V> {
                 self.renderer.render_train(progress);
             }
             Event::EndEpoch(epoch) => {
-                self.metrics.end_epoch_train();
+                let update = self.metrics.end_epoch_train();
+                self.store
+                    .add_event_train(crate::metric::store::Event::MetricsUpdate(update));
                 self.store
                     .add_event_train(crate::metric::store::Event::EndEpoch(epoch));
             }
@@ -91,7 +93,9 @@ impl<T, V> EventProcessor for FullEventProcessor<T, V> {
                 self.renderer.render_valid(progress);
             }
             Event::EndEpoch(epoch) => {
-                self.metrics.end_epoch_valid();
+                let update = self.metrics.end_epoch_valid();
+                self.store
+                    .add_event_valid(crate::metric::store::Event::MetricsUpdate(update));
                 self.store
                     .add_event_valid(crate::metric::store::Event::EndEpoch(epoch));
             }
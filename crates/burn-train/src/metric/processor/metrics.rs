@@ -1,6 +1,8 @@
 use super::LearnerItem;
 use crate::{
-    metric::{store::MetricsUpdate, Adaptor, Metric, MetricEntry, MetricMetadata, Numeric},
+    metric::{
+        store::MetricsUpdate, Adaptor, EpochMetric, Metric, MetricEntry, MetricMetadata, Numeric,
+    },
     renderer::TrainingProgress,
 };
 
@@ -9,6 +11,8 @@ pub(crate) struct Metrics<T, V> {
     valid: Vec<Box<dyn MetricUpdater<V>>>,
     train_numeric: Vec<Box<dyn NumericMetricUpdater<T>>>,
     valid_numeric: Vec<Box<dyn NumericMetricUpdater<V>>>,
+    train_epoch: Vec<Box<dyn EpochMetricUpdater<T>>>,
+    valid_epoch: Vec<Box<dyn EpochMetricUpdater<V>>>,
 }
 
 impl<T, V> Default for Metrics<T, V> {
@@ -18,6 +22,8 @@ impl<T, V> Default for Metrics<T, V> {
             valid: Vec::default(),
             train_numeric: Vec::default(),
             valid_numeric: Vec::default(),
+            train_epoch: Vec::default(),
+            valid_epoch: Vec::default(),
         }
     }
 }
@@ -63,6 +69,24 @@ impl<T, V> Metrics<T, V> {
         self.valid_numeric.push(Box::new(metric))
     }
 
+    /// Register an epoch training metric.
+    pub(crate) fn register_train_metric_epoch<Me: EpochMetric + 'static>(&mut self, metric: Me)
+    where
+        T: Adaptor<Me::Input> + 'static,
+    {
+        let metric = MetricWrapper::new(metric);
+        self.train_epoch.push(Box::new(metric))
+    }
+
+    /// Register an epoch validation metric.
+    pub(crate) fn register_valid_metric_epoch<Me: EpochMetric + 'static>(&mut self, metric: Me)
+    where
+        V: Adaptor<Me::Input> + 'static,
+    {
+        let metric = MetricWrapper::new(metric);
+        self.valid_epoch.push(Box::new(metric))
+    }
+
     /// Update the training information from the training item.
     pub(crate) fn update_train(
         &mut self,
@@ -82,6 +106,11 @@ impl<T, V> Metrics<T, V> {
             entries_numeric.push((state, value));
         }
 
+        for metric in self.train_epoch.iter_mut() {
+            let (state, value) = metric.update(item, metadata);
+            entries_numeric.push((state, value));
+        }
+
         MetricsUpdate::new(entries, entries_numeric)
     }
 
@@ -104,27 +133,54 @@ impl<T, V> Metrics<T, V> {
             entries_numeric.push((state, value));
         }
 
+        for metric in self.valid_epoch.iter_mut() {
+            let (state, value) = metric.update(item, metadata);
+            entries_numeric.push((state, value));
+        }
+
         MetricsUpdate::new(entries, entries_numeric)
     }
 
-    /// Signal the end of a training epoch.
-    pub(crate) fn end_epoch_train(&mut self) {
+    /// Signal the end of a training epoch, returning the entries of the epoch metrics.
+    pub(crate) fn end_epoch_train(&mut self) -> MetricsUpdate {
+        let entries = self
+            .train_epoch
+            .iter()
+            .filter_map(|metric| metric.epoch_entry())
+            .collect();
+
         for metric in self.train.iter_mut() {
             metric.clear();
         }
         for metric in self.train_numeric.iter_mut() {
             metric.clear();
         }
+        for metric in self.train_epoch.iter_mut() {
+            metric.clear();
+        }
+
+        MetricsUpdate::new(entries, Vec::new())
     }
 
-    /// Signal the end of a validation epoch.
-    pub(crate) fn end_epoch_valid(&mut self) {
+    /// Signal the end of a validation epoch, returning the entries of the epoch metrics.
+    pub(crate) fn end_epoch_valid(&mut self) -> MetricsUpdate {
+        let entries = self
+            .valid_epoch
+            .iter()
+            .filter_map(|metric| metric.epoch_entry())
+            .collect();
+
         for metric in self.valid.iter_mut() {
             metric.clear();
         }
         for metric in self.valid_numeric.iter_mut() {
             metric.clear();
         }
+        for metric in self.valid_epoch.iter_mut() {
+            metric.clear();
+        }
+
+        MetricsUpdate::new(entries, Vec::new())
     }
 }
 
@@ -147,6 +203,7 @@ impl<T> From<&LearnerItem<T>> for MetricMetadata {
             epoch_total: item.epoch_total,
             iteration: item.iteration,
             lr: item.lr,
+            gradients: item.gradients.clone(),
         }
     }
 }
@@ -156,6 +213,10 @@ trait NumericMetricUpdater<T>: Send + Sync {
     fn clear(&mut self);
 }
 
+trait EpochMetricUpdater<T>: NumericMetricUpdater<T> {
+    fn epoch_entry(&self) -> Option<MetricEntry>;
+}
+
 trait MetricUpdater<T>: Send + Sync {
     fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) -> MetricEntry;
     fn clear(&mut self);
@@ -198,3 +259,14 @@ where
         self.metric.clear()
     }
 }
+
+impl<T, M> EpochMetricUpdater<T> for MetricWrapper<M>
+where
+    T: 'static,
+    M: EpochMetric + 'static,
+    T: Adaptor<M::Input>,
+{
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        self.metric.epoch_entry()
+    }
+}
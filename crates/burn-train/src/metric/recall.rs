@@ -0,0 +1,133 @@
+use core::marker::PhantomData;
+
+use super::classification::{batch_entry, ClassCounts, Decision};
+use super::state::{epoch_entry, FormatOptions};
+use super::{ClassAverage, ClassificationInput, EpochMetric, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+
+/// The recall metric, the proportion of the targets that are predicted.
+///
+/// It is an [epoch metric](EpochMetric): the true and false positives and negatives are
+/// accumulated over the epoch, so the value of the epoch is the recall of all its items and not
+/// the mean of the batches.
+pub struct RecallMetric<B: Backend> {
+    counts: ClassCounts,
+    average: ClassAverage,
+    decision: Decision,
+    value: f64,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> RecallMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how the recall of each class is averaged, macro by default.
+    pub fn with_average(mut self, average: ClassAverage) -> Self {
+        self.average = average;
+        self
+    }
+
+    /// Sets the decision threshold of the multi-label classifications.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.decision.threshold = threshold;
+        self
+    }
+
+    /// Sets the sigmoid activation function usage on the multi-label outputs before the
+    /// threshold.
+    pub fn with_sigmoid(mut self, sigmoid: bool) -> Self {
+        self.decision.sigmoid = sigmoid;
+        self
+    }
+
+    fn format(&self) -> FormatOptions {
+        FormatOptions::new(Self::NAME).unit("%").precision(2)
+    }
+}
+
+impl<B: Backend> Default for RecallMetric<B> {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            counts: ClassCounts::default(),
+            average: ClassAverage::default(),
+            decision: Decision::default(),
+            value: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for RecallMetric<B> {
+    const NAME: &'static str = "Recall";
+
+    type Input = ClassificationInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let counts = ClassCounts::from_input(input, &self.decision);
+        self.counts.merge(&counts);
+        self.value = 100.0 * self.counts.recall(self.average);
+
+        batch_entry(
+            100.0 * counts.recall(self.average),
+            Some(self.value),
+            self.format(),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.counts = ClassCounts::default();
+        self.value = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for RecallMetric<B> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<B: Backend> EpochMetric for RecallMetric<B> {
+    fn epoch_entry(&self) -> Option<MetricEntry> {
+        if self.counts.is_empty() {
+            return None;
+        }
+
+        Some(epoch_entry(
+            100.0 * self.counts.recall(self.average),
+            self.format(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_recall_multilabel() {
+        let device = Default::default();
+        let mut metric = RecallMetric::<TestBackend>::new().with_average(ClassAverage::Micro);
+
+        let _entry = metric.update(
+            &ClassificationInput::multilabel(
+                Tensor::from_data([[0.7, 0.2, 0.6], [0.4, 0.9, 0.1]], &device), // [1, 0, 1], [0, 1, 0]
+                Tensor::from_data([[1, 0, 0], [1, 1, 0]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        // 2 of the 3 targets are predicted.
+        assert!((metric.value() - 200.0 / 3.0).abs() < 1e-9);
+    }
+}
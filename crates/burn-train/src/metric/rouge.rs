@@ -0,0 +1,108 @@
+use super::sequence::longest_common_subsequence;
+use super::state::{FormatOptions, NumericMetricState};
+use super::{MetricEntry, MetricMetadata, SequenceInput};
+use crate::metric::{Metric, Numeric};
+
+/// The ROUGE-L score, the F-measure of the longest common subsequence of the predicted and the
+/// reference sequences.
+///
+/// The value is the mean of the scores of the sequences, so the batches are weighted by their
+/// number of sequences.
+pub struct RougeLMetric {
+    state: NumericMetricState,
+    beta: f64,
+}
+
+impl RougeLMetric {
+    /// Creates the metric, with the harmonic mean of the precision and the recall.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the beta of the F-measure, where the recall is considered `beta` times as important
+    /// as the precision.
+    pub fn with_beta(mut self, beta: f64) -> Self {
+        self.beta = beta;
+        self
+    }
+
+    fn score(&self, prediction: &[usize], reference: &[usize]) -> f64 {
+        let lcs = longest_common_subsequence(prediction, reference) as f64;
+        if lcs == 0.0 {
+            return 0.0;
+        }
+
+        let precision = lcs / prediction.len() as f64;
+        let recall = lcs / reference.len() as f64;
+        let beta2 = self.beta * self.beta;
+
+        (1.0 + beta2) * precision * recall / (recall + beta2 * precision)
+    }
+}
+
+impl Default for RougeLMetric {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            state: NumericMetricState::default(),
+            beta: 1.0,
+        }
+    }
+}
+
+impl Metric for RougeLMetric {
+    const NAME: &'static str = "ROUGE-L";
+
+    type Input = SequenceInput;
+
+    fn update(&mut self, input: &SequenceInput, _metadata: &MetricMetadata) -> MetricEntry {
+        let (sum, num_sequences) =
+            input
+                .pairs()
+                .fold((0.0, 0), |(sum, count), (prediction, reference)| {
+                    (sum + self.score(prediction, reference), count + 1)
+                });
+        let score = match num_sequences {
+            0 => 0.0,
+            _ => sum / num_sequences as f64,
+        };
+
+        self.state.update(
+            100.0 * score,
+            num_sequences,
+            FormatOptions::new(Self::NAME).precision(2),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for RougeLMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rouge_l() {
+        let mut metric = RougeLMetric::new();
+
+        // LCS of 3 tokens: precision 3/4 and recall 3/5, then a perfect match.
+        let _entry = metric.update(
+            &SequenceInput::new(
+                vec![vec![1, 2, 9, 4], vec![7, 8]],
+                vec![vec![1, 2, 3, 4, 5], vec![7, 8]],
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        let f1 = 2.0 * 0.75 * 0.6 / (0.75 + 0.6);
+        assert!((metric.value() - 100.0 * (f1 + 1.0) / 2.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,77 @@
+use super::classification::ClassCounts;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// The input of the semantic segmentation metrics, e.g. the [mean IoU](super::MeanIouMetric) and
+/// the [Dice](super::DiceMetric) metrics.
+///
+/// Each pixel is predicted as the class of its highest output.
+#[derive(new)]
+pub struct SegmentationInput<B: Backend> {
+    /// The outputs of the model of shape `[batch_size, num_classes, height, width]`.
+    outputs: Tensor<B, 4>,
+    /// The target classes of shape `[batch_size, height, width]`.
+    targets: Tensor<B, 3, Int>,
+}
+
+impl<B: Backend> SegmentationInput<B> {
+    /// Counts the pixels of each class, the pixels targeted as the ignored class are skipped.
+    pub(crate) fn counts(&self, ignore_index: Option<usize>) -> ClassCounts {
+        let [batch_size, num_classes, height, width] = self.outputs.dims();
+        let num_pixels = batch_size * height * width;
+        let device = self.outputs.device();
+
+        let outputs = self
+            .outputs
+            .clone()
+            .swap_dims(1, 2)
+            .swap_dims(2, 3)
+            .reshape([num_pixels, num_classes]);
+        let mut targets = self.targets.clone().reshape([num_pixels, 1]);
+
+        // The ignored pixels are one-hot encoded with zeros.
+        let values = match ignore_index {
+            Some(index) => {
+                let ignored = targets.clone().equal_elem(index as i64);
+                targets = targets.mask_fill(ignored.clone(), 0);
+                ignored.bool_not().float()
+            }
+            None => Tensor::ones([num_pixels, 1], &device),
+        };
+
+        let predictions = Tensor::zeros([num_pixels, num_classes], &device).scatter(
+            1,
+            outputs.argmax(1),
+            values.clone(),
+        );
+        let targets = Tensor::zeros([num_pixels, num_classes], &device).scatter(1, targets, values);
+
+        ClassCounts::from_one_hot(predictions, targets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::ClassAverage;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_skip_the_ignored_pixels() {
+        let device = Default::default();
+        // Predicted classes [[0, 1], [1, 0]] for the targets [[0, 1], [255, 1]].
+        let input = SegmentationInput::<TestBackend>::new(
+            Tensor::from_data(
+                [[[[0.9, 0.2], [0.3, 0.6]], [[0.1, 0.8], [0.7, 0.4]]]],
+                &device,
+            ),
+            Tensor::from_data([[[0, 1], [255, 1]]], &device),
+        );
+
+        let counts = input.counts(Some(255));
+
+        // Class 0: 1 true positive, 1 false positive, class 1: 1 true positive, 1 false negative.
+        assert_eq!(counts.jaccard(ClassAverage::Micro), 0.5);
+        assert_eq!(counts.jaccard(ClassAverage::Macro), 0.5);
+    }
+}
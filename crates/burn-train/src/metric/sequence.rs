@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+/// The input type of the metrics comparing predicted and reference sequences of tokens, e.g. the
+/// [BLEU score](super::BleuMetric) or the [word error rate](super::WordErrorRateMetric).
+#[derive(new)]
+pub struct SequenceInput {
+    /// The predicted tokens of each sequence, without padding.
+    predictions: Vec<Vec<usize>>,
+    /// The reference tokens of each sequence, without padding.
+    references: Vec<Vec<usize>>,
+}
+
+impl SequenceInput {
+    /// The pairs of predicted and reference sequences.
+    pub(crate) fn pairs(&self) -> impl Iterator<Item = (&[usize], &[usize])> {
+        assert_eq!(
+            self.predictions.len(),
+            self.references.len(),
+            "Each predicted sequence should have a reference."
+        );
+
+        self.predictions
+            .iter()
+            .zip(&self.references)
+            .map(|(prediction, reference)| (prediction.as_slice(), reference.as_slice()))
+    }
+}
+
+/// Decodes a sequence of tokens to text, e.g. with the tokenizer of the model.
+pub type TokenDecoder = Arc<dyn Fn(&[usize]) -> String + Send + Sync>;
+
+/// The minimal number of insertions, deletions and substitutions transforming a sequence into
+/// another one.
+pub(crate) fn edit_distance<T: PartialEq>(source: &[T], target: &[T]) -> usize {
+    let mut previous = (0..=target.len()).collect::<Vec<_>>();
+    let mut current = vec![0; target.len() + 1];
+
+    for (i, source_item) in source.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, target_item) in target.iter().enumerate() {
+            let substitution = previous[j] + (source_item != target_item) as usize;
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[target.len()]
+}
+
+/// The length of the longest common subsequence of two sequences.
+pub(crate) fn longest_common_subsequence<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    let mut previous = vec![0; b.len() + 1];
+    let mut current = vec![0; b.len() + 1];
+
+    for a_item in a {
+        for (j, b_item) in b.iter().enumerate() {
+            current[j + 1] = match a_item == b_item {
+                true => previous[j] + 1,
+                false => current[j].max(previous[j + 1]),
+            };
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance(b"kitten", b"sitting"), 3);
+        assert_eq!(edit_distance(b"", b"abc"), 3);
+        assert_eq!(edit_distance(b"abc", b""), 3);
+        assert_eq!(edit_distance(b"abc", b"abc"), 0);
+    }
+
+    #[test]
+    fn test_longest_common_subsequence() {
+        assert_eq!(longest_common_subsequence(b"ABCBDAB", b"BDCABA"), 4);
+        assert_eq!(longest_common_subsequence(b"abc", b""), 0);
+    }
+}
@@ -40,6 +40,24 @@ impl FormatOptions {
         self.precision = Some(precision);
         self
     }
+
+    /// The name of the metric.
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Format a value with the precision and the unit.
+    pub(crate) fn format(&self, value: f64) -> String {
+        let formatted = match self.precision {
+            Some(precision) => format_float(value, precision),
+            None => format!("{value}"),
+        };
+
+        match &self.unit {
+            Some(unit) => format!("{formatted} {unit}"),
+            None => formatted,
+        }
+    }
 }
 
 impl NumericMetricState {
@@ -70,20 +88,11 @@ impl NumericMetricState {
         // Numeric metric state is an aggregated value
         let serialized = NumericEntry::Aggregated(value_current, batch_size).serialize();
 
-        let (formatted_current, formatted_running) = match format.precision {
-            Some(precision) => (
-                format_float(value_current, precision),
-                format_float(value_running, precision),
-            ),
-            None => (format!("{value_current}"), format!("{value_running}")),
-        };
-
-        let formatted = match format.unit {
-            Some(unit) => {
-                format!("epoch {formatted_running} {unit} - batch {formatted_current} {unit}")
-            }
-            None => format!("epoch {formatted_running} - batch {formatted_current}"),
-        };
+        let formatted = format!(
+            "epoch {} - batch {}",
+            format.format(value_running),
+            format.format(value_current)
+        );
 
         MetricEntry::new(format.name, formatted, serialized)
     }
@@ -100,3 +109,15 @@ impl Default for NumericMetricState {
         Self::new()
     }
 }
+
+/// Creates the [entry](MetricEntry) of the value of an epoch computed by an
+/// [epoch metric](crate::metric::EpochMetric).
+pub fn epoch_entry(value: f64, format: FormatOptions) -> MetricEntry {
+    let formatted = format!("epoch {}", format.format(value));
+
+    MetricEntry::new(
+        format.name,
+        formatted,
+        NumericEntry::Epoch(value).serialize(),
+    )
+}
@@ -48,6 +48,16 @@ impl NumericMetricsAggregate {
             return None;
         }
 
+        // The value computed by an epoch metric is the value of the epoch.
+        let epoch_value = points.iter().rev().find_map(|entry| match entry {
+            NumericEntry::Epoch(v) => Some(*v),
+            _ => None,
+        });
+        if let Some(value) = epoch_value {
+            self.value_for_each_epoch.insert(key, value);
+            return Some(value);
+        }
+
         // Accurately compute the aggregated value based on the *actual* number of points
         // since not all mini-batches are guaranteed to have the specified batch size
         let (sum, num_points) = points
@@ -57,6 +67,7 @@ impl NumericMetricsAggregate {
                 // Right now the mean is the only aggregate available, so we can assume that the sum
                 // of an entry corresponds to (value * number of elements)
                 NumericEntry::Aggregated(v, n) => (v * n as f64, n),
+                NumericEntry::Epoch(_) => unreachable!("The epoch entries are handled above"),
             })
             .reduce(|(acc_v, acc_n), (v, n)| (acc_v + v, acc_n + n))
             .unwrap();
@@ -68,48 +79,37 @@ impl NumericMetricsAggregate {
         Some(value)
     }
 
+    /// Find the best epoch up to the given one, ignoring the epochs without any value.
     pub(crate) fn find_epoch(
         &mut self,
         name: &str,
         aggregate: Aggregate,
         direction: Direction,
+        num_epochs: usize,
         loggers: &mut [Box<dyn MetricLogger>],
     ) -> Option<usize> {
-        let mut data = Vec::new();
-        let mut current_epoch = 1;
-
-        while let Some(value) = self.aggregate(name, current_epoch, aggregate, loggers) {
-            data.push(value);
-            current_epoch += 1;
-        }
+        let mut best = None;
 
-        if data.is_empty() {
-            return None;
-        }
+        for epoch in 1..=num_epochs {
+            let value = match self.aggregate(name, epoch, aggregate, loggers) {
+                Some(value) => value,
+                None => continue,
+            };
 
-        let mut current_value = match &direction {
-            Direction::Lowest => f64::MAX,
-            Direction::Highest => f64::MIN,
-        };
+            let is_best = match best {
+                Some((_, best_value)) => match &direction {
+                    Direction::Lowest => value < best_value,
+                    Direction::Highest => value > best_value,
+                },
+                None => true,
+            };
 
-        for (i, value) in data.into_iter().enumerate() {
-            match &direction {
-                Direction::Lowest => {
-                    if value < current_value {
-                        current_value = value;
-                        current_epoch = i + 1;
-                    }
-                }
-                Direction::Highest => {
-                    if value > current_value {
-                        current_value = value;
-                        current_epoch = i + 1;
-                    }
-                }
+            if is_best {
+                best = Some((epoch, value));
             }
         }
 
-        Some(current_epoch)
+        best.map(|(epoch, _)| epoch)
     }
 }
 
@@ -166,6 +166,7 @@ mod tests {
                 NAME,
                 Aggregate::Mean,
                 Direction::Lowest,
+                3,
                 &mut [Box::new(logger.logger)],
             )
             .unwrap();
@@ -173,6 +174,67 @@ mod tests {
         assert_eq!(value, 2);
     }
 
+    #[test]
+    fn should_find_epoch_skipping_missing_values() {
+        let mut logger = InMemoryMetricLogger::default();
+        let mut aggregate = NumericMetricsAggregate::default();
+        let log = |logger: &mut InMemoryMetricLogger, num: f64| {
+            logger.log(&MetricEntry::new(
+                NAME.into(),
+                num.to_string(),
+                num.to_string(),
+            ))
+        };
+
+        log(&mut logger, 3.); // Epoch 1
+        logger.end_epoch(1);
+        logger.end_epoch(2); // No value for epoch 2
+        log(&mut logger, 1.); // Epoch 3
+
+        let value = aggregate
+            .find_epoch(
+                NAME,
+                Aggregate::Mean,
+                Direction::Lowest,
+                3,
+                &mut [Box::new(logger)],
+            )
+            .unwrap();
+
+        assert_eq!(value, 3);
+    }
+
+    #[test]
+    fn should_find_epoch_after_skipped_epochs() {
+        let mut logger = InMemoryMetricLogger::default();
+        let mut aggregate = NumericMetricsAggregate::default();
+        let log = |logger: &mut InMemoryMetricLogger, num: f64| {
+            logger.log(&MetricEntry::new(
+                NAME.into(),
+                num.to_string(),
+                num.to_string(),
+            ))
+        };
+
+        logger.start_epoch(2, 0); // Epochs 1 and 2 aren't logged
+        log(&mut logger, 3.); // Epoch 2
+        logger.end_epoch(2);
+        logger.start_epoch(4, 0); // Epoch 3 isn't logged
+        log(&mut logger, 1.); // Epoch 4
+
+        let value = aggregate
+            .find_epoch(
+                NAME,
+                Aggregate::Mean,
+                Direction::Lowest,
+                4,
+                &mut [Box::new(logger)],
+            )
+            .unwrap();
+
+        assert_eq!(value, 4);
+    }
+
     #[test]
     fn should_aggregate_numeric_entry() {
         let mut logger = InMemoryMetricLogger::default();
@@ -202,4 +264,30 @@ mod tests {
         // Average should be (0.5 + 1.25 * 2) / 3 = 1.0, not (0.5 + 1.25) / 2 = 0.875
         assert_eq!(value, 1.0);
     }
+
+    #[test]
+    fn should_use_the_epoch_entry() {
+        let mut logger = InMemoryMetricLogger::default();
+        let mut aggregate = NumericMetricsAggregate::default();
+        let metric_name = "F1 Score";
+
+        for entry in [
+            NumericEntry::Value(0.5),
+            NumericEntry::Value(1.0),
+            NumericEntry::Epoch(0.6),
+        ] {
+            logger.log(&MetricEntry::new(
+                metric_name.to_string(),
+                String::new(),
+                entry.serialize(),
+            ));
+        }
+
+        let value = aggregate
+            .aggregate(metric_name, 1, Aggregate::Mean, &mut [Box::new(logger)])
+            .unwrap();
+
+        // The value of the epoch isn't the mean of the batches.
+        assert_eq!(value, 0.6);
+    }
 }
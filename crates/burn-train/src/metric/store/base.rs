@@ -6,6 +6,8 @@ pub enum Event {
     MetricsUpdate(MetricsUpdate),
     /// Signal the end of an epoch.
     EndEpoch(usize),
+    /// Signal the start of an epoch, with the number of training iterations done before it.
+    StartEpoch(usize, usize),
 }
 
 /// Contains all metric information.
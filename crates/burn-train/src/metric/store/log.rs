@@ -7,6 +7,8 @@ pub(crate) struct LogEventStore {
     loggers_valid: Vec<Box<dyn MetricLogger>>,
     aggregate_train: NumericMetricsAggregate,
     aggregate_valid: NumericMetricsAggregate,
+    last_epoch_train: usize,
+    last_epoch_valid: usize,
 }
 
 impl EventStore for LogEventStore {
@@ -37,14 +39,32 @@ impl EventStore for LogEventStore {
                 }
             },
             Event::EndEpoch(epoch) => match split {
-                Split::Train => self
-                    .loggers_train
-                    .iter_mut()
-                    .for_each(|logger| logger.end_epoch(epoch)),
-                Split::Valid => self
-                    .loggers_valid
-                    .iter_mut()
-                    .for_each(|logger| logger.end_epoch(epoch)),
+                Split::Train => {
+                    self.last_epoch_train = epoch;
+                    self.loggers_train
+                        .iter_mut()
+                        .for_each(|logger| logger.end_epoch(epoch))
+                }
+                Split::Valid => {
+                    self.last_epoch_valid = epoch;
+                    self.loggers_valid
+                        .iter_mut()
+                        .for_each(|logger| logger.end_epoch(epoch))
+                }
+            },
+            Event::StartEpoch(epoch, iteration) => match split {
+                Split::Train => {
+                    self.last_epoch_train = epoch.saturating_sub(1);
+                    self.loggers_train
+                        .iter_mut()
+                        .for_each(|logger| logger.start_epoch(epoch, iteration))
+                }
+                Split::Valid => {
+                    self.last_epoch_valid = epoch.saturating_sub(1);
+                    self.loggers_valid
+                        .iter_mut()
+                        .for_each(|logger| logger.start_epoch(epoch, iteration))
+                }
             },
         }
     }
@@ -56,15 +76,22 @@ impl EventStore for LogEventStore {
         direction: Direction,
         split: Split,
     ) -> Option<usize> {
+        // The current epoch is included.
         match split {
-            Split::Train => {
-                self.aggregate_train
-                    .find_epoch(name, aggregate, direction, &mut self.loggers_train)
-            }
-            Split::Valid => {
-                self.aggregate_valid
-                    .find_epoch(name, aggregate, direction, &mut self.loggers_valid)
-            }
+            Split::Train => self.aggregate_train.find_epoch(
+                name,
+                aggregate,
+                direction,
+                self.last_epoch_train + 1,
+                &mut self.loggers_train,
+            ),
+            Split::Valid => self.aggregate_valid.find_epoch(
+                name,
+                aggregate,
+                direction,
+                self.last_epoch_valid + 1,
+                &mut self.loggers_valid,
+            ),
         }
     }
 
@@ -31,6 +31,8 @@ impl ProgressBarState {
     }
     /// Update the training progress.
     pub(crate) fn update_train(&mut self, progress: &TrainingProgress) {
+        // The checkpoint may be an interrupted epoch or a step in the epoch.
+        self.starting_epoch = self.starting_epoch.min(progress.epoch.saturating_sub(1));
         self.progress_train = calculate_progress(progress, 0, 0);
         self.estimate.update(progress, self.starting_epoch);
     }
@@ -11,10 +11,14 @@ use ratatui::{
 pub(crate) struct StatusState {
     progress: TrainingProgress,
     mode: Mode,
+    /// The last training iteration, when the epoch isn't completed.
+    iteration_train: Option<usize>,
 }
 
 enum Mode {
     Valid,
+    /// Validating during the epoch, after the given training iteration.
+    IntermediateValid(usize),
     Train,
 }
 
@@ -23,6 +27,7 @@ impl Default for StatusState {
         Self {
             progress: TrainingProgress::none(),
             mode: Mode::Train,
+            iteration_train: None,
         }
     }
 }
@@ -30,13 +35,19 @@ impl Default for StatusState {
 impl StatusState {
     /// Update the training information.
     pub(crate) fn update_train(&mut self, progress: TrainingProgress) {
+        let items = &progress.progress;
+        self.iteration_train =
+            (items.items_processed < items.items_total).then_some(progress.iteration);
         self.progress = progress;
         self.mode = Mode::Train;
     }
     /// Update the validation information.
     pub(crate) fn update_valid(&mut self, progress: TrainingProgress) {
         self.progress = progress;
-        self.mode = Mode::Valid;
+        self.mode = match self.iteration_train {
+            Some(iteration) => Mode::IntermediateValid(iteration),
+            None => Mode::Valid,
+        };
     }
     /// Create a view.
     pub(crate) fn view(&self) -> StatusView {
@@ -53,13 +64,14 @@ impl StatusView {
         let title = |title: &str| Span::from(format!(" {title} ")).bold().yellow();
         let value = |value: String| Span::from(value).italic();
         let mode = match mode {
-            Mode::Valid => "Validating",
-            Mode::Train => "Training",
+            Mode::Valid => "Validating".to_string(),
+            Mode::IntermediateValid(iteration) => format!("Validating (iteration {iteration})"),
+            Mode::Train => "Training".to_string(),
         };
 
         Self {
             lines: vec![
-                vec![title("Mode      :"), value(mode.to_string())],
+                vec![title("Mode      :"), value(mode)],
                 vec![
                     title("Epoch     :"),
                     value(format!("{}/{}", progress.epoch, progress.epoch_total)),
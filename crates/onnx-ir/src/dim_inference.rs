@@ -26,8 +26,10 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Conv1d => conv1d_update_outputs(node),
         NodeType::Conv2d => conv2d_update_outputs(node),
         NodeType::Cos => same_as_input(node),
+        NodeType::DequantizeLinear => dequantize_linear_update_outputs(node),
         NodeType::Div => same_as_input_broadcast(node),
         NodeType::Dropout => same_as_input(node),
+        NodeType::DynamicQuantizeLinear => dynamic_quantize_linear_update_outputs(node),
         NodeType::Equal => elementwise_comparison_outputs(node),
         NodeType::Erf => same_as_input(node),
         NodeType::Exp => same_as_input(node),
@@ -49,6 +51,7 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Log => same_as_input(node),
         NodeType::LogSoftmax => same_as_input(node),
         NodeType::MatMul => matmul_update_outputs(node),
+        NodeType::MatMulInteger => matmul_integer_update_outputs(node),
         NodeType::Max => same_as_input_broadcast(node),
         NodeType::MaxPool1d => same_as_input(node),
         NodeType::MaxPool2d => same_as_input(node),
@@ -59,6 +62,9 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Pad => same_as_input(node),
         NodeType::PRelu => same_as_input_broadcast(node),
         NodeType::Pow => same_as_input_broadcast(node),
+        NodeType::QLinearConv => qlinear_conv_update_outputs(node),
+        NodeType::QLinearMatMul => qlinear_matmul_update_outputs(node),
+        NodeType::QuantizeLinear => quantize_linear_update_outputs(node),
         NodeType::RandomNormal => random_update_output(node),
         NodeType::RandomUniform => random_update_output(node),
         NodeType::Range => range_update_outputs(node),
@@ -611,7 +617,13 @@ fn conv_transpose2d_update_outputs(node: &mut Node) {
 
 fn matmul_update_outputs(node: &mut Node) {
     // NOTE: matmul only supported for float tensors
-    match (node.inputs[0].ty.clone(), node.inputs[1].ty.clone()) {
+    let elem_type = node.inputs[0].ty.elem_type().clone();
+    node.outputs[0].ty = matmul_output_type(&node.inputs[0].ty, &node.inputs[1].ty, elem_type);
+}
+
+/// Infers the output type of a matrix product between the two inputs.
+fn matmul_output_type(lhs: &ArgType, rhs: &ArgType, elem_type: ElementType) -> ArgType {
+    match (lhs, rhs) {
         (ArgType::Tensor(a), ArgType::Tensor(b)) => {
             // With broadcasting support, output dim has to be computed based on the inputs
             let mut out_dim = max(a.dim, b.dim);
@@ -621,16 +633,74 @@ fn matmul_update_outputs(node: &mut Node) {
                 out_dim -= 1;
             }
 
-            node.outputs[0].ty = ArgType::Tensor(TensorType {
-                elem_type: a.elem_type.clone(),
+            ArgType::Tensor(TensorType {
+                elem_type,
                 dim: out_dim,
                 shape: a.shape.clone(),
-            });
+            })
         }
         _ => panic!("Only tensor input is valid"),
     }
 }
 
+/// Returns the element type of the optional zero point input, which defaults to `uint8`.
+fn zero_point_elem_type(node: &Node, index: usize) -> ElementType {
+    node.inputs
+        .get(index)
+        .filter(|zero_point| !zero_point.name.is_empty())
+        .map(|zero_point| zero_point.ty.elem_type().clone())
+        .unwrap_or(ElementType::Uint8)
+}
+
+/// Updates the element type of the input tensor for the output.
+fn with_elem_type(ty: &ArgType, elem_type: ElementType) -> ArgType {
+    match ty {
+        ArgType::Tensor(tensor) => ArgType::Tensor(TensorType {
+            elem_type,
+            ..tensor.clone()
+        }),
+        ArgType::Scalar(_) => ArgType::Scalar(elem_type),
+        ArgType::Shape(_) => panic!("Only tensor input is valid"),
+    }
+}
+
+/// The output of QuantizeLinear has the type of the zero point.
+fn quantize_linear_update_outputs(node: &mut Node) {
+    let elem_type = zero_point_elem_type(node, 2);
+    node.outputs[0].ty = with_elem_type(&node.inputs[0].ty, elem_type);
+}
+
+/// The output of DequantizeLinear has the type of the scale.
+fn dequantize_linear_update_outputs(node: &mut Node) {
+    let elem_type = node.inputs[1].ty.elem_type().clone();
+    node.outputs[0].ty = with_elem_type(&node.inputs[0].ty, elem_type);
+}
+
+/// DynamicQuantizeLinear outputs the `uint8` tensor with its scalar scale and zero point.
+fn dynamic_quantize_linear_update_outputs(node: &mut Node) {
+    node.outputs[0].ty = with_elem_type(&node.inputs[0].ty, ElementType::Uint8);
+    node.outputs[1].ty = ArgType::Scalar(ElementType::Float32);
+    node.outputs[2].ty = ArgType::Scalar(ElementType::Uint8);
+}
+
+/// The output of MatMulInteger is an `int32` matrix product.
+fn matmul_integer_update_outputs(node: &mut Node) {
+    node.outputs[0].ty =
+        matmul_output_type(&node.inputs[0].ty, &node.inputs[1].ty, ElementType::Int32);
+}
+
+/// The output of QLinearMatMul has the type of the output zero point.
+fn qlinear_matmul_update_outputs(node: &mut Node) {
+    let elem_type = zero_point_elem_type(node, 7);
+    node.outputs[0].ty = matmul_output_type(&node.inputs[0].ty, &node.inputs[3].ty, elem_type);
+}
+
+/// The output of QLinearConv has the type of the output zero point.
+fn qlinear_conv_update_outputs(node: &mut Node) {
+    let elem_type = zero_point_elem_type(node, 7);
+    node.outputs[0].ty = with_elem_type(&node.inputs[0].ty, elem_type);
+}
+
 fn range_update_outputs(node: &mut Node) {
     if node.inputs.len() != 3 {
         panic!("Range: expected 3 inputs, found {}", node.inputs.len());
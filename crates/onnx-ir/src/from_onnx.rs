@@ -18,13 +18,18 @@ use super::ir::{ArgType, Argument, Node, NodeType};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 12] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 17] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::DequantizeLinear,
     NodeType::Dropout,
     NodeType::Expand,
+    NodeType::MatMulInteger,
+    NodeType::QLinearConv,
+    NodeType::QLinearMatMul,
+    NodeType::QuantizeLinear,
     NodeType::Reshape,
     NodeType::Resize,
     NodeType::Unsqueeze,
@@ -147,14 +152,12 @@ impl GraphData {
     fn add_node(&mut self, mut node: Node) {
         log::debug!("adding node {:?}", &node.name);
         self.mark_input_passed(&node);
-        let mut out_count = 1;
-        for output in node.outputs.iter_mut() {
+        for (i, output) in node.outputs.iter_mut().enumerate() {
             self.input_name_map.insert(
                 output.name.clone(),
-                IOEntry::Node(self.processed_nodes.len(), 0),
+                IOEntry::Node(self.processed_nodes.len(), i),
             );
-            output.name = format!("{}_out{}", node.name, out_count);
-            out_count += 1;
+            output.name = format!("{}_out{}", node.name, i + 1);
         }
         self.processed_nodes.push(node);
     }
@@ -101,6 +101,8 @@ pub enum ElementType {
     String,
     Float16,
     Bool,
+    Int8,
+    Uint8,
 }
 
 #[derive(Debug, Clone, Default)]
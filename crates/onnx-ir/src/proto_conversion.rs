@@ -70,6 +70,23 @@ impl TryFrom<TensorProto> for Tensor {
                 assert!(!tensor.raw_data.is_empty());
                 Data::Bools(tensor.raw_data.iter().map(|x| *x != 0).collect())
             }),
+            // 8-bit integers are widened to 32-bit, as done in the `int32_data` field
+            DataType::INT8 => (
+                ElementType::Int8,
+                if !tensor.raw_data.is_empty() {
+                    Data::Int32s(tensor.raw_data.iter().map(|x| *x as i8 as i32).collect())
+                } else {
+                    Data::Int32s(tensor.int32_data)
+                },
+            ),
+            DataType::UINT8 => (
+                ElementType::Uint8,
+                if !tensor.raw_data.is_empty() {
+                    Data::Int32s(tensor.raw_data.iter().map(|x| *x as i32).collect())
+                } else {
+                    Data::Int32s(tensor.int32_data)
+                },
+            ),
             // TODO : Add more types
             _ => {
                 return Err(ParseError::VariantNotFound);
@@ -111,6 +128,8 @@ impl TryFrom<&type_proto::Tensor> for Tensor {
             DataType::INT64 => ElementType::Int64,
             DataType::DOUBLE => ElementType::Float64,
             DataType::BOOL => ElementType::Bool,
+            DataType::INT8 => ElementType::Int8,
+            DataType::UINT8 => ElementType::Uint8,
 
             // TODO : Add more types
             _ => {
@@ -237,6 +256,8 @@ impl TryFrom<ValueInfoProto> for Argument {
             DataType::INT64 => ElementType::Int64,
             DataType::DOUBLE => ElementType::Float64,
             DataType::BOOL => ElementType::Bool,
+            DataType::INT8 => ElementType::Int8,
+            DataType::UINT8 => ElementType::Uint8,
             _ => {
                 return Err(ParseError::VariantNotFound);
             }